@@ -140,7 +140,9 @@ impl App {
             | Constraint::Max(v)
             | Constraint::Fill(v)
             | Constraint::Percentage(v) => *v = v.saturating_add(1),
-            Constraint::Ratio(_n, d) => *d = d.saturating_add(1),
+            Constraint::Ratio(_n, d) | Constraint::Bounded { ratio: (_n, d), .. } => {
+                *d = d.saturating_add(1);
+            }
         }
     }
 
@@ -154,7 +156,9 @@ impl App {
             | Constraint::Max(v)
             | Constraint::Fill(v)
             | Constraint::Percentage(v) => *v = v.saturating_sub(1),
-            Constraint::Ratio(_n, d) => *d = d.saturating_sub(1),
+            Constraint::Ratio(_n, d) | Constraint::Bounded { ratio: (_n, d), .. } => {
+                *d = d.saturating_sub(1);
+            }
         }
     }
 
@@ -229,7 +233,7 @@ impl From<Constraint> for ConstraintName {
         match constraint {
             Length(_) => Self::Length,
             Percentage(_) => Self::Percentage,
-            Ratio(_, _) => Self::Ratio,
+            Ratio(_, _) | Constraint::Bounded { .. } => Self::Ratio,
             Min(_) => Self::Min,
             Max(_) => Self::Max,
             Fill(_) => Self::Fill,
@@ -372,7 +372,7 @@ impl Example {
         let color = match constraint {
             Constraint::Length(_) => LENGTH_COLOR,
             Constraint::Percentage(_) => PERCENTAGE_COLOR,
-            Constraint::Ratio(_, _) => RATIO_COLOR,
+            Constraint::Ratio(_, _) | Constraint::Bounded { .. } => RATIO_COLOR,
             Constraint::Fill(_) => FILL_COLOR,
             Constraint::Min(_) => MIN_COLOR,
             Constraint::Max(_) => MAX_COLOR,
@@ -156,6 +156,7 @@ enum SelectedTab {
     Center,
     End,
     SpaceAround,
+    SpaceEvenly,
     SpaceBetween,
 }
 
@@ -373,7 +374,8 @@ impl SelectedTab {
             Self::Center => SKY.c300,
             Self::End => SKY.c200,
             Self::SpaceAround => INDIGO.c400,
-            Self::SpaceBetween => INDIGO.c300,
+            Self::SpaceEvenly => INDIGO.c300,
+            Self::SpaceBetween => INDIGO.c200,
         };
         format!(" {text} ").fg(color).bg(Color::Black).into()
     }
@@ -389,6 +391,7 @@ impl StatefulWidget for SelectedTab {
             Self::Center => Self::render_examples(area, buf, Flex::Center, spacing),
             Self::End => Self::render_examples(area, buf, Flex::End, spacing),
             Self::SpaceAround => Self::render_examples(area, buf, Flex::SpaceAround, spacing),
+            Self::SpaceEvenly => Self::render_examples(area, buf, Flex::SpaceEvenly, spacing),
             Self::SpaceBetween => Self::render_examples(area, buf, Flex::SpaceBetween, spacing),
         }
     }
@@ -516,7 +519,7 @@ const fn color_for_constraint(constraint: Constraint) -> Color {
         Constraint::Max(_) => BLUE.c800,
         Constraint::Length(_) => SLATE.c700,
         Constraint::Percentage(_) => SLATE.c800,
-        Constraint::Ratio(_, _) => SLATE.c900,
+        Constraint::Ratio(_, _) | Constraint::Bounded { .. } => SLATE.c900,
         Constraint::Fill(_) => SLATE.c950,
     }
 }
@@ -125,6 +125,31 @@ pub enum ClearType {
     UntilNewLine,
 }
 
+/// The shape of the cursor on the terminal screen, see [`Backend::set_cursor_shape`].
+///
+/// Terminals set the shape with the DECSCUSR escape sequence (`ESC [ n SP q`), where `n` is `0`
+/// for the [`Default`](CursorShape::Default) shape and `1` to `6` for the other shapes, in the
+/// order of the variants.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CursorShape {
+    /// The shape configured by the user of the terminal.
+    #[default]
+    Default,
+    /// A blinking block.
+    BlinkingBlock,
+    /// A steady block.
+    SteadyBlock,
+    /// A blinking underline.
+    BlinkingUnderline,
+    /// A steady underline.
+    SteadyUnderline,
+    /// A blinking vertical bar.
+    BlinkingBar,
+    /// A steady vertical bar.
+    SteadyBar,
+}
+
 /// The window size in characters (columns / rows) as well as pixels.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub struct WindowSize {
@@ -192,6 +217,50 @@ pub trait Backend {
     /// [`hide_cursor`]: Self::hide_cursor
     fn show_cursor(&mut self) -> Result<(), Self::Error>;
 
+    /// Set the shape of the cursor on the terminal screen.
+    ///
+    /// This method is optional and may not be implemented by all backends.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use ratatui::backend::{TestBackend};
+    /// # let mut backend = TestBackend::new(80, 25);
+    /// use ratatui::backend::{Backend, CursorShape};
+    ///
+    /// backend.set_cursor_shape(CursorShape::SteadyBar)?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    fn set_cursor_shape(&mut self, _shape: CursorShape) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Enable bracketed paste mode on the terminal.
+    ///
+    /// In bracketed paste mode, the terminal wraps pasted text between the `ESC [ 200 ~` and
+    /// `ESC [ 201 ~` sequences, so that it can be distinguished from typed input. This only enables
+    /// the mode; reading the pasted text from the input is up to the application.
+    ///
+    /// This method is optional and may not be implemented by all backends.
+    ///
+    /// See also [`disable_bracketed_paste`].
+    ///
+    /// [`disable_bracketed_paste`]: Self::disable_bracketed_paste
+    fn enable_bracketed_paste(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Disable bracketed paste mode on the terminal.
+    ///
+    /// This method is optional and may not be implemented by all backends.
+    ///
+    /// See [`enable_bracketed_paste`] for more details.
+    ///
+    /// [`enable_bracketed_paste`]: Self::enable_bracketed_paste
+    fn disable_bracketed_paste(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
     /// Get the current cursor position on the terminal screen.
     ///
     /// The returned tuple contains the x and y coordinates of the cursor.
@@ -8,7 +8,7 @@ use core::iter;
 
 use unicode_width::UnicodeWidthStr;
 
-use crate::backend::{Backend, ClearType, WindowSize};
+use crate::backend::{Backend, ClearType, CursorShape, WindowSize};
 use crate::buffer::{Buffer, Cell};
 use crate::layout::{Position, Rect, Size};
 
@@ -35,6 +35,8 @@ pub struct TestBackend {
     buffer: Buffer,
     scrollback: Buffer,
     cursor: bool,
+    cursor_shape: CursorShape,
+    bracketed_paste: bool,
     pos: (u16, u16),
 }
 
@@ -67,6 +69,53 @@ fn buffer_view(buffer: &Buffer) -> String {
     view
 }
 
+/// Returns the rows of `actual` that differ from `expected`, with a marker under each differing
+/// cell followed by the expected and actual cells.
+///
+/// Both buffers must have the same area. The returned string is empty when they are equal.
+fn buffer_diff(expected: &Buffer, actual: &Buffer) -> String {
+    if expected.area.is_empty() {
+        return String::new();
+    }
+    let width = usize::from(expected.area.width);
+    let expected_view = buffer_view(expected);
+    let actual_view = buffer_view(actual);
+    let rows = expected
+        .content
+        .chunks(width)
+        .zip(actual.content.chunks(width))
+        .zip(expected_view.lines().zip(actual_view.lines()));
+    let mut diff = String::new();
+    for (row, ((expected_cells, actual_cells), (expected_line, actual_line))) in rows.enumerate() {
+        let columns: vec::Vec<usize> = (0..width)
+            .filter(|&x| expected_cells[x] != actual_cells[x])
+            .collect();
+        if columns.is_empty() {
+            continue;
+        }
+        let y = expected.area.y + u16::try_from(row).unwrap_or(u16::MAX);
+        // the markers are aligned with the cells of the actual line, after its opening quote
+        let mut markers = String::from("             ");
+        let mut next_x = 0;
+        for &x in &columns {
+            markers.extend(iter::repeat_n(' ', x - next_x));
+            markers.push('^');
+            next_x = x + 1;
+        }
+        writeln!(diff, "row {y}:").unwrap();
+        writeln!(diff, "  expected: {expected_line}").unwrap();
+        writeln!(diff, "  actual:   {actual_line}").unwrap();
+        writeln!(diff, "{markers}").unwrap();
+        for x in columns {
+            let position = (expected.area.x + u16::try_from(x).unwrap_or(u16::MAX), y);
+            writeln!(diff, "  at {position:?}").unwrap();
+            writeln!(diff, "    expected: {:?}", expected_cells[x]).unwrap();
+            writeln!(diff, "    actual:   {:?}", actual_cells[x]).unwrap();
+        }
+    }
+    diff
+}
+
 impl TestBackend {
     /// Creates a new `TestBackend` with the specified width and height.
     pub fn new(width: u16, height: u16) -> Self {
@@ -74,6 +123,8 @@ impl TestBackend {
             buffer: Buffer::empty(Rect::new(0, 0, width, height)),
             scrollback: Buffer::empty(Rect::new(0, 0, width, 0)),
             cursor: false,
+            cursor_shape: CursorShape::Default,
+            bracketed_paste: false,
             pos: (0, 0),
         }
     }
@@ -96,6 +147,8 @@ impl TestBackend {
             buffer,
             scrollback,
             cursor: false,
+            cursor_shape: CursorShape::Default,
+            bracketed_paste: false,
             pos: (0, 0),
         }
     }
@@ -105,6 +158,54 @@ impl TestBackend {
         &self.buffer
     }
 
+    /// Returns the position of the cursor, or `None` if the cursor is hidden.
+    ///
+    /// The position is the one last set with [`Backend::set_cursor_position`] (or moved by
+    /// [`Backend::append_lines`]), and the cursor is hidden until [`Backend::show_cursor`] is
+    /// called. This matches the cursor position of the [`Frame`] drawn by a [`Terminal`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use ratatui::backend::{Backend, TestBackend};
+    /// use ratatui::layout::Position;
+    ///
+    /// let mut backend = TestBackend::new(10, 2);
+    /// backend.set_cursor_position((3, 1))?;
+    /// assert_eq!(backend.cursor_position(), None);
+    /// backend.show_cursor()?;
+    /// assert_eq!(backend.cursor_position(), Some(Position::new(3, 1)));
+    /// # Result::Ok(())
+    /// ```
+    ///
+    /// [`Frame`]: crate::terminal::Frame
+    /// [`Terminal`]: crate::terminal::Terminal
+    pub const fn cursor_position(&self) -> Option<Position> {
+        if self.cursor {
+            Some(Position::new(self.pos.0, self.pos.1))
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the cursor is visible, as last set with [`Backend::show_cursor`] and
+    /// [`Backend::hide_cursor`].
+    ///
+    /// The cursor is hidden when the `TestBackend` is created.
+    pub const fn cursor_visible(&self) -> bool {
+        self.cursor
+    }
+
+    /// Returns the shape of the cursor last set with [`Backend::set_cursor_shape`].
+    pub const fn cursor_shape(&self) -> CursorShape {
+        self.cursor_shape
+    }
+
+    /// Returns whether bracketed paste mode was enabled with [`Backend::enable_bracketed_paste`].
+    pub const fn bracketed_paste_enabled(&self) -> bool {
+        self.bracketed_paste
+    }
+
     /// Returns a reference to the internal scrollback buffer of the `TestBackend`.
     ///
     /// The scrollback buffer represents the part of the screen that is currently hidden from view,
@@ -136,13 +237,28 @@ impl TestBackend {
     ///
     /// # Panics
     ///
-    /// When they are not equal, a panic occurs with a detailed error message showing the
-    /// differences between the expected and actual buffers.
-    #[expect(deprecated)]
+    /// When they are not equal, a panic occurs with an error message showing the rows that differ,
+    /// with a `^` marker under each differing cell, followed by the expected and actual cells.
+    ///
+    /// ```text
+    /// row 1:
+    ///   expected: "abcde"
+    ///   actual:   "abXde"
+    ///                ^
+    ///   at (2, 1)
+    ///     expected: Cell { symbol: "c", .. }
+    ///     actual:   Cell { symbol: "X", .. }
+    /// ```
     #[track_caller]
     pub fn assert_buffer(&self, expected: &Buffer) {
-        // TODO: use assert_eq!()
-        crate::assert_buffer_eq!(&self.buffer, expected);
+        assert!(
+            self.buffer.area == expected.area,
+            "buffer areas not equal\nexpected: {:?}\nactual:   {:?}",
+            expected.area,
+            self.buffer.area,
+        );
+        let diff = buffer_diff(expected, &self.buffer);
+        assert!(diff.is_empty(), "buffer contents not equal\n{diff}");
     }
 
     /// Asserts that the `TestBackend`'s scrollback buffer is equal to the expected buffer.
@@ -181,8 +297,8 @@ impl TestBackend {
     ///
     /// # Panics
     ///
-    /// When they are not equal, a panic occurs with a detailed error message showing the
-    /// differences between the expected and actual buffers.
+    /// When they are not equal, a panic occurs with an error message showing the differing rows
+    /// and cells, see [`TestBackend::assert_buffer`].
     #[track_caller]
     pub fn assert_buffer_lines<'line, Lines>(&self, expected: Lines)
     where
@@ -257,6 +373,21 @@ impl Backend for TestBackend {
         Ok(())
     }
 
+    fn set_cursor_shape(&mut self, shape: CursorShape) -> Result<()> {
+        self.cursor_shape = shape;
+        Ok(())
+    }
+
+    fn enable_bracketed_paste(&mut self) -> Result<()> {
+        self.bracketed_paste = true;
+        Ok(())
+    }
+
+    fn disable_bracketed_paste(&mut self) -> Result<()> {
+        self.bracketed_paste = false;
+        Ok(())
+    }
+
     fn get_cursor_position(&mut self) -> Result<Position> {
         Ok(self.pos.into())
     }
@@ -458,6 +589,7 @@ mod tests {
     use itertools::Itertools as _;
 
     use super::*;
+    use crate::style::Style;
 
     #[test]
     fn new() {
@@ -467,6 +599,8 @@ mod tests {
                 buffer: Buffer::with_lines(["          "; 2]),
                 scrollback: Buffer::empty(Rect::new(0, 0, 10, 0)),
                 cursor: false,
+                cursor_shape: CursorShape::Default,
+                bracketed_paste: false,
                 pos: (0, 0),
             }
         );
@@ -516,6 +650,42 @@ mod tests {
         backend.assert_buffer_lines(["aaaaaaaaaa"; 2]);
     }
 
+    #[test]
+    #[should_panic = "buffer areas not equal"]
+    fn assert_buffer_panics_on_different_area() {
+        let backend = TestBackend::new(10, 2);
+        backend.assert_buffer_lines(["aaaaa"; 2]);
+    }
+
+    #[test]
+    fn buffer_diff_marks_changed_cells() {
+        let expected = Buffer::with_lines(["abcde", "fghij", "klmno"]);
+        let mut actual = expected.clone();
+        actual[(2, 1)].set_symbol("X");
+        actual[(4, 1)].set_style(Style::new().red());
+        let diff = buffer_diff(&expected, &actual);
+        let expected_diff = [
+            "row 1:",
+            r#"  expected: "fghij""#,
+            r#"  actual:   "fgXij""#,
+            "               ^ ^",
+            "  at (2, 1)",
+            &format!("    expected: {:?}", expected[(2, 1)]),
+            &format!("    actual:   {:?}", actual[(2, 1)]),
+            "  at (4, 1)",
+            &format!("    expected: {:?}", expected[(4, 1)]),
+            &format!("    actual:   {:?}", actual[(4, 1)]),
+            "",
+        ];
+        assert_eq!(diff, expected_diff.join("\n"));
+    }
+
+    #[test]
+    fn buffer_diff_of_equal_buffers_is_empty() {
+        let buffer = Buffer::with_lines(["abcde"; 2]);
+        assert_eq!(buffer_diff(&buffer, &buffer.clone()), "");
+    }
+
     #[test]
     #[should_panic = "assertion `left == right` failed"]
     fn assert_scrollback_panics() {
@@ -545,6 +715,24 @@ mod tests {
         assert!(!backend.cursor);
     }
 
+    #[test]
+    fn set_cursor_shape() {
+        let mut backend = TestBackend::new(10, 2);
+        assert_eq!(backend.cursor_shape(), CursorShape::Default);
+        backend.set_cursor_shape(CursorShape::SteadyBar).unwrap();
+        assert_eq!(backend.cursor_shape(), CursorShape::SteadyBar);
+    }
+
+    #[test]
+    fn bracketed_paste() {
+        let mut backend = TestBackend::new(10, 2);
+        assert!(!backend.bracketed_paste_enabled());
+        backend.enable_bracketed_paste().unwrap();
+        assert!(backend.bracketed_paste_enabled());
+        backend.disable_bracketed_paste().unwrap();
+        assert!(!backend.bracketed_paste_enabled());
+    }
+
     #[test]
     fn show_cursor() {
         let mut backend = TestBackend::new(10, 2);
@@ -552,6 +740,30 @@ mod tests {
         assert!(backend.cursor);
     }
 
+    #[test]
+    fn cursor_position_and_visibility() {
+        let mut backend = TestBackend::new(10, 4);
+        assert!(!backend.cursor_visible());
+        assert_eq!(backend.cursor_position(), None);
+
+        backend.set_cursor_position((2, 1)).unwrap();
+        assert_eq!(backend.cursor_position(), None);
+
+        backend.show_cursor().unwrap();
+        assert!(backend.cursor_visible());
+        assert_eq!(backend.cursor_position(), Some(Position::new(2, 1)));
+
+        backend.set_cursor_position((7, 3)).unwrap();
+        assert_eq!(backend.cursor_position(), Some(Position::new(7, 3)));
+
+        backend.append_lines(1).unwrap();
+        assert_eq!(backend.cursor_position(), Some(Position::new(8, 3)));
+
+        backend.hide_cursor().unwrap();
+        assert!(!backend.cursor_visible());
+        assert_eq!(backend.cursor_position(), None);
+    }
+
     #[test]
     fn get_cursor_position() {
         let mut backend = TestBackend::new(10, 2);
@@ -1,14 +1,15 @@
+use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
-use core::ops::{Index, IndexMut};
+use core::ops::{Index, IndexMut, Range};
 use core::{cmp, fmt};
 
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
 use crate::buffer::Cell;
-use crate::layout::{Position, Rect};
-use crate::style::Style;
+use crate::layout::{Alignment, Position, Rect};
+use crate::style::{Color, Style};
 use crate::text::{Line, Span};
 
 /// A buffer that maps to the desired content of the terminal after the draw call
@@ -379,6 +380,7 @@ impl Buffer {
                 remaining_width as usize,
                 line.style.patch(span.style),
             );
+            self.set_hyperlink(x..pos.0, y, span.hyperlink.as_deref());
             let w = pos.0.saturating_sub(x);
             x = pos.0;
             remaining_width = remaining_width.saturating_sub(w);
@@ -386,9 +388,59 @@ impl Buffer {
         (x, y)
     }
 
+    /// Print a line aligned within `width` columns, starting at the position (x, y)
+    ///
+    /// The line is placed to the left, center or right of the columns according to `alignment`,
+    /// which takes precedence over the alignment of the line. A line wider than `width` is
+    /// truncated on the side opposite to the alignment (both sides when centered) without
+    /// splitting graphemes. The style of the line is applied to all the columns, but like
+    /// [`Buffer::set_line`], the symbols of the columns that are not covered by the line are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::buffer::Buffer;
+    /// use ratatui_core::layout::{Alignment, Rect};
+    /// use ratatui_core::text::Line;
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+    /// buf.set_line_aligned(0, 0, &Line::from("Hello"), 10, Alignment::Right);
+    /// assert_eq!(buf, Buffer::with_lines(["     Hello"]));
+    /// ```
+    pub fn set_line_aligned(
+        &mut self,
+        x: u16,
+        y: u16,
+        line: &Line<'_>,
+        width: u16,
+        alignment: Alignment,
+    ) {
+        line.render_aligned(Rect::new(x, y, width, 1), self, Some(alignment));
+    }
+
     /// Print a span, starting at the position (x, y)
     pub fn set_span(&mut self, x: u16, y: u16, span: &Span<'_>, max_width: u16) -> (u16, u16) {
-        self.set_stringn(x, y, &span.content, max_width as usize, span.style)
+        let pos = self.set_stringn(x, y, &span.content, max_width as usize, span.style);
+        self.set_hyperlink(x..pos.0, y, span.hyperlink.as_deref());
+        pos
+    }
+
+    /// Links the cells of row `y` within the range of columns to `url`, skipping the cells hidden
+    /// by multi-width symbols.
+    fn set_hyperlink(&mut self, columns: Range<u16>, y: u16, url: Option<&str>) {
+        if url.is_none() {
+            return;
+        }
+        let mut hidden = 0;
+        for x in columns {
+            let cell = &mut self[(x, y)];
+            if hidden == 0 {
+                hidden = cell.symbol().width().saturating_sub(1);
+                cell.set_hyperlink(url);
+            } else {
+                hidden = hidden.saturating_sub(1);
+            }
+        }
     }
 
     /// Set the style of all cells in the given area.
@@ -407,6 +459,74 @@ impl Buffer {
         }
     }
 
+    /// Darken the background of all cells in the given area.
+    ///
+    /// The red, green and blue components of the background colors are multiplied by `factor`,
+    /// which is clamped between `0.0` (black) and `1.0` (unchanged). This dims the content behind
+    /// a popup while keeping the foreground colors, so that the text stays readable.
+    ///
+    /// [`Color::Indexed`] colors from the 256 color palette (16 to 255) are converted to
+    /// [`Color::Rgb`] before being darkened. The other colors depend on the palette of the
+    /// terminal and are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::buffer::Buffer;
+    /// use ratatui_core::layout::Rect;
+    /// use ratatui_core::style::{Color, Style};
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+    /// buf.set_style(buf.area, Style::new().bg(Color::Rgb(200, 100, 50)));
+    /// buf.darken(Rect::new(2, 1, 6, 1), 0.5);
+    /// assert_eq!(buf[(2, 1)].bg, Color::Rgb(100, 50, 25));
+    /// ```
+    pub fn darken(&mut self, area: Rect, factor: f64) {
+        let factor = factor.clamp(0.0, 1.0);
+        let area = self.area.intersection(area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let cell = &mut self[(x, y)];
+                cell.bg = darken_color(cell.bg, factor);
+            }
+        }
+    }
+
+    /// Returns the text of the cells in the given area, ignoring their styles
+    ///
+    /// The symbols of each row are concatenated, and the rows are separated by newlines. The cells
+    /// hidden by a preceding multi-width symbol (e.g. `"界"`) are skipped, so that the text reads
+    /// as it is displayed. The area is clipped to the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::buffer::Buffer;
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let buf = Buffer::with_lines(["Hello 世界", "ratatui   "]);
+    /// assert_eq!(buf.content_string(buf.area), "Hello 世界\nratatui   ");
+    /// assert_eq!(buf.content_string(Rect::new(2, 0, 3, 2)), "llo\ntat");
+    /// ```
+    pub fn content_string(&self, area: Rect) -> String {
+        let area = self.area.intersection(area);
+        let mut text = String::with_capacity(area.area() as usize + area.height as usize);
+        for y in area.top()..area.bottom() {
+            if y > area.top() {
+                text.push('\n');
+            }
+            let mut hidden: usize = 0;
+            for x in area.left()..area.right() {
+                let symbol = self[(x, y)].symbol();
+                if hidden == 0 {
+                    text.push_str(symbol);
+                }
+                hidden = cmp::max(hidden, symbol.width()).saturating_sub(1);
+            }
+        }
+        text
+    }
+
     /// Resize the buffer so that the mapped area matches the given area and that the buffer
     /// length is equal to area.width * area.height
     pub fn resize(&mut self, area: Rect) {
@@ -508,6 +628,34 @@ impl Buffer {
     }
 }
 
+/// Multiplies the red, green and blue components of the color by `factor`, converting the colors
+/// of the 256 color palette to RGB.
+fn darken_color(color: Color, factor: f64) -> Color {
+    let (r, g, b) = match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(index @ 16..=231) => {
+            // 6x6x6 color cube
+            let level = |value: u8| match value {
+                0 => 0,
+                value => value.saturating_mul(40).saturating_add(55),
+            };
+            let index = index.saturating_sub(16);
+            (level(index / 36), level(index / 6 % 6), level(index % 6))
+        }
+        Color::Indexed(index @ 232..=255) => {
+            // grayscale ramp
+            let gray = index
+                .saturating_sub(232)
+                .saturating_mul(10)
+                .saturating_add(8);
+            (gray, gray, gray)
+        }
+        color => return color,
+    };
+    let scale = |value: u8| (f64::from(value) * factor) as u8;
+    Color::Rgb(scale(r), scale(g), scale(b))
+}
+
 impl<P: Into<Position>> Index<P> for Buffer {
     type Output = Cell;
 
@@ -933,6 +1081,153 @@ mod tests {
         assert_eq!(small_one_line_buffer, expected_buffer);
     }
 
+    #[test]
+    fn set_line_hyperlink() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let line = Line::from(vec![
+            Span::raw("a").hyperlink("https://ratatui.rs"),
+            Span::raw("b"),
+            Span::raw("😃c").hyperlink("https://ratatui.rs"),
+        ]);
+        buffer.set_line(0, 0, &line, 5);
+        let url = Some("https://ratatui.rs");
+        let links = buffer.content.iter().map(Cell::hyperlink).collect_vec();
+        assert_eq!(links, [url, None, url, None, url, None]);
+    }
+
+    #[test]
+    fn set_span_hyperlink() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        let span = Span::raw("ab").hyperlink("https://ratatui.rs");
+        buffer.set_span(1, 0, &span, 3);
+        let url = Some("https://ratatui.rs");
+        let links = buffer.content.iter().map(Cell::hyperlink).collect_vec();
+        assert_eq!(links, [None, url, url]);
+    }
+
+    #[test]
+    fn content_string() {
+        let mut buf = Buffer::empty(Rect::new(2, 1, 8, 3));
+        buf.set_string(2, 1, "Hello", Style::new().red());
+        buf.set_string(2, 2, "a 👨‍👩‍👧‍👦 世", Style::new());
+        buf.set_string(4, 3, "end", Style::new().bold());
+        assert_eq!(buf.content_string(buf.area), "Hello   \na 👨‍👩‍👧‍👦 世 \n  end   ");
+    }
+
+    #[rstest]
+    #[case::inner(Rect::new(3, 1, 3, 2), "ell\n 👨‍👩‍👧‍👦")]
+    #[case::clipped(Rect::new(7, 2, 10, 10), "世 \n   ")]
+    #[case::starts_on_hidden_cell(Rect::new(5, 2, 2, 1), "  ")]
+    #[case::outside(Rect::new(20, 20, 5, 5), "")]
+    fn content_string_of_area(#[case] area: Rect, #[case] expected: &str) {
+        let mut buf = Buffer::empty(Rect::new(2, 1, 8, 3));
+        buf.set_string(2, 1, "Hello", Style::new());
+        buf.set_string(2, 2, "a 👨‍👩‍👧‍👦 世", Style::new());
+        assert_eq!(buf.content_string(area), expected);
+    }
+
+    #[test]
+    fn darken() {
+        let mut buffer = Buffer::with_lines(["abcd", "efgh"]);
+        buffer.set_style(buffer.area, Style::new().fg(Color::Rgb(1, 2, 3)));
+        buffer.set_style(
+            Rect::new(0, 0, 4, 1),
+            Style::new().bg(Color::Rgb(200, 100, 51)),
+        );
+        buffer.set_style(Rect::new(0, 1, 4, 1), Style::new().bg(Color::Indexed(196)));
+        buffer[(3, 1)].bg = Color::Blue;
+        buffer.darken(Rect::new(1, 0, 5, 5), 0.5);
+
+        let mut expected = Buffer::with_lines(["abcd", "efgh"]);
+        expected.set_style(expected.area, Style::new().fg(Color::Rgb(1, 2, 3)));
+        expected.set_style(
+            Rect::new(0, 0, 1, 1),
+            Style::new().bg(Color::Rgb(200, 100, 51)),
+        );
+        expected.set_style(
+            Rect::new(1, 0, 3, 1),
+            Style::new().bg(Color::Rgb(100, 50, 25)),
+        );
+        expected.set_style(Rect::new(0, 1, 1, 1), Style::new().bg(Color::Indexed(196)));
+        expected.set_style(
+            Rect::new(1, 1, 2, 1),
+            Style::new().bg(Color::Rgb(127, 0, 0)),
+        );
+        expected.set_style(Rect::new(3, 1, 1, 1), Style::new().bg(Color::Blue));
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::black(0.0, Color::Rgb(0, 0, 0))]
+    #[case::below_zero(-1.0, Color::Rgb(0, 0, 0))]
+    #[case::unchanged(1.0, Color::Rgb(200, 100, 50))]
+    #[case::above_one(2.0, Color::Rgb(200, 100, 50))]
+    #[case::quarter(0.25, Color::Rgb(50, 25, 12))]
+    fn darken_factor(#[case] factor: f64, #[case] expected: Color) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
+        buffer[(0, 0)].bg = Color::Rgb(200, 100, 50);
+        buffer.darken(buffer.area, factor);
+        assert_eq!(buffer[(0, 0)].bg, expected);
+    }
+
+    #[rstest]
+    #[case::cube_black(16, Color::Rgb(0, 0, 0))]
+    #[case::cube(110, Color::Rgb(135, 175, 215))]
+    #[case::cube_white(231, Color::Rgb(255, 255, 255))]
+    #[case::gray(232, Color::Rgb(8, 8, 8))]
+    #[case::gray_light(255, Color::Rgb(238, 238, 238))]
+    fn darken_indexed(#[case] index: u8, #[case] expected: Color) {
+        assert_eq!(darken_color(Color::Indexed(index), 1.0), expected);
+    }
+
+    #[rstest]
+    #[case::reset(Color::Reset)]
+    #[case::named(Color::Red)]
+    #[case::system(Color::Indexed(9))]
+    fn darken_terminal_colors(#[case] color: Color) {
+        assert_eq!(darken_color(color, 0.5), color);
+    }
+
+    #[rstest]
+    #[case::left_short(Alignment::Left, "ab", " ab    ")]
+    #[case::center_short(Alignment::Center, "ab", "  ab   ")]
+    #[case::right_short(Alignment::Right, "ab", "    ab ")]
+    #[case::left_exact(Alignment::Left, "abcde", " abcde ")]
+    #[case::right_exact(Alignment::Right, "abcde", " abcde ")]
+    #[case::left_long(Alignment::Left, "abcdefg", " abcde ")]
+    #[case::center_long(Alignment::Center, "abcdefg", " bcdef ")]
+    #[case::right_long(Alignment::Right, "abcdefg", " cdefg ")]
+    #[case::left_wide(Alignment::Left, "コンピ", " コン  ")]
+    #[case::right_wide(Alignment::Right, "コンピ", "  ンピ ")]
+    fn set_line_aligned(
+        #[case] alignment: Alignment,
+        #[case] content: &str,
+        #[case] expected: &str,
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 1));
+        buffer.set_line_aligned(1, 0, &Line::raw(content), 5, alignment);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn set_line_aligned_overrides_line_alignment() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let line = Line::from("ab").right_aligned();
+        buffer.set_line_aligned(0, 0, &line, 5, Alignment::Left);
+        assert_eq!(buffer, Buffer::with_lines(["ab   "]));
+    }
+
+    #[test]
+    fn set_line_aligned_styles_width() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let line = Line::from(vec![Span::raw("a"), Span::raw("b").bold()]).red();
+        buffer.set_line_aligned(0, 0, &line, 4, Alignment::Center);
+        let mut expected = Buffer::with_lines([" ab  "]);
+        expected.set_style(Rect::new(0, 0, 4, 1), Style::new().red());
+        expected.set_style(Rect::new(2, 0, 1, 1), Style::new().bold());
+        assert_eq!(buffer, expected);
+    }
+
     #[rstest]
     #[case::empty("", "     ")]
     #[case::one("1", "1    ")]
@@ -1120,6 +1415,45 @@ mod tests {
         assert_eq!(diff, [(0, 0, &Cell::new("4"))],);
     }
 
+    #[test]
+    fn diff_hyperlink() {
+        let mut prev = Buffer::with_lines(["abc"]);
+        prev.content[0].set_hyperlink(Some("https://ratatui.rs"));
+        prev.content[1].set_hyperlink(Some("https://ratatui.rs"));
+        let mut next = Buffer::with_lines(["abc"]);
+        next.content[0].set_hyperlink(Some("https://ratatui.rs"));
+        next.content[1].set_hyperlink(Some("https://docs.rs"));
+        next.content[2].set_hyperlink(Some("https://ratatui.rs"));
+
+        let diff = prev.diff(&next);
+        let changed = diff
+            .iter()
+            .map(|(x, _, cell)| (*x, cell.hyperlink()))
+            .collect_vec();
+        assert_eq!(
+            changed,
+            [
+                (1, Some("https://docs.rs")),
+                (2, Some("https://ratatui.rs"))
+            ]
+        );
+    }
+
+    #[cfg(feature = "underline-color")]
+    #[test]
+    fn diff_underline_color() {
+        let prev = Buffer::with_lines(["ab"]);
+        let mut next = Buffer::with_lines(["ab"]);
+        next.set_style(
+            Rect::new(1, 0, 1, 1),
+            Style::new().underline_color(Color::Red),
+        );
+        let diff = prev.diff(&next);
+        assert_eq!(diff.len(), 1);
+        assert_eq!((diff[0].0, diff[0].1), (1, 0));
+        assert_eq!(diff[0].2.underline_color, Color::Red);
+    }
+
     #[rstest]
     #[case(Rect::new(0, 0, 2, 2), Rect::new(0, 2, 2, 2), ["11", "11", "22", "22"])]
     #[case(Rect::new(2, 2, 2, 2), Rect::new(0, 0, 2, 2), ["22  ", "22  ", "  11", "  11"])]
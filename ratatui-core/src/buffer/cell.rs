@@ -31,6 +31,19 @@ pub struct Cell {
 
     /// Whether the cell should be skipped when copying (diffing) the buffer to the screen.
     pub skip: bool,
+
+    /// The URL of the hyperlink the cell is part of, if any.
+    ///
+    /// The link is stored in the cell rather than in a table of the [`Buffer`] so that it is
+    /// replaced with the symbol of the cell, reset, resized and diffed along with the rest of the
+    /// cell. This makes each cell 24 bytes larger.
+    ///
+    /// [`Buffer`]: crate::buffer::Buffer
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    hyperlink: Option<CompactString>,
 }
 
 impl Cell {
@@ -52,6 +65,7 @@ impl Cell {
             underline_color: Color::Reset,
             modifier: Modifier::empty(),
             skip: false,
+            hyperlink: None,
         }
     }
 
@@ -62,8 +76,11 @@ impl Cell {
     }
 
     /// Sets the symbol of the cell.
+    ///
+    /// This removes the hyperlink of the cell, as it belongs to the previous symbol.
     pub fn set_symbol(&mut self, symbol: &str) -> &mut Self {
         self.symbol = CompactString::new(symbol);
+        self.hyperlink = None;
         self
     }
 
@@ -76,9 +93,31 @@ impl Cell {
     }
 
     /// Sets the symbol of the cell to a single character.
+    ///
+    /// This removes the hyperlink of the cell, as it belongs to the previous symbol.
     pub fn set_char(&mut self, ch: char) -> &mut Self {
         let mut buf = [0; 4];
         self.symbol = CompactString::new(ch.encode_utf8(&mut buf));
+        self.hyperlink = None;
+        self
+    }
+
+    /// Gets the URL of the hyperlink the cell is part of, if any.
+    #[must_use]
+    pub fn hyperlink(&self) -> Option<&str> {
+        self.hyperlink.as_deref()
+    }
+
+    /// Sets the URL of the hyperlink the cell is part of.
+    ///
+    /// Backends that support hyperlinks (e.g. using the OSC 8 escape sequence) make the symbol of
+    /// the cell clickable. Pass `None` to remove the hyperlink.
+    ///
+    /// The bytes of the URL that are not printable ASCII (`0x20` to `0x7E`) are percent-encoded,
+    /// as they are not allowed in the URI of an OSC 8 hyperlink. This keeps the control characters
+    /// of an untrusted URL (e.g. `ESC` or `BEL`) from ending the escape sequence early.
+    pub fn set_hyperlink(&mut self, url: Option<&str>) -> &mut Self {
+        self.hyperlink = url.map(escape_url);
         self
     }
 
@@ -148,6 +187,7 @@ impl Cell {
         }
         self.modifier = Modifier::empty();
         self.skip = false;
+        self.hyperlink = None;
     }
 }
 
@@ -165,6 +205,25 @@ impl From<char> for Cell {
     }
 }
 
+/// Percent-encodes the bytes of `url` that are not printable ASCII.
+fn escape_url(url: &str) -> CompactString {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    if url.bytes().all(|byte| (0x20..=0x7e).contains(&byte)) {
+        return CompactString::new(url);
+    }
+    let mut escaped = CompactString::default();
+    for byte in url.bytes() {
+        if (0x20..=0x7e).contains(&byte) {
+            escaped.push(char::from(byte));
+        } else {
+            escaped.push('%');
+            escaped.push(char::from(HEX[usize::from(byte >> 4)]));
+            escaped.push(char::from(HEX[usize::from(byte & 0xf)]));
+        }
+    }
+    escaped
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -182,6 +241,7 @@ mod tests {
                 underline_color: Color::Reset,
                 modifier: Modifier::empty(),
                 skip: false,
+                hyperlink: None,
             }
         );
     }
@@ -245,6 +305,34 @@ mod tests {
         assert!(cell.skip);
     }
 
+    #[test]
+    fn set_hyperlink() {
+        let mut cell = Cell::new("a");
+        assert_eq!(cell.hyperlink(), None);
+        cell.set_hyperlink(Some("https://ratatui.rs"));
+        assert_eq!(cell.hyperlink(), Some("https://ratatui.rs"));
+        cell.set_hyperlink(None);
+        assert_eq!(cell.hyperlink(), None);
+    }
+
+    #[test]
+    fn set_hyperlink_escapes_unprintable_bytes() {
+        let mut cell = Cell::new("a");
+        cell.set_hyperlink(Some("https://x\x1b]8;;evil\x07\x1b\\é"));
+        assert_eq!(cell.hyperlink(), Some("https://x%1B]8;;evil%07%1B\\%C3%A9"));
+    }
+
+    #[test]
+    fn set_symbol_removes_hyperlink() {
+        let mut cell = Cell::new("a");
+        cell.set_hyperlink(Some("https://ratatui.rs"));
+        cell.set_symbol("b");
+        assert_eq!(cell.hyperlink(), None);
+        cell.set_hyperlink(Some("https://ratatui.rs"));
+        cell.set_char('c');
+        assert_eq!(cell.hyperlink(), None);
+    }
+
     #[test]
     fn reset() {
         let mut cell = Cell::EMPTY;
@@ -252,11 +340,13 @@ mod tests {
         cell.set_fg(Color::Red);
         cell.set_bg(Color::Blue);
         cell.set_skip(true);
+        cell.set_hyperlink(Some("https://ratatui.rs"));
         cell.reset();
         assert_eq!(cell.symbol(), " ");
         assert_eq!(cell.fg, Color::Reset);
         assert_eq!(cell.bg, Color::Reset);
         assert!(!cell.skip);
+        assert_eq!(cell.hyperlink(), None);
     }
 
     #[test]
@@ -22,6 +22,9 @@ use strum::EnumIs;
 /// 5. [`Constraint::Ratio`]
 /// 6. [`Constraint::Fill`]
 ///
+/// The bounds of a [`Constraint::Bounded`] have the same priority as [`Constraint::Min`] and
+/// [`Constraint::Max`], and its ratio has the same priority as [`Constraint::Ratio`].
+///
 /// # Examples
 ///
 /// `Constraint` provides helper methods to create lists of constraints from various input formats.
@@ -169,6 +172,38 @@ pub enum Constraint {
     /// ```
     Ratio(u32, u32),
 
+    /// Applies a ratio of the available space to the element, clamped between a minimum and a
+    /// maximum size
+    ///
+    /// The size is computed in the same way as with [`Constraint::Ratio`] and is then clamped to
+    /// the range `min..=max`. If `min` is greater than `max`, the minimum takes precedence.
+    ///
+    /// # Examples
+    ///
+    /// `[Bounded { ratio: (1, 4), min: 20, max: 30 }, Fill(1)]`
+    ///
+    /// ```plain
+    /// ┌──────────────────┐┌────────────────────────────┐
+    /// │       20 px      ││            30 px           │
+    /// └──────────────────┘└────────────────────────────┘
+    /// ```
+    ///
+    /// `[Bounded { ratio: (3, 4), min: 10, max: 30 }, Fill(1)]`
+    ///
+    /// ```plain
+    /// ┌────────────────────────────┐┌──────────────────┐
+    /// │            30 px           ││       20 px      │
+    /// └────────────────────────────┘└──────────────────┘
+    /// ```
+    Bounded {
+        /// The ratio of the available space, as a numerator and a denominator
+        ratio: (u32, u32),
+        /// The minimum size of the element
+        min: u16,
+        /// The maximum size of the element
+        max: u16,
+    },
+
     /// Applies the scaling factor proportional to all other [`Constraint::Fill`] elements
     /// to fill excess space
     ///
@@ -215,6 +250,15 @@ impl Constraint {
                 let length = f32::from(length);
                 (percentage * length).min(length) as u16
             }
+            Self::Bounded {
+                ratio: (numerator, denominator),
+                min,
+                max,
+            } => {
+                let percentage = numerator as f32 / denominator.max(1) as f32;
+                let length = f32::from(length);
+                ((percentage * length).min(length) as u16).min(max).max(min)
+            }
             Self::Length(l) | Self::Fill(l) => length.min(l),
             Self::Max(m) => length.min(m),
             Self::Min(m) => length.max(m),
@@ -374,6 +418,11 @@ impl fmt::Display for Constraint {
         match self {
             Self::Percentage(p) => write!(f, "Percentage({p})"),
             Self::Ratio(n, d) => write!(f, "Ratio({n}, {d})"),
+            Self::Bounded {
+                ratio: (n, d),
+                min,
+                max,
+            } => write!(f, "Bounded({n}, {d}, {min}..={max})"),
             Self::Length(l) => write!(f, "Length({l})"),
             Self::Fill(l) => write!(f, "Fill({l})"),
             Self::Max(m) => write!(f, "Max({m})"),
@@ -398,6 +447,12 @@ mod tests {
     fn to_string() {
         assert_eq!(Constraint::Percentage(50).to_string(), "Percentage(50)");
         assert_eq!(Constraint::Ratio(1, 2).to_string(), "Ratio(1, 2)");
+        let bounded = Constraint::Bounded {
+            ratio: (1, 2),
+            min: 20,
+            max: 60,
+        };
+        assert_eq!(bounded.to_string(), "Bounded(1, 2, 20..=60)");
         assert_eq!(Constraint::Length(10).to_string(), "Length(10)");
         assert_eq!(Constraint::Max(10).to_string(), "Max(10)");
         assert_eq!(Constraint::Min(10).to_string(), "Min(10)");
@@ -500,5 +555,15 @@ mod tests {
         assert_eq!(Constraint::Min(100).apply(100), 100);
         assert_eq!(Constraint::Min(200).apply(100), 200);
         assert_eq!(Constraint::Min(u16::MAX).apply(100), u16::MAX);
+
+        let bounded = |min, max| Constraint::Bounded {
+            ratio: (1, 2),
+            min,
+            max,
+        };
+        assert_eq!(bounded(20, 60).apply(100), 50);
+        assert_eq!(bounded(20, 40).apply(100), 40);
+        assert_eq!(bounded(20, 60).apply(30), 20);
+        assert_eq!(bounded(60, 20).apply(100), 60);
     }
 }
@@ -14,6 +14,7 @@ use crate::layout::Constraint;
 /// - `Center`: Centers items within the container.
 /// - `SpaceBetween`: Adds excess space between each element.
 /// - `SpaceAround`: Adds excess space around each element.
+/// - `SpaceEvenly`: Adds excess space evenly before, between and after the elements.
 #[derive(Copy, Debug, Default, Display, EnumString, Clone, Eq, PartialEq, Hash, EnumIs)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Flex {
@@ -158,6 +159,36 @@ pub enum Flex {
 
     /// Adds excess space around each element.
     ///
+    /// Each element gets the same amount of space on both sides, so the space between two elements
+    /// is twice the space before the first element and after the last element.
+    ///
+    /// # Examples
+    ///
+    /// ```plain
+    /// <------------------------------------80 px------------------------------------->
+    ///     ┌────16 px─────┐        ┌──────20 px───────┐        ┌──────20 px───────┐
+    ///     │Percentage(20)│        │    Length(20)    │        │     Length(20)   │
+    ///     └──────────────┘        └──────────────────┘        └──────────────────┘
+    ///
+    /// <------------------------------------80 px------------------------------------->
+    ///           ┌──────20 px───────┐                    ┌──────20 px───────┐
+    ///           │      Max(20)     │                    │      Max(20)     │
+    ///           └──────────────────┘                    └──────────────────┘
+    ///
+    /// <------------------------------------80 px------------------------------------->
+    ///                               ┌──────20 px───────┐
+    ///                               │      Max(20)     │
+    ///                               └──────────────────┘
+    /// ```
+    SpaceAround,
+
+    /// Adds excess space evenly before, between and after the elements.
+    ///
+    /// All the gaps have the same size, unlike [`Flex::SpaceAround`] where the gaps at the edges
+    /// are half the size of the gaps between the elements, and [`Flex::SpaceBetween`] where there
+    /// are no gaps at the edges. When the excess space can't be divided evenly, the positions of
+    /// the elements are rounded to the nearest cell, so the gaps differ by at most one cell.
+    ///
     /// # Examples
     ///
     /// ```plain
@@ -176,7 +207,7 @@ pub enum Flex {
     ///                               │      Max(20)     │
     ///                               └──────────────────┘
     /// ```
-    SpaceAround,
+    SpaceEvenly,
 }
 #[cfg(test)]
 mod tests {}
@@ -128,7 +128,8 @@ impl From<i16> for Spacing {
 ///
 /// When the layout is computed, the result is cached in a thread-local cache, so that subsequent
 /// calls with the same parameters are faster. The cache is a `LruCache`, and the size of the cache
-/// can be configured using [`Layout::init_cache()`].
+/// can be configured using [`Layout::init_cache()`] or [`Layout::set_cache_size()`]. The cache can
+/// be emptied with [`Layout::clear_cache()`].
 ///
 /// # Constructors
 ///
@@ -290,6 +291,44 @@ impl Layout {
         LAYOUT_CACHE.with_borrow_mut(|cache| cache.resize(cache_size));
     }
 
+    /// Set the maximum number of layouts stored in the cache of the current thread.
+    ///
+    /// When the cache is full, the least recently used layouts are evicted to make room for new
+    /// ones, which bounds the memory used by apps that split many different layouts. If the cache
+    /// holds more layouts than `cache_size`, the least recently used ones are evicted immediately.
+    /// A `cache_size` of 0 is treated as 1.
+    ///
+    /// By default, the cache size is [`Self::DEFAULT_CACHE_SIZE`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Layout;
+    ///
+    /// Layout::set_cache_size(100);
+    /// ```
+    #[cfg(feature = "layout-cache")]
+    pub fn set_cache_size(cache_size: usize) {
+        Self::init_cache(NonZeroUsize::new(cache_size).unwrap_or(NonZeroUsize::MIN));
+    }
+
+    /// Remove all the layouts from the cache of the current thread.
+    ///
+    /// The layouts are computed again the next time they are split. The size of the cache is
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Layout;
+    ///
+    /// Layout::clear_cache();
+    /// ```
+    #[cfg(feature = "layout-cache")]
+    pub fn clear_cache() {
+        LAYOUT_CACHE.with_borrow_mut(LruCache::clear);
+    }
+
     /// Set the direction of the layout.
     ///
     /// # Examples
@@ -440,7 +479,10 @@ impl Layout {
     ///   - [`Flex::Start`]: The items are aligned to the start of the layout.
     ///   - [`Flex::Center`]: The items are aligned to the center of the layout.
     ///   - [`Flex::End`]: The items are aligned to the end of the layout.
-    ///   - [`Flex::SpaceAround`]: The items are evenly distributed with equal space around them.
+    ///   - [`Flex::SpaceAround`]: The items are evenly distributed with equal space around them, so
+    ///     the space at the edges is half the space between the items.
+    ///   - [`Flex::SpaceEvenly`]: The items are evenly distributed with equal space before, between
+    ///     and after them.
     ///   - [`Flex::SpaceBetween`]: The items are evenly distributed with equal space between them.
     ///
     /// # Examples
@@ -480,7 +522,8 @@ impl Layout {
     /// passed to this function. See the documentation of the [`Spacing`] enum for more information.
     ///
     /// Note that if the layout has only one segment, the spacing will not be applied.
-    /// Also, spacing will not be applied for [`Flex::SpaceAround`] and [`Flex::SpaceBetween`]
+    /// Also, spacing will not be applied for [`Flex::SpaceAround`], [`Flex::SpaceEvenly`] and
+    /// [`Flex::SpaceBetween`]
     ///
     /// # Examples
     ///
@@ -525,7 +568,7 @@ impl Layout {
     /// # Examples
     ///
     /// ```rust
-    /// use ratatui_core::layout::{Layout, Constraint, Rect};
+    /// use ratatui_core::layout::{Constraint, Layout, Rect};
     ///
     /// let area = Rect::new(0, 0, 10, 10);
     /// let layout = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]);
@@ -533,6 +576,7 @@ impl Layout {
     ///
     /// // or explicitly specify the number of constraints:
     /// let areas = layout.areas::<2>(area);
+    /// ```
     pub fn areas<const N: usize>(&self, area: Rect) -> [Rect; N] {
         let (areas, _) = self.split_with_spacers(area);
         areas.as_ref().try_into().expect("invalid number of rects")
@@ -855,6 +899,17 @@ fn configure_constraints(
                 let size = area.size() * f64::from(num) / f64::from(den.max(1));
                 solver.add_constraint(segment.has_size(size, RATIO_SIZE_EQ))?;
             }
+            Constraint::Bounded {
+                ratio: (num, den),
+                min,
+                max,
+            } => {
+                // the bounds are stronger than the ratio, which clamps the size of the segment
+                let size = area.size() * f64::from(num) / f64::from(den.max(1));
+                solver.add_constraint(segment.has_min_size(min as i16, MIN_SIZE_GE))?;
+                solver.add_constraint(segment.has_max_size(max, MAX_SIZE_LE))?;
+                solver.add_constraint(segment.has_size(size, RATIO_SIZE_EQ))?;
+            }
             Constraint::Fill(_) => {
                 // given no other constraints, this segment will grow as much as possible.
                 solver.add_constraint(segment.has_size(area, FILL_GROW))?;
@@ -885,7 +940,7 @@ fn configure_flex_constraints(
         }
         // all spacers are the same size and will grow to fill any remaining space after the
         // constraints are satisfied
-        Flex::SpaceAround => {
+        Flex::SpaceEvenly => {
             for (left, right) in spacers.iter().tuple_combinations() {
                 solver.add_constraint(left.has_size(right, SPACER_SIZE_EQ))?;
             }
@@ -895,6 +950,31 @@ fn configure_flex_constraints(
             }
         }
 
+        // all spacers except the first and last are the same size and will grow to fill any
+        // remaining space after the constraints are satisfied, and the first and last spacers are
+        // half that size
+        Flex::SpaceAround => {
+            if let (Some(first), Some(last)) = (spacers.first(), spacers.last()) {
+                if let Some(middle) = spacers_except_first_and_last.first() {
+                    solver.add_constraint(middle.has_size(first.size() * 2.0, SPACER_SIZE_EQ))?;
+                    solver.add_constraint(middle.has_size(last.size() * 2.0, SPACER_SIZE_EQ))?;
+                } else {
+                    solver.add_constraint(first.has_size(last, SPACER_SIZE_EQ))?;
+                }
+                for spacer in [first, last] {
+                    solver.add_constraint(spacer.has_min_size(spacing / 2, SPACER_SIZE_EQ))?;
+                    solver.add_constraint(spacer.has_size(area, SPACE_GROW))?;
+                }
+            }
+            for (left, right) in spacers_except_first_and_last.iter().tuple_combinations() {
+                solver.add_constraint(left.has_size(right, SPACER_SIZE_EQ))?;
+            }
+            for spacer in spacers_except_first_and_last {
+                solver.add_constraint(spacer.has_min_size(spacing, SPACER_SIZE_EQ))?;
+                solver.add_constraint(spacer.has_size(area, SPACE_GROW))?;
+            }
+        }
+
         // all spacers are the same size and will grow to fill any remaining space after the
         // constraints are satisfied, but the first and last spacers are zero size
         Flex::SpaceBetween => {
@@ -1240,6 +1320,75 @@ mod tests {
         });
     }
 
+    #[test]
+    #[cfg(feature = "layout-cache")]
+    fn set_cache_size() {
+        Layout::set_cache_size(20);
+        LAYOUT_CACHE.with_borrow(|cache| assert_eq!(cache.cap().get(), 20));
+
+        Layout::set_cache_size(0);
+        LAYOUT_CACHE.with_borrow(|cache| assert_eq!(cache.cap().get(), 1));
+    }
+
+    #[test]
+    #[cfg(feature = "layout-cache")]
+    fn cache_evicts_least_recently_used() {
+        Layout::set_cache_size(3);
+        let layout = Layout::horizontal([Constraint::Fill(1); 2]);
+        let area = |width| Rect::new(0, 0, width, 1);
+        let halves = |width: u16| {
+            [
+                Rect::new(0, 0, width / 2, 1),
+                Rect::new(width / 2, 0, width / 2, 1),
+            ]
+        };
+
+        for width in [2, 4, 6, 8, 10] {
+            assert_eq!(*layout.split(area(width)), halves(width));
+        }
+        LAYOUT_CACHE.with_borrow(|cache| {
+            assert_eq!(cache.len(), 3);
+            assert!(!cache.contains(&(area(2), layout.clone())));
+            assert!(!cache.contains(&(area(4), layout.clone())));
+            assert!(cache.contains(&(area(6), layout.clone())));
+            assert!(cache.contains(&(area(10), layout.clone())));
+        });
+
+        // evicted layouts are computed again
+        assert_eq!(*layout.split(area(2)), halves(2));
+        LAYOUT_CACHE.with_borrow(|cache| {
+            assert_eq!(cache.len(), 3);
+            assert!(cache.contains(&(area(2), layout.clone())));
+            assert!(!cache.contains(&(area(6), layout.clone())));
+        });
+
+        // shrinking the cache evicts the least recently used layouts
+        Layout::set_cache_size(1);
+        LAYOUT_CACHE.with_borrow(|cache| {
+            assert_eq!(cache.len(), 1);
+            assert!(cache.contains(&(area(2), layout.clone())));
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "layout-cache")]
+    fn clear_cache() {
+        let layout = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]);
+        let area = Rect::new(0, 0, 5, 5);
+        let expected = [Rect::new(0, 0, 5, 1), Rect::new(0, 1, 5, 4)];
+        assert_eq!(*layout.split(area), expected);
+        LAYOUT_CACHE.with_borrow(|cache| assert!(cache.contains(&(area, layout.clone()))));
+
+        Layout::clear_cache();
+        LAYOUT_CACHE.with_borrow(|cache| {
+            assert!(cache.is_empty());
+            assert_eq!(cache.cap().get(), Layout::DEFAULT_CACHE_SIZE);
+        });
+
+        assert_eq!(*layout.split(area), expected);
+        LAYOUT_CACHE.with_borrow(|cache| assert_eq!(cache.len(), 1));
+    }
+
     #[test]
     fn default() {
         assert_eq!(
@@ -1415,6 +1564,49 @@ mod tests {
         assert_eq!(Layout::default().spacing(-10).spacing, Spacing::Overlap(10));
     }
 
+    #[test]
+    fn areas() {
+        let layout = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Fill(1),
+            Constraint::Length(2),
+        ]);
+        let [header, body, footer] = layout.areas(Rect::new(0, 0, 10, 10));
+        assert_eq!(header, Rect::new(0, 0, 10, 1));
+        assert_eq!(body, Rect::new(0, 1, 10, 7));
+        assert_eq!(footer, Rect::new(0, 8, 10, 2));
+    }
+
+    #[test]
+    #[should_panic = "invalid number of rects"]
+    fn areas_too_few() {
+        let layout = Layout::vertical([Constraint::Length(1); 3]);
+        let [_, _] = layout.areas(Rect::new(0, 0, 10, 10));
+    }
+
+    #[test]
+    #[should_panic = "invalid number of rects"]
+    fn areas_too_many() {
+        let layout = Layout::vertical([Constraint::Length(1); 3]);
+        let [_, _, _, _] = layout.areas(Rect::new(0, 0, 10, 10));
+    }
+
+    #[test]
+    fn spacers() {
+        let layout = Layout::horizontal([Constraint::Length(2); 2]).spacing(1);
+        let [before, between, after] = layout.spacers(Rect::new(0, 0, 10, 1));
+        assert_eq!(before, Rect::new(0, 0, 0, 1));
+        assert_eq!(between, Rect::new(2, 0, 1, 1));
+        assert_eq!(after, Rect::new(5, 0, 5, 1));
+    }
+
+    #[test]
+    #[should_panic = "invalid number of rects"]
+    fn spacers_mismatch() {
+        let layout = Layout::horizontal([Constraint::Length(2); 2]);
+        let [_, _] = layout.spacers(Rect::new(0, 0, 10, 1));
+    }
+
     /// Tests for the `Layout::split()` function.
     ///
     /// There are many tests in this as the number of edge cases that are caused by the interaction
@@ -2171,6 +2363,7 @@ mod tests {
                 Flex::End,
                 Flex::Center,
                 Flex::SpaceAround,
+                Flex::SpaceEvenly,
                 Flex::SpaceBetween,
             ] {
                 let widths = Layout::horizontal(&constraints)
@@ -2308,25 +2501,26 @@ mod tests {
         #[case::length_center2(vec![Length(25), Length(25)], vec![25..50, 50..75], Flex::Center)]
         #[case::length_end2(vec![Length(25), Length(25)], vec![50..75, 75..100], Flex::End)]
         #[case::length_spacebetween(vec![Length(25), Length(25)], vec![0..25, 75..100], Flex::SpaceBetween)]
-        #[case::length_spacearound(vec![Length(25), Length(25)], vec![17..42, 58..83], Flex::SpaceAround)]
+        #[case::length_spaceevenly(vec![Length(25), Length(25)], vec![17..42, 58..83], Flex::SpaceEvenly)]
         #[case::percentage_legacy(vec![Percentage(25), Percentage(25)], vec![0..25, 25..100], Flex::Legacy)]
         #[case::percentage_start(vec![Percentage(25), Percentage(25)], vec![0..25, 25..50], Flex::Start)]
         #[case::percentage_center(vec![Percentage(25), Percentage(25)], vec![25..50, 50..75], Flex::Center)]
         #[case::percentage_end(vec![Percentage(25), Percentage(25)], vec![50..75, 75..100], Flex::End)]
         #[case::percentage_spacebetween(vec![Percentage(25), Percentage(25)], vec![0..25, 75..100], Flex::SpaceBetween)]
-        #[case::percentage_spacearound(vec![Percentage(25), Percentage(25)], vec![17..42, 58..83], Flex::SpaceAround)]
+        #[case::percentage_spaceevenly(vec![Percentage(25), Percentage(25)], vec![17..42, 58..83], Flex::SpaceEvenly)]
         #[case::min_legacy2(vec![Min(25), Min(25)], vec![0..25, 25..100], Flex::Legacy)]
         #[case::min_start2(vec![Min(25), Min(25)], vec![0..50, 50..100], Flex::Start)]
         #[case::min_center2(vec![Min(25), Min(25)], vec![0..50, 50..100], Flex::Center)]
         #[case::min_end2(vec![Min(25), Min(25)], vec![0..50, 50..100], Flex::End)]
         #[case::min_spacebetween(vec![Min(25), Min(25)], vec![0..50, 50..100], Flex::SpaceBetween)]
-        #[case::min_spacearound(vec![Min(25), Min(25)], vec![0..50, 50..100], Flex::SpaceAround)]
+        #[case::min_spaceevenly(vec![Min(25), Min(25)], vec![0..50, 50..100], Flex::SpaceEvenly)]
         #[case::max_legacy2(vec![Max(25), Max(25)], vec![0..25, 25..100], Flex::Legacy)]
         #[case::max_start2(vec![Max(25), Max(25)], vec![0..25, 25..50], Flex::Start)]
         #[case::max_center2(vec![Max(25), Max(25)], vec![25..50, 50..75], Flex::Center)]
         #[case::max_end2(vec![Max(25), Max(25)], vec![50..75, 75..100], Flex::End)]
         #[case::max_spacebetween(vec![Max(25), Max(25)], vec![0..25, 75..100], Flex::SpaceBetween)]
-        #[case::max_spacearound(vec![Max(25), Max(25)], vec![17..42, 58..83], Flex::SpaceAround)]
+        #[case::max_spaceevenly(vec![Max(25), Max(25)], vec![17..42, 58..83], Flex::SpaceEvenly)]
+        #[case::max_spacearound(vec![Max(25), Max(25)], vec![13..38, 63..88], Flex::SpaceAround)]
         #[case::length_spaced_around(vec![Length(25), Length(25), Length(25)], vec![0..25, 38..63, 75..100], Flex::SpaceBetween)]
         fn flex_constraint(
             #[case] constraints: Vec<Constraint>,
@@ -2350,7 +2544,8 @@ mod tests {
         #[case::length_overlap4(vec![(42 , 20) , (61 , 20) , (80 , 20)] , vec![Length(20) , Length(20) , Length(20)] , Flex::End          , -1)]
         #[case::length_overlap5(vec![(0  , 20) , (19 , 20) , (38 , 62)] , vec![Length(20) , Length(20) , Length(20)] , Flex::Legacy       , -1)]
         #[case::length_overlap6(vec![(0  , 20) , (40 , 20) , (80 , 20)] , vec![Length(20) , Length(20) , Length(20)] , Flex::SpaceBetween , -1)]
-        #[case::length_overlap7(vec![(10 , 20) , (40 , 20) , (70 , 20)] , vec![Length(20) , Length(20) , Length(20)] , Flex::SpaceAround  , -1)]
+        #[case::length_overlap7(vec![(10 , 20) , (40 , 20) , (70 , 20)] , vec![Length(20) , Length(20) , Length(20)] , Flex::SpaceEvenly  , -1)]
+        #[case::length_overlap8(vec![(7  , 20) , (40 , 20) , (73 , 20)] , vec![Length(20) , Length(20) , Length(20)] , Flex::SpaceAround  , -1)]
         fn flex_overlap(
             #[case] expected: Vec<(u16, u16)>,
             #[case] constraints: Vec<Constraint>,
@@ -2377,7 +2572,8 @@ mod tests {
         #[case::length_spacing(vec![(36, 20), (58, 20) , (80, 20)], vec![Length(20), Length(20), Length(20)], Flex::End        , 2)]
         #[case::length_spacing(vec![(0 , 20), (22, 20) , (44, 56)], vec![Length(20), Length(20), Length(20)], Flex::Legacy     , 2)]
         #[case::length_spacing(vec![(0 , 20), (40, 20) , (80, 20)], vec![Length(20), Length(20), Length(20)], Flex::SpaceBetween, 2)]
-        #[case::length_spacing(vec![(10, 20), (40, 20) , (70, 20)], vec![Length(20), Length(20), Length(20)], Flex::SpaceAround, 2)]
+        #[case::length_spacing(vec![(10, 20), (40, 20) , (70, 20)], vec![Length(20), Length(20), Length(20)], Flex::SpaceEvenly, 2)]
+        #[case::length_spacing(vec![(7 , 20), (40, 20) , (73, 20)], vec![Length(20), Length(20), Length(20)], Flex::SpaceAround, 2)]
         fn flex_spacing(
             #[case] expected: Vec<(u16, u16)>,
             #[case] constraints: Vec<Constraint>,
@@ -2396,6 +2592,28 @@ mod tests {
             assert_eq!(result, expected);
         }
 
+        #[rstest]
+        #[case::space_between(Flex::SpaceBetween, 30, vec![(0, 5), (13, 5), (25, 5)])]
+        #[case::space_around(Flex::SpaceAround, 30, vec![(3, 5), (13, 5), (23, 5)])]
+        #[case::space_evenly(Flex::SpaceEvenly, 30, vec![(4, 5), (13, 5), (21, 5)])]
+        #[case::space_between_remainder(Flex::SpaceBetween, 31, vec![(0, 5), (13, 5), (26, 5)])]
+        #[case::space_around_remainder(Flex::SpaceAround, 31, vec![(3, 5), (13, 5), (23, 5)])]
+        #[case::space_evenly_remainder(Flex::SpaceEvenly, 31, vec![(4, 5), (13, 5), (22, 5)])]
+        fn space_modes_with_fixed_segments(
+            #[case] flex: Flex,
+            #[case] width: u16,
+            #[case] expected: Vec<(u16, u16)>,
+        ) {
+            let rect = Rect::new(0, 0, width, 1);
+            let result = Layout::horizontal([Length(5); 3])
+                .flex(flex)
+                .split(rect)
+                .iter()
+                .map(|r| (r.x, r.width))
+                .collect::<Vec<(u16, u16)>>();
+            assert_eq!(result, expected);
+        }
+
         #[rstest]
         #[case::a(vec![(0, 25), (25, 75)], vec![Length(25), Length(25)])]
         #[case::b(vec![(0, 25), (25, 75)], vec![Length(25), Percentage(25)])]
@@ -2434,7 +2652,7 @@ mod tests {
         #[case::c(vec![(36, 20), (58, 20), (80, 20)], vec![Length(20), Length(20), Length(20)], Flex::End, 2)]
         #[case::d(vec![(0, 20), (22, 20), (44, 56)], vec![Length(20), Length(20), Length(20)], Flex::Legacy, 2)]
         #[case::e(vec![(0, 20), (22, 20), (44, 56)], vec![Length(20), Length(20), Length(20)], Flex::Legacy, 2)]
-        #[case::f(vec![(10, 20), (40, 20), (70, 20)], vec![Length(20), Length(20), Length(20)], Flex::SpaceAround, 2)]
+        #[case::f(vec![(10, 20), (40, 20), (70, 20)], vec![Length(20), Length(20), Length(20)], Flex::SpaceEvenly, 2)]
         fn constraint_specification_tests_for_priority_with_spacing(
             #[case] expected: Vec<(u16, u16)>,
             #[case] constraints: Vec<Constraint>,
@@ -2456,7 +2674,7 @@ mod tests {
         #[case::prop(vec![(0 , 10), (10, 80), (90 , 10)] , vec![Length(10), Fill(1), Length(10)], Flex::Legacy)]
         #[case::flex(vec![(0 , 10), (90 , 10)] , vec![Length(10), Length(10)], Flex::SpaceBetween)]
         #[case::prop(vec![(0 , 27), (27, 10), (37, 26), (63, 10), (73, 27)] , vec![Fill(1), Length(10), Fill(1), Length(10), Fill(1)], Flex::Legacy)]
-        #[case::flex(vec![(27 , 10), (63, 10)] , vec![Length(10), Length(10)], Flex::SpaceAround)]
+        #[case::flex(vec![(27 , 10), (63, 10)] , vec![Length(10), Length(10)], Flex::SpaceEvenly)]
         #[case::prop(vec![(0 , 10), (10, 10), (20 , 80)] , vec![Length(10), Length(10), Fill(1)], Flex::Legacy)]
         #[case::flex(vec![(0 , 10), (10, 10)] , vec![Length(10), Length(10)], Flex::Start)]
         #[case::prop(vec![(0 , 80), (80 , 10), (90, 10)] , vec![Fill(1), Length(10), Length(10)], Flex::Legacy)]
@@ -2479,7 +2697,7 @@ mod tests {
 
         #[rstest]
         #[case::flex0(vec![(0 , 50), (50 , 50)] , vec![Fill(1), Fill(1)], Flex::Legacy , 0)]
-        #[case::flex0(vec![(0 , 50), (50 , 50)] , vec![Fill(1), Fill(1)], Flex::SpaceAround , 0)]
+        #[case::flex0(vec![(0 , 50), (50 , 50)] , vec![Fill(1), Fill(1)], Flex::SpaceEvenly , 0)]
         #[case::flex0(vec![(0 , 50), (50 , 50)] , vec![Fill(1), Fill(1)], Flex::SpaceBetween , 0)]
         #[case::flex0(vec![(0 , 50), (50 , 50)] , vec![Fill(1), Fill(1)], Flex::Start , 0)]
         #[case::flex0(vec![(0 , 50), (50 , 50)] , vec![Fill(1), Fill(1)], Flex::Center , 0)]
@@ -2488,10 +2706,10 @@ mod tests {
         #[case::flex10(vec![(0 , 45), (55 , 45)] , vec![Fill(1), Fill(1)], Flex::Start , 10)]
         #[case::flex10(vec![(0 , 45), (55 , 45)] , vec![Fill(1), Fill(1)], Flex::Center , 10)]
         #[case::flex10(vec![(0 , 45), (55 , 45)] , vec![Fill(1), Fill(1)], Flex::End , 10)]
-        #[case::flex10(vec![(10 , 35), (55 , 35)] , vec![Fill(1), Fill(1)], Flex::SpaceAround , 10)]
+        #[case::flex10(vec![(10 , 35), (55 , 35)] , vec![Fill(1), Fill(1)], Flex::SpaceEvenly , 10)]
         #[case::flex10(vec![(0 , 45), (55 , 45)] , vec![Fill(1), Fill(1)], Flex::SpaceBetween , 10)]
         #[case::flex_length0(vec![(0 , 45), (45, 10), (55 , 45)] , vec![Fill(1), Length(10), Fill(1)], Flex::Legacy , 0)]
-        #[case::flex_length0(vec![(0 , 45), (45, 10), (55 , 45)] , vec![Fill(1), Length(10), Fill(1)], Flex::SpaceAround , 0)]
+        #[case::flex_length0(vec![(0 , 45), (45, 10), (55 , 45)] , vec![Fill(1), Length(10), Fill(1)], Flex::SpaceEvenly , 0)]
         #[case::flex_length0(vec![(0 , 45), (45, 10), (55 , 45)] , vec![Fill(1), Length(10), Fill(1)], Flex::SpaceBetween , 0)]
         #[case::flex_length0(vec![(0 , 45), (45, 10), (55 , 45)] , vec![Fill(1), Length(10), Fill(1)], Flex::Start , 0)]
         #[case::flex_length0(vec![(0 , 45), (45, 10), (55 , 45)] , vec![Fill(1), Length(10), Fill(1)], Flex::Center , 0)]
@@ -2500,7 +2718,7 @@ mod tests {
         #[case::flex_length10(vec![(0 , 35), (45, 10), (65 , 35)] , vec![Fill(1), Length(10), Fill(1)], Flex::Start , 10)]
         #[case::flex_length10(vec![(0 , 35), (45, 10), (65 , 35)] , vec![Fill(1), Length(10), Fill(1)], Flex::Center , 10)]
         #[case::flex_length10(vec![(0 , 35), (45, 10), (65 , 35)] , vec![Fill(1), Length(10), Fill(1)], Flex::End , 10)]
-        #[case::flex_length10(vec![(10 , 25), (45, 10), (65 , 25)] , vec![Fill(1), Length(10), Fill(1)], Flex::SpaceAround , 10)]
+        #[case::flex_length10(vec![(10 , 25), (45, 10), (65 , 25)] , vec![Fill(1), Length(10), Fill(1)], Flex::SpaceEvenly , 10)]
         #[case::flex_length10(vec![(0 , 35), (45, 10), (65 , 35)] , vec![Fill(1), Length(10), Fill(1)], Flex::SpaceBetween , 10)]
         fn fill_spacing(
             #[case] expected: Vec<(u16, u16)>,
@@ -2522,7 +2740,7 @@ mod tests {
 
         #[rstest]
         #[case::flex0_1(vec![(0 , 55), (45 , 55)] , vec![Fill(1), Fill(1)], Flex::Legacy , -10)]
-        #[case::flex0_2(vec![(0 , 50), (50 , 50)] , vec![Fill(1), Fill(1)], Flex::SpaceAround , -10)]
+        #[case::flex0_2(vec![(0 , 50), (50 , 50)] , vec![Fill(1), Fill(1)], Flex::SpaceEvenly , -10)]
         #[case::flex0_3(vec![(0 , 55), (45 , 55)] , vec![Fill(1), Fill(1)], Flex::SpaceBetween , -10)]
         #[case::flex0_4(vec![(0 , 55), (45 , 55)] , vec![Fill(1), Fill(1)], Flex::Start , -10)]
         #[case::flex0_5(vec![(0 , 55), (45 , 55)] , vec![Fill(1), Fill(1)], Flex::Center , -10)]
@@ -2531,10 +2749,10 @@ mod tests {
         #[case::flex10_2(vec![(0 , 51), (50 , 50)] , vec![Fill(1), Fill(1)], Flex::Start , -1)]
         #[case::flex10_3(vec![(0 , 51), (50 , 50)] , vec![Fill(1), Fill(1)], Flex::Center , -1)]
         #[case::flex10_4(vec![(0 , 51), (50 , 50)] , vec![Fill(1), Fill(1)], Flex::End , -1)]
-        #[case::flex10_5(vec![(0 , 50), (50 , 50)] , vec![Fill(1), Fill(1)], Flex::SpaceAround , -1)]
+        #[case::flex10_5(vec![(0 , 50), (50 , 50)] , vec![Fill(1), Fill(1)], Flex::SpaceEvenly , -1)]
         #[case::flex10_6(vec![(0 , 51), (50 , 50)] , vec![Fill(1), Fill(1)], Flex::SpaceBetween , -1)]
         #[case::flex_length0_1(vec![(0 , 55), (45, 10), (45 , 55)] , vec![Fill(1), Length(10), Fill(1)], Flex::Legacy , -10)]
-        #[case::flex_length0_2(vec![(0 , 45), (45, 10), (55 , 45)] , vec![Fill(1), Length(10), Fill(1)], Flex::SpaceAround , -10)]
+        #[case::flex_length0_2(vec![(0 , 45), (45, 10), (55 , 45)] , vec![Fill(1), Length(10), Fill(1)], Flex::SpaceEvenly , -10)]
         #[case::flex_length0_3(vec![(0 , 55), (45, 10), (45 , 55)] , vec![Fill(1), Length(10), Fill(1)], Flex::SpaceBetween , -10)]
         #[case::flex_length0_4(vec![(0 , 55), (45, 10), (45 , 55)] , vec![Fill(1), Length(10), Fill(1)], Flex::Start , -10)]
         #[case::flex_length0_5(vec![(0 , 55), (45, 10), (45 , 55)] , vec![Fill(1), Length(10), Fill(1)], Flex::Center , -10)]
@@ -2543,7 +2761,7 @@ mod tests {
         #[case::flex_length10_2(vec![(0 , 46), (45, 10), (54 , 46)] , vec![Fill(1), Length(10), Fill(1)], Flex::Start , -1)]
         #[case::flex_length10_3(vec![(0 , 46), (45, 10), (54 , 46)] , vec![Fill(1), Length(10), Fill(1)], Flex::Center , -1)]
         #[case::flex_length10_4(vec![(0 , 46), (45, 10), (54 , 46)] , vec![Fill(1), Length(10), Fill(1)], Flex::End , -1)]
-        #[case::flex_length10_5(vec![(0 , 45), (45, 10), (55 , 45)] , vec![Fill(1), Length(10), Fill(1)], Flex::SpaceAround , -1)]
+        #[case::flex_length10_5(vec![(0 , 45), (45, 10), (55 , 45)] , vec![Fill(1), Length(10), Fill(1)], Flex::SpaceEvenly , -1)]
         #[case::flex_length10_6(vec![(0 , 46), (45, 10), (54 , 46)] , vec![Fill(1), Length(10), Fill(1)], Flex::SpaceBetween , -1)]
         fn fill_overlap(
             #[case] expected: Vec<(u16, u16)>,
@@ -2586,7 +2804,7 @@ mod tests {
         #[rstest]
         #[case::spacers(vec![(0, 0), (10, 0), (100, 0)], vec![Length(10), Length(10)], Flex::Legacy)]
         #[case::spacers(vec![(0, 0), (10, 80), (100, 0)], vec![Length(10), Length(10)], Flex::SpaceBetween)]
-        #[case::spacers(vec![(0, 27), (37, 26), (73, 27)], vec![Length(10), Length(10)], Flex::SpaceAround)]
+        #[case::spacers(vec![(0, 27), (37, 26), (73, 27)], vec![Length(10), Length(10)], Flex::SpaceEvenly)]
         #[case::spacers(vec![(0, 0), (10, 0), (20, 80)], vec![Length(10), Length(10)], Flex::Start)]
         #[case::spacers(vec![(0, 40), (50, 0), (60, 40)], vec![Length(10), Length(10)], Flex::Center)]
         #[case::spacers(vec![(0, 80), (90, 0), (100, 0)], vec![Length(10), Length(10)], Flex::End)]
@@ -2610,7 +2828,7 @@ mod tests {
         #[rstest]
         #[case::spacers(vec![(0, 0), (10, 5), (100, 0)], vec![Length(10), Length(10)], Flex::Legacy, 5)]
         #[case::spacers(vec![(0, 0), (10, 80), (100, 0)], vec![Length(10), Length(10)], Flex::SpaceBetween, 5)]
-        #[case::spacers(vec![(0, 27), (37, 26), (73, 27)], vec![Length(10), Length(10)], Flex::SpaceAround, 5)]
+        #[case::spacers(vec![(0, 27), (37, 26), (73, 27)], vec![Length(10), Length(10)], Flex::SpaceEvenly, 5)]
         #[case::spacers(vec![(0, 0), (10, 5), (25, 75)], vec![Length(10), Length(10)], Flex::Start, 5)]
         #[case::spacers(vec![(0, 38), (48, 5), (63, 37)], vec![Length(10), Length(10)], Flex::Center, 5)]
         #[case::spacers(vec![(0, 75), (85, 5), (100, 0)], vec![Length(10), Length(10)], Flex::End, 5)]
@@ -2636,7 +2854,7 @@ mod tests {
         #[rstest]
         #[case::spacers_1(vec![(0, 0), (10, 0), (100, 0)], vec![Length(10), Length(10)], Flex::Legacy, -1)]
         #[case::spacers_2(vec![(0, 0), (10, 80), (100, 0)], vec![Length(10), Length(10)], Flex::SpaceBetween, -1)]
-        #[case::spacers_3(vec![(0, 27), (37, 26), (73, 27)], vec![Length(10), Length(10)], Flex::SpaceAround, -1)]
+        #[case::spacers_3(vec![(0, 27), (37, 26), (73, 27)], vec![Length(10), Length(10)], Flex::SpaceEvenly, -1)]
         #[case::spacers_4(vec![(0, 0), (10, 0), (19, 81)], vec![Length(10), Length(10)], Flex::Start, -1)]
         #[case::spacers_5(vec![(0, 41), (51, 0), (60, 40)], vec![Length(10), Length(10)], Flex::Center, -1)]
         #[case::spacers_6(vec![(0, 81), (91, 0), (100, 0)], vec![Length(10), Length(10)], Flex::End, -1)]
@@ -2662,7 +2880,7 @@ mod tests {
         #[rstest]
         #[case::spacers(vec![(0, 0), (0, 100), (100, 0)], vec![Length(10), Length(10)], Flex::Legacy, 200)]
         #[case::spacers(vec![(0, 0), (0, 100), (100, 0)], vec![Length(10), Length(10)], Flex::SpaceBetween, 200)]
-        #[case::spacers(vec![(0, 33), (33, 34), (67, 33)], vec![Length(10), Length(10)], Flex::SpaceAround, 200)]
+        #[case::spacers(vec![(0, 33), (33, 34), (67, 33)], vec![Length(10), Length(10)], Flex::SpaceEvenly, 200)]
         #[case::spacers(vec![(0, 0), (0, 100), (100, 0)], vec![Length(10), Length(10)], Flex::Start, 200)]
         #[case::spacers(vec![(0, 0), (0, 100), (100, 0)], vec![Length(10), Length(10)], Flex::Center, 200)]
         #[case::spacers(vec![(0, 0), (0, 100), (100, 0)], vec![Length(10), Length(10)], Flex::End, 200)]
@@ -2685,6 +2903,45 @@ mod tests {
             assert_eq!(result, expected);
         }
 
+        #[rstest]
+        #[case::smaller_than_min(10, vec![(0, 10), (10, 0)])]
+        #[case::small(30, vec![(0, 20), (20, 10)])]
+        #[case::medium(100, vec![(0, 50), (50, 50)])]
+        #[case::large(200, vec![(0, 60), (60, 140)])]
+        fn bounded(#[case] width: u16, #[case] expected: Vec<(u16, u16)>) {
+            let bounded = Bounded {
+                ratio: (1, 2),
+                min: 20,
+                max: 60,
+            };
+            let rect = Rect::new(0, 0, width, 1);
+            let result = Layout::horizontal([bounded, Fill(1)])
+                .split(rect)
+                .iter()
+                .map(|r| (r.x, r.width))
+                .collect::<Vec<(u16, u16)>>();
+            assert_eq!(result, expected);
+        }
+
+        #[rstest]
+        #[case::small(30, vec![(0, 15), (15, 15)])]
+        #[case::medium(100, vec![(0, 50), (50, 50)])]
+        #[case::large(200, vec![(0, 60), (60, 140)])]
+        fn bounded_with_min_constraint(#[case] width: u16, #[case] expected: Vec<(u16, u16)>) {
+            let bounded = Bounded {
+                ratio: (1, 2),
+                min: 10,
+                max: 60,
+            };
+            let rect = Rect::new(0, 0, width, 1);
+            let result = Layout::horizontal([bounded, Min(15)])
+                .split(rect)
+                .iter()
+                .map(|r| (r.x, r.width))
+                .collect::<Vec<(u16, u16)>>();
+            assert_eq!(result, expected);
+        }
+
         #[rstest]
         #[case::compare(vec![(0, 90), (90, 10)], vec![Min(10), Length(10)], Flex::Legacy)]
         #[case::compare(vec![(0, 90), (90, 10)], vec![Min(10), Length(10)], Flex::Start)]
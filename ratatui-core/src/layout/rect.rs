@@ -1,4 +1,5 @@
 #![warn(missing_docs)]
+use alloc::vec::Vec;
 use core::cmp::{max, min};
 use core::fmt;
 
@@ -155,6 +156,55 @@ impl Rect {
         }
     }
 
+    /// Returns a new `Rect` inside the current one, shrunk by the given amount on each side.
+    ///
+    /// This is like [`Rect::inner`], with a different amount for each side. If the amounts are
+    /// larger than the `Rect`, the returned `Rect` has no area instead of underflowing, and its
+    /// position stays within the bounds of the current one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let rect = Rect::new(10, 10, 20, 10).inset(1, 2, 3, 4);
+    /// assert_eq!(rect, Rect::new(14, 11, 14, 6));
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub fn inset(self, top: u16, right: u16, bottom: u16, left: u16) -> Self {
+        Self {
+            x: self.x.saturating_add(left).min(self.right()),
+            y: self.y.saturating_add(top).min(self.bottom()),
+            width: self.width.saturating_sub(left.saturating_add(right)),
+            height: self.height.saturating_sub(top.saturating_add(bottom)),
+        }
+    }
+
+    /// Returns a new `Rect` around the current one, grown by the given amount on each side.
+    ///
+    /// This is the counterpart of [`Rect::inset`]. The returned `Rect` is clamped so that its
+    /// bounds stay within `u16`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let rect = Rect::new(10, 10, 20, 10).outset(1, 2, 3, 4);
+    /// assert_eq!(rect, Rect::new(6, 9, 26, 14));
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub const fn outset(self, top: u16, right: u16, bottom: u16, left: u16) -> Self {
+        let x = self.x.saturating_sub(left);
+        let y = self.y.saturating_sub(top);
+        Self {
+            x,
+            y,
+            width: self.right().saturating_add(right).saturating_sub(x),
+            height: self.bottom().saturating_add(bottom).saturating_sub(y),
+        }
+    }
+
     /// Moves the `Rect` without modifying its size.
     ///
     /// Moves the `Rect` according to the given offset without modifying its [`width`](Rect::width)
@@ -177,6 +227,18 @@ impl Rect {
     }
 
     /// Returns a new `Rect` that contains both the current one and the given one.
+    ///
+    /// This is the bounding box of the two `Rect`s, which also covers the space between them when
+    /// they do not intersect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let rect = Rect::new(0, 0, 2, 2).union(Rect::new(5, 5, 2, 2));
+    /// assert_eq!(rect, Rect::new(0, 0, 7, 7));
+    /// ```
     #[must_use = "method returns the modified value"]
     pub fn union(self, other: Self) -> Self {
         let x1 = min(self.x, other.x);
@@ -193,7 +255,8 @@ impl Rect {
 
     /// Returns a new `Rect` that is the intersection of the current one and the given one.
     ///
-    /// If the two `Rect`s do not intersect, the returned `Rect` will have no area.
+    /// If the two `Rect`s do not intersect, the returned `Rect` will have no area. See
+    /// [`Rect::checked_intersection`] to get `None` instead.
     #[must_use = "method returns the modified value"]
     pub fn intersection(self, other: Self) -> Self {
         let x1 = max(self.x, other.x);
@@ -208,6 +271,27 @@ impl Rect {
         }
     }
 
+    /// Returns the intersection of the current `Rect` and the given one, or `None` if the
+    /// intersection has no area.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let rect = Rect::new(0, 0, 4, 4);
+    /// assert_eq!(
+    ///     rect.checked_intersection(Rect::new(2, 2, 4, 4)),
+    ///     Some(Rect::new(2, 2, 2, 2))
+    /// );
+    /// assert_eq!(rect.checked_intersection(Rect::new(5, 5, 2, 2)), None);
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub fn checked_intersection(self, other: Self) -> Option<Self> {
+        let intersection = self.intersection(other);
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
     /// Returns true if the two `Rect`s intersect.
     pub const fn intersects(self, other: Self) -> bool {
         self.x < other.right()
@@ -235,6 +319,27 @@ impl Rect {
             && position.y < self.bottom()
     }
 
+    /// Returns true if the given `Rect` is entirely inside this `Rect`.
+    ///
+    /// A `Rect` with no area is inside this `Rect` if its position is within the bounds of this
+    /// `Rect`, including its right and bottom edges.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let rect = Rect::new(0, 0, 10, 10);
+    /// assert!(rect.contains_rect(Rect::new(2, 2, 8, 8)));
+    /// assert!(!rect.contains_rect(Rect::new(2, 2, 9, 8)));
+    /// ```
+    pub const fn contains_rect(self, other: Self) -> bool {
+        other.x >= self.x
+            && other.right() <= self.right()
+            && other.y >= self.y
+            && other.bottom() <= self.bottom()
+    }
+
     /// Clamp this `Rect` to fit inside the other `Rect`.
     ///
     /// If the width or height of this `Rect` is larger than the other `Rect`, it will be clamped to
@@ -417,6 +522,66 @@ impl Rect {
             .centered_vertically(vertical_constraint)
     }
 
+    /// Splits the `Rect` into a grid of `rows` by `columns` equally sized tiles.
+    ///
+    /// The tiles are returned in a row-major order (left-to-right, top-to-bottom). When the area
+    /// cannot be divided evenly, the first rows and columns are one cell larger than the others,
+    /// so that the tiles cover the whole area without gaps. An empty `Vec` is returned when
+    /// `rows` or `columns` is 0.
+    ///
+    /// See [`Rect::grid_with_gutter`] to leave space between the tiles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let tiles = Rect::new(0, 0, 10, 10).grid(3, 3);
+    /// assert_eq!(tiles.len(), 9);
+    /// assert_eq!(tiles[0], Rect::new(0, 0, 4, 4));
+    /// assert_eq!(tiles[4], Rect::new(4, 4, 3, 3));
+    /// assert_eq!(tiles[8], Rect::new(7, 7, 3, 3));
+    /// ```
+    #[must_use]
+    pub fn grid(self, rows: u16, columns: u16) -> Vec<Self> {
+        self.grid_with_gutter(rows, columns, 0)
+    }
+
+    /// Splits the `Rect` into a grid of `rows` by `columns` equally sized tiles, separated by
+    /// `gutter` cells horizontally and vertically.
+    ///
+    /// This is the same as [`Rect::grid`], except that the gutter is removed from the space
+    /// available to the tiles before it is divided between them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let tiles = Rect::new(0, 0, 10, 4).grid_with_gutter(1, 3, 1);
+    /// assert_eq!(
+    ///     tiles,
+    ///     [
+    ///         Rect::new(0, 0, 3, 4),
+    ///         Rect::new(4, 0, 3, 4),
+    ///         Rect::new(8, 0, 2, 4),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn grid_with_gutter(self, rows: u16, columns: u16, gutter: u16) -> Vec<Self> {
+        let row_spans = split_evenly(self.y, self.height, rows, gutter);
+        let column_spans = split_evenly(self.x, self.width, columns, gutter);
+        row_spans
+            .iter()
+            .flat_map(|&(y, height)| {
+                column_spans
+                    .iter()
+                    .map(move |&(x, width)| Self::new(x, y, width, height))
+            })
+            .collect()
+    }
+
     /// indents the x value of the `Rect` by a given `offset`
     ///
     /// This is pub(crate) for now as we need to stabilize the naming / design of this API.
@@ -430,6 +595,29 @@ impl Rect {
     }
 }
 
+/// Splits `length` cells starting at `start` into `count` spans separated by `gutter` cells,
+/// returning the start and length of each span. The remainder of the division is given to the
+/// first spans.
+fn split_evenly(start: u16, length: u16, count: u16, gutter: u16) -> Vec<(u16, u16)> {
+    let gutters = gutter.saturating_mul(count.saturating_sub(1));
+    let available = length.saturating_sub(gutters);
+    let size = available.checked_div(count).unwrap_or_default();
+    let remainder = available.checked_rem(count).unwrap_or_default();
+    let mut position = start;
+    (0..count)
+        .map(|index| {
+            let span_length = if index < remainder {
+                size.saturating_add(1)
+            } else {
+                size
+            };
+            let span = (position, span_length);
+            position = position.saturating_add(span_length).saturating_add(gutter);
+            span
+        })
+        .collect()
+}
+
 impl From<(Position, Size)> for Rect {
     fn from((position, size): (Position, Size)) -> Self {
         Self {
@@ -511,6 +699,41 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::none((0, 0, 0, 0), Rect::new(10, 10, 20, 10))]
+    #[case::each_side((1, 2, 3, 4), Rect::new(14, 11, 14, 6))]
+    #[case::exact((5, 10, 5, 10), Rect::new(20, 15, 0, 0))]
+    #[case::larger_than_rect((20, 30, 20, 30), Rect::new(30, 20, 0, 0))]
+    #[case::larger_on_one_side((0, 0, 0, 50), Rect::new(30, 10, 0, 10))]
+    #[case::max((u16::MAX, u16::MAX, u16::MAX, u16::MAX), Rect::new(30, 20, 0, 0))]
+    fn inset(#[case] (top, right, bottom, left): (u16, u16, u16, u16), #[case] expected: Rect) {
+        let rect = Rect::new(10, 10, 20, 10);
+        assert_eq!(rect.inset(top, right, bottom, left), expected);
+    }
+
+    #[rstest]
+    #[case::none(Rect::new(10, 10, 20, 10), (0, 0, 0, 0), Rect::new(10, 10, 20, 10))]
+    #[case::each_side(Rect::new(10, 10, 20, 10), (1, 2, 3, 4), Rect::new(6, 9, 26, 14))]
+    #[case::past_origin(Rect::new(1, 2, 3, 4), (5, 0, 0, 5), Rect::new(0, 0, 4, 6))]
+    #[case::past_max(
+        Rect::new(u16::MAX - 5, u16::MAX - 5, 3, 3),
+        (0, 10, 10, 0),
+        Rect::new(u16::MAX - 5, u16::MAX - 5, 5, 5)
+    )]
+    fn outset(
+        #[case] rect: Rect,
+        #[case] (top, right, bottom, left): (u16, u16, u16, u16),
+        #[case] expected: Rect,
+    ) {
+        assert_eq!(rect.outset(top, right, bottom, left), expected);
+    }
+
+    #[test]
+    fn inset_then_outset() {
+        let rect = Rect::new(10, 10, 20, 10);
+        assert_eq!(rect.inset(1, 2, 3, 4).outset(1, 2, 3, 4), rect);
+    }
+
     #[test]
     fn offset() {
         assert_eq!(
@@ -568,6 +791,49 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::overlapping(Rect::new(1, 2, 3, 4), Rect::new(2, 3, 4, 5), Rect::new(1, 2, 5, 6))]
+    #[case::contained(
+        Rect::new(0, 0, 10, 10),
+        Rect::new(2, 2, 3, 3),
+        Rect::new(0, 0, 10, 10)
+    )]
+    #[case::disjoint(Rect::new(0, 0, 2, 2), Rect::new(5, 6, 1, 1), Rect::new(0, 0, 6, 7))]
+    fn union_cases(#[case] a: Rect, #[case] b: Rect, #[case] expected: Rect) {
+        assert_eq!(a.union(b), expected);
+        assert_eq!(b.union(a), expected);
+    }
+
+    #[rstest]
+    #[case::overlapping(
+        Rect::new(1, 2, 3, 4),
+        Rect::new(2, 3, 4, 5),
+        Some(Rect::new(2, 3, 2, 3))
+    )]
+    #[case::contained(
+        Rect::new(0, 0, 10, 10),
+        Rect::new(2, 2, 3, 3),
+        Some(Rect::new(2, 2, 3, 3))
+    )]
+    #[case::disjoint(Rect::new(0, 0, 2, 2), Rect::new(5, 6, 1, 1), None)]
+    #[case::adjacent(Rect::new(0, 0, 2, 2), Rect::new(2, 0, 2, 2), None)]
+    #[case::empty(Rect::new(0, 0, 10, 10), Rect::new(2, 2, 0, 0), None)]
+    fn checked_intersection(#[case] a: Rect, #[case] b: Rect, #[case] expected: Option<Rect>) {
+        assert_eq!(a.checked_intersection(b), expected);
+        assert_eq!(b.checked_intersection(a), expected);
+    }
+
+    #[rstest]
+    #[case::same(Rect::new(1, 2, 3, 4), true)]
+    #[case::contained(Rect::new(2, 3, 1, 1), true)]
+    #[case::overlapping(Rect::new(2, 3, 4, 5), false)]
+    #[case::disjoint(Rect::new(10, 10, 1, 1), false)]
+    #[case::empty_on_edge(Rect::new(4, 6, 0, 0), true)]
+    #[case::empty_outside(Rect::new(5, 6, 0, 0), false)]
+    fn contains_rect(#[case] other: Rect, #[case] expected: bool) {
+        assert_eq!(Rect::new(1, 2, 3, 4).contains_rect(other), expected);
+    }
+
     #[test]
     fn intersects() {
         assert!(Rect::new(1, 2, 3, 4).intersects(Rect::new(2, 3, 4, 5)));
@@ -756,4 +1022,87 @@ mod tests {
             Rect::new(1, 2, 3, 1)
         );
     }
+
+    #[test]
+    fn grid() {
+        let area = Rect::new(0, 0, 10, 10);
+        let tiles = area.grid(3, 3);
+        assert_eq!(
+            tiles,
+            [
+                Rect::new(0, 0, 4, 4),
+                Rect::new(4, 0, 3, 4),
+                Rect::new(7, 0, 3, 4),
+                Rect::new(0, 4, 4, 3),
+                Rect::new(4, 4, 3, 3),
+                Rect::new(7, 4, 3, 3),
+                Rect::new(0, 7, 4, 3),
+                Rect::new(4, 7, 3, 3),
+                Rect::new(7, 7, 3, 3),
+            ]
+        );
+        // every position is covered by exactly one tile
+        for position in area.positions() {
+            let count = tiles.iter().filter(|tile| tile.contains(position)).count();
+            assert_eq!(count, 1, "{position:?} is covered by {count} tiles");
+        }
+    }
+
+    #[test]
+    fn grid_offset() {
+        assert_eq!(
+            Rect::new(5, 10, 4, 2).grid(2, 2),
+            [
+                Rect::new(5, 10, 2, 1),
+                Rect::new(7, 10, 2, 1),
+                Rect::new(5, 11, 2, 1),
+                Rect::new(7, 11, 2, 1),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case::no_rows(0, 3)]
+    #[case::no_columns(3, 0)]
+    fn grid_empty(#[case] rows: u16, #[case] columns: u16) {
+        assert_eq!(Rect::new(0, 0, 10, 10).grid(rows, columns), []);
+    }
+
+    #[test]
+    fn grid_more_tiles_than_cells() {
+        assert_eq!(
+            Rect::new(0, 0, 2, 1).grid(1, 3),
+            [
+                Rect::new(0, 0, 1, 1),
+                Rect::new(1, 0, 1, 1),
+                Rect::new(2, 0, 0, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn grid_with_gutter() {
+        let area = Rect::new(0, 0, 10, 10);
+        let tiles = area.grid_with_gutter(3, 3, 1);
+        assert_eq!(
+            tiles,
+            [
+                Rect::new(0, 0, 3, 3),
+                Rect::new(4, 0, 3, 3),
+                Rect::new(8, 0, 2, 3),
+                Rect::new(0, 4, 3, 3),
+                Rect::new(4, 4, 3, 3),
+                Rect::new(8, 4, 2, 3),
+                Rect::new(0, 8, 3, 2),
+                Rect::new(4, 8, 3, 2),
+                Rect::new(8, 8, 2, 2),
+            ]
+        );
+        for (i, a) in tiles.iter().enumerate() {
+            assert_eq!(area.union(*a), area, "{a} is outside of {area}");
+            for b in &tiles[i + 1..] {
+                assert!(!a.intersects(*b), "{a} overlaps {b}");
+            }
+        }
+    }
 }
@@ -72,12 +72,14 @@ use core::fmt;
 
 use bitflags::bitflags;
 pub use color::{Color, ParseColorError};
+pub use gradient::Gradient;
 use stylize::ColorDebugKind;
 pub use stylize::{Styled, Stylize};
 
 #[cfg(feature = "anstyle")]
 mod anstyle;
 mod color;
+mod gradient;
 pub mod palette;
 #[cfg(feature = "palette")]
 mod palette_conversion;
@@ -136,6 +136,342 @@ impl Color {
         let b = u as u8;
         Self::Rgb(r, g, b)
     }
+
+    /// Parses a hex color code into a [`Color::Rgb`].
+    ///
+    /// The code has either 6 digits (`rrggbb`) or 3 digits (`rgb`, where each digit is repeated,
+    /// so `f80` is the same as `ff8800`), with or without a leading `#`. The digits are case
+    /// insensitive. Returns a [`ParseColorError`] if the code is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::from_hex("#1e90ff"), Ok(Color::Rgb(30, 144, 255)));
+    /// assert_eq!(Color::from_hex("1E90FF"), Ok(Color::Rgb(30, 144, 255)));
+    /// assert_eq!(Color::from_hex("#f80"), Ok(Color::Rgb(255, 136, 0)));
+    /// assert!(Color::from_hex("#12345").is_err());
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Self, ParseColorError> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex).as_bytes();
+        let mut nibbles = [0_u8; 6];
+        for (nibble, &digit) in nibbles.iter_mut().zip(digits) {
+            *nibble = char::from(digit).to_digit(16).ok_or(ParseColorError)? as u8;
+        }
+        let byte = |high: u8, low: u8| high << 4 | low;
+        match (digits.len(), nibbles) {
+            (3, [r, g, b, ..]) => Ok(Self::Rgb(byte(r, r), byte(g, g), byte(b, b))),
+            (6, [r1, r2, g1, g2, b1, b2]) => {
+                Ok(Self::Rgb(byte(r1, r2), byte(g1, g2), byte(b1, b2)))
+            }
+            _ => Err(ParseColorError),
+        }
+    }
+
+    /// Mixes this color with another color.
+    ///
+    /// Both colors are converted to RGB and linearly interpolated, where a `t` of 0.0 returns this
+    /// color and a `t` of 1.0 returns the other color, as an [`Color::Rgb`]. `t` is clamped between
+    /// 0.0 and 1.0. The named and indexed colors are converted with [`Color::to_rgb`].
+    ///
+    /// [`Color::Reset`] has no RGB value, so blending it with another color returns the other
+    /// color unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// let red = Color::Rgb(255, 0, 0);
+    /// let blue = Color::Rgb(0, 0, 255);
+    /// assert_eq!(red.blend(blue, 0.25), Color::Rgb(191, 0, 64));
+    /// assert_eq!(Color::Reset.blend(blue, 0.25), blue);
+    /// ```
+    #[must_use = "method returns the blended color"]
+    pub fn blend(self, other: Self, t: f64) -> Self {
+        let (Some((r1, g1, b1)), Some((r2, g2, b2))) = (self.to_rgb(), other.to_rgb()) else {
+            return if self == Self::Reset { other } else { self };
+        };
+        let t = t.clamp(0.0, 1.0);
+        // the interpolated values are between 0 and 255 so adding 0.5 rounds them
+        let mix = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t + 0.5) as u8;
+        Self::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+    }
+
+    /// Converts the color to its RGB value, or `None` for [`Color::Reset`].
+    ///
+    /// The actual values of the named and indexed colors depend on the terminal, so they are
+    /// converted to the values of the default xterm palette:
+    ///
+    /// - the 16 named colors and the indexes 0 to 15 are the standard ANSI colors
+    /// - the indexes 16 to 231 are a 6x6x6 color cube, with each component in the levels 0, 95,
+    ///   135, 175, 215 and 255
+    /// - the indexes 232 to 255 are a grayscale ramp from (8, 8, 8) to (238, 238, 238) in steps of
+    ///   10
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Red.to_rgb(), Some((128, 0, 0)));
+    /// assert_eq!(Color::Indexed(67).to_rgb(), Some((95, 135, 175)));
+    /// assert_eq!(Color::Indexed(244).to_rgb(), Some((128, 128, 128)));
+    /// assert_eq!(Color::Rgb(1, 2, 3).to_rgb(), Some((1, 2, 3)));
+    /// assert_eq!(Color::Reset.to_rgb(), None);
+    /// ```
+    pub const fn to_rgb(self) -> Option<(u8, u8, u8)> {
+        let index = match self {
+            Self::Reset => return None,
+            Self::Rgb(r, g, b) => return Some((r, g, b)),
+            Self::Black => 0,
+            Self::Red => 1,
+            Self::Green => 2,
+            Self::Yellow => 3,
+            Self::Blue => 4,
+            Self::Magenta => 5,
+            Self::Cyan => 6,
+            Self::Gray => 7,
+            Self::DarkGray => 8,
+            Self::LightRed => 9,
+            Self::LightGreen => 10,
+            Self::LightYellow => 11,
+            Self::LightBlue => 12,
+            Self::LightMagenta => 13,
+            Self::LightCyan => 14,
+            Self::White => 15,
+            Self::Indexed(index) => index,
+        };
+        Some(indexed_rgb(index))
+    }
+
+    /// Returns the contrast ratio between this color and another color.
+    ///
+    /// The ratio is computed from the relative luminance of the colors as defined by the [WCAG],
+    /// and ranges from 1.0 for colors of the same luminance to 21.0 for black and white. The WCAG
+    /// recommends a ratio of at least 4.5 between text and its background. The named and indexed
+    /// colors are converted with [`Color::to_rgb`], and the ratio is 1.0 when either color is
+    /// [`Color::Reset`], as its actual value is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Black.contrast_ratio(Color::White), 21.0);
+    /// assert!(Color::Rgb(0, 0, 139).contrast_ratio(Color::White) > 4.5);
+    /// ```
+    ///
+    /// [WCAG]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    pub fn contrast_ratio(self, other: Self) -> f64 {
+        let (Some(a), Some(b)) = (self.relative_luminance(), other.relative_luminance()) else {
+            return 1.0;
+        };
+        let (lighter, darker) = if a > b { (a, b) } else { (b, a) };
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    /// Returns whichever of [`Color::Black`] and [`Color::White`] has the highest contrast with
+    /// the given background color.
+    ///
+    /// This is useful to pick a readable text color over an arbitrary background. See
+    /// [`Color::contrast_ratio`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::best_text_color(Color::Rgb(0, 0, 139)), Color::White);
+    /// assert_eq!(
+    ///     Color::best_text_color(Color::Rgb(255, 255, 204)),
+    ///     Color::Black
+    /// );
+    /// ```
+    pub fn best_text_color(background: Self) -> Self {
+        if Self::White.contrast_ratio(background) >= Self::Black.contrast_ratio(background) {
+            Self::White
+        } else {
+            Self::Black
+        }
+    }
+
+    /// Creates a [`Color::Rgb`] from its hue, saturation and lightness.
+    ///
+    /// The hue is in degrees and wraps around, so that `-120.0`, `240.0` and `600.0` are the same
+    /// hue. The saturation and lightness are clamped between 0.0 and 1.0. This is convenient for
+    /// generating palettes, for example with colors of evenly spaced hues. Unlike
+    /// [`Color::from_hsl`], this doesn't need the `palette` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::from_hsl_values(0.0, 1.0, 0.5), Color::Rgb(255, 0, 0));
+    /// assert_eq!(
+    ///     Color::from_hsl_values(-120.0, 1.0, 0.5),
+    ///     Color::Rgb(0, 0, 255)
+    /// );
+    /// assert_eq!(
+    ///     Color::from_hsl_values(0.0, 0.0, 0.5),
+    ///     Color::Rgb(128, 128, 128)
+    /// );
+    ///
+    /// // six colors with evenly spaced hues
+    /// let palette: Vec<Color> = (0..6)
+    ///     .map(|i| Color::from_hsl_values(f64::from(i) * 60.0, 0.8, 0.6))
+    ///     .collect();
+    /// ```
+    pub fn from_hsl_values(hue: f64, saturation: f64, lightness: f64) -> Self {
+        let hue = hue % 360.0;
+        let hue = if hue < 0.0 { hue + 360.0 } else { hue };
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let sector = hue / 60.0;
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+        let (r, g, b) = match sector as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let min = lightness - chroma / 2.0;
+        // the components are between 0 and 255 so adding 0.5 rounds them
+        let component = |value: f64| ((value + min) * 255.0 + 0.5) as u8;
+        Self::Rgb(component(r), component(g), component(b))
+    }
+
+    /// Converts the color to its hue, saturation and lightness, or `None` for [`Color::Reset`].
+    ///
+    /// The hue is in degrees between 0.0 and 360.0, and the saturation and lightness are between
+    /// 0.0 and 1.0. The hue and saturation of grays are 0.0. The named and indexed colors are
+    /// converted from their RGB value, see [`Color::to_rgb`]. This is the inverse of
+    /// [`Color::from_hsl_values`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Rgb(255, 0, 0).to_hsl(), Some((0.0, 1.0, 0.5)));
+    /// assert_eq!(Color::Rgb(0, 0, 255).to_hsl(), Some((240.0, 1.0, 0.5)));
+    /// assert_eq!(Color::Reset.to_hsl(), None);
+    /// ```
+    pub fn to_hsl(self) -> Option<(f64, f64, f64)> {
+        let (r, g, b) = self.to_rgb()?;
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let lightness = (f64::from(max) + f64::from(min)) / 510.0;
+        if max == min {
+            return Some((0.0, 0.0, lightness));
+        }
+
+        let delta = f64::from(max - min) / 255.0;
+        let saturation = (delta / (1.0 - (2.0 * lightness - 1.0).abs())).min(1.0);
+        let [red, green, blue] = [r, g, b].map(|component| f64::from(component) / 255.0);
+        let sector = if max == r {
+            (green - blue) / delta
+        } else if max == g {
+            (blue - red) / delta + 2.0
+        } else {
+            (red - green) / delta + 4.0
+        };
+        let hue = sector * 60.0;
+        let hue = if hue < 0.0 { hue + 360.0 } else { hue };
+        Some((hue, saturation, lightness))
+    }
+
+    /// Returns the relative luminance of the color as defined by the WCAG, from 0.0 for black to
+    /// 1.0 for white, or `None` for [`Color::Reset`].
+    fn relative_luminance(self) -> Option<f64> {
+        let (r, g, b) = self.to_rgb()?;
+        let linear = |component: u8| {
+            let value = f64::from(component) / 255.0;
+            if value <= 0.03928 {
+                value / 12.92
+            } else {
+                gamma_expand((value + 0.055) / 1.055)
+            }
+        };
+        Some(0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b))
+    }
+}
+
+// Used instead of `f64::powf` directly, to provide a fallback for `no_std`.
+#[cfg(feature = "std")]
+#[inline]
+fn gamma_expand(value: f64) -> f64 {
+    value.powf(2.4)
+}
+
+// A fallback for `no_std` in pure rust, which computes the power of 2.4 of a value between 0 and 1
+// as its square times the fifth root of its square, found with Newton's method.
+#[cfg(not(feature = "std"))]
+fn gamma_expand(value: f64) -> f64 {
+    let square = value * value;
+    if square <= 0.0 {
+        return 0.0;
+    }
+    let mut root = 1.0_f64;
+    for _ in 0..64 {
+        let next = (4.0 * root + square / (root * root * root * root)) / 5.0;
+        if next >= root {
+            break;
+        }
+        root = next;
+    }
+    square * root
+}
+
+/// The RGB values of the 16 standard colors of the default xterm palette.
+const STANDARD_RGB: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (128, 0, 0),
+    (0, 128, 0),
+    (128, 128, 0),
+    (0, 0, 128),
+    (128, 0, 128),
+    (0, 128, 128),
+    (192, 192, 192),
+    (128, 128, 128),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (0, 0, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// The levels of each component of the 6x6x6 color cube of the 256 color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Returns the RGB value of an index of the 256 color palette.
+///
+/// The indexes 0 to 15 are the standard colors, 16 to 231 are a 6x6x6 color cube and 232 to 255
+/// are a grayscale ramp from dark to light.
+const fn indexed_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => STANDARD_RGB[index as usize],
+        16..=231 => {
+            let cube = index - 16;
+            (
+                CUBE_LEVELS[(cube / 36) as usize],
+                CUBE_LEVELS[(cube / 6 % 6) as usize],
+                CUBE_LEVELS[(cube % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -516,7 +852,6 @@ mod tests {
 
     #[cfg(feature = "palette")]
     use palette::{Hsl, Hsluv};
-    #[cfg(feature = "palette")]
     use rstest::rstest;
     #[cfg(feature = "serde")]
     use serde::de::{Deserialize, IntoDeserializer};
@@ -785,4 +1120,167 @@ mod tests {
         let from_tuple4 = Color::from((200, 150, 100, 0));
         assert_eq!(from_tuple4, Color::Rgb(200, 150, 100));
     }
+
+    #[rstest]
+    #[case::start(0.0, Color::Rgb(128, 0, 0))]
+    #[case::middle(0.5, Color::Rgb(64, 0, 64))]
+    #[case::end(1.0, Color::Rgb(0, 0, 128))]
+    #[case::before_start(-1.0, Color::Rgb(128, 0, 0))]
+    #[case::after_end(2.0, Color::Rgb(0, 0, 128))]
+    fn blend_red_and_blue(#[case] t: f64, #[case] expected: Color) {
+        assert_eq!(Color::Red.blend(Color::Blue, t), expected);
+    }
+
+    #[rstest]
+    #[case::rgb(Color::Rgb(255, 0, 0), Color::Rgb(0, 0, 255), Color::Rgb(128, 0, 128))]
+    #[case::named(Color::Black, Color::White, Color::Rgb(128, 128, 128))]
+    #[case::indexed(Color::Indexed(16), Color::Indexed(231), Color::Rgb(128, 128, 128))]
+    #[case::grayscale(Color::Indexed(232), Color::Indexed(255), Color::Rgb(123, 123, 123))]
+    #[case::reset_self(Color::Reset, Color::Green, Color::Green)]
+    #[case::reset_other(Color::Green, Color::Reset, Color::Green)]
+    #[case::reset_both(Color::Reset, Color::Reset, Color::Reset)]
+    fn blend(#[case] color: Color, #[case] other: Color, #[case] expected: Color) {
+        assert_eq!(color.blend(other, 0.5), expected);
+    }
+
+    #[rstest]
+    #[case::reset(Color::Reset, None)]
+    #[case::rgb(Color::Rgb(1, 2, 3), Some((1, 2, 3)))]
+    #[case::named(Color::Gray, Some((192, 192, 192)))]
+    #[case::bright_named(Color::LightBlue, Some((0, 0, 255)))]
+    #[case::standard_index(Color::Indexed(1), Some((128, 0, 0)))]
+    #[case::cube_start(Color::Indexed(16), Some((0, 0, 0)))]
+    #[case::cube(Color::Indexed(67), Some((95, 135, 175)))]
+    #[case::cube_red(Color::Indexed(196), Some((255, 0, 0)))]
+    #[case::cube_end(Color::Indexed(231), Some((255, 255, 255)))]
+    #[case::grayscale_start(Color::Indexed(232), Some((8, 8, 8)))]
+    #[case::grayscale(Color::Indexed(244), Some((128, 128, 128)))]
+    #[case::grayscale_end(Color::Indexed(255), Some((238, 238, 238)))]
+    fn to_rgb(#[case] color: Color, #[case] expected: Option<(u8, u8, u8)>) {
+        assert_eq!(color.to_rgb(), expected);
+    }
+
+    #[test]
+    fn to_rgb_named_matches_index() {
+        let named = [
+            Color::Black,
+            Color::Red,
+            Color::Green,
+            Color::Yellow,
+            Color::Blue,
+            Color::Magenta,
+            Color::Cyan,
+            Color::Gray,
+            Color::DarkGray,
+            Color::LightRed,
+            Color::LightGreen,
+            Color::LightYellow,
+            Color::LightBlue,
+            Color::LightMagenta,
+            Color::LightCyan,
+            Color::White,
+        ];
+        for (index, color) in (0..).zip(named) {
+            assert_eq!(color.to_rgb(), Color::Indexed(index).to_rgb(), "{color}");
+        }
+    }
+
+    #[rstest]
+    #[case::long("#1e90ff", Color::Rgb(30, 144, 255))]
+    #[case::long_without_hash("1e90ff", Color::Rgb(30, 144, 255))]
+    #[case::uppercase("#1E90FF", Color::Rgb(30, 144, 255))]
+    #[case::black("#000000", Color::Rgb(0, 0, 0))]
+    #[case::short("#f80", Color::Rgb(255, 136, 0))]
+    #[case::short_without_hash("abc", Color::Rgb(170, 187, 204))]
+    #[case::short_white("#FFF", Color::Rgb(255, 255, 255))]
+    fn from_hex(#[case] hex: &str, #[case] expected: Color) {
+        assert_eq!(Color::from_hex(hex), Ok(expected));
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::hash_only("#")]
+    #[case::too_short("#12")]
+    #[case::between_lengths("#12345")]
+    #[case::too_long("#1234567")]
+    #[case::not_hex("#12345g")]
+    #[case::sign("+12345")]
+    #[case::double_hash("##123")]
+    #[case::non_ascii("#1é3")]
+    fn from_hex_invalid(#[case] hex: &str) {
+        assert_eq!(Color::from_hex(hex), Err(ParseColorError));
+    }
+
+    #[test]
+    fn contrast_ratio() {
+        assert!((Color::Black.contrast_ratio(Color::White) - 21.0).abs() < 1e-9);
+        assert!((Color::White.contrast_ratio(Color::Black) - 21.0).abs() < 1e-9);
+        assert!((Color::Red.contrast_ratio(Color::Red) - 1.0).abs() < 1e-9);
+        assert!((Color::Reset.contrast_ratio(Color::White) - 1.0).abs() < 1e-9);
+        // #767676 is the lightest gray with the ratio of 4.5 recommended for text over white
+        let gray = Color::Rgb(118, 118, 118);
+        assert!((gray.contrast_ratio(Color::White) - 4.54).abs() < 0.01);
+        assert!((gray.contrast_ratio(Color::Black) - 4.62).abs() < 0.01);
+    }
+
+    #[rstest]
+    #[case::red(0.0, 1.0, 0.5, Color::Rgb(255, 0, 0))]
+    #[case::green(120.0, 1.0, 0.5, Color::Rgb(0, 255, 0))]
+    #[case::blue(240.0, 1.0, 0.5, Color::Rgb(0, 0, 255))]
+    #[case::gray(0.0, 0.0, 0.5, Color::Rgb(128, 128, 128))]
+    #[case::black(200.0, 0.7, 0.0, Color::Rgb(0, 0, 0))]
+    #[case::white(200.0, 0.7, 1.0, Color::Rgb(255, 255, 255))]
+    #[case::pastel(120.0, 0.5, 0.75, Color::Rgb(159, 223, 159))]
+    #[case::negative_hue(-120.0, 1.0, 0.5, Color::Rgb(0, 0, 255))]
+    #[case::wrapped_hue(480.0, 1.0, 0.5, Color::Rgb(0, 255, 0))]
+    #[case::full_turn(360.0, 1.0, 0.5, Color::Rgb(255, 0, 0))]
+    #[case::clamped(0.0, 2.0, -1.0, Color::Rgb(0, 0, 0))]
+    fn from_hsl_values(
+        #[case] hue: f64,
+        #[case] saturation: f64,
+        #[case] lightness: f64,
+        #[case] expected: Color,
+    ) {
+        assert_eq!(Color::from_hsl_values(hue, saturation, lightness), expected);
+    }
+
+    #[test]
+    fn to_hsl() {
+        assert_eq!(Color::Rgb(255, 0, 0).to_hsl(), Some((0.0, 1.0, 0.5)));
+        assert_eq!(Color::Rgb(0, 255, 0).to_hsl(), Some((120.0, 1.0, 0.5)));
+        assert_eq!(Color::Rgb(255, 0, 255).to_hsl(), Some((300.0, 1.0, 0.5)));
+        assert_eq!(Color::White.to_hsl(), Some((0.0, 0.0, 1.0)));
+        assert_eq!(Color::Reset.to_hsl(), None);
+        let (hue, saturation, lightness) = Color::Rgb(128, 128, 128).to_hsl().unwrap();
+        assert_eq!((hue, saturation), (0.0, 0.0));
+        assert!((lightness - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn hsl_round_trip() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let color = Color::Rgb(r, g, b);
+                    let (hue, saturation, lightness) = color.to_hsl().unwrap();
+                    assert_eq!(
+                        Color::from_hsl_values(hue, saturation, lightness),
+                        color,
+                        "{hue} {saturation} {lightness}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[rstest]
+    #[case::dark_blue(Color::Rgb(0, 0, 139), Color::White)]
+    #[case::pale_yellow(Color::Rgb(255, 255, 204), Color::Black)]
+    #[case::black(Color::Black, Color::White)]
+    #[case::white(Color::White, Color::Black)]
+    #[case::named_yellow(Color::LightYellow, Color::Black)]
+    #[case::indexed_navy(Color::Indexed(17), Color::White)]
+    fn best_text_color(#[case] background: Color, #[case] expected: Color) {
+        assert_eq!(Color::best_text_color(background), expected);
+    }
 }
@@ -0,0 +1,152 @@
+use alloc::vec::Vec;
+
+use crate::style::Color;
+
+/// A color gradient, made of colors at positions between which the colors are interpolated.
+///
+/// Each stop is a position and the color of the gradient at that position. The positions usually
+/// range from 0.0 to 1.0, e.g. the ratio of a [`Gauge`], but any range can be used. The color
+/// between two stops is interpolated in RGB with [`Color::blend`], and the colors before the first
+/// stop and after the last stop are the colors of these stops.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_core::style::{Color, Gradient};
+///
+/// let gradient = Gradient::new([
+///     (0.0, Color::Rgb(0, 255, 0)),
+///     (0.5, Color::Rgb(255, 255, 0)),
+///     (1.0, Color::Rgb(255, 0, 0)),
+/// ]);
+/// assert_eq!(gradient.sample(0.25), Color::Rgb(128, 255, 0));
+/// assert_eq!(gradient.sample(1.5), Color::Rgb(255, 0, 0));
+/// ```
+///
+/// [`Gauge`]: https://docs.rs/ratatui/latest/ratatui/widgets/struct.Gauge.html
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gradient {
+    /// The stops, sorted by position
+    stops: Vec<(f64, Color)>,
+}
+
+impl Gradient {
+    /// Creates a gradient from stops made of a position and a color.
+    ///
+    /// The stops can be given in any order, they are sorted by position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::{Color, Gradient};
+    ///
+    /// let gradient = Gradient::new([(1.0, Color::White), (0.0, Color::Black)]);
+    /// assert_eq!(gradient.stops(), [(0.0, Color::Black), (1.0, Color::White)]);
+    /// ```
+    pub fn new<I>(stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f64, Color)>,
+    {
+        let mut stops = stops.into_iter().collect::<Vec<_>>();
+        stops.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        Self { stops }
+    }
+
+    /// Returns the stops of the gradient, sorted by position.
+    pub fn stops(&self) -> &[(f64, Color)] {
+        &self.stops
+    }
+
+    /// Returns the color of the gradient at the position `t`.
+    ///
+    /// The color is interpolated between the stops before and after `t`, and it is the color of
+    /// the first or the last stop when `t` is outside of the stops. A gradient without stops
+    /// returns [`Color::Reset`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::{Color, Gradient};
+    ///
+    /// let gradient = Gradient::new([(0.0, Color::Rgb(0, 0, 0)), (1.0, Color::Rgb(200, 100, 0))]);
+    /// assert_eq!(gradient.sample(0.5), Color::Rgb(100, 50, 0));
+    /// ```
+    #[must_use = "method returns the sampled color"]
+    pub fn sample(&self, t: f64) -> Color {
+        let Some(&(first_position, first_color)) = self.stops.first() else {
+            return Color::Reset;
+        };
+        if t <= first_position {
+            return first_color;
+        }
+        for stops in self.stops.windows(2) {
+            let [(start, start_color), (end, end_color)] = [stops[0], stops[1]];
+            if t <= end {
+                return if end > start {
+                    start_color.blend(end_color, (t - start) / (end - start))
+                } else {
+                    end_color
+                };
+            }
+        }
+        self.stops[self.stops.len() - 1].1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const RED: Color = Color::Rgb(255, 0, 0);
+    const BLUE: Color = Color::Rgb(0, 0, 255);
+
+    #[rstest]
+    #[case::before(-1.0, RED)]
+    #[case::start(0.0, RED)]
+    #[case::quarter(0.25, Color::Rgb(191, 0, 64))]
+    #[case::middle(0.5, Color::Rgb(128, 0, 128))]
+    #[case::end(1.0, BLUE)]
+    #[case::after(2.0, BLUE)]
+    fn sample_two_stops(#[case] t: f64, #[case] expected: Color) {
+        let gradient = Gradient::new([(0.0, RED), (1.0, BLUE)]);
+        assert_eq!(gradient.sample(t), expected);
+    }
+
+    #[rstest]
+    #[case::start(0.0, Color::Rgb(0, 0, 0))]
+    #[case::first_middle(0.25, Color::Rgb(128, 0, 0))]
+    #[case::middle_stop(0.5, RED)]
+    #[case::second_middle(0.75, Color::Rgb(255, 128, 128))]
+    #[case::end(1.0, Color::Rgb(255, 255, 255))]
+    fn sample_three_stops(#[case] t: f64, #[case] expected: Color) {
+        // the stops are sorted by position
+        let gradient = Gradient::new([
+            (1.0, Color::Rgb(255, 255, 255)),
+            (0.0, Color::Rgb(0, 0, 0)),
+            (0.5, RED),
+        ]);
+        assert_eq!(gradient.sample(t), expected);
+    }
+
+    #[test]
+    fn sample_stops_at_same_position() {
+        let gradient = Gradient::new([(0.0, RED), (0.5, RED), (0.5, BLUE), (1.0, BLUE)]);
+        assert_eq!(gradient.sample(0.5), RED);
+        assert_eq!(gradient.sample(0.51), BLUE);
+    }
+
+    #[test]
+    fn sample_without_stops() {
+        assert_eq!(Gradient::default().sample(0.5), Color::Reset);
+    }
+
+    #[test]
+    fn sample_single_stop() {
+        let gradient = Gradient::new([(0.5, RED)]);
+        assert_eq!(gradient.sample(0.0), RED);
+        assert_eq!(gradient.sample(1.0), RED);
+    }
+}
@@ -4,3 +4,81 @@
 
 pub mod material;
 pub mod tailwind;
+
+use alloc::vec::Vec;
+
+use crate::style::Color;
+
+/// The saturation of the colors returned by [`distinct`].
+const DISTINCT_SATURATION: f64 = 0.75;
+
+/// The lightness of the colors returned by [`distinct`].
+const DISTINCT_LIGHTNESS: f64 = 0.6;
+
+/// Returns `n` visually distinct colors, for example to tell apart the series of a chart.
+///
+/// The hues of the colors are evenly spaced around the HSL color wheel, starting from red, and
+/// all the colors have the same saturation and lightness, so that none of them stands out. The
+/// more colors there are, the closer their hues are to each other.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_core::style::Color;
+/// use ratatui_core::style::palette::distinct;
+///
+/// let colors = distinct(3);
+/// assert_eq!(colors.len(), 3);
+/// assert_eq!(colors[0], Color::from_hsl_values(0.0, 0.75, 0.6));
+/// assert_eq!(colors[1], Color::from_hsl_values(120.0, 0.75, 0.6));
+/// assert_eq!(colors[2], Color::from_hsl_values(240.0, 0.75, 0.6));
+/// ```
+pub fn distinct(n: usize) -> Vec<Color> {
+    let step = 360.0 / n as f64;
+    (0..n)
+        .map(|i| Color::from_hsl_values(i as f64 * step, DISTINCT_SATURATION, DISTINCT_LIGHTNESS))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeSet;
+
+    use super::*;
+
+    #[test]
+    fn distinct_empty() {
+        assert_eq!(distinct(0), Vec::new());
+    }
+
+    #[test]
+    fn distinct_single() {
+        assert_eq!(
+            distinct(1),
+            [Color::from_hsl_values(
+                0.0,
+                DISTINCT_SATURATION,
+                DISTINCT_LIGHTNESS
+            )]
+        );
+    }
+
+    #[test]
+    fn distinct_six() {
+        let colors = distinct(6);
+        assert_eq!(colors.len(), 6);
+        let unique: BTreeSet<_> = colors.iter().map(|color| color.to_rgb()).collect();
+        assert_eq!(unique.len(), 6);
+        for (i, color) in colors.iter().enumerate() {
+            let (hue, _, _) = color.to_hsl().unwrap();
+            let expected = i as f64 * 60.0;
+            assert!((hue - expected).abs() < 1.0, "{i}: {hue} != {expected}");
+        }
+    }
+
+    #[test]
+    fn distinct_many_are_unique() {
+        let unique: BTreeSet<_> = distinct(64).iter().map(|color| color.to_rgb()).collect();
+        assert_eq!(unique.len(), 64);
+    }
+}
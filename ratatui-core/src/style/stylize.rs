@@ -2,7 +2,7 @@ use alloc::borrow::Cow;
 use alloc::string::{String, ToString};
 use core::fmt;
 
-use crate::style::{Color, Modifier, Style};
+use crate::style::{Color, Modifier, ParseColorError, Style};
 use crate::text::Span;
 
 /// A trait for objects that have a `Style`.
@@ -237,6 +237,54 @@ pub trait Stylize<'a, T>: Sized {
     fn fg<C: Into<Color>>(self, color: C) -> T;
     #[must_use = "`reset` returns the modified style without modifying the original"]
     fn reset(self) -> T;
+
+    /// Sets the foreground color from a hex color code, see [`Color::from_hex`] for the accepted
+    /// formats.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseColorError`] if the code is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::{Color, Style, Stylize};
+    /// use ratatui_core::text::Span;
+    ///
+    /// let span = "hello".fg_hex("#1e90ff")?;
+    /// assert_eq!(
+    ///     span,
+    ///     Span::styled("hello", Style::new().fg(Color::Rgb(30, 144, 255)))
+    /// );
+    /// # Ok::<(), ratatui_core::style::ParseColorError>(())
+    /// ```
+    fn fg_hex(self, hex: &str) -> Result<T, ParseColorError> {
+        Ok(self.fg(Color::from_hex(hex)?))
+    }
+
+    /// Sets the background color from a hex color code, see [`Color::from_hex`] for the accepted
+    /// formats.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseColorError`] if the code is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::{Color, Style, Stylize};
+    /// use ratatui_core::text::Span;
+    ///
+    /// let span = "hello".bg_hex("#f80")?;
+    /// assert_eq!(
+    ///     span,
+    ///     Span::styled("hello", Style::new().bg(Color::Rgb(255, 136, 0)))
+    /// );
+    /// # Ok::<(), ratatui_core::style::ParseColorError>(())
+    /// ```
+    fn bg_hex(self, hex: &str) -> Result<T, ParseColorError> {
+        Ok(self.bg(Color::from_hex(hex)?))
+    }
     #[must_use = "`add_modifier` returns the modified style without modifying the original"]
     fn add_modifier(self, modifier: Modifier) -> T;
     #[must_use = "`remove_modifier` returns the modified style without modifying the original"]
@@ -540,6 +588,20 @@ mod tests {
         assert_eq!("hello".on_cyan(), Span::styled("hello", cyan_bg));
     }
 
+    #[test]
+    fn fg_hex() {
+        let fg = Style::default().fg(Color::Rgb(30, 144, 255));
+        assert_eq!("hello".fg_hex("#1e90ff"), Ok(Span::styled("hello", fg)));
+        assert_eq!("hello".fg_hex("#1e90f"), Err(ParseColorError));
+    }
+
+    #[test]
+    fn bg_hex() {
+        let bg = Style::default().bg(Color::Rgb(255, 136, 0));
+        assert_eq!("hello".bg_hex("f80"), Ok(Span::styled("hello", bg)));
+        assert_eq!("hello".bg_hex("orange"), Err(ParseColorError));
+    }
+
     #[test]
     fn color_modifier() {
         let cyan_bold = Style::default()
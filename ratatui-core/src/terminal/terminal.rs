@@ -1,4 +1,4 @@
-use crate::backend::{Backend, ClearType};
+use crate::backend::{Backend, ClearType, CursorShape};
 use crate::buffer::{Buffer, Cell};
 use crate::layout::{Position, Rect, Size};
 use crate::terminal::{CompletedFrame, Frame, TerminalOptions, Viewport};
@@ -62,6 +62,10 @@ where
     current: usize,
     /// Whether the cursor is currently hidden
     hidden_cursor: bool,
+    /// The shape of the cursor, which is restored to the default when the terminal is dropped
+    cursor_shape: CursorShape,
+    /// Whether bracketed paste mode is enabled, which is disabled when the terminal is dropped
+    bracketed_paste: bool,
     /// Viewport
     viewport: Viewport,
     /// Area of the viewport
@@ -95,6 +99,20 @@ where
                 std::eprintln!("Failed to show the cursor: {err}");
             }
         }
+        if self.cursor_shape != CursorShape::Default {
+            #[allow(unused_variables)]
+            if let Err(err) = self.set_cursor_shape(CursorShape::Default) {
+                #[cfg(feature = "std")]
+                std::eprintln!("Failed to restore the cursor shape: {err}");
+            }
+        }
+        if self.bracketed_paste {
+            #[allow(unused_variables)]
+            if let Err(err) = self.disable_bracketed_paste() {
+                #[cfg(feature = "std")]
+                std::eprintln!("Failed to disable bracketed paste: {err}");
+            }
+        }
     }
 }
 
@@ -157,6 +175,8 @@ where
             buffers: [Buffer::empty(viewport_area), Buffer::empty(viewport_area)],
             current: 0,
             hidden_cursor: false,
+            cursor_shape: CursorShape::Default,
+            bracketed_paste: false,
             viewport: options.viewport,
             viewport_area,
             last_known_area: area,
@@ -231,6 +251,49 @@ where
         Ok(())
     }
 
+    /// Changes the height of an inline viewport. This has no effect when the viewport is not
+    /// inline.
+    ///
+    /// A viewport that grows past the bottom of the screen scrolls the screen up, moving the lines
+    /// above it into the terminal's scrollback buffer. A viewport that shrinks keeps its top line
+    /// and clears the lines it no longer covers. The height is limited to the height of the
+    /// screen.
+    ///
+    /// The viewport is cleared, so it is redrawn entirely on the next draw call.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use ratatui::{backend::TestBackend, Terminal, TerminalOptions, Viewport};
+    ///
+    /// let backend = TestBackend::new(10, 10);
+    /// let mut terminal = Terminal::with_options(
+    ///     backend,
+    ///     TerminalOptions {
+    ///         viewport: Viewport::Inline(2),
+    ///     },
+    /// )?;
+    /// // show more details in the viewport
+    /// terminal.set_viewport_height(5)?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn set_viewport_height(&mut self, height: u16) -> Result<(), B::Error> {
+        if !matches!(self.viewport, Viewport::Inline(_)) {
+            return Ok(());
+        }
+        // The viewport grows from its top line, as it was first laid out from the cursor
+        let height = height.min(self.last_known_area.height);
+        self.backend
+            .set_cursor_position(self.viewport_area.as_position())?;
+        let (area, _) =
+            compute_inline_size(&mut self.backend, height, self.last_known_area.as_size(), 0)?;
+        self.viewport = Viewport::Inline(height);
+        self.set_viewport_area(area);
+        self.clear()?;
+        self.last_known_cursor_pos = area.as_position();
+        Ok(())
+    }
+
     fn set_viewport_area(&mut self, area: Rect) {
         self.buffers[self.current].resize(area);
         self.buffers[1 - self.current].resize(area);
@@ -434,6 +497,49 @@ where
         Ok(())
     }
 
+    /// Sets the shape of the cursor, such as a block, an underline, or a bar.
+    ///
+    /// The default shape is restored when the terminal is dropped if the shape was changed. This
+    /// has no effect on backends that don't support setting the cursor shape.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use ratatui::backend::CursorShape;
+    ///
+    /// terminal.set_cursor_shape(CursorShape::SteadyBar)?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn set_cursor_shape(&mut self, shape: CursorShape) -> Result<(), B::Error> {
+        self.backend.set_cursor_shape(shape)?;
+        self.cursor_shape = shape;
+        Ok(())
+    }
+
+    /// Enables bracketed paste mode, so that pasted text can be distinguished from typed input.
+    ///
+    /// The mode is disabled when the terminal is dropped if it was enabled. This has no effect on
+    /// backends that don't support bracketed paste, see [`Backend::enable_bracketed_paste`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// terminal.enable_bracketed_paste()?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn enable_bracketed_paste(&mut self) -> Result<(), B::Error> {
+        self.backend.enable_bracketed_paste()?;
+        self.bracketed_paste = true;
+        Ok(())
+    }
+
+    /// Disables bracketed paste mode, see [`Terminal::enable_bracketed_paste`].
+    pub fn disable_bracketed_paste(&mut self) -> Result<(), B::Error> {
+        self.backend.disable_bracketed_paste()?;
+        self.bracketed_paste = false;
+        Ok(())
+    }
+
     /// Gets the current cursor position.
     ///
     /// This is the position of the cursor after the last draw call and is returned as a tuple of
@@ -498,8 +604,8 @@ where
         self.backend.size()
     }
 
-    /// Insert some content before the current inline viewport. This has no effect when the
-    /// viewport is not inline.
+    /// Insert some content before the current inline or fullscreen viewport. This has no effect
+    /// when the viewport is fixed.
     ///
     /// The `draw_fn` closure will be called to draw into a writable `Buffer` that is `height`
     /// lines tall. The content of that `Buffer` will then be inserted before the viewport.
@@ -549,7 +655,8 @@ where
     ///
     /// If more lines are inserted than there is space on the screen, then the top lines will go
     /// directly into the terminal's scrollback buffer. At the limit, if the viewport takes up the
-    /// whole screen, all lines will be inserted directly into the scrollback buffer.
+    /// whole screen, all lines will be inserted directly into the scrollback buffer. This is always
+    /// the case with a fullscreen viewport, which stays at the top of the screen.
     ///
     /// # Examples
     ///
@@ -580,10 +687,14 @@ where
     {
         match self.viewport {
             #[cfg(feature = "scrolling-regions")]
-            Viewport::Inline(_) => self.insert_before_scrolling_regions(height, draw_fn),
+            Viewport::Inline(_) | Viewport::Fullscreen => {
+                self.insert_before_scrolling_regions(height, draw_fn)
+            }
             #[cfg(not(feature = "scrolling-regions"))]
-            Viewport::Inline(_) => self.insert_before_no_scrolling_regions(height, draw_fn),
-            _ => Ok(()),
+            Viewport::Inline(_) | Viewport::Fullscreen => {
+                self.insert_before_no_scrolling_regions(height, draw_fn)
+            }
+            Viewport::Fixed(_) => Ok(()),
         }
     }
 
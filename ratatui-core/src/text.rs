@@ -48,6 +48,9 @@
 //! ]);
 //! ```
 
+#[cfg(feature = "ansi")]
+mod ansi;
+
 mod grapheme;
 pub use grapheme::StyledGrapheme;
 
@@ -0,0 +1,254 @@
+//! Parsing of text with ANSI escape sequences into [`Text`].
+use alloc::vec::Vec;
+
+use crate::style::{Color, Modifier, Style};
+use crate::text::{Line, Span, Text};
+
+/// The escape character that starts the escape sequences.
+const ESC: char = '\x1b';
+
+/// The colors of the SGR codes 30 to 37 (and 40 to 47) followed by those of the codes 90 to 97
+/// (and 100 to 107).
+const ANSI_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+impl<'a> Text<'a> {
+    /// Creates a [`Text`] from a string containing ANSI escape sequences, e.g. the colored output of
+    /// a program.
+    ///
+    /// The SGR (Select Graphic Rendition) sequences, such as `\x1b[1;31m`, are interpreted into the
+    /// styles of the spans of the text:
+    ///
+    /// - the 16 standard and bright foreground and background colors (30-37, 90-97, 40-47,
+    ///   100-107), the 256 indexed colors (`38;5;n` and `48;5;n`) and the RGB colors
+    ///   (`38;2;r;g;b` and `48;2;r;g;b`), as well as the default colors (39 and 49),
+    /// - the bold, dim, italic, underlined, blinking, reversed, hidden and crossed out modifiers
+    ///   (1-9) and the codes that turn them off (21-29),
+    /// - the reset code (0, or an empty sequence), after which the text has no style.
+    ///
+    /// The style carries over to the next lines, like in a terminal. The other escape sequences
+    /// (e.g. cursor movements or hyperlinks) and the unknown SGR codes are skipped. The content of
+    /// the spans is borrowed from `s`.
+    ///
+    /// This requires the `ansi` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::Stylize;
+    /// use ratatui_core::text::{Line, Text};
+    ///
+    /// let text = Text::from_ansi("\x1b[1;31mError:\x1b[0m file not found");
+    /// assert_eq!(
+    ///     text,
+    ///     Text::from(Line::from(vec!["Error:".red().bold(), " file not found".into()]))
+    /// );
+    /// ```
+    pub fn from_ansi(s: &'a str) -> Self {
+        let mut style = Style::new();
+        let lines = s.lines().map(|line| {
+            let mut spans = Vec::new();
+            let mut rest = line;
+            while let Some(start) = rest.find(ESC) {
+                let (content, sequence) = rest.split_at(start);
+                if !content.is_empty() {
+                    spans.push(Span::styled(content, style));
+                }
+                let (parameters, remainder) = split_escape_sequence(sequence);
+                if let Some(parameters) = parameters {
+                    apply_sgr(&mut style, parameters);
+                }
+                rest = remainder;
+            }
+            if !rest.is_empty() {
+                spans.push(Span::styled(rest, style));
+            }
+            Line::from(spans)
+        });
+        lines.collect()
+    }
+}
+
+/// Splits the escape sequence at the start of `s` from the rest of the string.
+///
+/// Returns the parameters of the sequence if it is an SGR sequence (e.g. `1;31` for `\x1b[1;31m`),
+/// and the string after the sequence. An unterminated sequence extends to the end of `s`.
+fn split_escape_sequence(s: &str) -> (Option<&str>, &str) {
+    let mut chars = s.char_indices().skip(1);
+    match chars.next() {
+        // Control Sequence Introducer: parameters and intermediate bytes, then a final byte
+        Some((_, '[')) => {
+            for (index, c) in chars {
+                if ('\x40'..='\x7e').contains(&c) {
+                    let (sequence, rest) = s.split_at(index + c.len_utf8());
+                    let parameters = sequence.get(2..index);
+                    return (parameters.filter(|_| c == 'm'), rest);
+                }
+            }
+            (None, "")
+        }
+        // Operating System Command: terminated by BEL or by ESC \
+        Some((_, ']')) => {
+            let bel = s.find('\x07').map(|index| index + 1);
+            let string_terminator = s.find("\x1b\\").map(|index| index + 2);
+            let end = match (bel, string_terminator) {
+                (Some(bel), Some(string_terminator)) => Some(bel.min(string_terminator)),
+                (end, None) | (None, end) => end,
+            };
+            (None, end.and_then(|end| s.get(end..)).unwrap_or_default())
+        }
+        // other sequences are made of the escape character and one character
+        Some((index, c)) => (None, s.get(index + c.len_utf8()..).unwrap_or_default()),
+        None => (None, ""),
+    }
+}
+
+/// Applies the parameters of an SGR sequence to `style`.
+fn apply_sgr(style: &mut Style, parameters: &str) {
+    // an empty code is a reset, and the codes that are not numbers are skipped
+    let mut codes = parameters.split([';', ':']).map(|code| {
+        if code.is_empty() {
+            Some(0)
+        } else {
+            code.parse::<u16>().ok()
+        }
+    });
+    while let Some(code) = codes.next() {
+        let Some(code) = code else {
+            continue;
+        };
+        match code {
+            0 => *style = Style::new(),
+            1 => style.add_modifier.insert(Modifier::BOLD),
+            2 => style.add_modifier.insert(Modifier::DIM),
+            3 => style.add_modifier.insert(Modifier::ITALIC),
+            4 => style.add_modifier.insert(Modifier::UNDERLINED),
+            5 => style.add_modifier.insert(Modifier::SLOW_BLINK),
+            6 => style.add_modifier.insert(Modifier::RAPID_BLINK),
+            7 => style.add_modifier.insert(Modifier::REVERSED),
+            8 => style.add_modifier.insert(Modifier::HIDDEN),
+            9 => style.add_modifier.insert(Modifier::CROSSED_OUT),
+            21 | 22 => style.add_modifier.remove(Modifier::BOLD | Modifier::DIM),
+            23 => style.add_modifier.remove(Modifier::ITALIC),
+            24 => style.add_modifier.remove(Modifier::UNDERLINED),
+            25 => style
+                .add_modifier
+                .remove(Modifier::SLOW_BLINK | Modifier::RAPID_BLINK),
+            27 => style.add_modifier.remove(Modifier::REVERSED),
+            28 => style.add_modifier.remove(Modifier::HIDDEN),
+            29 => style.add_modifier.remove(Modifier::CROSSED_OUT),
+            code @ 30..=37 => style.fg = Some(ANSI_COLORS[usize::from(code - 30)]),
+            38 => style.fg = extended_color(&mut codes).or(style.fg),
+            39 => style.fg = None,
+            code @ 40..=47 => style.bg = Some(ANSI_COLORS[usize::from(code - 40)]),
+            48 => style.bg = extended_color(&mut codes).or(style.bg),
+            49 => style.bg = None,
+            #[cfg(feature = "underline-color")]
+            58 => style.underline_color = extended_color(&mut codes).or(style.underline_color),
+            #[cfg(feature = "underline-color")]
+            59 => style.underline_color = None,
+            code @ 90..=97 => style.fg = Some(ANSI_COLORS[usize::from(code - 90 + 8)]),
+            code @ 100..=107 => style.bg = Some(ANSI_COLORS[usize::from(code - 100 + 8)]),
+            _ => {}
+        }
+    }
+}
+
+/// Reads the color of an extended color code (38, 48 or 58) from the following codes, which are
+/// `5;n` for an indexed color or `2;r;g;b` for an RGB color.
+fn extended_color(codes: &mut impl Iterator<Item = Option<u16>>) -> Option<Color> {
+    let mut component = || u8::try_from(codes.next()??).ok();
+    match component()? {
+        5 => Some(Color::Indexed(component()?)),
+        2 => Some(Color::Rgb(component()?, component()?, component()?)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::{format, vec};
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::Stylize;
+
+    #[test]
+    fn bold_red_then_reset() {
+        let text = Text::from_ansi("\x1b[1;31mbold red\x1b[0m plain");
+        let expected = Line::from(vec!["bold red".red().bold(), " plain".into()]);
+        assert_eq!(text, Text::from(expected));
+    }
+
+    #[test]
+    fn style_carries_over_lines() {
+        let text = Text::from_ansi("\x1b[32mgreen\nstill green\x1b[m\r\nplain");
+        let expected = Text::from(vec![
+            Line::from("green".green()),
+            Line::from("still green".green()),
+            Line::from("plain"),
+        ]);
+        assert_eq!(text, expected);
+    }
+
+    #[rstest]
+    #[case::standard_fg("\x1b[34m", Style::new().blue())]
+    #[case::standard_bg("\x1b[47m", Style::new().on_gray())]
+    #[case::bright_fg("\x1b[91m", Style::new().light_red())]
+    #[case::bright_bg("\x1b[107m", Style::new().on_white())]
+    #[case::indexed_fg("\x1b[38;5;208m", Style::new().fg(Color::Indexed(208)))]
+    #[case::indexed_bg("\x1b[48;5;17m", Style::new().bg(Color::Indexed(17)))]
+    #[case::rgb_fg("\x1b[38;2;255;128;0m", Style::new().fg(Color::Rgb(255, 128, 0)))]
+    #[case::rgb_bg("\x1b[48;2;1;2;3m", Style::new().bg(Color::Rgb(1, 2, 3)))]
+    #[case::colon_separated("\x1b[38:5:208m", Style::new().fg(Color::Indexed(208)))]
+    #[case::default_fg("\x1b[31;44;39m", Style::new().on_blue())]
+    #[case::default_bg("\x1b[31;44;49m", Style::new().red())]
+    #[case::modifiers("\x1b[1;3;4m", Style::new().bold().italic().underlined())]
+    #[case::dim_reversed("\x1b[2;7m", Style::new().dim().reversed())]
+    #[case::modifiers_off("\x1b[1;2;3;4;9m\x1b[22;24m", Style::new().italic().crossed_out())]
+    #[case::empty_reset("\x1b[1;31m\x1b[m", Style::new())]
+    #[case::unknown_code("\x1b[31;1000;73m", Style::new().red())]
+    #[case::incomplete_rgb("\x1b[32m\x1b[38;2;1m", Style::new().green())]
+    fn sgr(#[case] sequence: &str, #[case] expected: Style) {
+        let content = format!("{sequence}text");
+        let text = Text::from_ansi(&content);
+        assert_eq!(text, Text::from(Span::styled("text", expected)));
+    }
+
+    #[rstest]
+    #[case::cursor_movement("a\x1b[2Kb\x1b[1;1Hc")]
+    #[case::hyperlink("\x1b]8;;https://ratatui.rs\x07a\x1b]8;;\x1b\\bc")]
+    #[case::two_character_sequence("a\x1b7bc")]
+    #[case::unterminated_at_end("abc\x1b[1")]
+    #[case::escape_at_end("abc\x1b")]
+    fn skips_other_sequences(#[case] content: &str) {
+        let text = Text::from_ansi(content);
+        let content: String = text.lines[0].iter().map(|span| &*span.content).collect();
+        assert_eq!(content, "abc");
+        assert!(text.lines[0].iter().all(|span| span.style == Style::new()));
+    }
+
+    #[test]
+    fn without_escape_sequences() {
+        assert_eq!(Text::from_ansi("a\nb"), Text::from("a\nb"));
+        assert_eq!(Text::from_ansi(""), Text::default());
+    }
+}
@@ -12,6 +12,10 @@ const ZWSP: &str = "\u{200b}";
 pub struct StyledGrapheme<'a> {
     pub symbol: &'a str,
     pub style: Style,
+    /// The URL of the hyperlink the grapheme is part of, if any, see [`Span::hyperlink`]
+    ///
+    /// [`Span::hyperlink`]: crate::text::Span::hyperlink
+    pub hyperlink: Option<&'a str>,
 }
 
 impl<'a> StyledGrapheme<'a> {
@@ -25,9 +29,19 @@ impl<'a> StyledGrapheme<'a> {
         Self {
             symbol,
             style: style.into(),
+            hyperlink: None,
         }
     }
 
+    /// Sets the URL of the hyperlink the grapheme is part of.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn hyperlink(mut self, url: Option<&'a str>) -> Self {
+        self.hyperlink = url;
+        self
+    }
+
     pub fn is_whitespace(&self) -> bool {
         let symbol = self.symbol;
         symbol == ZWSP || symbol.chars().all(char::is_whitespace) && symbol != NBSP
@@ -60,6 +74,14 @@ mod tests {
         assert_eq!(sg.style, style);
     }
 
+    #[test]
+    fn hyperlink() {
+        let sg = StyledGrapheme::new("a", Style::new());
+        assert_eq!(sg.hyperlink, None);
+        let sg = sg.hyperlink(Some("https://ratatui.rs"));
+        assert_eq!(sg.hyperlink, Some("https://ratatui.rs"));
+    }
+
     #[test]
     fn style() {
         let style = Style::new().yellow();
@@ -1,12 +1,15 @@
 #![deny(missing_docs)]
 #![warn(clippy::pedantic, clippy::nursery, clippy::arithmetic_side_effects)]
-use alloc::borrow::Cow;
+use alloc::borrow::{Cow, ToOwned};
 use alloc::string::{String, ToString};
 use alloc::vec;
 use alloc::vec::Vec;
 use core::fmt;
+use core::ops::Range;
 
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_truncate::UnicodeTruncateStr;
+use unicode_width::UnicodeWidthStr;
 
 use crate::buffer::Buffer;
 use crate::layout::{Alignment, Rect};
@@ -439,6 +442,194 @@ impl<'a> Line<'a> {
         self.spans.iter().map(Span::width).sum()
     }
 
+    /// Truncates the line to `width` columns, ending it with `ellipsis` if it is too wide.
+    ///
+    /// The ellipsis counts toward the width, so a line that is wider than `width` keeps as many of
+    /// its first graphemes as fit in `width` minus the width of the ellipsis. The line is cut on a
+    /// grapheme boundary, so a wide grapheme (e.g. an emoji) that doesn't fully fit is removed and
+    /// the result can be narrower than `width`. The ellipsis is styled like the last visible span
+    /// and links to the [hyperlink](Span::hyperlink) of the span that is cut, if any. If the
+    /// ellipsis itself is wider than `width`, it is truncated, and an empty ellipsis adds no span.
+    /// Lines that fit in `width` are returned unchanged.
+    ///
+    /// `ellipsis` accepts any type that can be converted into a [`Cow<str>`] (e.g. `&str` or
+    /// `String`).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::Stylize;
+    /// use ratatui_core::text::Line;
+    ///
+    /// let line = Line::from(vec!["Hello".blue(), " world!".green()]);
+    /// assert_eq!(
+    ///     line.truncate_with_ellipsis(9, "…"),
+    ///     Line::from(vec!["Hello".blue(), " wo".green(), "…".green()])
+    /// );
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn truncate_with_ellipsis<E>(mut self, width: usize, ellipsis: E) -> Self
+    where
+        E: Into<Cow<'a, str>>,
+    {
+        if self.width() <= width {
+            return self;
+        }
+        let ellipsis = ellipsis.into();
+        let (ellipsis, ellipsis_width) = match ellipsis {
+            Cow::Borrowed(ellipsis) => {
+                let (ellipsis, ellipsis_width) = ellipsis.unicode_truncate(width);
+                (Cow::Borrowed(ellipsis), ellipsis_width)
+            }
+            Cow::Owned(ellipsis) => {
+                let (ellipsis, ellipsis_width) = ellipsis.unicode_truncate(width);
+                (Cow::Owned(ellipsis.to_string()), ellipsis_width)
+            }
+        };
+
+        let mut remaining_width = width.saturating_sub(ellipsis_width);
+        let mut ellipsis_style = self.spans.first().map(|span| span.style);
+        let mut ellipsis_hyperlink = None;
+        let mut spans = Vec::with_capacity(self.spans.len().saturating_add(1));
+        for span in self.spans {
+            if remaining_width == 0 {
+                ellipsis_hyperlink = span.hyperlink;
+                break;
+            }
+            let span_width = span.width();
+            if span_width <= remaining_width {
+                remaining_width = remaining_width.saturating_sub(span_width);
+                ellipsis_style = Some(span.style);
+                spans.push(span);
+                continue;
+            }
+            let (content, content_width) = span.content.unicode_truncate(remaining_width);
+            let content = content.to_string();
+            ellipsis_hyperlink.clone_from(&span.hyperlink);
+            if content_width > 0 {
+                ellipsis_style = Some(span.style);
+                spans.push(Span {
+                    content: content.into(),
+                    ..span
+                });
+            }
+            break;
+        }
+        if !ellipsis.is_empty() {
+            spans.push(Span {
+                content: ellipsis,
+                style: ellipsis_style.unwrap_or_default(),
+                hyperlink: ellipsis_hyperlink,
+            });
+        }
+        self.spans = spans;
+        self
+    }
+
+    /// Patches the style of the graphemes displayed in the columns `start..end` of the line with
+    /// `style`.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The columns are display columns, so a wide grapheme (e.g. a CJK character or an emoji) takes
+    /// two columns, and it is styled if any of its columns is in the range. The spans are split at
+    /// the bounds of the range, and the parts in the range keep the properties that `style`
+    /// doesn't set. The range is clamped to the width of the line, so an empty range or a range
+    /// after the end of the line leaves it unchanged.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Style, Stylize};
+    /// use ratatui_core::text::Line;
+    ///
+    /// let line = Line::from(vec!["Hello ".blue(), "world!".green()]);
+    /// assert_eq!(
+    ///     line.style_range(4, 8, Style::new().on_yellow()),
+    ///     Line::from(vec![
+    ///         "Hell".blue(),
+    ///         "o ".blue().on_yellow(),
+    ///         "wo".green().on_yellow(),
+    ///         "rld!".green(),
+    ///     ])
+    /// );
+    /// ```
+    ///
+    /// [`Color`]: crate::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style_range<S: Into<Style>>(mut self, start: usize, end: usize, style: S) -> Self {
+        // the byte range of the graphemes that have a column in `start..end`
+        let mut range: Option<Range<usize>> = None;
+        let (mut column, mut offset) = (0_usize, 0_usize);
+        let graphemes = self
+            .spans
+            .iter()
+            .flat_map(|span| span.content.graphemes(true));
+        for grapheme in graphemes {
+            let grapheme_end = column.saturating_add(grapheme.width().max(1));
+            if start < grapheme_end && column < end {
+                let range_start = range.map_or(offset, |range| range.start);
+                range = Some(range_start..offset.saturating_add(grapheme.len()));
+            }
+            column = column.saturating_add(grapheme.width());
+            offset = offset.saturating_add(grapheme.len());
+        }
+        if let Some(range) = range {
+            self.spans = style_spans(&self.spans, &[range], style.into());
+        }
+        self
+    }
+
+    /// Expands the tab characters of the line to the next tab stop, every `tab_width` columns.
+    ///
+    /// Tab characters are not displayed when a line is rendered, so this replaces each `\t` by as
+    /// many spaces as needed to reach the next multiple of `tab_width` columns from the start of
+    /// the line, e.g. to align the columns of code or tab-separated values. The columns are display
+    /// columns, so a wide character (e.g. a CJK character) before a tab counts for two columns. The
+    /// spaces keep the style of the span of the tab, and a width of 0 removes the tabs. The
+    /// [`Line::width`] of the result is the width of the line as it is rendered.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::Line;
+    ///
+    /// let line = Line::from("a\tb").expand_tabs(4);
+    /// assert_eq!(line, Line::from("a   b"));
+    /// assert_eq!(line.width(), 5);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn expand_tabs(mut self, tab_width: usize) -> Self {
+        let mut column = 0_usize;
+        for span in &mut self.spans {
+            if !span.content.contains('\t') {
+                column = column.saturating_add(span.width());
+                continue;
+            }
+            let mut content = String::with_capacity(span.content.len());
+            for grapheme in span.content.graphemes(true) {
+                if grapheme == "\t" {
+                    let spaces =
+                        tab_width.saturating_sub(column.checked_rem(tab_width).unwrap_or(0));
+                    content.extend(core::iter::repeat_n(' ', spaces));
+                    column = column.saturating_add(spaces);
+                } else {
+                    content.push_str(grapheme);
+                    column = column.saturating_add(grapheme.width());
+                }
+            }
+            span.content = Cow::Owned(content);
+        }
+        self
+    }
+
     /// Returns an iterator over the graphemes held by this line.
     ///
     /// `base_style` is the [`Style`] that will be patched with each grapheme [`Style`] to get
@@ -691,6 +882,17 @@ impl Line<'_> {
         area: Rect,
         buf: &mut Buffer,
         parent_alignment: Option<Alignment>,
+    ) {
+        self.render_aligned(area, buf, self.alignment.or(parent_alignment));
+    }
+
+    /// Renders the line on the first row of the area with the given alignment, ignoring
+    /// `Line::alignment`.
+    pub(crate) fn render_aligned(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        alignment: Option<Alignment>,
     ) {
         let area = area.intersection(buf.area);
         if area.is_empty() {
@@ -704,8 +906,6 @@ impl Line<'_> {
 
         buf.set_style(area, self.style);
 
-        let alignment = self.alignment.or(parent_alignment);
-
         let area_width = usize::from(area.width);
         let can_render_complete_line = line_width <= area_width;
         if can_render_complete_line {
@@ -733,6 +933,14 @@ impl Line<'_> {
 /// Renders all the spans of the line that should be visible.
 fn render_spans(spans: &[Span], mut area: Rect, buf: &mut Buffer, span_skip_width: usize) {
     for (span, span_width, offset) in spans_after_width(spans, span_skip_width) {
+        // the columns left by a wide grapheme cut at the start are padded with spaces
+        let padding = Rect {
+            width: offset.min(area.width),
+            ..area
+        };
+        for position in padding.positions() {
+            buf[position].set_symbol(" ").set_style(span.style);
+        }
         area = area.indent_x(offset);
         if area.is_empty() {
             break;
@@ -780,14 +988,66 @@ fn spans_after_width<'a>(
             // that takes that into account by indenting the start of the area
             let first_grapheme_offset = available_width.saturating_sub(actual_width);
             let first_grapheme_offset = u16::try_from(first_grapheme_offset).unwrap_or(u16::MAX);
-            (
-                Span::styled(content, span.style),
-                actual_width,
-                first_grapheme_offset,
-            )
+            let span = Span {
+                content: content.into(),
+                style: span.style,
+                hyperlink: span.hyperlink.clone(),
+            };
+            (span, actual_width, first_grapheme_offset)
         })
 }
 
+/// Splits `spans` at the bounds of `ranges`, which are sorted byte ranges of the content of the
+/// spans, and patches the parts in the ranges with `style`.
+pub(super) fn style_spans<'a>(
+    spans: &[Span<'a>],
+    ranges: &[Range<usize>],
+    style: Style,
+) -> Vec<Span<'a>> {
+    let mut styled = Vec::with_capacity(spans.len());
+    let mut offset = 0_usize;
+    for span in spans {
+        let span_range = offset..offset.saturating_add(span.content.len());
+        offset = span_range.end;
+        // the bounds of the parts of the span, relative to its start
+        let mut bounds = vec![0];
+        for range in ranges {
+            for bound in [range.start, range.end] {
+                if span_range.start < bound && bound < span_range.end {
+                    bounds.push(bound.saturating_sub(span_range.start));
+                }
+            }
+        }
+        bounds.push(span.content.len());
+        for part in bounds.windows(2) {
+            let (start, end) = (part[0], part[1]);
+            let absolute = span_range.start.saturating_add(start);
+            let in_match = ranges
+                .iter()
+                .any(|range| range.start <= absolute && absolute < range.end);
+            let content = match &span.content {
+                Cow::Borrowed(content) => {
+                    Cow::Borrowed(content.get(start..end).unwrap_or_default())
+                }
+                Cow::Owned(content) => {
+                    Cow::Owned(content.get(start..end).unwrap_or_default().to_owned())
+                }
+            };
+            let part_style = if in_match {
+                span.style.patch(style)
+            } else {
+                span.style
+            };
+            styled.push(Span {
+                style: part_style,
+                content,
+                hyperlink: span.hyperlink.clone(),
+            });
+        }
+    }
+    styled
+}
+
 /// A trait for converting a value to a [`Line`].
 ///
 /// This trait is automatically implemented for any type that implements the [`Display`] trait. As
@@ -926,6 +1186,170 @@ mod tests {
         assert_eq!(None, line.alignment);
     }
 
+    #[test]
+    fn truncate_with_ellipsis_fits() {
+        let line = Line::from(vec!["Hello".blue(), " world".green()]);
+        assert_eq!(line.clone().truncate_with_ellipsis(11, "…"), line);
+        assert_eq!(line.clone().truncate_with_ellipsis(20, "…"), line);
+    }
+
+    #[rstest]
+    #[case::inside_span(8, Line::from(vec!["Hello".blue(), " w".green(), "…".green()]))]
+    #[case::end_of_span(6, Line::from(vec!["Hello".blue(), "…".blue()]))]
+    #[case::inside_first_span(3, Line::from(vec!["He".blue(), "…".blue()]))]
+    #[case::only_ellipsis(1, Line::from(vec!["…".blue()]))]
+    #[case::zero_width(0, Line::default())]
+    fn truncate_with_ellipsis(#[case] width: usize, #[case] expected: Line) {
+        let line = Line::from(vec!["Hello".blue(), " world".green()]);
+        assert_eq!(line.truncate_with_ellipsis(width, "…"), expected);
+    }
+
+    #[test]
+    fn truncate_with_ellipsis_keeps_line_style_and_alignment() {
+        let line = Line::from("Hello world").red().centered();
+        let truncated = line.truncate_with_ellipsis(8, "...");
+        assert_eq!(truncated.spans, [Span::raw("Hello"), Span::raw("...")]);
+        assert_eq!(truncated.style, Style::new().red());
+        assert_eq!(truncated.alignment, Some(Alignment::Center));
+    }
+
+    #[test]
+    fn truncate_with_ellipsis_multi_byte_content() {
+        let line = Line::from("こんにちは世界");
+        assert_eq!(
+            line.clone().truncate_with_ellipsis(7, "…"),
+            Line::from(vec![Span::raw("こんに"), Span::raw("…")])
+        );
+        // a wide grapheme that doesn't fit is removed entirely
+        assert_eq!(
+            line.truncate_with_ellipsis(6, "…"),
+            Line::from(vec![Span::raw("こん"), Span::raw("…")])
+        );
+    }
+
+    #[test]
+    fn truncate_with_ellipsis_emoji_near_cut() {
+        let line = Line::from(vec!["ab".red(), "😃👨‍👩‍👧cd".blue()]);
+        assert_eq!(
+            line.clone().truncate_with_ellipsis(5, "…"),
+            Line::from(vec!["ab".red(), "😃".blue(), "…".blue()])
+        );
+        // the family emoji is a single grapheme made of several characters
+        assert_eq!(
+            line.clone().truncate_with_ellipsis(6, "…"),
+            Line::from(vec!["ab".red(), "😃".blue(), "…".blue()])
+        );
+        assert_eq!(
+            line.truncate_with_ellipsis(7, "…"),
+            Line::from(vec!["ab".red(), "😃👨‍👩‍👧".blue(), "…".blue()])
+        );
+    }
+
+    #[test]
+    fn truncate_with_ellipsis_keeps_hyperlink() {
+        let url = "https://ratatui.rs";
+        let line = Line::from(vec!["Hello".blue(), " world".green().hyperlink(url)]);
+        assert_eq!(
+            line.clone().truncate_with_ellipsis(8, "…").spans,
+            [
+                "Hello".blue(),
+                " w".green().hyperlink(url),
+                "…".green().hyperlink(url)
+            ]
+        );
+        // the ellipsis replaces the content of the cut span, so it links to it
+        assert_eq!(
+            line.clone().truncate_with_ellipsis(6, "…").spans,
+            ["Hello".blue(), "…".blue().hyperlink(url)]
+        );
+        assert_eq!(
+            line.truncate_with_ellipsis(8, "").spans,
+            ["Hello".blue(), " wo".green().hyperlink(url)]
+        );
+    }
+
+    #[test]
+    fn truncate_with_ellipsis_wider_than_width() {
+        let line = Line::from("Hello world".blue());
+        assert_eq!(
+            line.truncate_with_ellipsis(2, "..."),
+            Line::from(vec!["..".blue()])
+        );
+    }
+
+    #[test]
+    fn style_range_background_in_middle() {
+        let line = Line::from(vec!["one ".red(), "two".into(), " three".italic()]);
+        let highlighted = line.style_range(2, 9, Style::new().on_yellow());
+        let expected = Line::from(vec![
+            "on".red(),
+            "e ".red().on_yellow(),
+            "two".on_yellow(),
+            " t".italic().on_yellow(),
+            "hree".italic(),
+        ]);
+        assert_eq!(highlighted, expected);
+    }
+
+    #[rstest]
+    #[case::whole_span(0, 3, Line::from(vec!["abc".on_yellow(), "def".blue()]))]
+    #[case::clamped_end(4, 100, Line::from(vec!["abc".into(), "d".blue(), "ef".blue().on_yellow()]))]
+    #[case::after_end(6, 10, Line::from(vec!["abc".into(), "def".blue()]))]
+    #[case::empty(2, 2, Line::from(vec!["abc".into(), "def".blue()]))]
+    #[case::reversed(4, 2, Line::from(vec!["abc".into(), "def".blue()]))]
+    fn style_range(#[case] start: usize, #[case] end: usize, #[case] expected: Line) {
+        let line = Line::from(vec!["abc".into(), "def".blue()]).centered();
+        assert_eq!(
+            line.style_range(start, end, Style::new().on_yellow()),
+            expected.centered()
+        );
+    }
+
+    #[rstest]
+    #[case::first_half_of_wide_char(2, 3, Line::from(vec!["a".into(), "界".on_yellow(), "b".into()]))]
+    #[case::second_half_of_wide_char(0, 2, Line::from(vec!["a界".on_yellow(), "b".into()]))]
+    fn style_range_wide_characters(
+        #[case] start: usize,
+        #[case] end: usize,
+        #[case] expected: Line,
+    ) {
+        let line = Line::from("a界b");
+        assert_eq!(
+            line.style_range(start, end, Style::new().on_yellow()),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::after_one_column(Line::from("a\tb"), Line::from("a   b"))]
+    #[case::after_wide_character(Line::from("字\tb"), Line::from("字  b"))]
+    #[case::at_stop(Line::from("abcd\tb"), Line::from("abcd    b"))]
+    #[case::consecutive_tabs(Line::from("a\t\tb"), Line::from("a       b"))]
+    #[case::across_spans(
+        Line::from(vec!["ab".red(), "c\td".blue(), "\te".into()]),
+        Line::from(vec!["ab".red(), "c d".blue(), "   e".into()]),
+    )]
+    #[case::without_tabs(Line::from("abc").centered(), Line::from("abc").centered())]
+    fn expand_tabs(#[case] line: Line, #[case] expected: Line) {
+        assert_eq!(line.expand_tabs(4), expected);
+    }
+
+    #[test]
+    fn expand_tabs_with_zero_width() {
+        assert_eq!(Line::from("a\tb").expand_tabs(0), Line::from("ab"));
+    }
+
+    #[rstest]
+    #[case::ascii("a\tb", "a   b   ")]
+    #[case::wide_character("字\tb", "字  b   ")]
+    fn render_expanded_tabs(#[case] content: &str, #[case] expected: &str) {
+        let line = Line::from(content).expand_tabs(4);
+        assert_eq!(line.width(), 5);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        line.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([expected]));
+    }
+
     #[test]
     fn width() {
         let line = Line::from(vec![
@@ -1285,6 +1709,28 @@ mod tests {
             assert_eq!(buf, Buffer::with_lines(["Hello"]));
         }
 
+        #[test]
+        fn render_truncated_hyperlink() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+            Line::from(vec![
+                Span::raw("Hello").hyperlink("https://ratatui.rs"),
+                Span::raw(" world"),
+            ])
+            .right_aligned()
+            .render(buf.area, &mut buf);
+            assert_eq!(buf[(0, 0)].hyperlink(), None);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+            Line::from(vec![
+                Span::raw("Hello").hyperlink("https://ratatui.rs"),
+                Span::raw("!"),
+            ])
+            .right_aligned()
+            .render(buf.area, &mut buf);
+            assert_eq!(buf[(0, 0)].hyperlink(), Some("https://ratatui.rs"));
+            assert_eq!(buf[(1, 0)].hyperlink(), Some("https://ratatui.rs"));
+            assert_eq!(buf[(2, 0)].hyperlink(), None);
+        }
+
         #[test]
         fn render_truncates_right() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
@@ -1333,6 +1779,24 @@ mod tests {
             assert_eq!(crab.width(), 2); // display width
         }
 
+        /// The remaining half of a wide grapheme cut at the start is rendered as a space in the
+        /// style of its span, so that the following graphemes keep their columns.
+        #[rstest]
+        #[case::cjk("你好世界", 5, " 世界")]
+        #[case::emoji("a🦀b🦀c", 5, " b🦀c")]
+        fn render_pads_cut_wide_grapheme(
+            #[case] content: &str,
+            #[case] width: u16,
+            #[case] expected: &str,
+        ) {
+            let line = Line::from(content.red()).right_aligned();
+            let mut buf = Buffer::filled(Rect::new(0, 0, width, 1), Cell::new("X"));
+            line.render(buf.area, &mut buf);
+            let mut expected_buf = Buffer::filled(buf.area, Cell::new("X"));
+            expected_buf.set_string(0, 0, expected, Style::new().red());
+            assert_eq!(buf, expected_buf);
+        }
+
         /// Part of a regression test for <https://github.com/ratatui/ratatui/issues/1032> which
         /// found panics with truncating lines that contained multi-byte characters.
         #[rstest]
@@ -1410,13 +1874,14 @@ mod tests {
 
         /// Ensures the rendering also works away from the 0x0 position.
         ///
-        /// Particularly of note is that an emoji that is truncated will not overwrite the
-        /// characters that are already in the buffer. This is inentional (consider how a line
-        /// that is rendered on a border should not overwrite the border with a partial emoji).
+        /// Particularly of note is that the remaining half of an emoji that is truncated is
+        /// rendered as a space within the area, while the characters that are already in the
+        /// buffer outside of the area are not overwritten (consider how a line that is rendered
+        /// next to a border should not overwrite the border with a partial emoji).
         #[rstest]
         #[case::left(Alignment::Left, "XXa🦀bcXXX")]
         #[case::center(Alignment::Center, "XX🦀bc🦀XX")]
-        #[case::right(Alignment::Right, "XXXbc🦀dXX")]
+        #[case::right(Alignment::Right, "XX bc🦀dXX")]
         fn render_truncates_away_from_0x0(#[case] alignment: Alignment, #[case] expected: &str) {
             let line = Line::from(vec![Span::raw("a🦀b"), Span::raw("c🦀d")]).alignment(alignment);
             // Fill buffer with stuff to ensure the output is indeed padded
@@ -1427,12 +1892,12 @@ mod tests {
         }
 
         /// When two spans are rendered after each other the first needs to be padded in accordance
-        /// to the skipped unicode width. In this case the first crab does not fit at width 6 which
-        /// takes a front white space.
+        /// to the skipped unicode width. In this case the first crab does not fit at width 6, so its
+        /// remaining half is rendered as a space.
         #[rstest]
         #[case::right_4(4, "c🦀d")]
         #[case::right_5(5, "bc🦀d")]
-        #[case::right_6(6, "Xbc🦀d")]
+        #[case::right_6(6, " bc🦀d")]
         #[case::right_7(7, "🦀bc🦀d")]
         #[case::right_8(8, "a🦀bc🦀d")]
         fn render_right_aligned_multi_span(#[case] buf_width: u16, #[case] expected: &str) {
@@ -28,6 +28,7 @@ use crate::widgets::Widget;
 ///
 /// - [`Span::content`] sets the content of the span.
 /// - [`Span::style`] sets the style of the span.
+/// - [`Span::hyperlink`] sets the URL the span links to.
 ///
 /// # Other Methods
 ///
@@ -101,6 +102,8 @@ pub struct Span<'a> {
     pub style: Style,
     /// The content of the span as a Clone-on-write string.
     pub content: Cow<'a, str>,
+    /// The URL the span links to, if any.
+    pub hyperlink: Option<Cow<'a, str>>,
 }
 
 impl fmt::Debug for Span<'_> {
@@ -113,6 +116,9 @@ impl fmt::Debug for Span<'_> {
         if self.style != Style::default() {
             self.style.fmt_stylize(f)?;
         }
+        if let Some(url) = &self.hyperlink {
+            write!(f, ".hyperlink({url:?})")?;
+        }
         Ok(())
     }
 }
@@ -135,6 +141,7 @@ impl<'a> Span<'a> {
         Self {
             content: content.into(),
             style: Style::default(),
+            hyperlink: None,
         }
     }
 
@@ -166,6 +173,7 @@ impl<'a> Span<'a> {
         Self {
             content: content.into(),
             style: style.into(),
+            hyperlink: None,
         }
     }
 
@@ -192,6 +200,36 @@ impl<'a> Span<'a> {
         self
     }
 
+    /// Sets the URL the span links to.
+    ///
+    /// The cells the span is rendered to are linked to `url`, which backends that support
+    /// hyperlinks (e.g. using the OSC 8 escape sequence) make clickable. Terminals that don't
+    /// support hyperlinks display the content as usual.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// Accepts any type that can be converted to [`Cow<str>`] (e.g. `&str`, `String`, `&String`,
+    /// etc.).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::Stylize;
+    /// use ratatui_core::text::Span;
+    ///
+    /// let span = Span::raw("ratatui.rs")
+    ///     .underlined()
+    ///     .hyperlink("https://ratatui.rs");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hyperlink<T>(mut self, url: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.hyperlink = Some(url.into());
+        self
+    }
+
     /// Sets the style of the span.
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -308,11 +346,12 @@ impl<'a> Span<'a> {
         base_style: S,
     ) -> impl Iterator<Item = StyledGrapheme<'a>> {
         let style = base_style.into().patch(self.style);
+        let hyperlink = self.hyperlink.as_deref();
         self.content
             .as_ref()
             .graphemes(true)
             .filter(|g| !g.contains(char::is_control))
-            .map(move |g| StyledGrapheme { symbol: g, style })
+            .map(move |g| StyledGrapheme::new(g, style).hyperlink(hyperlink))
     }
 
     /// Converts this Span into a left-aligned [`Line`]
@@ -429,7 +468,8 @@ impl Widget for &Span<'_> {
                 // the first grapheme is always set on the cell
                 buf[(x, y)]
                     .set_symbol(grapheme.symbol)
-                    .set_style(grapheme.style);
+                    .set_style(grapheme.style)
+                    .set_hyperlink(self.hyperlink.as_deref());
             } else if x == area.x {
                 // there is one or more zero-width graphemes in the first cell, so the first cell
                 // must be appended to.
@@ -445,7 +485,8 @@ impl Widget for &Span<'_> {
                 // just a normal grapheme (not first, not zero-width, not overflowing the area)
                 buf[(x, y)]
                     .set_symbol(grapheme.symbol)
-                    .set_style(grapheme.style);
+                    .set_style(grapheme.style)
+                    .set_hyperlink(self.hyperlink.as_deref());
             }
 
             // multi-width graphemes must clear the cells of characters that are hidden by the
@@ -496,6 +537,7 @@ impl fmt::Display for Span<'_> {
 #[cfg(test)]
 mod tests {
     use alloc::string::String;
+    use alloc::vec::Vec;
     use alloc::{format, vec};
 
     use rstest::{fixture, rstest};
@@ -612,6 +654,17 @@ mod tests {
         assert_eq!(span.style, Style::new().red().on_yellow().bold());
     }
 
+    #[test]
+    fn hyperlink() {
+        let span = Span::raw("ratatui").hyperlink("https://ratatui.rs");
+        assert_eq!(span.hyperlink, Some(Cow::Borrowed("https://ratatui.rs")));
+        assert_eq!(Span::raw("ratatui").hyperlink, None);
+        assert_eq!(
+            format!("{span:?}"),
+            r#"Span::from("ratatui").hyperlink("https://ratatui.rs")"#
+        );
+    }
+
     #[test]
     fn width() {
         assert_eq!(Span::raw("").width(), 0);
@@ -750,6 +803,25 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_hyperlink() {
+            let span = Span::raw("a😃b").hyperlink("https://ratatui.rs");
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+            buf[(5, 0)].set_hyperlink(Some("https://example.com"));
+            span.render(buf.area, &mut buf);
+            let links = buf
+                .content()
+                .iter()
+                .map(Cell::hyperlink)
+                .collect::<Vec<_>>();
+            let link = Some("https://ratatui.rs");
+            // the cell hidden by the emoji is not linked, and the cells after the span are kept
+            assert_eq!(
+                links,
+                [link, link, None, link, None, Some("https://example.com")]
+            );
+        }
+
         /// When the span contains a multi-width grapheme that does not fit in the area passed to
         /// render, the entire grapheme will be truncated.
         #[test]
@@ -4,10 +4,12 @@ use alloc::string::{String, ToString};
 use alloc::vec;
 use alloc::vec::Vec;
 use core::fmt;
+use core::ops::Range;
 
 use crate::buffer::Buffer;
 use crate::layout::{Alignment, Rect};
 use crate::style::{Style, Styled};
+use crate::text::line::style_spans;
 use crate::text::{Line, Span};
 use crate::widgets::Widget;
 
@@ -275,6 +277,11 @@ impl<'a> Text<'a> {
 
     /// Returns the max width of all the lines.
     ///
+    /// This is the number of columns taken by the widest line when it is rendered, so wide
+    /// characters (e.g. CJK characters or emoji) count for two columns, and the widths of the spans
+    /// of a line are added up regardless of their styles. This can be used to size an area to fit
+    /// the text, e.g. a popup.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -282,6 +289,9 @@ impl<'a> Text<'a> {
     ///
     /// let text = Text::from("The first line\nThe second line");
     /// assert_eq!(15, text.width());
+    ///
+    /// let text = Text::from("你好\nHi");
+    /// assert_eq!(4, text.width());
     /// ```
     pub fn width(&self) -> usize {
         self.iter().map(Line::width).max().unwrap_or_default()
@@ -506,6 +516,133 @@ impl<'a> Text<'a> {
         self.alignment(Alignment::Right)
     }
 
+    /// Expands the tab characters of every line to the next tab stop, every `tab_width` columns.
+    ///
+    /// See [`Line::expand_tabs`] for more information. The tab stops are computed from the start
+    /// of each line, and the [`Text::width`] of the result is the width of the text as it is
+    /// rendered.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::Text;
+    ///
+    /// let text = Text::from("name\tvalue\nid\t42").expand_tabs(8);
+    /// assert_eq!(text, Text::from("name    value\nid      42"));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn expand_tabs(mut self, tab_width: usize) -> Self {
+        self.lines = self
+            .lines
+            .into_iter()
+            .map(|line| line.expand_tabs(tab_width))
+            .collect();
+        self
+    }
+
+    /// Returns a copy of the text with every occurrence of `needle` highlighted with `style`.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The search is case-sensitive, see [`Text::highlight_ignore_case`] for a case-insensitive
+    /// search. Each line is searched on its own, and a match can span several spans of the line.
+    /// The spans are split at the bounds of the matches, and the highlight style is patched over
+    /// the style of the matching parts, so that they keep the properties the highlight style
+    /// doesn't set. Overlapping and adjacent matches are highlighted as a single range. An empty
+    /// `needle` doesn't match anything.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Style, Stylize};
+    /// use ratatui_core::text::{Line, Text};
+    ///
+    /// let text = Text::from("one two one").highlight("one", Style::new().yellow());
+    /// assert_eq!(
+    ///     text,
+    ///     Text::from(Line::from(vec!["one".yellow(), " two ".into(), "one".yellow()]))
+    /// );
+    /// ```
+    ///
+    /// [`Color`]: crate::style::Color
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn highlight<S: Into<Style>>(&self, needle: &str, style: S) -> Self {
+        self.highlight_matches(style.into(), |haystack| {
+            haystack.starts_with(needle).then_some(needle.len())
+        })
+    }
+
+    /// Returns a copy of the text with every occurrence of `needle` highlighted with `style`,
+    /// ignoring the case of the characters.
+    ///
+    /// This is the case-insensitive variant of [`Text::highlight`]. Characters are compared by
+    /// their lowercase form, so this works for non-ASCII text as well.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Style, Stylize};
+    /// use ratatui_core::text::{Line, Text};
+    ///
+    /// let text = Text::from("Rust and rust").highlight_ignore_case("rust", Style::new().bold());
+    /// assert_eq!(
+    ///     text,
+    ///     Text::from(Line::from(vec!["Rust".bold(), " and ".into(), "rust".bold()]))
+    /// );
+    /// ```
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn highlight_ignore_case<S: Into<Style>>(&self, needle: &str, style: S) -> Self {
+        self.highlight_matches(style.into(), |haystack| {
+            let mut haystack_chars = haystack.char_indices();
+            for needle_char in needle.chars() {
+                let (_, haystack_char) = haystack_chars.next()?;
+                if !haystack_char.to_lowercase().eq(needle_char.to_lowercase()) {
+                    return None;
+                }
+            }
+            Some(haystack_chars.next().map_or(haystack.len(), |(end, _)| end))
+        })
+    }
+
+    /// Returns a copy of the text with the matches patched with `style`.
+    ///
+    /// `match_len` returns the length in bytes of the match at the start of a string, if any.
+    fn highlight_matches<F>(&self, style: Style, match_len: F) -> Self
+    where
+        F: Fn(&str) -> Option<usize>,
+    {
+        let lines = self.lines.iter().map(|line| {
+            let content: String = line.spans.iter().map(|span| &*span.content).collect();
+            let mut ranges: Vec<Range<usize>> = Vec::new();
+            for (start, _) in content.char_indices() {
+                let Some(len) = content.get(start..).and_then(&match_len) else {
+                    continue;
+                };
+                if len == 0 {
+                    continue;
+                }
+                let end = start + len;
+                // overlapping and adjacent matches are merged
+                match ranges.last_mut() {
+                    Some(last) if last.end >= start => last.end = last.end.max(end),
+                    _ => ranges.push(start..end),
+                }
+            }
+            let spans = style_spans(&line.spans, &ranges, style);
+            Line {
+                spans,
+                ..line.clone()
+            }
+        });
+        Self {
+            lines: lines.collect(),
+            ..self.clone()
+        }
+    }
+
     /// Returns an iterator over the lines of the text.
     pub fn iter(&self) -> core::slice::Iter<Line<'a>> {
         self.lines.iter()
@@ -785,6 +922,95 @@ mod tests {
         assert_eq!(15, text.width());
     }
 
+    #[test]
+    fn expand_tabs() {
+        let text = Text::from("a\tb\n字\tb").centered().expand_tabs(4);
+        assert_eq!(text, Text::from("a   b\n字  b").centered());
+        assert_eq!(text.width(), 5);
+    }
+
+    #[test]
+    fn highlight_word_twice_in_line() {
+        let text = Text::from("one two one").highlight("one", Style::new().yellow());
+        let expected = Line::from(vec!["one".yellow(), " two ".into(), "one".yellow()]);
+        assert_eq!(text, Text::from(expected));
+    }
+
+    #[test]
+    fn highlight_patches_span_styles() {
+        let line = Line::from(vec!["one t".red(), "wo o".italic(), "ne".into()]);
+        let text = Text::from(line).highlight("two one", Style::new().on_yellow());
+        let expected = Line::from(vec![
+            "one ".red(),
+            "t".red().on_yellow(),
+            "wo o".italic().on_yellow(),
+            "ne".on_yellow(),
+        ]);
+        assert_eq!(text, Text::from(expected));
+    }
+
+    #[rstest]
+    #[case::overlapping("aaaa ab", "aa", Line::from(vec!["aaaa".bold(), " ab".into()]))]
+    #[case::adjacent("abab ab", "ab", Line::from(vec!["abab".bold(), " ".into(), "ab".bold()]))]
+    #[case::no_match("abc", "x", Line::from("abc"))]
+    #[case::empty_needle("abc", "", Line::from("abc"))]
+    fn highlight_merges_matches(
+        #[case] content: &str,
+        #[case] needle: &str,
+        #[case] expected: Line,
+    ) {
+        let text = Text::from(content).highlight(needle, Modifier::BOLD);
+        assert_eq!(text, Text::from(expected));
+    }
+
+    #[test]
+    fn highlight_keeps_lines_and_owned_content() {
+        let text = Text::from(String::from("Foo bar\nbaz foo"))
+            .centered()
+            .highlight("foo", Color::Red);
+        let expected = Text::from(vec![
+            Line::from("Foo bar"),
+            Line::from(vec!["baz ".into(), "foo".red()]),
+        ])
+        .centered();
+        assert_eq!(text, expected);
+    }
+
+    #[test]
+    fn highlight_ignore_case() {
+        let text = Text::from("Rust and RUST and Ümlaut ümlaut")
+            .highlight_ignore_case("rust", Style::new().bold())
+            .highlight_ignore_case("ümlaut", Style::new().italic());
+        let expected = Line::from(vec![
+            "Rust".bold(),
+            " and ".into(),
+            "RUST".bold(),
+            " and ".into(),
+            "Ümlaut".italic(),
+            " ".into(),
+            "ümlaut".italic(),
+        ]);
+        assert_eq!(text, Text::from(expected));
+    }
+
+    #[rstest]
+    #[case::empty(Text::default(), 0)]
+    #[case::empty_line(Text::from(""), 0)]
+    #[case::ascii(Text::from("abc\nabcdef\nab"), 6)]
+    #[case::cjk(Text::from("abc\n你好世界"), 8)]
+    #[case::emoji(Text::from("😀😀😀\nabcde"), 6)]
+    #[case::mixed(Text::from("a你😀b"), 6)]
+    #[case::styled_spans(
+        Text::from(vec![
+            Line::from(vec!["ab".red(), "你好".bold(), "😀".on_blue()]),
+            Line::from("abcdefg"),
+        ]),
+        8
+    )]
+    fn width_of_wide_characters(#[case] text: Text, #[case] expected: usize) {
+        assert_eq!(text.width(), expected);
+    }
+
     #[test]
     fn height() {
         let text = Text::from("The first line\nThe second line");
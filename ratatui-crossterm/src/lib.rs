@@ -13,10 +13,12 @@
 #![cfg_attr(feature = "document-features", doc = "\n## Features")]
 #![cfg_attr(feature = "document-features", doc = document_features::document_features!())]
 
+use std::fmt;
 use std::io::{self, Write};
 
 pub use crossterm;
-use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::cursor::{Hide, MoveTo, SetCursorStyle, Show};
+use crossterm::event::{DisableBracketedPaste, EnableBracketedPaste};
 #[cfg(feature = "underline-color")]
 use crossterm::style::SetUnderlineColor;
 use crossterm::style::{
@@ -24,9 +26,9 @@ use crossterm::style::{
     Colors as CrosstermColors, ContentStyle, Print, SetAttribute, SetBackgroundColor, SetColors,
     SetForegroundColor,
 };
-use crossterm::terminal::{self, Clear};
-use crossterm::{execute, queue};
-use ratatui_core::backend::{Backend, ClearType, WindowSize};
+use crossterm::terminal::{self, BeginSynchronizedUpdate, Clear, EndSynchronizedUpdate};
+use crossterm::{Command, execute, queue};
+use ratatui_core::backend::{Backend, ClearType, CursorShape, WindowSize};
 use ratatui_core::buffer::Cell;
 use ratatui_core::layout::{Position, Size};
 use ratatui_core::style::{Color, Modifier, Style};
@@ -81,15 +83,28 @@ use ratatui_core::style::{Color, Modifier, Style};
 /// See the the [Examples] directory for more examples. See the [`backend`] module documentation
 /// for more details on raw mode and alternate screen.
 ///
+/// # Synchronized output
+///
+/// On slow terminals, a frame that is only partially written may be repainted, which causes
+/// flickering. When enabled with [`CrosstermBackend::with_synchronized_output`], each frame is
+/// written between the begin and end synchronized update escapes (`ESC [ ? 2026 h` and
+/// `ESC [ ? 2026 l`), so that terminals supporting [synchronized output] only repaint once per
+/// frame. This is disabled by default, as some terminals may not support it.
+///
 /// [`Write`]: std::io::Write
 /// [`Terminal`]: https://docs.rs/ratatui/latest/ratatui/struct.Terminal.html
 /// [`backend`]: ratatui_core::backend
 /// [Crossterm]: https://crates.io/crates/crossterm
 /// [Examples]: https://github.com/ratatui/ratatui/tree/main/ratatui/examples/README.md
+/// [synchronized output]: https://gist.github.com/christianparpart/d8a62cc1ab659194337d73e399004036
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct CrosstermBackend<W: Write> {
     /// The writer used to send commands to the terminal.
     writer: W,
+    /// Whether each frame is written as a synchronized update
+    synchronized_output: bool,
+    /// Whether a synchronized update was begun and not yet ended by a flush
+    in_synchronized_update: bool,
 }
 
 impl<W> CrosstermBackend<W>
@@ -113,7 +128,34 @@ where
     /// let backend = CrosstermBackend::new(stdout());
     /// ```
     pub const fn new(writer: W) -> Self {
-        Self { writer }
+        Self {
+            writer,
+            synchronized_output: false,
+            in_synchronized_update: false,
+        }
+    }
+
+    /// Sets whether each frame is written as a synchronized update (mode 2026).
+    ///
+    /// When enabled, the begin synchronized update escape is written before the first content of
+    /// each frame is drawn, and the end escape when the backend is flushed, so that supporting
+    /// terminals only repaint once the whole frame is written. Terminals that don't support it
+    /// should ignore the escapes, but this is disabled by default to avoid issues on terminals that
+    /// don't. See the [synchronized output](CrosstermBackend#synchronized-output) section.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::io::stdout;
+    ///
+    /// use ratatui::backend::CrosstermBackend;
+    ///
+    /// let backend = CrosstermBackend::new(stdout()).with_synchronized_output(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_synchronized_output(mut self, enabled: bool) -> Self {
+        self.synchronized_output = enabled;
+        self
     }
 
     /// Gets the writer.
@@ -163,15 +205,36 @@ where
     where
         I: Iterator<Item = (u16, u16, &'a Cell)>,
     {
+        if self.synchronized_output && !self.in_synchronized_update {
+            queue!(self.writer, BeginSynchronizedUpdate)?;
+            self.in_synchronized_update = true;
+        }
         let mut fg = Color::Reset;
         let mut bg = Color::Reset;
         #[cfg(feature = "underline-color")]
         let mut underline_color = Color::Reset;
         let mut modifier = Modifier::empty();
+        let mut hyperlink = None;
         let mut last_pos: Option<Position> = None;
+        // the symbols of contiguous cells that share the same style are printed in a single write
+        let mut run = String::new();
         for (x, y, cell) in content {
+            let is_contiguous = matches!(last_pos, Some(p) if x == p.x + 1 && y == p.y);
+            #[cfg(feature = "underline-color")]
+            let underline_color_changed = cell.underline_color != underline_color;
+            #[cfg(not(feature = "underline-color"))]
+            let underline_color_changed = false;
+            let style_changed = cell.modifier != modifier
+                || cell.fg != fg
+                || cell.bg != bg
+                || underline_color_changed
+                || cell.hyperlink() != hyperlink;
+            if (!is_contiguous || style_changed) && !run.is_empty() {
+                queue!(self.writer, Print(&run))?;
+                run.clear();
+            }
             // Move the cursor if the previous location was not (x - 1, y)
-            if !matches!(last_pos, Some(p) if x == p.x + 1 && y == p.y) {
+            if !is_contiguous {
                 queue!(self.writer, MoveTo(x, y))?;
             }
             last_pos = Some(Position { x, y });
@@ -195,13 +258,23 @@ where
                 bg = cell.bg;
             }
             #[cfg(feature = "underline-color")]
-            if cell.underline_color != underline_color {
+            if underline_color_changed {
                 let color = cell.underline_color.into_crossterm();
                 queue!(self.writer, SetUnderlineColor(color))?;
                 underline_color = cell.underline_color;
             }
+            if cell.hyperlink() != hyperlink {
+                queue!(self.writer, SetHyperlink(cell.hyperlink()))?;
+                hyperlink = cell.hyperlink();
+            }
 
-            queue!(self.writer, Print(cell.symbol()))?;
+            run.push_str(cell.symbol());
+        }
+        if !run.is_empty() {
+            queue!(self.writer, Print(&run))?;
+        }
+        if hyperlink.is_some() {
+            queue!(self.writer, SetHyperlink(None))?;
         }
 
         #[cfg(feature = "underline-color")]
@@ -229,6 +302,27 @@ where
         execute!(self.writer, Show)
     }
 
+    fn set_cursor_shape(&mut self, shape: CursorShape) -> io::Result<()> {
+        let style = match shape {
+            CursorShape::Default => SetCursorStyle::DefaultUserShape,
+            CursorShape::BlinkingBlock => SetCursorStyle::BlinkingBlock,
+            CursorShape::SteadyBlock => SetCursorStyle::SteadyBlock,
+            CursorShape::BlinkingUnderline => SetCursorStyle::BlinkingUnderScore,
+            CursorShape::SteadyUnderline => SetCursorStyle::SteadyUnderScore,
+            CursorShape::BlinkingBar => SetCursorStyle::BlinkingBar,
+            CursorShape::SteadyBar => SetCursorStyle::SteadyBar,
+        };
+        execute!(self.writer, style)
+    }
+
+    fn enable_bracketed_paste(&mut self) -> io::Result<()> {
+        execute!(self.writer, EnableBracketedPaste)
+    }
+
+    fn disable_bracketed_paste(&mut self) -> io::Result<()> {
+        execute!(self.writer, DisableBracketedPaste)
+    }
+
     fn get_cursor_position(&mut self) -> io::Result<Position> {
         crossterm::cursor::position()
             .map(|(x, y)| Position { x, y })
@@ -286,6 +380,10 @@ where
     }
 
     fn flush(&mut self) -> io::Result<()> {
+        if self.in_synchronized_update {
+            queue!(self.writer, EndSynchronizedUpdate)?;
+            self.in_synchronized_update = false;
+        }
         self.writer.flush()
     }
 
@@ -386,6 +484,34 @@ impl FromCrossterm<CrosstermColor> for Color {
     }
 }
 
+/// A command that starts a hyperlink to the given URL using the OSC 8 escape sequence, or ends
+/// the current hyperlink when the URL is `None`.
+///
+/// The symbols printed while a hyperlink is started are clickable in terminals that support it,
+/// and displayed as usual in other terminals. The bytes of the URL that are not printable ASCII
+/// are percent-encoded, so that the URL can't end the escape sequence.
+struct SetHyperlink<'a>(Option<&'a str>);
+
+impl Command for SetHyperlink<'_> {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        f.write_str("\x1b]8;;")?;
+        for byte in self.0.unwrap_or_default().bytes() {
+            if (0x20..=0x7e).contains(&byte) {
+                f.write_char(char::from(byte))?;
+            } else {
+                write!(f, "%{byte:02X}")?;
+            }
+        }
+        f.write_str("\x1b\\")
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> io::Result<()> {
+        // hyperlinks are not supported by the legacy Windows console
+        Ok(())
+    }
+}
+
 /// The `ModifierDiff` struct is used to calculate the difference between two `Modifier`
 /// values. This is useful when updating the terminal display, as it allows for more
 /// efficient updates by only sending the necessary changes.
@@ -642,6 +768,11 @@ impl crate::crossterm::Command for ScrollDownInRegion {
 
 #[cfg(test)]
 mod tests {
+    use ratatui_core::buffer::Buffer;
+    use ratatui_core::layout::Rect;
+    use ratatui_core::style::Stylize;
+    use ratatui_core::terminal::{Terminal, TerminalOptions, Viewport};
+    use ratatui_core::text::Line;
     use rstest::rstest;
 
     use super::*;
@@ -672,6 +803,309 @@ mod tests {
         assert_eq!(Color::from_crossterm(crossterm_color), color);
     }
 
+    #[test]
+    fn draw_hyperlink() {
+        let mut linked = Cell::new("a");
+        linked.set_hyperlink(Some("https://ratatui.rs"));
+        let plain = Cell::new("b");
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let content = [
+            (0, 0, &linked),
+            (1, 0, &linked),
+            (2, 0, &plain),
+            (3, 0, &linked),
+        ];
+        backend.draw(content.into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        let start = "\x1b]8;;https://ratatui.rs\x1b\\";
+        let end = "\x1b]8;;\x1b\\";
+        assert!(
+            output.contains(&format!("{start}aa{end}b{start}a{end}")),
+            "{output:?}"
+        );
+    }
+
+    #[test]
+    fn hyperlink_url_is_never_written_raw() {
+        let mut output = String::new();
+        SetHyperlink(Some("https://x\x1b]8;;evil\x07\x1b\\é"))
+            .write_ansi(&mut output)
+            .unwrap();
+        assert_eq!(output, "\x1b]8;;https://x%1B]8;;evil%07%1B\\%C3%A9\x1b\\");
+
+        let mut linked = Cell::new("a");
+        linked.set_hyperlink(Some("https://x\x07\x1b[2J"));
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.draw([(0, 0, &linked)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert!(!output.contains('\x07'), "{output:?}");
+        assert!(!output.contains("\x1b[2J"), "{output:?}");
+        assert!(
+            output.contains("\x1b]8;;https://x%07%1B[2J\x1b\\"),
+            "{output:?}"
+        );
+    }
+
+    #[rstest]
+    #[case(CursorShape::Default, "\x1b[0 q")]
+    #[case(CursorShape::BlinkingBlock, "\x1b[1 q")]
+    #[case(CursorShape::SteadyBlock, "\x1b[2 q")]
+    #[case(CursorShape::BlinkingUnderline, "\x1b[3 q")]
+    #[case(CursorShape::SteadyUnderline, "\x1b[4 q")]
+    #[case(CursorShape::BlinkingBar, "\x1b[5 q")]
+    #[case(CursorShape::SteadyBar, "\x1b[6 q")]
+    fn set_cursor_shape(#[case] shape: CursorShape, #[case] expected: &str) {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.set_cursor_shape(shape).unwrap();
+        assert_eq!(String::from_utf8(backend.writer).unwrap(), expected);
+    }
+
+    #[test]
+    fn terminal_drop_restores_cursor_shape() {
+        let mut output = Vec::new();
+        {
+            let backend = CrosstermBackend::new(&mut output);
+            let viewport = Viewport::Fixed(Rect::new(0, 0, 10, 2));
+            let mut terminal =
+                Terminal::with_options(backend, TerminalOptions { viewport }).unwrap();
+            terminal.set_cursor_shape(CursorShape::SteadyBar).unwrap();
+        }
+        assert_eq!(String::from_utf8(output).unwrap(), "\x1b[6 q\x1b[0 q");
+    }
+
+    #[test]
+    fn bracketed_paste() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.enable_bracketed_paste().unwrap();
+        backend.disable_bracketed_paste().unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert_eq!(output, "\x1b[?2004h\x1b[?2004l");
+    }
+
+    #[test]
+    fn terminal_drop_disables_bracketed_paste() {
+        let mut output = Vec::new();
+        {
+            let backend = CrosstermBackend::new(&mut output);
+            let viewport = Viewport::Fixed(Rect::new(0, 0, 10, 2));
+            let mut terminal =
+                Terminal::with_options(backend, TerminalOptions { viewport }).unwrap();
+            terminal.enable_bracketed_paste().unwrap();
+        }
+        assert_eq!(String::from_utf8(output).unwrap(), "\x1b[?2004h\x1b[?2004l");
+    }
+
+    #[test]
+    fn terminal_drop_keeps_disabled_bracketed_paste() {
+        let mut output = Vec::new();
+        {
+            let backend = CrosstermBackend::new(&mut output);
+            let viewport = Viewport::Fixed(Rect::new(0, 0, 10, 2));
+            let mut terminal =
+                Terminal::with_options(backend, TerminalOptions { viewport }).unwrap();
+            terminal.enable_bracketed_paste().unwrap();
+            terminal.disable_bracketed_paste().unwrap();
+        }
+        assert_eq!(String::from_utf8(output).unwrap(), "\x1b[?2004h\x1b[?2004l");
+    }
+
+    #[test]
+    fn terminal_drop_keeps_unchanged_cursor_shape() {
+        let mut output = Vec::new();
+        {
+            let backend = CrosstermBackend::new(&mut output);
+            let viewport = Viewport::Fixed(Rect::new(0, 0, 10, 2));
+            let _terminal = Terminal::with_options(backend, TerminalOptions { viewport }).unwrap();
+        }
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn draw_with_synchronized_output() {
+        let cell = Cell::new("a");
+        let mut backend = CrosstermBackend::new(Vec::new()).with_synchronized_output(true);
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        backend.set_cursor_position((1, 0)).unwrap();
+        Backend::flush(&mut backend).unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert!(output.starts_with("\x1b[?2026h\x1b[1;1Ha"), "{output:?}");
+        assert!(output.ends_with("\x1b[1;2H\x1b[?2026l"), "{output:?}");
+    }
+
+    #[test]
+    fn draw_with_synchronized_output_brackets_each_frame() {
+        let cell = Cell::new("a");
+        let mut backend = CrosstermBackend::new(Vec::new()).with_synchronized_output(true);
+        for _ in 0..2 {
+            backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+            Backend::flush(&mut backend).unwrap();
+        }
+        let output = String::from_utf8(backend.writer).unwrap();
+        let frames: Vec<_> = output.split_inclusive("\x1b[?2026l").collect();
+        assert_eq!(frames.len(), 2, "{output:?}");
+        for frame in frames {
+            assert!(frame.starts_with("\x1b[?2026h\x1b[1;1Ha"), "{frame:?}");
+            assert_eq!(frame.matches("\x1b[?2026h").count(), 1, "{frame:?}");
+        }
+    }
+
+    #[test]
+    fn draw_without_synchronized_output() {
+        let cell = Cell::new("a");
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        Backend::flush(&mut backend).unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert!(!output.contains("2026"), "{output:?}");
+    }
+
+    /// A writer that counts the number of writes, to measure how many segments are emitted
+    #[derive(Default)]
+    struct CountingWriter {
+        writes: usize,
+        bytes: Vec<u8>,
+    }
+
+    impl Write for CountingWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.writes += 1;
+            self.bytes.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// The reference implementation of `draw`, printing the symbol of each cell separately
+    fn draw_per_cell<'a, W: Write>(
+        writer: &mut W,
+        content: impl Iterator<Item = (u16, u16, &'a Cell)>,
+    ) -> io::Result<()> {
+        let mut fg = Color::Reset;
+        let mut bg = Color::Reset;
+        #[cfg(feature = "underline-color")]
+        let mut underline_color = Color::Reset;
+        let mut modifier = Modifier::empty();
+        let mut hyperlink = None;
+        let mut last_pos: Option<Position> = None;
+        for (x, y, cell) in content {
+            if !matches!(last_pos, Some(p) if x == p.x + 1 && y == p.y) {
+                queue!(writer, MoveTo(x, y))?;
+            }
+            last_pos = Some(Position { x, y });
+            if cell.modifier != modifier {
+                let diff = ModifierDiff {
+                    from: modifier,
+                    to: cell.modifier,
+                };
+                diff.queue(&mut *writer)?;
+                modifier = cell.modifier;
+            }
+            if cell.fg != fg || cell.bg != bg {
+                let colors =
+                    CrosstermColors::new(cell.fg.into_crossterm(), cell.bg.into_crossterm());
+                queue!(writer, SetColors(colors))?;
+                fg = cell.fg;
+                bg = cell.bg;
+            }
+            #[cfg(feature = "underline-color")]
+            if cell.underline_color != underline_color {
+                queue!(
+                    writer,
+                    SetUnderlineColor(cell.underline_color.into_crossterm())
+                )?;
+                underline_color = cell.underline_color;
+            }
+            if cell.hyperlink() != hyperlink {
+                queue!(writer, SetHyperlink(cell.hyperlink()))?;
+                hyperlink = cell.hyperlink();
+            }
+            queue!(writer, Print(cell.symbol()))?;
+        }
+        if hyperlink.is_some() {
+            queue!(writer, SetHyperlink(None))?;
+        }
+        queue!(
+            writer,
+            SetForegroundColor(CrosstermColor::Reset),
+            SetBackgroundColor(CrosstermColor::Reset),
+        )?;
+        #[cfg(feature = "underline-color")]
+        queue!(writer, SetUnderlineColor(CrosstermColor::Reset))?;
+        queue!(writer, SetAttribute(CrosstermAttribute::Reset))
+    }
+
+    #[test]
+    fn draw_matches_per_cell_output() {
+        let mut buffer = Buffer::with_lines([
+            Line::from(vec!["ab".red(), "cd".red().bold(), "ef".into()]),
+            Line::from(vec!["称号".blue(), " ".into(), "link".underlined()]),
+            Line::from(vec!["gh".on_green(), "ij".on_green().italic()]),
+        ]);
+        for x in 5..9 {
+            buffer[(x, 1)].set_hyperlink(Some("https://ratatui.rs"));
+        }
+        let previous = Buffer::with_lines(["ab    ", "      ", "  kl  "]);
+        let diff = previous.diff(&buffer);
+
+        let mut expected = Vec::new();
+        draw_per_cell(&mut expected, diff.iter().copied()).unwrap();
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.draw(diff.into_iter()).unwrap();
+        assert_eq!(
+            String::from_utf8(backend.writer),
+            String::from_utf8(expected)
+        );
+    }
+
+    #[test]
+    fn draw_full_screen_color_change_merges_cells() {
+        let area = Rect::new(0, 0, 80, 24);
+        let previous = Buffer::empty(area);
+        let next = Buffer::filled(area, Cell::new("x").set_fg(Color::Red).clone());
+        let diff = previous.diff(&next);
+
+        let mut per_cell = CountingWriter::default();
+        draw_per_cell(&mut per_cell, diff.iter().copied()).unwrap();
+        let mut backend = CrosstermBackend::new(CountingWriter::default());
+        backend.draw(diff.into_iter()).unwrap();
+        let merged = backend.writer;
+
+        assert_eq!(merged.bytes, per_cell.bytes);
+        // one print per row instead of one print per cell
+        assert!(per_cell.writes > 80 * 24, "{}", per_cell.writes);
+        assert_eq!(per_cell.writes - merged.writes, 24 * 79);
+    }
+
+    #[cfg(feature = "underline-color")]
+    #[test]
+    fn draw_underline_color() {
+        let mut cell = Cell::new("a");
+        cell.set_style(
+            Style::new()
+                .fg(Color::Blue)
+                .underline_color(Color::Red)
+                .add_modifier(Modifier::UNDERLINED),
+        );
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert!(output.contains("\x1b[58;5;1m"), "{output:?}");
+        // the underline color is reset after drawing
+        assert!(output.contains("\x1b[59m"), "{output:?}");
+    }
+
+    #[test]
+    fn draw_without_hyperlink() {
+        let cell = Cell::new("a");
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert!(!output.contains("\x1b]8;"), "{output:?}");
+    }
+
     mod modifier {
         use super::*;
 
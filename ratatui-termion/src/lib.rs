@@ -17,7 +17,7 @@
 use std::fmt;
 use std::io::{self, Write};
 
-use ratatui_core::backend::{Backend, ClearType, WindowSize};
+use ratatui_core::backend::{Backend, ClearType, CursorShape, WindowSize};
 use ratatui_core::buffer::Cell;
 use ratatui_core::layout::{Position, Size};
 use ratatui_core::style::{Color, Modifier, Style};
@@ -174,6 +174,21 @@ where
         self.writer.flush()
     }
 
+    fn set_cursor_shape(&mut self, shape: CursorShape) -> io::Result<()> {
+        let sequence: &dyn fmt::Display = match shape {
+            // termion has no sequence for the default shape
+            CursorShape::Default => &"\x1b[0 q",
+            CursorShape::BlinkingBlock => &termion::cursor::BlinkingBlock,
+            CursorShape::SteadyBlock => &termion::cursor::SteadyBlock,
+            CursorShape::BlinkingUnderline => &termion::cursor::BlinkingUnderline,
+            CursorShape::SteadyUnderline => &termion::cursor::SteadyUnderline,
+            CursorShape::BlinkingBar => &termion::cursor::BlinkingBar,
+            CursorShape::SteadyBar => &termion::cursor::SteadyBar,
+        };
+        write!(self.writer, "{sequence}")?;
+        self.writer.flush()
+    }
+
     fn get_cursor_position(&mut self) -> io::Result<Position> {
         termion::cursor::DetectCursorPos::cursor_pos(&mut self.writer)
             .map(|(x, y)| Position { x: x - 1, y: y - 1 })
@@ -543,6 +558,28 @@ impl fmt::Display for ResetRegion {
 mod tests {
     use super::*;
 
+    #[test]
+    fn set_cursor_shape() {
+        let shapes = [
+            (CursorShape::Default, "\x1b[0 q"),
+            (CursorShape::BlinkingBlock, "\x1b[1 q"),
+            (CursorShape::SteadyBlock, "\x1b[2 q"),
+            (CursorShape::BlinkingUnderline, "\x1b[3 q"),
+            (CursorShape::SteadyUnderline, "\x1b[4 q"),
+            (CursorShape::BlinkingBar, "\x1b[5 q"),
+            (CursorShape::SteadyBar, "\x1b[6 q"),
+        ];
+        for (shape, expected) in shapes {
+            let mut backend = TermionBackend::new(Vec::new());
+            backend.set_cursor_shape(shape).unwrap();
+            assert_eq!(
+                String::from_utf8(backend.writer).unwrap(),
+                expected,
+                "{shape}"
+            );
+        }
+    }
+
     #[test]
     fn from_termion_color() {
         assert_eq!(Color::from_termion(tcolor::Reset), Color::Reset);
@@ -719,4 +756,37 @@ mod tests {
         assert_eq!(Modifier::from_termion(tstyle::Blink), Modifier::SLOW_BLINK);
         assert_eq!(Modifier::from_termion(tstyle::Reset), Modifier::empty());
     }
+
+    #[test]
+    fn modifier_diff_crossed_out() {
+        let add = ModifierDiff {
+            from: Modifier::empty(),
+            to: Modifier::CROSSED_OUT,
+        };
+        assert_eq!(add.to_string(), "\u{1b}[9m");
+        let remove = ModifierDiff {
+            from: Modifier::CROSSED_OUT | Modifier::BOLD,
+            to: Modifier::BOLD,
+        };
+        assert_eq!(remove.to_string(), "\u{1b}[29m");
+    }
+
+    #[test]
+    fn draw_crossed_out_cells() -> io::Result<()> {
+        let crossed_out = Style::new().add_modifier(Modifier::CROSSED_OUT);
+        let mut a = Cell::new("a");
+        a.set_style(crossed_out);
+        let b = Cell::new("b");
+        let mut c = Cell::new("c");
+        c.set_style(crossed_out);
+
+        let mut backend = TermionBackend::new(Vec::new());
+        backend.draw([(0, 0, &a), (1, 0, &b), (2, 0, &c)].into_iter())?;
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert_eq!(
+            output,
+            "\u{1b}[1;1H\u{1b}[9ma\u{1b}[29mb\u{1b}[9mc\u{1b}[39m\u{1b}[49m\u{1b}[m"
+        );
+        Ok(())
+    }
 }
@@ -17,7 +17,7 @@
 use std::error::Error;
 use std::io;
 
-use ratatui_core::backend::{Backend, ClearType, WindowSize};
+use ratatui_core::backend::{Backend, ClearType, CursorShape, WindowSize};
 use ratatui_core::buffer::Cell;
 use ratatui_core::layout::{Position, Size};
 use ratatui_core::style::{Color, Modifier, Style};
@@ -25,7 +25,9 @@ pub use termwiz;
 use termwiz::caps::Capabilities;
 use termwiz::cell::{AttributeChange, Blink, CellAttributes, Intensity, Underline};
 use termwiz::color::{AnsiColor, ColorAttribute, ColorSpec, LinearRgba, RgbColor, SrgbaTuple};
-use termwiz::surface::{Change, CursorVisibility, Position as TermwizPosition};
+use termwiz::surface::{
+    Change, CursorShape as TermwizCursorShape, CursorVisibility, Position as TermwizPosition,
+};
 use termwiz::terminal::buffered::BufferedTerminal;
 use termwiz::terminal::{ScreenSize, SystemTerminal, Terminal};
 
@@ -203,6 +205,21 @@ impl Backend for TermwizBackend {
         Ok(())
     }
 
+    fn set_cursor_shape(&mut self, shape: CursorShape) -> io::Result<()> {
+        let shape = match shape {
+            CursorShape::Default => TermwizCursorShape::Default,
+            CursorShape::BlinkingBlock => TermwizCursorShape::BlinkingBlock,
+            CursorShape::SteadyBlock => TermwizCursorShape::SteadyBlock,
+            CursorShape::BlinkingUnderline => TermwizCursorShape::BlinkingUnderline,
+            CursorShape::SteadyUnderline => TermwizCursorShape::SteadyUnderline,
+            CursorShape::BlinkingBar => TermwizCursorShape::BlinkingBar,
+            CursorShape::SteadyBar => TermwizCursorShape::SteadyBar,
+        };
+        self.buffered_terminal
+            .add_change(Change::CursorShape(shape));
+        Ok(())
+    }
+
     fn get_cursor_position(&mut self) -> io::Result<Position> {
         let (x, y) = self.buffered_terminal.cursor_position();
         Ok(Position::new(x as u16, y as u16))
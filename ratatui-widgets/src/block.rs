@@ -10,17 +10,21 @@ use alloc::vec::Vec;
 
 use itertools::Itertools;
 use ratatui_core::buffer::Buffer;
-use ratatui_core::layout::{Alignment, Rect};
+use ratatui_core::layout::{Alignment, Rect, VerticalAlignment};
 use ratatui_core::style::{Style, Styled};
 use ratatui_core::symbols::border;
 use ratatui_core::text::Line;
 use ratatui_core::widgets::Widget;
 
+pub use self::gradient::BorderGradient;
 pub use self::padding::Padding;
+pub use self::shadow::{ShadowPlacement, ShadowStyle};
 pub use self::title::{Position, Title};
 use crate::borders::{BorderType, Borders};
 
+mod gradient;
 mod padding;
+mod shadow;
 pub mod title;
 
 /// Base widget to be used to display a box border around all other built-in widgets.
@@ -42,6 +46,10 @@ pub mod title;
 ///
 /// Without left border───
 /// ```
+///
+/// Titles can also run vertically along the left or right border, one character per row (see
+/// [`Block::title_left`] and [`Block::title_right`]). They are rendered on the border cells, so
+/// they don't change the [inner area](Block::inner) of the block.
 /// # Constructor methods
 ///
 /// - [`Block::new`] creates a new [`Block`] with no border or paddings.
@@ -53,16 +61,25 @@ pub mod title;
 ///
 /// - [`Block::borders`] Defines which borders to display.
 /// - [`Block::border_style`] Defines the style of the borders.
+/// - [`Block::top_border_style`], [`Block::right_border_style`], [`Block::bottom_border_style`]
+///   and [`Block::left_border_style`] Define the style of one side of the borders.
+/// - [`Block::border_gradient`] Draws a color gradient along the top and bottom borders.
+/// - [`Block::border_set_top`], [`Block::border_set_right`], [`Block::border_set_bottom`] and
+///   [`Block::border_set_left`] Override the symbols of one side of the borders.
 /// - [`Block::border_type`] Sets the symbols used to display the border (e.g. single line, double
 ///   line, thick or rounded borders).
 /// - [`Block::padding`] Defines the padding inside a [`Block`].
+/// - [`Block::shadow`] Paints a drop shadow on the right and at the bottom of the block.
 /// - [`Block::style`] Sets the base style of the widget.
 /// - [`Block::title`] Adds a title to the block.
 /// - [`Block::title_alignment`] Sets the default [`Alignment`] for all block titles.
 /// - [`Block::title_style`] Applies the style to all titles.
 /// - [`Block::title_top`] Adds a title to the top of the block.
 /// - [`Block::title_bottom`] Adds a title to the bottom of the block.
+/// - [`Block::title_left`] Adds a vertical title to the left border of the block.
+/// - [`Block::title_right`] Adds a vertical title to the right border of the block.
 /// - [`Block::title_position`] Adds a title to the block.
+/// - [`Block::vertical_title_alignment`] Sets the [`VerticalAlignment`] of the vertical titles.
 ///
 /// # Other Methods
 /// - [`Block::inner`] Compute the inner area of a block based on its border visibility rules.
@@ -115,17 +132,33 @@ pub struct Block<'a> {
     titles_alignment: Alignment,
     /// The default position of the titles that don't have one
     titles_position: Position,
+    /// The alignment of the titles along the left and right borders
+    vertical_titles_alignment: VerticalAlignment,
     /// Visible borders
     borders: Borders,
     /// Border style
     border_style: Style,
+    /// The styles patched over the border style on the top, right, bottom and left sides
+    top_border_style: Style,
+    right_border_style: Style,
+    bottom_border_style: Style,
+    left_border_style: Style,
+    /// The gradient of the foreground color along the top and bottom borders
+    border_gradient: Option<BorderGradient>,
     /// The symbols used to render the border. The default is plain lines but one can choose to
     /// have rounded or doubled lines instead or a custom set of symbols
     border_set: border::Set,
+    /// The symbols overriding the border set on the top, right, bottom and left sides
+    top_border_set: Option<border::Set>,
+    right_border_set: Option<border::Set>,
+    bottom_border_set: Option<border::Set>,
+    left_border_set: Option<border::Set>,
     /// Widget style
     style: Style,
     /// Block padding
     padding: Padding,
+    /// The drop shadow painted on the right and at the bottom of the block
+    shadow: Option<ShadowStyle>,
 }
 
 impl<'a> Block<'a> {
@@ -136,11 +169,22 @@ impl<'a> Block<'a> {
             titles_style: Style::new(),
             titles_alignment: Alignment::Left,
             titles_position: Position::Top,
+            vertical_titles_alignment: VerticalAlignment::Top,
             borders: Borders::NONE,
             border_style: Style::new(),
+            top_border_style: Style::new(),
+            right_border_style: Style::new(),
+            bottom_border_style: Style::new(),
+            left_border_style: Style::new(),
+            border_gradient: None,
             border_set: BorderType::Plain.to_border_set(),
+            top_border_set: None,
+            right_border_set: None,
+            bottom_border_set: None,
+            left_border_set: None,
             style: Style::new(),
             padding: Padding::ZERO,
+            shadow: None,
         }
     }
 
@@ -295,6 +339,69 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Adds a vertical title to the left border of the block.
+    ///
+    /// The title is rendered one character per row on the left border cells, so it doesn't change
+    /// the [inner area](Block::inner) of the block. It is aligned along the border with
+    /// [`Block::vertical_title_alignment`] and truncated if it is longer than the border. Multiple
+    /// titles on the same border are separated by a single row. Wide characters are rendered one per
+    /// row in the single border column on a best-effort basis.
+    ///
+    /// You can provide any type that can be converted into [`Line`]. The style of the line and its
+    /// spans is applied to the title, after [`Block::title_style`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::Block;
+    ///
+    /// Block::bordered().title_left("Menu");
+    ///
+    /// // Renders
+    /// // ┌─────┐
+    /// // M     │
+    /// // e     │
+    /// // n     │
+    /// // u     │
+    /// // └─────┘
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn title_left<T: Into<Line<'a>>>(mut self, title: T) -> Self {
+        let line = title.into();
+        self.titles.push((Some(Position::Left), line));
+        self
+    }
+
+    /// Adds a vertical title to the right border of the block.
+    ///
+    /// This behaves like [`Block::title_left`], on the right border.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::layout::VerticalAlignment;
+    /// use ratatui::widgets::Block;
+    ///
+    /// Block::bordered()
+    ///     .title_right("Menu")
+    ///     .vertical_title_alignment(VerticalAlignment::Bottom);
+    ///
+    /// // Renders
+    /// // ┌─────┐
+    /// // │     │
+    /// // │     M
+    /// // │     e
+    /// // │     n
+    /// // │     u
+    /// // └─────┘
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn title_right<T: Into<Line<'a>>>(mut self, title: T) -> Self {
+        let line = title.into();
+        self.titles.push((Some(Position::Right), line));
+        self
+    }
+
     /// Applies the style to all titles.
     ///
     /// This style will be applied to all titles of the block. If a title has a style set, it will
@@ -364,6 +471,27 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Sets the [`VerticalAlignment`] of the titles along the left and right borders.
+    ///
+    /// The titles are placed at the top, in the middle or at the bottom of the border, between the
+    /// corners. Defaults to [`VerticalAlignment::Top`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::layout::VerticalAlignment;
+    /// use ratatui::widgets::Block;
+    ///
+    /// Block::bordered()
+    ///     .title_left("Menu")
+    ///     .vertical_title_alignment(VerticalAlignment::Center);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn vertical_title_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.vertical_titles_alignment = alignment;
+        self
+    }
+
     /// Defines the style of the borders.
     ///
     /// This style is applied only to the areas covered by borders, and is applied to the block
@@ -390,6 +518,101 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Defines the style of the top border.
+    ///
+    /// This style is patched over the [`Block::border_style`] on the top border, including the top
+    /// corners, which take the style of the top border rather than the one of the left or right
+    /// border.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Example
+    ///
+    /// This example shows a `Block` with a yellow top border and dark gray sides.
+    /// ```
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::Block;
+    ///
+    /// Block::bordered()
+    ///     .border_style(Style::new().dark_gray())
+    ///     .top_border_style(Style::new().yellow());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn top_border_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.top_border_style = style.into();
+        self
+    }
+
+    /// Defines the style of the right border.
+    ///
+    /// This style is patched over the [`Block::border_style`] on the right border, excluding the
+    /// corners, which take the style of the top and bottom borders.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn right_border_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.right_border_style = style.into();
+        self
+    }
+
+    /// Defines the style of the bottom border.
+    ///
+    /// This style is patched over the [`Block::border_style`] on the bottom border, including the
+    /// bottom corners.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn bottom_border_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.bottom_border_style = style.into();
+        self
+    }
+
+    /// Defines the style of the left border.
+    ///
+    /// This style is patched over the [`Block::border_style`] on the left border, excluding the
+    /// corners, which take the style of the top and bottom borders.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn left_border_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.left_border_style = style.into();
+        self
+    }
+
+    /// Draws a color gradient along the top and bottom borders.
+    ///
+    /// The foreground color of each cell of the top and bottom borders, corners included, is
+    /// interpolated from the start color of the gradient on the left to its end color on the
+    /// right. It is applied over the [`Block::border_style`] and the style of each side. Pass
+    /// `None` to remove the gradient.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use ratatui::widgets::Block;
+    /// use ratatui::widgets::block::BorderGradient;
+    ///
+    /// Block::bordered().border_gradient(BorderGradient::new(Color::Cyan, Color::Magenta));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn border_gradient<G: Into<Option<BorderGradient>>>(mut self, gradient: G) -> Self {
+        self.border_gradient = gradient.into();
+        self
+    }
+
     /// Defines the style of the entire block.
     ///
     /// This is the most generic [`Style`] a block can receive, it will be merged with any other
@@ -496,6 +719,76 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Overrides the symbols of the top border with the ones of a
+    /// [`ratatui_core::symbols::border::Set`].
+    ///
+    /// The other sides keep using the [`border_set`](Block::border_set). The titles are rendered
+    /// over the top border as usual.
+    ///
+    /// The corners between two sides that use different sets join their lines when a box drawing
+    /// character exists for this combination, e.g. a thick top border and a plain left border
+    /// meet at `┍`. This is the case for plain lines combined with thick or double lines.
+    /// Otherwise, the corner is the one of the set of the top or bottom border.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{widgets::Block, symbols};
+    ///
+    /// Block::bordered().border_set_top(symbols::border::THICK).title("Block");
+    /// // Renders
+    /// // ┍Block┑
+    /// // │     │
+    /// // └─────┘
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn border_set_top(mut self, border_set: border::Set) -> Self {
+        self.top_border_set = Some(border_set);
+        self
+    }
+
+    /// Overrides the symbols of the right border with the ones of a
+    /// [`ratatui_core::symbols::border::Set`].
+    ///
+    /// See [`Block::border_set_top`] for how the corners are chosen.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn border_set_right(mut self, border_set: border::Set) -> Self {
+        self.right_border_set = Some(border_set);
+        self
+    }
+
+    /// Overrides the symbols of the bottom border with the ones of a
+    /// [`ratatui_core::symbols::border::Set`].
+    ///
+    /// See [`Block::border_set_top`] for how the corners are chosen.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn border_set_bottom(mut self, border_set: border::Set) -> Self {
+        self.bottom_border_set = Some(border_set);
+        self
+    }
+
+    /// Overrides the symbols of the left border with the ones of a
+    /// [`ratatui_core::symbols::border::Set`].
+    ///
+    /// See [`Block::border_set_top`] for how the corners are chosen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{widgets::Block, symbols};
+    ///
+    /// Block::bordered().border_set_left(symbols::border::DOUBLE);
+    /// // Renders
+    /// // ╓─────┐
+    /// // ║     │
+    /// // ╙─────┘
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn border_set_left(mut self, border_set: border::Set) -> Self {
+        self.left_border_set = Some(border_set);
+        self
+    }
+
     /// Defines the padding inside a `Block`.
     ///
     /// See [`Padding`] for more information.
@@ -530,6 +823,35 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Paints a drop shadow offset by one column and one row from the block.
+    ///
+    /// The shadow is painted on the right of the area of the block and below it, which is only
+    /// visible when [`ShadowStyle::allow_overflow`] is enabled and the buffer has room for it, or
+    /// over the last column and row of the area with [`ShadowPlacement::Inside`]. See
+    /// [`ShadowStyle`] for the details. The shadow doesn't change the [inner area](Block::inner).
+    ///
+    /// Passing `None` removes the shadow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use ratatui::widgets::Block;
+    /// use ratatui::widgets::block::ShadowStyle;
+    ///
+    /// Block::bordered().shadow(ShadowStyle::half_block(Color::DarkGray).allow_overflow(true));
+    /// // Renders
+    /// // ┌─────┐
+    /// // │     │█
+    /// // └─────┘█
+    /// //  ▀▀▀▀▀▀▀
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn shadow<S: Into<Option<ShadowStyle>>>(mut self, shadow: S) -> Self {
+        self.shadow = shadow.into();
+        self
+    }
+
     /// Compute the inner area of a block based on its border visibility rules.
     ///
     /// # Examples
@@ -602,6 +924,7 @@ impl Widget for Block<'_> {
 
 impl Widget for &Block<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        let full_area = area;
         let area = area.intersection(buf.area);
         if area.is_empty() {
             return;
@@ -609,6 +932,9 @@ impl Widget for &Block<'_> {
         buf.set_style(area, self.style);
         self.render_borders(area, buf);
         self.render_titles(area, buf);
+        if let Some(shadow) = self.shadow {
+            shadow.render(full_area, buf);
+        }
     }
 }
 
@@ -628,6 +954,8 @@ impl Block<'_> {
     fn render_titles(&self, area: Rect, buf: &mut Buffer) {
         self.render_title_position(Position::Top, area, buf);
         self.render_title_position(Position::Bottom, area, buf);
+        self.render_vertical_titles(Position::Left, area, buf);
+        self.render_vertical_titles(Position::Right, area, buf);
     }
 
     fn render_title_position(&self, position: Position, area: Rect, buf: &mut Buffer) {
@@ -639,10 +967,11 @@ impl Block<'_> {
 
     fn render_left_side(&self, area: Rect, buf: &mut Buffer) {
         if self.borders.contains(Borders::LEFT) {
+            let style = self.border_style.patch(self.left_border_style);
             for y in area.top()..area.bottom() {
                 buf[(area.left(), y)]
-                    .set_symbol(self.border_set.vertical_left)
-                    .set_style(self.border_style);
+                    .set_symbol(self.left_set().vertical_left)
+                    .set_style(style);
             }
         }
     }
@@ -651,19 +980,20 @@ impl Block<'_> {
         if self.borders.contains(Borders::TOP) {
             for x in area.left()..area.right() {
                 buf[(x, area.top())]
-                    .set_symbol(self.border_set.horizontal_top)
-                    .set_style(self.border_style);
+                    .set_symbol(self.top_set().horizontal_top)
+                    .set_style(self.horizontal_border_style(self.top_border_style, area, x));
             }
         }
     }
 
     fn render_right_side(&self, area: Rect, buf: &mut Buffer) {
         if self.borders.contains(Borders::RIGHT) {
+            let style = self.border_style.patch(self.right_border_style);
             let x = area.right() - 1;
             for y in area.top()..area.bottom() {
                 buf[(x, y)]
-                    .set_symbol(self.border_set.vertical_right)
-                    .set_style(self.border_style);
+                    .set_symbol(self.right_set().vertical_right)
+                    .set_style(style);
             }
         }
     }
@@ -673,41 +1003,100 @@ impl Block<'_> {
             let y = area.bottom() - 1;
             for x in area.left()..area.right() {
                 buf[(x, y)]
-                    .set_symbol(self.border_set.horizontal_bottom)
-                    .set_style(self.border_style);
+                    .set_symbol(self.bottom_set().horizontal_bottom)
+                    .set_style(self.horizontal_border_style(self.bottom_border_style, area, x));
             }
         }
     }
 
     fn render_bottom_right_corner(&self, buf: &mut Buffer, area: Rect) {
         if self.borders.contains(Borders::RIGHT | Borders::BOTTOM) {
-            buf[(area.right() - 1, area.bottom() - 1)]
-                .set_symbol(self.border_set.bottom_right)
-                .set_style(self.border_style);
+            let x = area.right() - 1;
+            buf[(x, area.bottom() - 1)]
+                .set_symbol(corner_symbol(
+                    (self.bottom_set(), self.right_set()),
+                    self.bottom_set().bottom_right,
+                    (
+                        self.bottom_set().horizontal_bottom,
+                        self.right_set().vertical_right,
+                    ),
+                    ["┙", "┚", "╛", "╜"],
+                ))
+                .set_style(self.horizontal_border_style(self.bottom_border_style, area, x));
         }
     }
 
     fn render_top_right_corner(&self, buf: &mut Buffer, area: Rect) {
         if self.borders.contains(Borders::RIGHT | Borders::TOP) {
-            buf[(area.right() - 1, area.top())]
-                .set_symbol(self.border_set.top_right)
-                .set_style(self.border_style);
+            let x = area.right() - 1;
+            buf[(x, area.top())]
+                .set_symbol(corner_symbol(
+                    (self.top_set(), self.right_set()),
+                    self.top_set().top_right,
+                    (
+                        self.top_set().horizontal_top,
+                        self.right_set().vertical_right,
+                    ),
+                    ["┑", "┒", "╕", "╖"],
+                ))
+                .set_style(self.horizontal_border_style(self.top_border_style, area, x));
         }
     }
 
     fn render_bottom_left_corner(&self, buf: &mut Buffer, area: Rect) {
         if self.borders.contains(Borders::LEFT | Borders::BOTTOM) {
-            buf[(area.left(), area.bottom() - 1)]
-                .set_symbol(self.border_set.bottom_left)
-                .set_style(self.border_style);
+            let x = area.left();
+            buf[(x, area.bottom() - 1)]
+                .set_symbol(corner_symbol(
+                    (self.bottom_set(), self.left_set()),
+                    self.bottom_set().bottom_left,
+                    (
+                        self.bottom_set().horizontal_bottom,
+                        self.left_set().vertical_left,
+                    ),
+                    ["┕", "┖", "╘", "╙"],
+                ))
+                .set_style(self.horizontal_border_style(self.bottom_border_style, area, x));
         }
     }
 
     fn render_top_left_corner(&self, buf: &mut Buffer, area: Rect) {
         if self.borders.contains(Borders::LEFT | Borders::TOP) {
-            buf[(area.left(), area.top())]
-                .set_symbol(self.border_set.top_left)
-                .set_style(self.border_style);
+            let x = area.left();
+            buf[(x, area.top())]
+                .set_symbol(corner_symbol(
+                    (self.top_set(), self.left_set()),
+                    self.top_set().top_left,
+                    (self.top_set().horizontal_top, self.left_set().vertical_left),
+                    ["┍", "┎", "╒", "╓"],
+                ))
+                .set_style(self.horizontal_border_style(self.top_border_style, area, x));
+        }
+    }
+
+    fn top_set(&self) -> &border::Set {
+        self.top_border_set.as_ref().unwrap_or(&self.border_set)
+    }
+
+    fn right_set(&self) -> &border::Set {
+        self.right_border_set.as_ref().unwrap_or(&self.border_set)
+    }
+
+    fn bottom_set(&self) -> &border::Set {
+        self.bottom_border_set.as_ref().unwrap_or(&self.border_set)
+    }
+
+    fn left_set(&self) -> &border::Set {
+        self.left_border_set.as_ref().unwrap_or(&self.border_set)
+    }
+
+    /// The style of the cell of the top or bottom border in the column `x`, made of the border
+    /// style, the style of the side and the color of the gradient at `x`
+    fn horizontal_border_style(&self, side_style: Style, area: Rect, x: u16) -> Style {
+        let style = self.border_style.patch(side_style);
+        match self.border_gradient {
+            Some(gradient) => style.fg(gradient.color(x - area.left(), area.width)),
+            None => style,
         }
     }
 
@@ -809,6 +1198,66 @@ impl Block<'_> {
         }
     }
 
+    /// Render the titles along the left or right border, one grapheme per row
+    fn render_vertical_titles(&self, position: Position, area: Rect, buf: &mut Buffer) {
+        let titles = self
+            .titles
+            .iter()
+            .filter(|(pos, _)| pos.unwrap_or(self.titles_position) == position)
+            .map(|(_, line)| line);
+        // a blank row separates the titles
+        let mut graphemes = Vec::new();
+        for title in titles {
+            if !graphemes.is_empty() {
+                graphemes.push(None);
+            }
+            graphemes.extend(
+                title
+                    .styled_graphemes(self.titles_style)
+                    .filter(|grapheme| !grapheme.symbol.is_empty())
+                    .map(Some),
+            );
+        }
+        if graphemes.is_empty() {
+            return;
+        }
+
+        let titles_area = self.vertical_titles_area(area, position);
+        let height = titles_area.height.min(graphemes.len() as u16);
+        let y = match self.vertical_titles_alignment {
+            VerticalAlignment::Top => titles_area.top(),
+            VerticalAlignment::Center => titles_area.top() + (titles_area.height - height) / 2,
+            VerticalAlignment::Bottom => titles_area.bottom() - height,
+        };
+        for (row, grapheme) in (y..y + height).zip(graphemes) {
+            let Some(grapheme) = grapheme else {
+                continue;
+            };
+            buf[(titles_area.x, row)]
+                .set_style(self.titles_style)
+                .set_symbol(grapheme.symbol)
+                .set_style(grapheme.style);
+        }
+    }
+
+    /// An area that is one column wide and spans the height of the block excluding the top and
+    /// bottom rows when they hold a border or titles, and is positioned at the left or right of
+    /// the block.
+    fn vertical_titles_area(&self, area: Rect, position: Position) -> Rect {
+        let (top, bottom) = self.vertical_space();
+        let top = top.saturating_sub(self.padding.top);
+        let bottom = bottom.saturating_sub(self.padding.bottom);
+        Rect {
+            x: match position {
+                Position::Right => area.right() - 1,
+                _ => area.left(),
+            },
+            y: area.top().saturating_add(top).min(area.bottom()),
+            width: 1,
+            height: area.height.saturating_sub(top).saturating_sub(bottom),
+        }
+    }
+
     /// An iterator over the titles that match the position and alignment
     fn filtered_titles(
         &self,
@@ -830,8 +1279,8 @@ impl Block<'_> {
         Rect {
             x: area.left() + left_border,
             y: match position {
-                Position::Top => area.top(),
                 Position::Bottom => area.bottom() - 1,
+                _ => area.top(),
             },
             width: area
                 .width
@@ -871,6 +1320,51 @@ impl Block<'_> {
     }
 }
 
+/// The weight of the line of a border symbol
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LineWeight {
+    Light,
+    Heavy,
+    Double,
+}
+
+impl LineWeight {
+    fn of(symbol: &str) -> Option<Self> {
+        match symbol {
+            "─" | "│" | "╌" | "╎" | "┄" | "┆" | "┈" | "┊" => Some(Self::Light),
+            "━" | "┃" | "╍" | "╏" | "┅" | "┇" | "┉" | "┋" => Some(Self::Heavy),
+            "═" | "║" => Some(Self::Double),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the symbol of a corner between a horizontal and a vertical border
+///
+/// When the borders use different sets of different line weights, the corner is the mixed symbol joining them,
+/// taken from `mixed` which holds the symbols for a heavy horizontal and light vertical line, a
+/// light horizontal and heavy vertical line, a double horizontal and light vertical line, and a
+/// light horizontal and double vertical line. Otherwise, the corner is `corner`, the corner of the
+/// horizontal border's set.
+fn corner_symbol(
+    (horizontal_set, vertical_set): (&border::Set, &border::Set),
+    corner: &'static str,
+    (horizontal, vertical): (&str, &str),
+    mixed: [&'static str; 4],
+) -> &'static str {
+    use LineWeight::{Double, Heavy, Light};
+    if horizontal_set == vertical_set {
+        return corner;
+    }
+    match (LineWeight::of(horizontal), LineWeight::of(vertical)) {
+        (Some(Heavy), Some(Light)) => mixed[0],
+        (Some(Light), Some(Heavy)) => mixed[1],
+        (Some(Double), Some(Light)) => mixed[2],
+        (Some(Light), Some(Double)) => mixed[3],
+        _ => corner,
+    }
+}
+
 /// An extension trait for [`Block`] that provides some convenience methods.
 ///
 /// This is implemented for [`Option<Block>`](Option) to simplify the common case of having a
@@ -904,6 +1398,7 @@ impl Styled for Block<'_> {
 mod tests {
     use alloc::{format, vec};
 
+    use ratatui_core::buffer::Cell;
     use ratatui_core::layout::HorizontalAlignment;
     use ratatui_core::style::{Color, Modifier, Stylize};
     use rstest::rstest;
@@ -1145,11 +1640,22 @@ mod tests {
                 titles_style: Style::new(),
                 titles_alignment: Alignment::Left,
                 titles_position: Position::Top,
+                vertical_titles_alignment: VerticalAlignment::Top,
                 borders: Borders::NONE,
                 border_style: Style::new(),
+                top_border_style: Style::new(),
+                right_border_style: Style::new(),
+                bottom_border_style: Style::new(),
+                left_border_style: Style::new(),
+                border_gradient: None,
                 border_set: BorderType::Plain.to_border_set(),
+                top_border_set: None,
+                right_border_set: None,
+                bottom_border_set: None,
+                left_border_set: None,
                 style: Style::new(),
                 padding: Padding::ZERO,
+                shadow: None,
             }
         );
     }
@@ -1228,7 +1734,7 @@ mod tests {
     #[test]
     fn title() {
         use HorizontalAlignment::*;
-        use Position::*;
+        use Position::{Bottom, Top};
         let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 3));
         #[expect(deprecated)] // until Title is removed
         Block::bordered()
@@ -1323,6 +1829,153 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(["    ", "test"]));
     }
 
+    #[rstest]
+    #[case::left_top(Position::Left, VerticalAlignment::Top, [
+        "┌───┐",
+        "a   │",
+        "b   │",
+        "c   │",
+        "│   │",
+        "│   │",
+        "└───┘",
+    ])]
+    #[case::left_center(Position::Left, VerticalAlignment::Center, [
+        "┌───┐",
+        "│   │",
+        "a   │",
+        "b   │",
+        "c   │",
+        "│   │",
+        "└───┘",
+    ])]
+    #[case::left_bottom(Position::Left, VerticalAlignment::Bottom, [
+        "┌───┐",
+        "│   │",
+        "│   │",
+        "a   │",
+        "b   │",
+        "c   │",
+        "└───┘",
+    ])]
+    #[case::right_top(Position::Right, VerticalAlignment::Top, [
+        "┌───┐",
+        "│   a",
+        "│   b",
+        "│   c",
+        "│   │",
+        "│   │",
+        "└───┘",
+    ])]
+    #[case::right_center(Position::Right, VerticalAlignment::Center, [
+        "┌───┐",
+        "│   │",
+        "│   a",
+        "│   b",
+        "│   c",
+        "│   │",
+        "└───┘",
+    ])]
+    #[case::right_bottom(Position::Right, VerticalAlignment::Bottom, [
+        "┌───┐",
+        "│   │",
+        "│   │",
+        "│   a",
+        "│   b",
+        "│   c",
+        "└───┘",
+    ])]
+    fn render_vertical_title<'line, Lines>(
+        #[case] position: Position,
+        #[case] alignment: VerticalAlignment,
+        #[case] expected: Lines,
+    ) where
+        Lines: IntoIterator,
+        Lines::Item: Into<Line<'line>>,
+    {
+        let block = match position {
+            Position::Left => Block::bordered().title_left("abc"),
+            _ => Block::bordered().title_right("abc"),
+        };
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 7));
+        block
+            .vertical_title_alignment(alignment)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn vertical_titles_do_not_change_inner_area() {
+        let area = Rect::new(0, 0, 5, 7);
+        let block = Block::bordered().title_left("abc").title_right("def");
+        assert_eq!(block.inner(area), Block::bordered().inner(area));
+        assert_eq!(Block::new().title_left("abc").inner(area), area);
+    }
+
+    #[test]
+    fn render_vertical_titles_truncated() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 4));
+        Block::bordered()
+            .title_left("abcdef")
+            .title_right("x")
+            .title_right("yz")
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌─┐",
+            "a x",
+            "b │",
+            "└─┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_vertical_titles_without_borders() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Block::new()
+            .title_top("top")
+            .title_left("ab")
+            .title_right("cd")
+            .vertical_title_alignment(VerticalAlignment::Bottom)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["top ", "a  c", "b  d"]));
+    }
+
+    #[test]
+    fn render_vertical_title_wide_graphemes() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 4));
+        Block::bordered()
+            .title_right("表示")
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["┌───┐", "│   │", "│   │", "└───┘"]);
+        expected[(4, 1)].set_symbol("表");
+        expected[(4, 2)].set_symbol("示");
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_vertical_title_style() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 4));
+        Block::bordered()
+            .title_style(Style::new().green().on_red())
+            .title_left(Line::from(vec!["a".yellow(), "b".into()]))
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["┌─┐", "a │", "b │", "└─┘"]);
+        expected.set_style(Rect::new(0, 1, 1, 1), Style::new().yellow().on_red());
+        expected.set_style(Rect::new(0, 2, 1, 1), Style::new().green().on_red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn title_position_vertical() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 4));
+        Block::bordered()
+            .title_position(Position::Right)
+            .title("ab")
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["┌─┐", "│ a", "│ b", "└─┘"]));
+    }
+
     #[test]
     fn title_content_style() {
         for alignment in [Alignment::Left, Alignment::Center, Alignment::Right] {
@@ -1379,6 +2032,90 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn render_two_tone_border() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 3));
+        Block::bordered()
+            .border_style(Style::new().dark_gray())
+            .top_border_style(Style::new().yellow().bold())
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌────┐",
+            "│    │",
+            "└────┘",
+        ]);
+        expected.set_style(Rect::new(0, 0, 6, 3), Style::new().dark_gray());
+        expected.set_style(Rect::new(1, 1, 4, 1), Style::reset());
+        expected.set_style(Rect::new(0, 0, 6, 1), Style::new().yellow().bold());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_border_style_per_side() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Block::bordered()
+            .top_border_style(Color::Red)
+            .right_border_style(Color::Green)
+            .bottom_border_style(Color::Blue)
+            .left_border_style(Color::Yellow)
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["┌──┐", "│  │", "└──┘"]);
+        expected.set_style(Rect::new(0, 0, 4, 1), Style::new().red());
+        expected.set_style(Rect::new(3, 1, 1, 1), Style::new().green());
+        expected.set_style(Rect::new(0, 2, 4, 1), Style::new().blue());
+        expected.set_style(Rect::new(0, 1, 1, 1), Style::new().yellow());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_gradient_top_border() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        Block::new()
+            .borders(Borders::TOP)
+            .border_style(Style::new().bold())
+            .border_gradient(BorderGradient::new(
+                Color::Rgb(0, 0, 0),
+                Color::Rgb(200, 100, 0),
+            ))
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["─────", "     ", "     "]);
+        for (x, fg) in (0..).zip([
+            Color::Rgb(0, 0, 0),
+            Color::Rgb(50, 25, 0),
+            Color::Rgb(100, 50, 0),
+            Color::Rgb(150, 75, 0),
+            Color::Rgb(200, 100, 0),
+        ]) {
+            expected.set_style(Rect::new(x, 0, 1, 1), Style::new().fg(fg).bold());
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_gradient_ignores_vertical_borders() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        Block::bordered()
+            .border_style(Style::new().dark_gray())
+            .border_gradient(BorderGradient::new(
+                Color::Rgb(0, 0, 0),
+                Color::Rgb(200, 0, 0),
+            ))
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["┌─┐", "│ │", "└─┘"]);
+        expected.set_style(Rect::new(0, 1, 1, 1), Style::new().dark_gray());
+        expected.set_style(Rect::new(2, 1, 1, 1), Style::new().dark_gray());
+        for y in [0, 2] {
+            for (x, fg) in (0..).zip([(0, 0, 0), (100, 0, 0), (200, 0, 0)]) {
+                expected.set_style(
+                    Rect::new(x, y, 1, 1),
+                    Style::new().fg(Color::Rgb(fg.0, fg.1, fg.2)),
+                );
+            }
+        }
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn border_type_to_string() {
         assert_eq!(format!("{}", BorderType::Plain), "Plain");
@@ -1459,6 +2196,190 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn render_thick_top_border() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        Block::bordered()
+            .border_set_top(border::THICK)
+            .title("Title")
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┍Title━━━┑",
+            "│        │",
+            "└────────┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::double_left(Block::bordered().border_set_left(border::DOUBLE), [
+        "╓────┐",
+        "║    │",
+        "╙────┘",
+    ])]
+    #[case::thick_right(Block::bordered().border_set_right(border::THICK), [
+        "┌────┒",
+        "│    ┃",
+        "└────┚",
+    ])]
+    #[case::double_bottom(Block::bordered().border_set_bottom(border::DOUBLE), [
+        "┌────┐",
+        "│    │",
+        "╘════╛",
+    ])]
+    #[case::thick_sides_thick_border_set(
+        Block::bordered()
+            .border_set(border::THICK)
+            .border_set_top(border::PLAIN)
+            .border_set_bottom(border::PLAIN),
+        [
+            "┎────┒",
+            "┃    ┃",
+            "┖────┚",
+        ]
+    )]
+    #[case::rounded_top_keeps_corners(Block::bordered().border_set_top(border::ROUNDED), [
+        "╭────╮",
+        "│    │",
+        "└────┘",
+    ])]
+    #[case::thick_top_double_left(
+        Block::bordered()
+            .border_set_top(border::THICK)
+            .border_set_left(border::DOUBLE),
+        [
+            "┏━━━━┑",
+            "║    │",
+            "╙────┘",
+        ]
+    )]
+    fn render_border_set_per_side<'line, Lines>(#[case] block: Block, #[case] expected: Lines)
+    where
+        Lines: IntoIterator,
+        Lines::Item: Into<Line<'line>>,
+    {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 3));
+        block.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn render_half_block_shadow() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 10));
+        let shadow = ShadowStyle::half_block(Color::DarkGray).allow_overflow(true);
+        Block::bordered()
+            .shadow(shadow)
+            .render(Rect::new(2, 2, 10, 5), &mut buffer);
+        let mut expected = Buffer::with_lines([
+            "                    ",
+            "                    ",
+            "  ┌────────┐        ",
+            "  │        │█       ",
+            "  │        │█       ",
+            "  │        │█       ",
+            "  └────────┘█       ",
+            "   ▀▀▀▀▀▀▀▀▀▀       ",
+            "                    ",
+            "                    ",
+        ]);
+        expected.set_style(Rect::new(12, 3, 1, 5), Style::new().dark_gray());
+        expected.set_style(Rect::new(3, 7, 10, 1), Style::new().dark_gray());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_dim_shadow() {
+        let mut buffer = Buffer::filled(Rect::new(0, 0, 20, 10), Cell::new("x"));
+        Block::bordered()
+            .shadow(ShadowStyle::dim().allow_overflow(true))
+            .render(Rect::new(2, 2, 10, 5), &mut buffer);
+        let mut expected = Buffer::with_lines([
+            "xxxxxxxxxxxxxxxxxxxx",
+            "xxxxxxxxxxxxxxxxxxxx",
+            "xx┌────────┐xxxxxxxx",
+            "xx│xxxxxxxx│xxxxxxxx",
+            "xx│xxxxxxxx│xxxxxxxx",
+            "xx│xxxxxxxx│xxxxxxxx",
+            "xx└────────┘xxxxxxxx",
+            "xxxxxxxxxxxxxxxxxxxx",
+            "xxxxxxxxxxxxxxxxxxxx",
+            "xxxxxxxxxxxxxxxxxxxx",
+        ]);
+        let style = Style::new().dark_gray().on_black();
+        expected.set_style(Rect::new(12, 3, 1, 5), style);
+        expected.set_style(Rect::new(3, 7, 10, 1), style);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_shadow_without_overflow() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 10));
+        Block::bordered()
+            .shadow(ShadowStyle::half_block(Color::DarkGray))
+            .render(Rect::new(2, 2, 10, 5), &mut buffer);
+        let mut expected = Buffer::empty(Rect::new(0, 0, 20, 10));
+        Block::bordered().render(Rect::new(2, 2, 10, 5), &mut expected);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_shadow_inside() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 10));
+        let shadow = ShadowStyle::half_block(Color::DarkGray).placement(ShadowPlacement::Inside);
+        Block::bordered()
+            .shadow(shadow)
+            .render(Rect::new(2, 2, 10, 5), &mut buffer);
+        let mut expected = Buffer::with_lines([
+            "                    ",
+            "                    ",
+            "  ┌────────┐        ",
+            "  │        █        ",
+            "  │        █        ",
+            "  │        █        ",
+            "  └▀▀▀▀▀▀▀▀▀        ",
+            "                    ",
+            "                    ",
+            "                    ",
+        ]);
+        expected.set_style(Rect::new(11, 3, 1, 4), Style::new().dark_gray());
+        expected.set_style(Rect::new(3, 6, 9, 1), Style::new().dark_gray());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_shadow_clipped_to_buffer() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 10));
+        let shadow = ShadowStyle::half_block(Color::DarkGray).allow_overflow(true);
+        Block::bordered()
+            .shadow(shadow)
+            .render(Rect::new(10, 6, 9, 4), &mut buffer);
+        let mut expected = Buffer::with_lines([
+            "                    ",
+            "                    ",
+            "                    ",
+            "                    ",
+            "                    ",
+            "                    ",
+            "          ┌───────┐ ",
+            "          │       │█",
+            "          │       │█",
+            "          └───────┘█",
+        ]);
+        expected.set_style(Rect::new(19, 7, 1, 3), Style::new().dark_gray());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn shadow_does_not_change_inner_area() {
+        let area = Rect::new(2, 2, 10, 5);
+        let shadow = ShadowStyle::dim().allow_overflow(true);
+        assert_eq!(
+            Block::bordered().shadow(shadow).inner(area),
+            Block::bordered().inner(area)
+        );
+    }
+
     #[test]
     fn render_rounded_border() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
@@ -0,0 +1,62 @@
+use ratatui_core::style::Color;
+
+/// A color gradient drawn along the horizontal borders of a [`Block`].
+///
+/// See the [`border_gradient`] method of [`Block`]. The foreground color of each cell of the top
+/// and bottom borders, corners included, is interpolated in RGB from `start` on the left to `end`
+/// on the right with [`Color::blend`].
+///
+/// # Example
+///
+/// ```
+/// use ratatui::style::Color;
+/// use ratatui::widgets::Block;
+/// use ratatui::widgets::block::BorderGradient;
+///
+/// Block::bordered().border_gradient(BorderGradient::new(
+///     Color::Rgb(255, 0, 0),
+///     Color::Rgb(0, 0, 255),
+/// ));
+/// ```
+///
+/// [`Block`]: crate::block::Block
+/// [`border_gradient`]: crate::block::Block::border_gradient
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BorderGradient {
+    /// The color of the leftmost cell of the borders
+    pub start: Color,
+    /// The color of the rightmost cell of the borders
+    pub end: Color,
+}
+
+impl BorderGradient {
+    /// Creates a gradient from `start` on the left to `end` on the right.
+    pub const fn new(start: Color, end: Color) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the color of the cell at `index` in a border `width` cells wide.
+    pub(crate) fn color(self, index: u16, width: u16) -> Color {
+        let t = if width > 1 {
+            f64::from(index) / f64::from(width - 1)
+        } else {
+            0.0
+        };
+        self.start.blend(self.end, t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color() {
+        let gradient = BorderGradient::new(Color::Rgb(0, 0, 0), Color::Rgb(200, 100, 0));
+        assert_eq!(gradient.color(0, 5), Color::Rgb(0, 0, 0));
+        assert_eq!(gradient.color(2, 5), Color::Rgb(100, 50, 0));
+        assert_eq!(gradient.color(4, 5), Color::Rgb(200, 100, 0));
+        assert_eq!(gradient.color(0, 1), Color::Rgb(0, 0, 0));
+    }
+}
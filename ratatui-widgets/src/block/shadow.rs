@@ -0,0 +1,161 @@
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::{Color, Style};
+use ratatui_core::symbols::half_block;
+use strum::{Display, EnumString};
+
+/// A drop shadow painted by a [`Block`] on the right and at the bottom of its area.
+///
+/// See the [`shadow`] method of [`Block`]. The shadow is the area of the block offset by one column
+/// to the right and one row down, excluding the area itself:
+///
+/// - with [`ShadowPlacement::Outside`] (the default), the shadow is the column on the right of the
+///   area and the row below it. As widgets only draw in the area they are given, these cells are
+///   only painted when [`ShadowStyle::allow_overflow`] is enabled, in which case the area passed
+///   to the block must leave room for them in the buffer (e.g. a popup rendered in a larger
+///   screen). The cells outside of the buffer are never painted.
+/// - with [`ShadowPlacement::Inside`], the shadow is painted over the last column and the last row
+///   of the area, i.e. over the right and bottom borders, so it never leaves the area.
+///
+/// The shadow doesn't change the [inner area](crate::block::Block::inner) of the block.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::style::Color;
+/// use ratatui::widgets::Block;
+/// use ratatui::widgets::block::ShadowStyle;
+///
+/// Block::bordered().shadow(ShadowStyle::half_block(Color::DarkGray).allow_overflow(true));
+/// ```
+///
+/// [`Block`]: crate::block::Block
+/// [`shadow`]: crate::block::Block::shadow
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ShadowStyle {
+    /// The style patched over the cells of the shadow
+    pub style: Style,
+    /// Whether the cells of the shadow are filled with blocks instead of keeping their content
+    pub half_block: bool,
+    /// Where the shadow is painted relative to the area of the block
+    pub placement: ShadowPlacement,
+    /// Whether the shadow can be painted outside of the area of the block
+    pub allow_overflow: bool,
+}
+
+/// Defines where the [shadow](ShadowStyle) of a [`Block`](crate::block::Block) is painted.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ShadowPlacement {
+    /// The shadow is painted on the right of the area of the block and below it.
+    ///
+    /// This is the default.
+    #[default]
+    Outside,
+    /// The shadow is painted over the last column and the last row of the area of the block.
+    Inside,
+}
+
+impl Default for ShadowStyle {
+    fn default() -> Self {
+        Self::dim()
+    }
+}
+
+impl ShadowStyle {
+    /// A shadow that keeps the content of its cells and dims them with a dark gray foreground on a
+    /// black background.
+    pub const fn dim() -> Self {
+        Self {
+            style: Style::new().fg(Color::DarkGray).bg(Color::Black),
+            half_block: false,
+            placement: ShadowPlacement::Outside,
+            allow_overflow: false,
+        }
+    }
+
+    /// A shadow drawn with blocks of the given color: full blocks in the right column and upper
+    /// half blocks in the bottom row, so that it looks about as thick on both sides.
+    pub const fn half_block(color: Color) -> Self {
+        Self {
+            style: Style::new().fg(color),
+            half_block: true,
+            placement: ShadowPlacement::Outside,
+            allow_overflow: false,
+        }
+    }
+
+    /// Sets where the shadow is painted relative to the area of the block.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn placement(mut self, placement: ShadowPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets whether the shadow can be painted outside of the area of the block.
+    ///
+    /// This is needed for a [`ShadowPlacement::Outside`] shadow to be visible. The shadow is still
+    /// clipped to the buffer.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn allow_overflow(mut self, allow_overflow: bool) -> Self {
+        self.allow_overflow = allow_overflow;
+        self
+    }
+
+    /// Paints the shadow of a block rendered in `area`
+    pub(crate) fn render(self, area: Rect, buf: &mut Buffer) {
+        let (right, bottom) = match self.placement {
+            ShadowPlacement::Outside => (
+                Rect::new(area.right(), area.y.saturating_add(1), 1, area.height),
+                Rect::new(area.x.saturating_add(1), area.bottom(), area.width, 1),
+            ),
+            ShadowPlacement::Inside => (
+                Rect::new(
+                    area.right().saturating_sub(1),
+                    area.y.saturating_add(1),
+                    1,
+                    area.height.saturating_sub(1),
+                ),
+                Rect::new(
+                    area.x.saturating_add(1),
+                    area.bottom().saturating_sub(1),
+                    area.width.saturating_sub(1),
+                    1,
+                ),
+            ),
+        };
+        let clip = if self.allow_overflow {
+            buf.area
+        } else {
+            area.intersection(buf.area)
+        };
+        for (region, symbol) in [(right, half_block::FULL), (bottom, half_block::UPPER)] {
+            for position in region.intersection(clip).positions() {
+                let cell = &mut buf[position];
+                if self.half_block {
+                    cell.set_char(symbol);
+                }
+                cell.set_style(self.style);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn placement_to_string() {
+        assert_eq!(ShadowPlacement::Outside.to_string(), "Outside");
+        assert_eq!(ShadowPlacement::Inside.to_string(), "Inside");
+    }
+
+    #[test]
+    fn default_is_dim() {
+        assert_eq!(ShadowStyle::default(), ShadowStyle::dim());
+        assert_eq!(ShadowStyle::default().placement, ShadowPlacement::Outside);
+        assert!(!ShadowStyle::default().allow_overflow);
+    }
+}
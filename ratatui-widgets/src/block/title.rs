@@ -78,8 +78,8 @@ pub struct Title<'a> {
 
 /// Defines the [title](crate::block::Title) position.
 ///
-/// The title can be positioned on top or at the bottom of the block.
-/// Defaults to [`Position::Top`].
+/// The title can be positioned on top or at the bottom of the block, or vertically along its left
+/// or right border. Defaults to [`Position::Top`].
 ///
 /// # Example
 ///
@@ -98,6 +98,14 @@ pub enum Position {
     Top,
     /// Position the title at the bottom of the block.
     Bottom,
+    /// Position the title vertically along the left border of the block, one character per row.
+    ///
+    /// See [`Block::title_left`](crate::block::Block::title_left).
+    Left,
+    /// Position the title vertically along the right border of the block, one character per row.
+    ///
+    /// See [`Block::title_right`](crate::block::Block::title_right).
+    Right,
 }
 
 impl<'a> Title<'a> {
@@ -154,12 +162,16 @@ mod tests {
     fn position_to_string() {
         assert_eq!(Position::Top.to_string(), "Top");
         assert_eq!(Position::Bottom.to_string(), "Bottom");
+        assert_eq!(Position::Left.to_string(), "Left");
+        assert_eq!(Position::Right.to_string(), "Right");
     }
 
     #[test]
     fn position_from_str() {
         assert_eq!("Top".parse::<Position>(), Ok(Position::Top));
         assert_eq!("Bottom".parse::<Position>(), Ok(Position::Bottom));
+        assert_eq!("Left".parse::<Position>(), Ok(Position::Left));
+        assert_eq!("Right".parse::<Position>(), Ok(Position::Right));
         assert_eq!("".parse::<Position>(), Err(ParseError::VariantNotFound));
     }
 
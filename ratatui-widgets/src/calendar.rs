@@ -5,9 +5,13 @@
 //! The [`Monthly`] widget will display a calendar for the month provided in `display_date`. Days
 //! are styled using the default style unless:
 //! * `show_surrounding` is set, then days not in the `display_date` month will use that style.
+//! * the day is part of a range added with [`Monthly::range`]
 //! * a style is returned by the [`DateStyler`] for the day
 //!
 //! [`Monthly`] has several controls for what should be displayed
+//!
+//! Several months can be displayed at once by setting a [`CalendarView`], in which case the months
+//! are tiled in a grid and each month is styled the same way as a single month.
 use alloc::format;
 use alloc::vec::Vec;
 
@@ -17,15 +21,64 @@ use ratatui_core::layout::{Alignment, Constraint, Layout, Rect};
 use ratatui_core::style::Style;
 use ratatui_core::text::{Line, Span};
 use ratatui_core::widgets::Widget;
-use time::{Date, Duration};
+use time::{Date, Duration, Month};
 
 use crate::block::{Block, BlockExt};
 
+/// Width of a single month, which is 7 days of 3 columns each
+const MONTH_WIDTH: u16 = 21;
+
+/// Maximum number of weeks containing a day of a month
+const MAX_WEEKS: u16 = 6;
+
+/// Defines which months are displayed by a [`Monthly`] calendar
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::calendar::{CalendarEventStore, CalendarView, Monthly};
+/// use time::{Date, Month};
+///
+/// let date = Date::from_calendar_date(2023, Month::January, 1).unwrap();
+/// let calendar = Monthly::new(date, CalendarEventStore::default()).view(CalendarView::Year);
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CalendarView {
+    /// Display the month containing the display date (default)
+    #[default]
+    Month,
+    /// Display `rows * columns` months in a grid, starting with the month containing the display
+    /// date
+    Grid {
+        /// Number of rows of months
+        rows: u16,
+        /// Number of months in each row
+        columns: u16,
+    },
+    /// Display the 12 months of the year containing the display date in 3 rows of 4 months
+    Year,
+}
+
+impl CalendarView {
+    /// Returns the number of rows and columns of months in the view
+    const fn grid_size(self) -> (u16, u16) {
+        match self {
+            Self::Month => (1, 1),
+            Self::Grid { rows, columns } => (rows, columns),
+            Self::Year => (3, 4),
+        }
+    }
+}
+
 /// Display a month calendar for the month containing `display_date`
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Monthly<'a, DS: DateStyler> {
     display_date: Date,
+    view: CalendarView,
     events: DS,
+    ranges: Vec<(Date, Date, Style)>,
+    range_ends_style: Style,
     show_surrounding: Option<Style>,
     show_weekday: Option<Style>,
     show_month: Option<Style>,
@@ -38,7 +91,10 @@ impl<'a, DS: DateStyler> Monthly<'a, DS> {
     pub const fn new(display_date: Date, events: DS) -> Self {
         Self {
             display_date,
+            view: CalendarView::Month,
             events,
+            ranges: Vec::new(),
+            range_ends_style: Style::new(),
             show_surrounding: None,
             show_weekday: None,
             show_month: None,
@@ -97,6 +153,65 @@ impl<'a, DS: DateStyler> Monthly<'a, DS> {
         self
     }
 
+    /// Set which months are displayed
+    ///
+    /// By default, only the month containing the display date is displayed. See [`CalendarView`]
+    /// for the other views. When several months are displayed, each month is rendered with the
+    /// same headers and styles as a single month, and the block surrounds all of them.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn view(mut self, view: CalendarView) -> Self {
+        self.view = view;
+        self
+    }
+
+    /// Highlight all the days from `start` to `end` (inclusive) with `style`
+    ///
+    /// The dates can be given in any order: a range whose `start` is after its `end` highlights the
+    /// same days as the range from `end` to `start`.
+    ///
+    /// Ranges can be added several times. When ranges overlap, the styles of the overlapping days
+    /// are merged, with the styles of later ranges patched over earlier ones. The style returned by
+    /// the [`DateStyler`] for a day is patched over the style of its ranges. Use
+    /// [`Monthly::range_ends_style`] to differentiate the first and last days of the ranges.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::calendar::{CalendarEventStore, Monthly};
+    /// use time::{Date, Month};
+    ///
+    /// let start = Date::from_calendar_date(2023, Month::July, 24).unwrap();
+    /// let end = Date::from_calendar_date(2023, Month::August, 4).unwrap();
+    /// let calendar = Monthly::new(start, CalendarEventStore::default())
+    ///     .range(start, end, Style::new().on_blue())
+    ///     .range_ends_style(Style::new().bold());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn range<S: Into<Style>>(mut self, start: Date, end: Date, style: S) -> Self {
+        self.ranges
+            .push((start.min(end), start.max(end), style.into()));
+        self
+    }
+
+    /// Set the style patched over the first and last days of each range added with
+    /// [`Monthly::range`]
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn range_ends_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.range_ends_style = style.into();
+        self
+    }
+
     /// Render the calendar within a [Block]
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn block(mut self, block: Block<'a>) -> Self {
@@ -112,21 +227,34 @@ impl<'a, DS: DateStyler> Monthly<'a, DS> {
         }
     }
 
+    /// Return the style of the ranges containing the date, patched with the style of its events
+    fn date_style(&self, date: Date) -> Style {
+        self.ranges
+            .iter()
+            .filter(|(start, end, _)| (*start..=*end).contains(&date))
+            .fold(Style::new(), |style, (start, end, range_style)| {
+                let style = style.patch(*range_style);
+                if date == *start || date == *end {
+                    style.patch(self.range_ends_style)
+                } else {
+                    style
+                }
+            })
+            .patch(self.events.get_style(date))
+    }
+
     /// All logic to style a date goes here.
-    fn format_date(&self, date: Date) -> Span {
-        if date.month() == self.display_date.month() {
+    fn format_date(&self, display_date: Date, date: Date) -> Span {
+        if date.month() == display_date.month() {
             Span::styled(
                 format!("{:2?}", date.day()),
-                self.default_style.patch(self.events.get_style(date)),
+                self.default_style.patch(self.date_style(date)),
             )
         } else {
             match self.show_surrounding {
                 None => Span::styled("  ", self.default_bg()),
                 Some(s) => {
-                    let style = self
-                        .default_style
-                        .patch(s)
-                        .patch(self.events.get_style(date));
+                    let style = self.default_style.patch(s).patch(self.date_style(date));
                     Span::styled(format!("{:2?}", date.day()), style)
                 }
             }
@@ -144,12 +272,43 @@ impl<DS: DateStyler> Widget for &Monthly<'_, DS> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         self.block.as_ref().render(area, buf);
         let inner = self.block.inner_if_some(area);
-        self.render_monthly(inner, buf);
+        match self.view {
+            CalendarView::Month => self.render_monthly(self.display_date, inner, buf),
+            view => self.render_grid(view, inner, buf),
+        }
     }
 }
 
 impl<DS: DateStyler> Monthly<'_, DS> {
-    fn render_monthly(&self, area: Rect, buf: &mut Buffer) {
+    /// Renders the months of a multi-month view, one row of months at a time.
+    fn render_grid(&self, view: CalendarView, area: Rect, buf: &mut Buffer) {
+        let (rows, columns) = view.grid_size();
+        let first_month = match view {
+            CalendarView::Year => self.display_date.replace_month(Month::January).ok(),
+            _ => self.display_date.replace_day(1).ok(),
+        };
+        let Some(first_month) = first_month else {
+            return;
+        };
+        let month_height = u16::from(self.show_month.is_some())
+            + u16::from(self.show_weekday.is_some())
+            + MAX_WEEKS;
+        let row_areas = Layout::vertical((0..rows).map(|_| Constraint::Length(month_height)))
+            .spacing(1)
+            .split(area);
+        let column_layout =
+            Layout::horizontal((0..columns).map(|_| Constraint::Length(MONTH_WIDTH))).spacing(1);
+        for (row, row_area) in row_areas.iter().enumerate() {
+            for (column, month_area) in column_layout.split(*row_area).iter().enumerate() {
+                let index = row * usize::from(columns) + column;
+                if let Some(date) = add_months(first_month, index) {
+                    self.render_monthly(date, *month_area, buf);
+                }
+            }
+        }
+    }
+
+    fn render_monthly(&self, display_date: Date, area: Rect, buf: &mut Buffer) {
         let layout = Layout::vertical([
             Constraint::Length(self.show_month.is_some().into()),
             Constraint::Length(self.show_weekday.is_some().into()),
@@ -160,7 +319,7 @@ impl<DS: DateStyler> Monthly<'_, DS> {
         // Draw the month name and year
         if let Some(style) = self.show_month {
             Line::styled(
-                format!("{} {}", self.display_date.month(), self.display_date.year()),
+                format!("{} {}", display_date.month(), display_date.year()),
                 style,
             )
             .alignment(Alignment::Center)
@@ -173,13 +332,13 @@ impl<DS: DateStyler> Monthly<'_, DS> {
         }
 
         // Set the start of the calendar to the Sunday before the 1st (or the sunday of the first)
-        let first_of_month = self.display_date.replace_day(1).unwrap();
+        let first_of_month = display_date.replace_day(1).unwrap();
         let offset = Duration::days(first_of_month.weekday().number_days_from_sunday().into());
         let mut curr_day = first_of_month - offset;
 
         let mut y = days_area.y;
         // go through all the weeks containing a day in the target month.
-        while curr_day.month() != self.display_date.month().next() {
+        while curr_day.month() != display_date.month().next() {
             let mut spans = Vec::with_capacity(14);
             for i in 0..7 {
                 // Draw the gutter. Do it here so we can avoid worrying about
@@ -189,10 +348,10 @@ impl<DS: DateStyler> Monthly<'_, DS> {
                 } else {
                     spans.push(Span::styled(" ", self.default_bg()));
                 }
-                spans.push(self.format_date(curr_day));
+                spans.push(self.format_date(display_date, curr_day));
                 curr_day += Duration::DAY;
             }
-            if buf.area.height > y {
+            if y < days_area.bottom() {
                 buf.set_line(days_area.x, y, &spans.into(), area.width);
             }
             y += 1;
@@ -200,6 +359,14 @@ impl<DS: DateStyler> Monthly<'_, DS> {
     }
 }
 
+/// Returns the first day of the month `months` months after the month of `date`
+fn add_months(date: Date, months: usize) -> Option<Date> {
+    let months = i32::try_from(months).ok()?;
+    let index = date.year() * 12 + i32::from(u8::from(date.month())) - 1 + months;
+    let month = Month::try_from(u8::try_from(index.rem_euclid(12) + 1).ok()?).ok()?;
+    Date::from_calendar_date(index.div_euclid(12), month, 1).ok()
+}
+
 /// Provides a method for styling a given date. [Monthly] is generic on this trait, so any type
 /// that implements this trait can be used.
 pub trait DateStyler {
@@ -269,7 +436,6 @@ impl Default for CalendarEventStore {
 #[cfg(test)]
 mod tests {
     use ratatui_core::style::Color;
-    use time::Month;
 
     use super::*;
 
@@ -298,6 +464,135 @@ mod tests {
         );
     }
 
+    #[test]
+    fn add_months_wraps_around_years() {
+        let date = Date::from_calendar_date(2023, Month::November, 15).unwrap();
+        assert_eq!(
+            add_months(date, 0),
+            Date::from_calendar_date(2023, Month::November, 1).ok()
+        );
+        assert_eq!(
+            add_months(date, 3),
+            Date::from_calendar_date(2024, Month::February, 1).ok()
+        );
+        assert_eq!(
+            add_months(date, 14),
+            Date::from_calendar_date(2025, Month::January, 1).ok()
+        );
+    }
+
+    #[test]
+    fn render_two_months_side_by_side() {
+        let date = Date::from_calendar_date(2023, Month::January, 15).unwrap();
+        let mut events = CalendarEventStore::default();
+        events.add(date, Style::new().red());
+        events.add(
+            Date::from_calendar_date(2023, Month::February, 14).unwrap(),
+            Style::new().blue(),
+        );
+        let calendar = Monthly::new(date, events)
+            .show_month_header(Style::new())
+            .show_weekdays_header(Style::new())
+            .view(CalendarView::Grid {
+                rows: 1,
+                columns: 2,
+            });
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 43, 8));
+        calendar.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([
+            "    January 2023          February 2023    ",
+            " Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa",
+            "  1  2  3  4  5  6  7            1  2  3  4",
+            "  8  9 10 11 12 13 14   5  6  7  8  9 10 11",
+            " 15 16 17 18 19 20 21  12 13 14 15 16 17 18",
+            " 22 23 24 25 26 27 28  19 20 21 22 23 24 25",
+            " 29 30 31              26 27 28            ",
+            "                                           ",
+        ]);
+        expected.set_style(Rect::new(1, 4, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(29, 4, 2, 1), Style::new().blue());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_range_across_month_boundary() {
+        let date = |month, day| Date::from_calendar_date(2023, month, day).unwrap();
+        let calendar = Monthly::new(date(Month::January, 1), CalendarEventStore::default())
+            .range(
+                date(Month::January, 30),
+                date(Month::February, 2),
+                Style::new().on_blue(),
+            )
+            .range(
+                date(Month::January, 31),
+                date(Month::February, 1),
+                Style::new().red(),
+            )
+            .range_ends_style(Style::new().bold())
+            .view(CalendarView::Grid {
+                rows: 1,
+                columns: 2,
+            });
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 43, 6));
+        calendar.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([
+            "  1  2  3  4  5  6  7            1  2  3  4",
+            "  8  9 10 11 12 13 14   5  6  7  8  9 10 11",
+            " 15 16 17 18 19 20 21  12 13 14 15 16 17 18",
+            " 22 23 24 25 26 27 28  19 20 21 22 23 24 25",
+            " 29 30 31              26 27 28            ",
+            "                                           ",
+        ]);
+        // January 30th and February 2nd are the ends of the first range
+        expected.set_style(Rect::new(4, 4, 2, 1), Style::new().on_blue().bold());
+        expected.set_style(Rect::new(35, 0, 2, 1), Style::new().on_blue().bold());
+        // January 31st and February 1st are the ends of the second, overlapping, range
+        expected.set_style(Rect::new(7, 4, 2, 1), Style::new().on_blue().red().bold());
+        expected.set_style(Rect::new(32, 0, 2, 1), Style::new().on_blue().red().bold());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn range_with_start_after_end() {
+        let date = |day| Date::from_calendar_date(2023, Month::January, day).unwrap();
+        let calendar = Monthly::new(date(1), CalendarEventStore::default())
+            .range(date(12), date(10), Style::new().on_blue())
+            .range_ends_style(Style::new().bold());
+        assert_eq!(calendar.date_style(date(9)), Style::new());
+        assert_eq!(calendar.date_style(date(10)), Style::new().on_blue().bold());
+        assert_eq!(calendar.date_style(date(11)), Style::new().on_blue());
+        assert_eq!(calendar.date_style(date(12)), Style::new().on_blue().bold());
+        assert_eq!(calendar.date_style(date(13)), Style::new());
+    }
+
+    #[test]
+    fn render_year_view_starts_in_january() {
+        let date = Date::from_calendar_date(2023, Month::June, 15).unwrap();
+        let calendar = Monthly::new(date, CalendarEventStore::default())
+            .show_month_header(Style::new())
+            .view(CalendarView::Year);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 87, 23));
+        calendar.render(buffer.area, &mut buffer);
+        let month_headers = [(0, 0), (22, 0), (44, 0), (66, 0), (0, 8), (66, 16)].map(|(x, y)| {
+            let start = buffer.index_of(x, y);
+            buffer.content[start..start + 21]
+                .iter()
+                .map(ratatui_core::buffer::Cell::symbol)
+                .collect::<alloc::string::String>()
+        });
+        assert_eq!(
+            month_headers.each_ref().map(|header| header.trim()),
+            [
+                "January 2023",
+                "February 2023",
+                "March 2023",
+                "April 2023",
+                "May 2023",
+                "December 2023",
+            ]
+        );
+    }
+
     #[test]
     fn test_today() {
         CalendarEventStore::today(Style::default());
@@ -1,7 +1,6 @@
 //! The [`Chart`] widget is used to plot one or more [`Dataset`] in a cartesian coordinate system.
 use alloc::vec::Vec;
 use core::cmp::max;
-use core::ops::Not;
 
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::{Alignment, Constraint, Flex, Layout, Position, Rect};
@@ -17,7 +16,8 @@ use crate::canvas::{Canvas, Line as CanvasLine, Points};
 /// An X or Y axis for the [`Chart`] widget
 ///
 /// An axis can have a [title](Axis::title) which will be displayed at the end of the axis. For an
-/// X axis this is the right, for a Y axis, this is the top.
+/// X axis this is the right, for a Y axis, this is the top. The title can also be displayed
+/// outside of the graph area, see [`Axis::title_position`].
 ///
 /// You can also set the bounds and labels on this axis using respectively [`Axis::bounds`] and
 /// [`Axis::labels`].
@@ -48,6 +48,8 @@ pub struct Axis<'a> {
     style: Style,
     /// The alignment of the labels of the Axis
     labels_alignment: Alignment,
+    /// Where the title is displayed
+    title_position: AxisTitlePosition,
 }
 
 impl<'a> Axis<'a> {
@@ -151,6 +153,62 @@ impl<'a> Axis<'a> {
         self.labels_alignment = alignment;
         self
     }
+
+    /// Sets where the axis title is displayed
+    ///
+    /// By default ([`AxisTitlePosition::End`]) the title is drawn over the graph area at the end of
+    /// the axis. The other positions reserve space outside of the graph area for the title, which
+    /// shrinks the graph accordingly. See [`AxisTitlePosition`] for details.
+    ///
+    /// When the chart area is too small, titles displayed outside of the graph area are hidden
+    /// before the labels are.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Axis, AxisTitlePosition};
+    ///
+    /// let axis = Axis::default()
+    ///     .title("requests per second")
+    ///     .title_position(AxisTitlePosition::Vertical);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn title_position(mut self, position: AxisTitlePosition) -> Self {
+        self.title_position = position;
+        self
+    }
+
+    /// Returns the title if it is displayed over the graph area
+    fn title_over_graph(&self) -> Option<&Line<'a>> {
+        self.title
+            .as_ref()
+            .filter(|_| self.title_position == AxisTitlePosition::End)
+    }
+}
+
+/// Where the title of an [`Axis`] is displayed
+///
+/// See [`Axis::title_position`]
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum AxisTitlePosition {
+    /// The title is displayed over the graph area at the end of the axis. This is the **default**.
+    ///
+    /// For an X axis this is the right, for a Y axis, this is the top.
+    #[default]
+    End,
+    /// The title is displayed on its own row outside of the graph area.
+    ///
+    /// For an X axis the title is centered under the axis labels. For a Y axis the title is
+    /// displayed horizontally above the axis labels.
+    Outside,
+    /// The title is displayed vertically, one character per row, in its own column along the left
+    /// edge of the chart.
+    ///
+    /// The title is centered vertically alongside the graph area and truncated if it is taller.
+    /// This only applies to the Y axis, an X axis treats this as [`AxisTitlePosition::Outside`].
+    Vertical,
 }
 
 /// Used to determine which style of graphing to use
@@ -423,6 +481,9 @@ impl<'a> Dataset<'a> {
     }
 }
 
+/// The minimum width and height of the graph area for titles outside of it to be displayed
+const MIN_GRAPH_SIZE_WITH_OUTSIDE_TITLES: u16 = 3;
+
 /// A container that holds all the infos about where to display each elements of the chart (axis,
 /// labels, legend, ...).
 struct ChartLayout {
@@ -729,24 +790,73 @@ impl<'a> Chart<'a> {
 
     /// Compute the internal layout of the chart given the area. If the area is too small some
     /// elements may be automatically hidden
+    #[expect(clippy::too_many_lines)]
     fn layout(&self, area: Rect) -> Option<ChartLayout> {
         if area.height == 0 || area.width == 0 {
             return None;
         }
         let mut x = area.left();
         let mut y = area.bottom() - 1;
+        let mut top = area.top();
+
+        let has_x_labels = !self.x_axis.labels.is_empty();
+        let show_y_axis = !self.y_axis.labels.is_empty();
+
+        // Titles outside of the graph area are only shown if the labels, the axis and a graph of
+        // a minimum size still fit, so that they are the first thing to be hidden in small areas.
+        let mut outside_rows = if has_x_labels { 2 } else { 0 };
+        let mut outside_title_x = None;
+        if self.x_axis.title.is_some()
+            && self.x_axis.title_position != AxisTitlePosition::End
+            && area.height >= outside_rows + 1 + MIN_GRAPH_SIZE_WITH_OUTSIDE_TITLES
+        {
+            outside_title_x = Some(y);
+            outside_rows += 1;
+            y -= 1;
+        }
+
+        let mut outside_title_y = None;
+        if self.y_axis.title.is_some() {
+            match self.y_axis.title_position {
+                AxisTitlePosition::End => {}
+                AxisTitlePosition::Outside => {
+                    if area.height >= outside_rows + 1 + MIN_GRAPH_SIZE_WITH_OUTSIDE_TITLES {
+                        outside_title_y = Some(Position::new(area.left(), top));
+                        top += 1;
+                    }
+                }
+                AxisTitlePosition::Vertical => {
+                    let labels_area = Rect {
+                        x: area.x + 1,
+                        width: area.width - 1,
+                        ..area
+                    };
+                    let labels_width =
+                        self.max_width_of_labels_left_of_y_axis(labels_area, show_y_axis);
+                    if area.width
+                        >= 1 + labels_width
+                            + u16::from(show_y_axis)
+                            + MIN_GRAPH_SIZE_WITH_OUTSIDE_TITLES
+                    {
+                        outside_title_y = Some(Position::new(area.left(), top));
+                        x += 1;
+                    }
+                }
+            }
+        }
+        let labels_area = Rect::new(x, area.y, area.right() - x, area.height);
 
         let mut label_x = None;
-        if !self.x_axis.labels.is_empty() && y > area.top() {
+        if has_x_labels && y > top {
             label_x = Some(y);
             y -= 1;
         }
 
-        let label_y = self.y_axis.labels.is_empty().not().then_some(x);
-        x += self.max_width_of_labels_left_of_y_axis(area, !self.y_axis.labels.is_empty());
+        let label_y = show_y_axis.then_some(x);
+        x += self.max_width_of_labels_left_of_y_axis(labels_area, show_y_axis);
 
         let mut axis_x = None;
-        if !self.x_axis.labels.is_empty() && y > area.top() {
+        if has_x_labels && y > top {
             axis_x = Some(y);
             y -= 1;
         }
@@ -758,7 +868,7 @@ impl<'a> Chart<'a> {
         }
 
         let graph_width = area.right().saturating_sub(x);
-        let graph_height = y.saturating_sub(area.top()).saturating_add(1);
+        let graph_height = y.saturating_sub(top).saturating_add(1);
         debug_assert_ne!(
             graph_width, 0,
             "Axis and labels should have been hidden due to the small area"
@@ -767,20 +877,33 @@ impl<'a> Chart<'a> {
             graph_height, 0,
             "Axis and labels should have been hidden due to the small area"
         );
-        let graph_area = Rect::new(x, area.top(), graph_width, graph_height);
+        let graph_area = Rect::new(x, top, graph_width, graph_height);
 
         let mut title_x = None;
         if let Some(ref title) = self.x_axis.title {
             let w = title.width() as u16;
-            if w < graph_area.width && graph_area.height > 2 {
-                title_x = Some(Position::new(x + graph_area.width - w, y));
+            match outside_title_x {
+                Some(title_y) => {
+                    let offset = graph_area.width.saturating_sub(w) / 2;
+                    title_x = Some(Position::new(graph_area.left() + offset, title_y));
+                }
+                None if self.x_axis.title_position == AxisTitlePosition::End
+                    && w < graph_area.width
+                    && graph_area.height > 2 =>
+                {
+                    title_x = Some(Position::new(x + graph_area.width - w, y));
+                }
+                None => {}
             }
         }
 
-        let mut title_y = None;
+        let mut title_y = outside_title_y;
         if let Some(ref title) = self.y_axis.title {
             let w = title.width() as u16;
-            if w + 1 < graph_area.width && graph_area.height > 2 {
+            if self.y_axis.title_position == AxisTitlePosition::End
+                && w + 1 < graph_area.width
+                && graph_area.height > 2
+            {
                 title_y = Some(Position::new(x, area.top()));
             }
         }
@@ -813,11 +936,11 @@ impl<'a> Chart<'a> {
                         legend_width,
                         legend_height,
                         title_x
-                            .and(self.x_axis.title.as_ref())
+                            .and_then(|_| self.x_axis.title_over_graph())
                             .map(|t| t.width() as u16)
                             .unwrap_or_default(),
                         title_y
-                            .and(self.y_axis.title.as_ref())
+                            .and_then(|_| self.y_axis.title_over_graph())
                             .map(|t| t.width() as u16)
                             .unwrap_or_default(),
                     );
@@ -1077,20 +1200,35 @@ impl Widget for &Chart<'_> {
 
         if let Some(Position { x, y }) = layout.title_y {
             let title = self.y_axis.title.as_ref().unwrap();
-            let width = graph_area
-                .right()
-                .saturating_sub(x)
-                .min(title.width() as u16);
-            buf.set_style(
-                Rect {
-                    x,
-                    y,
-                    width,
-                    height: 1,
-                },
-                original_style,
-            );
-            buf.set_line(x, y, title, width);
+            if self.y_axis.title_position == AxisTitlePosition::Vertical {
+                let graphemes = title.styled_graphemes(Style::default()).collect::<Vec<_>>();
+                let offset = graph_area.height.saturating_sub(graphemes.len() as u16) / 2;
+                for (i, grapheme) in graphemes
+                    .iter()
+                    .take(graph_area.height as usize)
+                    .enumerate()
+                {
+                    buf[(x, y + offset + i as u16)]
+                        .set_style(original_style)
+                        .set_symbol(grapheme.symbol)
+                        .set_style(grapheme.style);
+                }
+            } else {
+                let width = graph_area
+                    .right()
+                    .saturating_sub(x)
+                    .min(title.width() as u16);
+                buf.set_style(
+                    Rect {
+                        x,
+                        y,
+                        width,
+                        height: 1,
+                    },
+                    original_style,
+                );
+                buf.set_line(x, y, title, width);
+            }
         }
 
         if let Some(legend_area) = layout.legend_area {
@@ -1511,6 +1649,127 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(expected));
     }
 
+    fn chart_with_outside_titles(y_title_position: AxisTitlePosition) -> Chart<'static> {
+        Chart::new(vec![])
+            .x_axis(
+                Axis::default()
+                    .title("time")
+                    .title_position(AxisTitlePosition::Outside)
+                    .bounds([0.0, 10.0])
+                    .labels(["0", "10"]),
+            )
+            .y_axis(
+                Axis::default()
+                    .title("req/s")
+                    .title_position(y_title_position)
+                    .bounds([0.0, 100.0])
+                    .labels(["0", "100"]),
+            )
+    }
+
+    #[test]
+    fn outside_axis_titles_80x24() {
+        let chart = chart_with_outside_titles(AxisTitlePosition::Outside);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 80, 24));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "req/s                                                                           ",
+            "100│                                                                            ",
+            "   │                                                                            ",
+            "   │                                                                            ",
+            "   │                                                                            ",
+            "   │                                                                            ",
+            "   │                                                                            ",
+            "   │                                                                            ",
+            "   │                                                                            ",
+            "   │                                                                            ",
+            "   │                                                                            ",
+            "   │                                                                            ",
+            "   │                                                                            ",
+            "   │                                                                            ",
+            "   │                                                                            ",
+            "   │                                                                            ",
+            "   │                                                                            ",
+            "   │                                                                            ",
+            "   │                                                                            ",
+            "   │                                                                            ",
+            "0  │                                                                            ",
+            "   └────────────────────────────────────────────────────────────────────────────",
+            "   0                                                                          10",
+            "                                        time                                    ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn vertical_y_axis_title_80x24() {
+        let chart = chart_with_outside_titles(AxisTitlePosition::Vertical);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 80, 24));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            " 100│                                                                           ",
+            "    │                                                                           ",
+            "    │                                                                           ",
+            "    │                                                                           ",
+            "    │                                                                           ",
+            "    │                                                                           ",
+            "    │                                                                           ",
+            "    │                                                                           ",
+            "r   │                                                                           ",
+            "e   │                                                                           ",
+            "q   │                                                                           ",
+            "/   │                                                                           ",
+            "s   │                                                                           ",
+            "    │                                                                           ",
+            "    │                                                                           ",
+            "    │                                                                           ",
+            "    │                                                                           ",
+            "    │                                                                           ",
+            "    │                                                                           ",
+            "    │                                                                           ",
+            " 0  │                                                                           ",
+            "    └───────────────────────────────────────────────────────────────────────────",
+            "    0                                                                         10",
+            "                                        time                                    ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn vertical_y_axis_title_20x10() {
+        let chart = chart_with_outside_titles(AxisTitlePosition::Vertical);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 10));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            " 100│               ",
+            "r   │               ",
+            "e   │               ",
+            "q   │               ",
+            "/   │               ",
+            "s   │               ",
+            " 0  │               ",
+            "    └───────────────",
+            "    0             10",
+            "          time      ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn outside_axis_titles_are_hidden_before_labels() {
+        let chart = chart_with_outside_titles(AxisTitlePosition::Outside);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 5));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "100│                ",
+            "   │                ",
+            "0  │                ",
+            "   └────────────────",
+            "   0              10",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn bar_chart() {
         let data = [
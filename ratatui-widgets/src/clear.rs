@@ -1,6 +1,7 @@
 //! The [`Clear`] widget allows you to clear a certain area to allow overdrawing (e.g. for popups).
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
+use ratatui_core::style::{Style, Styled};
 use ratatui_core::widgets::Widget;
 
 /// A widget to clear/reset a certain area to allow overdrawing (e.g. for popups).
@@ -26,9 +27,57 @@ use ratatui_core::widgets::Widget;
 ///
 /// For a more complete example how to utilize `Clear` to realize popups see
 /// the example `examples/popup.rs`
+///
+/// Use [`Clear::with_style`] to clear the area to a style instead of the default style.
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct Clear;
 
+/// A widget to clear a certain area to a style, e.g. to draw a solid panel.
+///
+/// This widget is created with [`Clear::with_style`]. It resets the cells of the area like
+/// [`Clear`] does, and then applies its style to them, so that each cell contains a space with the
+/// colors and modifiers of the style.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::Frame;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Style, Stylize};
+/// use ratatui::widgets::Clear;
+///
+/// fn draw_panel(f: &mut Frame, area: Rect) {
+///     f.render_widget(Clear::with_style(Style::new().on_blue()), area);
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct StyledClear {
+    style: Style,
+}
+
+impl Clear {
+    /// Creates a widget that clears the area to the given style instead of the default style
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use ratatui::widgets::Clear;
+    ///
+    /// let clear = Clear::with_style(Style::new().bg(Color::Blue));
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    pub fn with_style<S: Into<Style>>(style: S) -> StyledClear {
+        StyledClear {
+            style: style.into(),
+        }
+    }
+}
+
 impl Widget for Clear {
     fn render(self, area: Rect, buf: &mut Buffer) {
         Widget::render(&self, area, buf);
@@ -45,10 +94,38 @@ impl Widget for &Clear {
     }
 }
 
+impl Widget for StyledClear {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &StyledClear {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        buf.set_style(area, self.style);
+    }
+}
+
+impl Styled for StyledClear {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        Self {
+            style: style.into(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use ratatui_core::buffer::Buffer;
     use ratatui_core::layout::Rect;
+    use ratatui_core::style::{Color, Modifier, Stylize};
     use ratatui_core::widgets::Widget;
 
     use super::*;
@@ -69,4 +146,32 @@ mod tests {
         ]);
         assert_eq!(buffer, expected);
     }
+
+    #[test]
+    fn render_with_style() {
+        let mut buffer = Buffer::with_lines(["xxxxx"; 4]);
+        buffer.set_style(buffer.area, Style::new().red().on_green().bold());
+        Clear::with_style(Style::new().on_blue()).render(Rect::new(1, 1, 3, 2), &mut buffer);
+
+        for y in 0..4 {
+            for x in 0..5 {
+                let cell = &buffer[(x, y)];
+                if (1..4).contains(&x) && (1..3).contains(&y) {
+                    assert_eq!(cell.symbol(), " ", "symbol at ({x}, {y})");
+                    assert_eq!(cell.fg, Color::Reset, "fg at ({x}, {y})");
+                    assert_eq!(cell.bg, Color::Blue, "bg at ({x}, {y})");
+                    assert_eq!(cell.modifier, Modifier::empty(), "modifier at ({x}, {y})");
+                } else {
+                    assert_eq!(cell.symbol(), "x", "symbol at ({x}, {y})");
+                    assert_eq!(cell.bg, Color::Green, "bg at ({x}, {y})");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn stylize() {
+        let clear = Clear::with_style(Style::new()).white().on_blue();
+        assert_eq!(clear.style, Style::new().white().on_blue());
+    }
 }
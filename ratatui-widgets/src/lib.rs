@@ -36,7 +36,7 @@
 //!
 //! - [`BarChart`]: displays multiple datasets as bars with optional grouping.
 //! - [`Block`]: a basic widget that draws a block with optional borders, titles, and styles.
-//! - [`calendar::Monthly`]: displays a single month.
+//! - [`calendar::Monthly`]: displays a single month, or several months in a grid.
 //! - [`Canvas`]: draws arbitrary shapes using drawing characters.
 //! - [`Chart`]: displays multiple datasets as lines or scatter graphs.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
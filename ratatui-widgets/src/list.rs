@@ -7,14 +7,20 @@ use ratatui_core::style::{Style, Styled};
 use ratatui_core::text::Line;
 use strum::{Display, EnumString};
 
+pub use self::checkbox::{CheckboxList, CheckboxListState};
 pub use self::item::ListItem;
+pub use self::separator::ListSeparator;
 pub use self::state::ListState;
+use self::virtual_items::VirtualItems;
 use crate::block::Block;
 use crate::table::HighlightSpacing;
 
+mod checkbox;
 mod item;
 mod rendering;
+mod separator;
 mod state;
+mod virtual_items;
 
 /// A widget to display several items among which one can be selected (optional)
 ///
@@ -39,10 +45,24 @@ mod state;
 /// # Fluent setters
 ///
 /// - [`List::highlight_style`] sets the style of the selected item.
+/// - [`List::multi_highlight_style`] sets the style of the items in the
+///   [selection set](ListState::selected_set).
+/// - [`List::disabled_style`] sets the style of the [disabled](ListItem::disabled) items.
+/// - [`List::grabbed_style`] sets the style of the item [grabbed](ListState::grab) to be moved.
+/// - [`List::alternating_item_styles`] alternates the style of the items.
 /// - [`List::highlight_symbol`] sets the symbol to be displayed in front of the selected item.
 /// - [`List::repeat_highlight_symbol`] sets whether to repeat the symbol and style over selected
 ///   multi-line items
 /// - [`List::direction`] sets the list direction
+/// - [`List::item_spacing`] sets the spacing between the items of a horizontal list
+/// - [`List::item_separator`] sets the line drawn between the items of a vertical list
+/// - [`List::wrap_items`] wraps the content of the items and grows them to fit it
+/// - [`List::max_item_height`] limits the height of items with wrapped content
+/// - [`List::sticky_headers`] pins the header of the displayed items at the top of the list
+/// - [`List::anchor`] sets the edge the items are laid out from, e.g. the bottom for a chat
+/// - [`List::items_from`] sets items that are created on demand, for huge datasets
+/// - [`List::item_heights_from`] sets the height of the items created on demand
+/// - [`List::filter`] shows only the items matching a predicate
 ///
 /// # Examples
 ///
@@ -117,6 +137,14 @@ pub struct List<'a> {
     pub(crate) direction: ListDirection,
     /// Style used to render selected item
     pub(crate) highlight_style: Style,
+    /// Style used to render the items in the selection set
+    pub(crate) multi_highlight_style: Style,
+    /// Style used to render disabled items
+    pub(crate) disabled_style: Style,
+    /// Style used to render the grabbed item at its tentative position
+    pub(crate) grabbed_style: Style,
+    /// Styles alternately applied to the even and odd items
+    pub(crate) alternating_item_styles: Option<(Style, Style)>,
     /// Symbol in front of the selected item (Shift all items to the right)
     pub(crate) highlight_symbol: Option<Line<'a>>,
     /// Whether to repeat the highlight symbol for each line of the selected item
@@ -125,11 +153,28 @@ pub struct List<'a> {
     pub(crate) highlight_spacing: HighlightSpacing,
     /// How many items to try to keep visible before and after the selected item
     pub(crate) scroll_padding: usize,
+    /// The number of columns between the items of a horizontal list
+    pub(crate) item_spacing: u16,
+    /// The line drawn between the items of a vertical list
+    pub(crate) item_separator: Option<ListSeparator<'a>>,
+    /// Whether the content of the items is wrapped to the width of the list
+    pub(crate) wrap_items: bool,
+    /// Maximum height of an item when its height is increased to fit its wrapped content
+    pub(crate) max_item_height: Option<u16>,
+    /// Whether the header of the displayed items is pinned at the top of the list
+    pub(crate) sticky_headers: bool,
+    /// The edge the items are laid out from when they don't fill the list
+    pub(crate) anchor: ListAnchor,
+    /// The items created on demand, which replace `items` when set
+    pub(crate) virtual_items: Option<VirtualItems<'a>>,
+    /// The indices of the items shown when the list is filtered, in ascending order
+    pub(crate) filtered: Option<Vec<usize>>,
 }
 
 /// Defines the direction in which the list will be rendered.
 ///
-/// If there are too few items to fill the screen, the list will stick to the starting edge.
+/// If there are too few items to fill the screen, the list will stick to the starting edge, unless
+/// it is anchored to the bottom with [`List::anchor`].
 ///
 /// See [`List::direction`].
 #[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
@@ -140,6 +185,47 @@ pub enum ListDirection {
     TopToBottom,
     /// The first value is on the bottom, going to the top.
     BottomToTop,
+    /// The first value is on the left, going to the right.
+    ///
+    /// The items are rendered on the first line of the list, each as wide as its content plus the
+    /// width of the highlight symbol when [`List::highlight_spacing`] allocates it, and separated
+    /// by [`List::item_spacing`] columns. Only the first line of each item is rendered; the other
+    /// lines are truncated. Items that don't fit are scrolled horizontally based on the offset of
+    /// the [`ListState`], in the same way the items of a vertical list are scrolled.
+    ///
+    /// [`ListState`]: crate::list::ListState
+    Horizontal,
+}
+
+/// Defines the edge a [`ListDirection::TopToBottom`] list is laid out from when its items don't
+/// fill it.
+///
+/// See [`List::anchor`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ListAnchor {
+    /// The first item is on the top of the list, and the unused space is below the last item
+    #[default]
+    Top,
+    /// The last item is on the bottom of the list, and the unused space is above the first item
+    Bottom,
+}
+
+/// Defines which item is selected when navigating a list, see [`List::navigate`].
+#[derive(Debug, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ListNavigation {
+    /// Selects the next item, or the first one if no item is selected
+    Next,
+    /// Selects the previous item, or the last one if no item is selected
+    Previous,
+    /// Selects the first item
+    First,
+    /// Selects the last item
+    Last,
+    /// Selects the next item, wrapping around to the first item after the last one
+    NextWrapping,
+    /// Selects the previous item, wrapping around to the last item before the first one
+    PreviousWrapping,
 }
 
 impl<'a> List<'a> {
@@ -219,6 +305,122 @@ impl<'a> List<'a> {
         T::Item: Into<ListItem<'a>>,
     {
         self.items = items.into_iter().map(Into::into).collect();
+        self.virtual_items = None;
+        self.filtered = None;
+        self
+    }
+
+    /// Set the items to be created on demand from their index
+    ///
+    /// Instead of building every [`ListItem`] up front, the list calls `item` only for the indexes
+    /// of the items it displays, which are computed from `len` and the offset and selection of the
+    /// [`ListState`]. This makes it possible to display huge datasets without creating an item for
+    /// each entry on every frame. The selection and offset are clamped to `len`, so
+    /// [`ListState::select_last`] selects the item at `len - 1`.
+    ///
+    /// The items are assumed to be 1 line high, unless [`List::item_heights_from`] is used. The
+    /// items of a [`ListDirection::Horizontal`] list are created to measure their width while
+    /// scrolling, which can create more items than are displayed.
+    ///
+    /// This replaces the items set with [`List::new`] or [`List::items`], and setting them again
+    /// replaces the virtual items. Either way, the [filter](List::filter) is removed.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::List;
+    ///
+    /// let lines: Vec<String> = (0..1_000_000).map(|i| format!("line {i}")).collect();
+    /// let list = List::default().items_from(lines.len(), |i| lines[i].as_str().into());
+    /// ```
+    ///
+    /// [`ListState`]: crate::list::ListState
+    /// [`ListState::select_last`]: crate::list::ListState::select_last
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn items_from<F>(mut self, len: usize, item: F) -> Self
+    where
+        F: Fn(usize) -> ListItem<'a> + 'a,
+    {
+        self.items = Vec::new();
+        self.virtual_items = Some(VirtualItems::new(len, item));
+        self.filtered = None;
+        self
+    }
+
+    /// Set the height of each of the items created with [`List::items_from`]
+    ///
+    /// `height` is called with the index of an item and returns the number of lines it takes. It
+    /// should match the items created by [`List::items_from`]. This has no effect if the items are
+    /// not virtual.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::List;
+    ///
+    /// let list = List::default()
+    ///     .items_from(1000, |i| format!("Item {i}\ndetails").into())
+    ///     .item_heights_from(|_| 2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn item_heights_from<F>(mut self, height: F) -> Self
+    where
+        F: Fn(usize) -> u16 + 'a,
+    {
+        if let Some(virtual_items) = &mut self.virtual_items {
+            virtual_items.set_height(height);
+        }
+        self
+    }
+
+    /// Shows only the items matching `predicate`, e.g. the results of a search
+    ///
+    /// The indices used by the [`ListState`] (the selected item, the offset and the
+    /// [selection set](ListState::selected_set)) and by [`ListState::index_at`] keep referring to
+    /// the items of the list rather than to their position among the shown items, so the selection
+    /// survives a change of the filter without the application building another list of items.
+    ///
+    /// When the selected item is filtered out, the next shown item is selected when rendering, or
+    /// the last one if there is none after it. Nothing is selected when no item matches. The items
+    /// selected in the set stay in it while they are hidden. [`List::navigate`] skips the hidden
+    /// items, unlike the selection methods of [`ListState`] which don't know them.
+    ///
+    /// `predicate` is called once for each item when this method is called, which creates the
+    /// items set with [`List::items_from`], so the items must be set first. Setting them again
+    /// removes the filter.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListState};
+    ///
+    /// let query = "an";
+    /// let list = List::new(["Apple", "Banana", "Cherry", "Mango"])
+    ///     .filter(|item| item.content().to_string().contains(query));
+    /// let mut state = ListState::default();
+    /// list.select_next(&mut state);
+    /// assert_eq!(state.selected(), Some(1));
+    /// list.select_next(&mut state);
+    /// assert_eq!(state.selected(), Some(3));
+    /// ```
+    ///
+    /// [`ListState`]: crate::list::ListState
+    /// [`ListState::index_at`]: crate::list::ListState::index_at
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn filter<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&ListItem<'a>) -> bool,
+    {
+        let filtered = (0..self.len())
+            .filter(|&i| self.check_item(i, &predicate))
+            .collect();
+        self.filtered = Some(filtered);
         self
     }
 
@@ -331,6 +533,136 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Set the style of the items in the selection set
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style will be applied to each visible item of the set of items selected with
+    /// [`ListState::toggle`]. The [`List::highlight_style`] of the selected item is applied on top
+    /// of it, so the selected item, which acts as a cursor, stands out when it is also in the set.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::List;
+    ///
+    /// let items = ["Item 1", "Item 2"];
+    /// let list = List::new(items)
+    ///     .highlight_style(Style::new().reversed())
+    ///     .multi_highlight_style(Style::new().on_blue());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn multi_highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.multi_highlight_style = style.into();
+        self
+    }
+
+    /// Set the style of the [disabled](ListItem::disabled) items
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style will be applied to the entire item, overriding the style set on the item or on
+    /// the individual cells. The [`List::highlight_style`] is applied over it when a disabled item
+    /// is selected.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::{List, ListItem};
+    ///
+    /// let items = [ListItem::new("Copy"), ListItem::new("Paste").disabled(true)];
+    /// let list = List::new(items).disabled_style(Style::new().dark_gray());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.disabled_style = style.into();
+        self
+    }
+
+    /// Set the style of the item [grabbed](ListState::grab) to be moved
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style is applied to the entire grabbed item, over the [`List::highlight_style`], at
+    /// the position the item is moved to.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::List;
+    ///
+    /// let list = List::new(["Intro", "Verse", "Chorus"]).grabbed_style(Style::new().on_blue());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn grabbed_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.grabbed_style = style.into();
+        self
+    }
+
+    /// Alternate the style of the items between `even` and `odd`
+    ///
+    /// The first item of the list uses `even`, the second item `odd` and so on, in the order the
+    /// items are rendered, so the styles don't change when the list is scrolled. The styles are
+    /// applied over [`List::style`], and are overridden by [`ListItem::style`], the styles of the
+    /// item's content, and the highlight styles. The space after the last item is not striped.
+    ///
+    /// `even` and `odd` accept any type that is convertible to [`Style`] (e.g. [`Style`],
+    /// [`Color`], or your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::List;
+    ///
+    /// let list = List::new(["Item 0", "Item 1", "Item 2"])
+    ///     .alternating_item_styles(Style::new(), Style::new().on_dark_gray());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn alternating_item_styles<S: Into<Style>, T: Into<Style>>(
+        mut self,
+        even: S,
+        odd: T,
+    ) -> Self {
+        self.alternating_item_styles = Some((even.into(), odd.into()));
+        self
+    }
+
+    /// Returns the base style of the item at `index` in the rendered items
+    pub(crate) fn item_style(&self, index: usize, item: &ListItem<'_>) -> Style {
+        let stripe = self
+            .alternating_item_styles
+            .map_or_else(
+                Style::new,
+                |(even, odd)| {
+                    if index % 2 == 0 { even } else { odd }
+                },
+            );
+        self.style.patch(stripe).patch(item.style)
+    }
+
     /// Set whether to repeat the highlight symbol and style over selected multi-line items
     ///
     /// This is `false` by default.
@@ -374,9 +706,10 @@ impl<'a> List<'a> {
         self
     }
 
-    /// Defines the list direction (up or down)
+    /// Defines the list direction (up, down or horizontal)
     ///
-    /// Defines if the `List` is displayed *top to bottom* (default) or *bottom to top*.
+    /// Defines if the `List` is displayed *top to bottom* (default), *bottom to top* or *left to
+    /// right*, see [`ListDirection::Horizontal`] for how horizontal lists are laid out.
     /// If there is too few items to fill the screen, the list will stick to the starting edge.
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -397,8 +730,167 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Sets the number of columns between the items of a horizontal list
+    ///
+    /// This only applies to lists with the [`ListDirection::Horizontal`] direction. The default is
+    /// no spacing.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListDirection};
+    ///
+    /// let tags = ["rust", "tui", "terminal"];
+    /// let list = List::new(tags)
+    ///     .direction(ListDirection::Horizontal)
+    ///     .item_spacing(1);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn item_spacing(mut self, spacing: u16) -> Self {
+        self.item_spacing = spacing;
+        self
+    }
+
+    /// Sets the line drawn between the items of the list
+    ///
+    /// The separator takes one row between each item and the next one, but not after the last
+    /// item. With multi-line items, the separator is drawn after all the lines of the item. The
+    /// separator is never highlighted, and the rows it takes are counted when scrolling the list
+    /// to keep the selected item visible.
+    ///
+    /// This only applies to vertical lists, the items of a [`ListDirection::Horizontal`] list are
+    /// separated by [`List::item_spacing`] instead. Defaults to no separator.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::{List, ListSeparator};
+    ///
+    /// let items = ["Item 1", "Item 2"];
+    /// let list =
+    ///     List::new(items).item_separator(ListSeparator::new("─").style(Style::new().dark_gray()));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn item_separator<T: Into<Option<ListSeparator<'a>>>>(mut self, separator: T) -> Self {
+        self.item_separator = separator.into();
+        self
+    }
+
+    /// Set whether the content of the items is word-wrapped to the width of the list
+    ///
+    /// By default, the lines of an item that are wider than the list are truncated. When wrapping
+    /// is enabled, the lines are wrapped on word boundaries, or on grapheme boundaries for words
+    /// that are wider than the list, to the width of the list minus the column of the highlight
+    /// symbol. Each item grows to the number of its wrapped lines, up to
+    /// [`List::max_item_height`], and scrolling takes the resulting heights into account. The
+    /// selection still addresses whole items, not their wrapped lines.
+    ///
+    /// This has no effect on [`ListDirection::Horizontal`] lists. The heights of the items created
+    /// with [`List::items_from`] are given by [`List::item_heights_from`] instead, so they should
+    /// account for the wrapped lines.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::List;
+    ///
+    /// let items = ["A notification with a long message that wraps"];
+    /// let list = List::new(items).wrap_items(true).max_item_height(3);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn wrap_items(mut self, wrap_items: bool) -> Self {
+        self.wrap_items = wrap_items;
+        self
+    }
+
+    /// Set the maximum height of items whose content is wrapped
+    ///
+    /// This only has an effect when [`List::wrap_items`] is enabled. The wrapped lines of an item
+    /// that exceed the maximum height are not displayed. `None` (the default) lets items grow to
+    /// fit all of their lines.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::List;
+    ///
+    /// let list = List::new(["Item 1"]).wrap_items(true).max_item_height(2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn max_item_height<T: Into<Option<u16>>>(mut self, max_item_height: T) -> Self {
+        self.max_item_height = max_item_height.into();
+        self
+    }
+
+    /// Set whether the header of the displayed items is pinned at the top of the list
+    ///
+    /// When the list is scrolled past the [header](ListItem::header) of the first displayed item,
+    /// the header is drawn on the top rows of the list instead of the items it hides, so that the
+    /// group of the displayed items stays visible while they scroll.
+    ///
+    /// This only applies to [`ListDirection::TopToBottom`] lists, and not to the items created with
+    /// [`List::items_from`]. Defaults to `false`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListItem};
+    ///
+    /// let list = List::new([
+    ///     ListItem::header("Network"),
+    ///     ListItem::new("Proxy"),
+    ///     ListItem::new("Timeout"),
+    /// ])
+    /// .sticky_headers(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn sticky_headers(mut self, sticky_headers: bool) -> Self {
+        self.sticky_headers = sticky_headers;
+        self
+    }
+
+    /// Set the edge the items are laid out from when they don't fill the list
+    ///
+    /// With [`ListAnchor::Bottom`], the items keep their order from top to bottom, but the space
+    /// that they don't use is left above them instead of below, as in the messages of a chat.
+    /// Unlike [`ListDirection::BottomToTop`], the last item is displayed at the bottom. Combined
+    /// with [`ListState::follow`], new items appear at the bottom and push the others up.
+    ///
+    /// This only applies to [`ListDirection::TopToBottom`] lists. Defaults to [`ListAnchor::Top`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListAnchor};
+    ///
+    /// let list = List::new(["Hello", "Hi!"]).anchor(ListAnchor::Bottom);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn anchor(mut self, anchor: ListAnchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
     /// Sets the number of items around the currently selected item that should be kept visible
     ///
+    /// When the selection moves, the offset of the [`ListState`] is adjusted so that `padding`
+    /// items stay visible above and below the selected item, like the `scrolloff` option of text
+    /// editors. Near the start and the end of the list, the padding shrinks to the items that are
+    /// left. Defaults to `0`.
+    ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
     /// # Example
@@ -418,13 +910,169 @@ impl<'a> List<'a> {
     }
 
     /// Returns the number of [`ListItem`]s in the list
+    ///
+    /// This is the `len` given to [`List::items_from`] when the items are created on demand.
     pub fn len(&self) -> usize {
-        self.items.len()
+        self.virtual_items
+            .as_ref()
+            .map_or(self.items.len(), |virtual_items| virtual_items.len)
     }
 
     /// Returns true if the list contains no elements.
     pub fn is_empty(&self) -> bool {
-        self.items.is_empty()
+        self.len() == 0
+    }
+
+    /// Moves the selection of `state` to another item of the list, skipping the items that can't
+    /// be navigated to
+    ///
+    /// Unlike the selection methods of [`ListState`], which don't know the items of the list, this
+    /// skips the [headers](ListItem::header) and the [disabled](ListItem::disabled) items. When
+    /// there is no such item in the given direction, the selection stops at the current item, or
+    /// wraps around to the other end of the list with [`ListNavigation::NextWrapping`] and
+    /// [`ListNavigation::PreviousWrapping`]. When no item of the list can be navigated to, nothing
+    /// is selected.
+    ///
+    /// A disabled item can still be selected with [`ListState::select`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListItem, ListNavigation, ListState};
+    ///
+    /// let list = List::new([
+    ///     ListItem::new("Cut"),
+    ///     ListItem::new("Copy").disabled(true),
+    ///     ListItem::new("Paste").disabled(true),
+    ///     ListItem::new("Delete"),
+    /// ]);
+    /// let mut state = ListState::default();
+    /// list.navigate(&mut state, ListNavigation::Next);
+    /// assert_eq!(state.selected(), Some(0));
+    /// list.navigate(&mut state, ListNavigation::Next);
+    /// assert_eq!(state.selected(), Some(3));
+    /// list.navigate(&mut state, ListNavigation::First);
+    /// assert_eq!(state.selected(), Some(0));
+    /// ```
+    ///
+    /// [`ListState::select`]: crate::list::ListState::select
+    pub fn navigate(&self, state: &mut ListState, navigation: ListNavigation) {
+        let len = self.len();
+        let next_from = |start: usize| (start..len).find(|&i| self.is_selectable(i));
+        let previous_from = |end: usize| (0..end.min(len)).rev().find(|&i| self.is_selectable(i));
+        let selected = match (navigation, state.selected) {
+            (ListNavigation::First, _)
+            | (ListNavigation::Next | ListNavigation::NextWrapping, None) => next_from(0),
+            (ListNavigation::Last, _)
+            | (ListNavigation::Previous | ListNavigation::PreviousWrapping, None) => {
+                previous_from(len)
+            }
+            (ListNavigation::Next, Some(selected)) => next_from(selected.saturating_add(1))
+                .or_else(|| previous_from(selected.saturating_add(1))),
+            (ListNavigation::Previous, Some(selected)) => {
+                previous_from(selected).or_else(|| next_from(selected))
+            }
+            (ListNavigation::NextWrapping, Some(selected)) => {
+                next_from(selected.saturating_add(1)).or_else(|| next_from(0))
+            }
+            (ListNavigation::PreviousWrapping, Some(selected)) => {
+                previous_from(selected).or_else(|| previous_from(len))
+            }
+        };
+        state.select(selected);
+    }
+
+    /// Selects the next item of the list that is not a [header](ListItem::header)
+    ///
+    /// This is equivalent to [`List::navigate`] with [`ListNavigation::Next`], so it also skips
+    /// the [disabled](ListItem::disabled) items. Unlike [`ListState::select_next`], which doesn't
+    /// know the items of the list, this skips the headers. If no item is selected, the first item
+    /// that is not a header is selected, and if there is no such item after the selected one, the
+    /// selection is unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListItem, ListState};
+    ///
+    /// let list = List::new([
+    ///     ListItem::header("Appearance"),
+    ///     ListItem::new("Theme"),
+    ///     ListItem::header("Network"),
+    ///     ListItem::new("Proxy"),
+    /// ]);
+    /// let mut state = ListState::default();
+    /// list.select_next(&mut state);
+    /// assert_eq!(state.selected(), Some(1));
+    /// list.select_next(&mut state);
+    /// assert_eq!(state.selected(), Some(3));
+    /// ```
+    ///
+    /// [`ListState::select_next`]: crate::list::ListState::select_next
+    pub fn select_next(&self, state: &mut ListState) {
+        self.navigate(state, ListNavigation::Next);
+    }
+
+    /// Selects the previous item of the list that is not a [header](ListItem::header)
+    ///
+    /// This is equivalent to [`List::navigate`] with [`ListNavigation::Previous`], so it also
+    /// skips the [disabled](ListItem::disabled) items. Unlike [`ListState::select_previous`],
+    /// which doesn't know the items of the list, this skips the headers. If no item is selected,
+    /// the last item that is not a header is selected, and if there is no such item before the
+    /// selected one, the selection is unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListItem, ListState};
+    ///
+    /// let list = List::new([
+    ///     ListItem::header("Appearance"),
+    ///     ListItem::new("Theme"),
+    ///     ListItem::header("Network"),
+    ///     ListItem::new("Proxy"),
+    /// ]);
+    /// let mut state = ListState::default().with_selected(Some(3));
+    /// list.select_previous(&mut state);
+    /// assert_eq!(state.selected(), Some(1));
+    /// list.select_previous(&mut state);
+    /// assert_eq!(state.selected(), Some(1));
+    /// ```
+    ///
+    /// [`ListState::select_previous`]: crate::list::ListState::select_previous
+    pub fn select_previous(&self, state: &mut ListState) {
+        self.navigate(state, ListNavigation::Previous);
+    }
+
+    /// Returns whether the item at `index` is a header
+    fn is_header(&self, index: usize) -> bool {
+        self.check_item(index, |item| item.header)
+    }
+
+    /// Returns whether the item at `index` can be navigated to, which is shown and is neither a
+    /// header nor disabled
+    fn is_selectable(&self, index: usize) -> bool {
+        self.is_shown(index) && self.check_item(index, |item| !item.header && !item.disabled)
+    }
+
+    /// Returns whether the item at `index` is kept by the [filter](List::filter), if any
+    fn is_shown(&self, index: usize) -> bool {
+        self.filtered
+            .as_ref()
+            .is_none_or(|filtered| filtered.binary_search(&index).is_ok())
+    }
+
+    /// Returns whether the item at `index` exists and matches `predicate`
+    ///
+    /// An item created with [`List::items_from`] is created to be checked.
+    fn check_item<F>(&self, index: usize, predicate: F) -> bool
+    where
+        F: Fn(&ListItem<'a>) -> bool,
+    {
+        self.virtual_items.as_ref().map_or_else(
+            || self.items.get(index).is_some_and(&predicate),
+            |virtual_items| index < virtual_items.len && predicate(&virtual_items.item(index)),
+        )
     }
 }
 
@@ -463,6 +1111,7 @@ where
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
     use alloc::{format, vec};
 
     use pretty_assertions::assert_eq;
@@ -588,6 +1237,188 @@ mod tests {
         );
     }
 
+    fn grouped_list() -> List<'static> {
+        List::new([
+            ListItem::header("Appearance"),
+            ListItem::new("Theme"),
+            ListItem::new("Font"),
+            ListItem::header("Network"),
+            ListItem::new("Proxy"),
+            ListItem::header("Empty"),
+        ])
+    }
+
+    #[rstest::rstest]
+    #[case::none_selected(None, Some(1))]
+    #[case::next_item(Some(1), Some(2))]
+    #[case::skips_header(Some(2), Some(4))]
+    #[case::keeps_last_item(Some(4), Some(4))]
+    fn select_next_skips_headers(#[case] selected: Option<usize>, #[case] expected: Option<usize>) {
+        let mut state = ListState::default().with_selected(selected);
+        grouped_list().select_next(&mut state);
+        assert_eq!(state.selected(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case::none_selected(None, Some(4))]
+    #[case::skips_header(Some(4), Some(2))]
+    #[case::previous_item(Some(2), Some(1))]
+    #[case::keeps_first_item(Some(1), Some(1))]
+    fn select_previous_skips_headers(
+        #[case] selected: Option<usize>,
+        #[case] expected: Option<usize>,
+    ) {
+        let mut state = ListState::default().with_selected(selected);
+        grouped_list().select_previous(&mut state);
+        assert_eq!(state.selected(), expected);
+    }
+
+    #[test]
+    fn select_next_skips_virtual_headers() {
+        let list = List::default().items_from(6, |i| {
+            if i % 3 == 0 {
+                ListItem::header(format!("Group {i}"))
+            } else {
+                ListItem::new(format!("Item {i}"))
+            }
+        });
+        let mut state = ListState::default().with_selected(Some(2));
+        list.select_next(&mut state);
+        assert_eq!(state.selected(), Some(4));
+    }
+
+    fn menu() -> List<'static> {
+        List::new([
+            ListItem::new("Cut"),
+            ListItem::new("Copy").disabled(true),
+            ListItem::new("Paste").disabled(true),
+            ListItem::header("Edit"),
+            ListItem::new("Delete"),
+            ListItem::new("Rename").disabled(true),
+        ])
+    }
+
+    #[rstest::rstest]
+    #[case::next_skips_disabled_run(ListNavigation::Next, Some(0), Some(4))]
+    #[case::next_keeps_last_item(ListNavigation::Next, Some(4), Some(4))]
+    #[case::next_from_disabled(ListNavigation::Next, Some(1), Some(4))]
+    #[case::next_from_last_disabled(ListNavigation::Next, Some(5), Some(4))]
+    #[case::next_none_selected(ListNavigation::Next, None, Some(0))]
+    #[case::previous_skips_disabled_run(ListNavigation::Previous, Some(4), Some(0))]
+    #[case::previous_keeps_first_item(ListNavigation::Previous, Some(0), Some(0))]
+    #[case::previous_from_disabled(ListNavigation::Previous, Some(2), Some(0))]
+    #[case::previous_none_selected(ListNavigation::Previous, None, Some(4))]
+    #[case::first(ListNavigation::First, Some(4), Some(0))]
+    #[case::last(ListNavigation::Last, Some(0), Some(4))]
+    #[case::next_wrapping(ListNavigation::NextWrapping, Some(0), Some(4))]
+    #[case::next_wrapping_from_last(ListNavigation::NextWrapping, Some(4), Some(0))]
+    #[case::next_wrapping_from_last_disabled(ListNavigation::NextWrapping, Some(5), Some(0))]
+    #[case::next_wrapping_none_selected(ListNavigation::NextWrapping, None, Some(0))]
+    #[case::previous_wrapping(ListNavigation::PreviousWrapping, Some(4), Some(0))]
+    #[case::previous_wrapping_from_first(ListNavigation::PreviousWrapping, Some(0), Some(4))]
+    #[case::previous_wrapping_none_selected(ListNavigation::PreviousWrapping, None, Some(4))]
+    fn navigate_skips_disabled_items(
+        #[case] navigation: ListNavigation,
+        #[case] selected: Option<usize>,
+        #[case] expected: Option<usize>,
+    ) {
+        let mut state = ListState::default().with_selected(selected);
+        menu().navigate(&mut state, navigation);
+        assert_eq!(state.selected(), expected);
+    }
+
+    #[rstest::rstest]
+    fn navigate_all_disabled_selects_none(
+        #[values(
+            ListNavigation::Next,
+            ListNavigation::Previous,
+            ListNavigation::First,
+            ListNavigation::Last,
+            ListNavigation::NextWrapping,
+            ListNavigation::PreviousWrapping
+        )]
+        navigation: ListNavigation,
+    ) {
+        let list = List::new([
+            ListItem::new("Copy").disabled(true),
+            ListItem::header("Edit"),
+            ListItem::new("Paste").disabled(true),
+        ]);
+        let mut state = ListState::default().with_selected(Some(0));
+        list.navigate(&mut state, navigation);
+        assert_eq!(state.selected(), None);
+    }
+
+    #[test]
+    fn navigate_empty_list_selects_none() {
+        let mut state = ListState::default().with_selected(Some(0));
+        List::default().navigate(&mut state, ListNavigation::Next);
+        assert_eq!(state.selected(), None);
+    }
+
+    #[test]
+    fn navigate_wrapping_with_single_selectable_item() {
+        let list = List::new([ListItem::header("Edit"), ListItem::new("Paste")]);
+        let mut state = ListState::default().with_selected(Some(1));
+        list.navigate(&mut state, ListNavigation::NextWrapping);
+        assert_eq!(state.selected(), Some(1));
+        list.navigate(&mut state, ListNavigation::PreviousWrapping);
+        assert_eq!(state.selected(), Some(1));
+    }
+
+    #[test]
+    fn navigate_wrapping_resets_offset() {
+        let list = List::new((0..10).map(|i| format!("Item {i}")));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 3));
+        let mut state = ListState::default();
+        list.navigate(&mut state, ListNavigation::PreviousWrapping);
+        StatefulWidget::render(&list, buffer.area, &mut buffer, &mut state);
+        assert_eq!((state.offset(), state.selected()), (7, Some(9)));
+
+        list.navigate(&mut state, ListNavigation::NextWrapping);
+        StatefulWidget::render(&list, buffer.area, &mut buffer, &mut state);
+        assert_eq!((state.offset(), state.selected()), (0, Some(0)));
+        assert_eq!(buffer, Buffer::with_lines(["Item 0", "Item 1", "Item 2"]));
+
+        list.navigate(&mut state, ListNavigation::PreviousWrapping);
+        StatefulWidget::render(&list, buffer.area, &mut buffer, &mut state);
+        assert_eq!((state.offset(), state.selected()), (7, Some(9)));
+        assert_eq!(buffer, Buffer::with_lines(["Item 7", "Item 8", "Item 9"]));
+    }
+
+    #[rstest::rstest]
+    #[case::next(ListNavigation::Next, Some(0), Some(3))]
+    #[case::next_from_hidden(ListNavigation::Next, Some(1), Some(3))]
+    #[case::previous(ListNavigation::Previous, Some(3), Some(0))]
+    #[case::first(ListNavigation::First, None, Some(0))]
+    #[case::last(ListNavigation::Last, None, Some(3))]
+    #[case::next_wrapping(ListNavigation::NextWrapping, Some(3), Some(0))]
+    fn navigate_skips_filtered_out_items(
+        #[case] navigation: ListNavigation,
+        #[case] selected: Option<usize>,
+        #[case] expected: Option<usize>,
+    ) {
+        let list = List::new(["Cut", "Copy", "Paste", "Delete"])
+            .filter(|item| !matches!(item.content().to_string().as_str(), "Copy" | "Paste"));
+        let mut state = ListState::default().with_selected(selected);
+        list.navigate(&mut state, navigation);
+        assert_eq!(state.selected(), expected);
+    }
+
+    #[test]
+    fn items_remove_filter() {
+        let list = List::new(["a", "b"]).filter(|_| false).items(["c"]);
+        assert_eq!(list.filtered, None);
+    }
+
+    #[test]
+    fn disabled_item_can_be_selected() {
+        let mut state = ListState::default().with_selected(Some(1));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        StatefulWidget::render(menu(), buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.selected(), Some(1));
+    }
+
     #[test]
     fn style_inheritance() {
         let bold = Modifier::BOLD;
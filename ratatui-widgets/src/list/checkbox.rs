@@ -0,0 +1,516 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::Style;
+use ratatui_core::text::{Line, Span};
+use ratatui_core::widgets::{StatefulWidget, Widget};
+
+use crate::list::{List, ListItem, ListState};
+
+/// A [`List`] with a checkbox before each item, to choose several items among the list
+///
+/// The checkboxes are rendered by prepending a checked or an unchecked symbol to each item, which
+/// are `"[x] "` and `"[ ] "` by default. The item contents are not modified, as the symbols are
+/// only added to the items that are rendered. [Section headers](ListItem::header) don't have a
+/// checkbox.
+///
+/// Which items are checked is stored in a [`CheckboxListState`], along with the [`ListState`]
+/// of the wrapped list. Handling the keys to toggle the items is left to the application.
+///
+/// # Fluent setters
+///
+/// - [`CheckboxList::checked_symbol`] sets the symbol of the checked items.
+/// - [`CheckboxList::unchecked_symbol`] sets the symbol of the unchecked items.
+/// - [`CheckboxList::checked_style`] sets the style of the checked items.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::Frame;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Style, Stylize};
+/// use ratatui::widgets::{CheckboxList, CheckboxListState, List};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let list = List::new(["Eggs", "Milk", "Bread"]).highlight_symbol("> ");
+/// let checkbox_list = CheckboxList::new(list).checked_style(Style::new().green());
+/// let mut state = CheckboxListState::new(3);
+/// state.list_state_mut().select_first();
+/// state.toggle_current();
+///
+/// frame.render_stateful_widget(checkbox_list, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CheckboxList<'a> {
+    /// The list of items
+    list: List<'a>,
+    /// The symbol shown before the checked items
+    checked_symbol: Line<'a>,
+    /// The symbol shown before the unchecked items
+    unchecked_symbol: Line<'a>,
+    /// The style of the checked items
+    checked_style: Style,
+}
+
+/// State of a [`CheckboxList`]
+///
+/// This holds the [`ListState`] of the list, which is used to scroll and select an item, and
+/// whether each item is checked. The list of checked flags is resized to the number of items when
+/// the list is rendered.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::widgets::CheckboxListState;
+///
+/// let mut state = CheckboxListState::new(4);
+/// state.list_state_mut().select(Some(1));
+/// state.toggle_current();
+/// state.set_checked(3, true);
+/// assert_eq!(state.checked_indices(), [1, 3]);
+///
+/// state.set_all(false);
+/// assert_eq!(state.checked_count(), 0);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CheckboxListState {
+    list: ListState,
+    checked: Vec<bool>,
+}
+
+impl Default for CheckboxList<'_> {
+    fn default() -> Self {
+        Self::new(List::default())
+    }
+}
+
+impl<'a> CheckboxList<'a> {
+    /// Creates a new checkbox list wrapping the given [`List`]
+    ///
+    /// The list is configured as usual, and its highlight symbol is shown before the checkbox.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::{Block, CheckboxList, List};
+    ///
+    /// let list = List::new(["Eggs", "Milk"]).block(Block::bordered().title("Groceries"));
+    /// let checkbox_list = CheckboxList::new(list);
+    /// ```
+    pub fn new(list: List<'a>) -> Self {
+        Self {
+            list,
+            checked_symbol: Line::from("[x] "),
+            unchecked_symbol: Line::from("[ ] "),
+            checked_style: Style::new(),
+        }
+    }
+
+    /// Set the symbol shown before the checked items (default `"[x] "`)
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::{CheckboxList, List};
+    ///
+    /// let list = CheckboxList::new(List::new(["Eggs", "Milk"])).checked_symbol("◉ ");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn checked_symbol<L: Into<Line<'a>>>(mut self, symbol: L) -> Self {
+        self.checked_symbol = symbol.into();
+        self
+    }
+
+    /// Set the symbol shown before the unchecked items (default `"[ ] "`)
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::{CheckboxList, List};
+    ///
+    /// let list = CheckboxList::new(List::new(["Eggs", "Milk"])).unchecked_symbol("○ ");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn unchecked_symbol<L: Into<Line<'a>>>(mut self, symbol: L) -> Self {
+        self.unchecked_symbol = symbol.into();
+        self
+    }
+
+    /// Set the style of the checked items
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style is combined with the style of the item, including its checkbox.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::{CheckboxList, List};
+    ///
+    /// let list = CheckboxList::new(List::new(["Eggs", "Milk"])).checked_style(Style::new().green());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn checked_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.checked_style = style.into();
+        self
+    }
+
+    /// Returns the list with the checkboxes prepended to its items
+    fn checked_list(&self, checked: &[bool]) -> List<'a> {
+        let mut list = self.list.clone();
+        if let Some(virtual_items) = &list.virtual_items {
+            let checked = checked.to_vec();
+            let checkbox = self.clone();
+            list.virtual_items = Some(virtual_items.map(move |i, item| {
+                checkbox.with_checkbox(item, checked.get(i).copied().unwrap_or_default())
+            }));
+        } else {
+            list.items = list
+                .items
+                .into_iter()
+                .zip(checked)
+                .map(|(item, &checked)| self.with_checkbox(item, checked))
+                .collect();
+        }
+        list
+    }
+
+    /// Prepends the checkbox to the first line of the item, and indents its other lines by the
+    /// width of the checkbox
+    fn with_checkbox(&self, mut item: ListItem<'a>, checked: bool) -> ListItem<'a> {
+        if item.header {
+            return item;
+        }
+        let symbol = if checked {
+            item.style = item.style.patch(self.checked_style);
+            &self.checked_symbol
+        } else {
+            &self.unchecked_symbol
+        };
+        if item.content.lines.is_empty() {
+            item.content.lines.push(Line::default());
+        }
+        let indent = " ".repeat(symbol.width());
+        for (i, line) in item.content.lines.iter_mut().enumerate() {
+            let prefix = if i == 0 {
+                symbol
+                    .spans
+                    .iter()
+                    .map(|span| span.clone().patch_style(symbol.style))
+                    .collect()
+            } else {
+                vec![Span::raw(indent.clone())]
+            };
+            line.spans.splice(0..0, prefix);
+        }
+        item
+    }
+}
+
+impl<'a> From<List<'a>> for CheckboxList<'a> {
+    fn from(list: List<'a>) -> Self {
+        Self::new(list)
+    }
+}
+
+impl CheckboxListState {
+    /// Creates a new state for a list of `len` items, none of which are checked
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::CheckboxListState;
+    ///
+    /// let state = CheckboxListState::new(3);
+    /// assert_eq!(state.checked(), [false; 3]);
+    /// ```
+    pub fn new(len: usize) -> Self {
+        Self {
+            list: ListState::default(),
+            checked: vec![false; len],
+        }
+    }
+
+    /// Sets whether each item is checked
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::CheckboxListState;
+    ///
+    /// let state = CheckboxListState::default().with_checked(vec![true, false, true]);
+    /// assert_eq!(state.checked_indices(), [0, 2]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_checked(mut self, checked: Vec<bool>) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    /// Returns the state of the list, which holds the selection and the offset
+    pub const fn list_state(&self) -> &ListState {
+        &self.list
+    }
+
+    /// Returns a mutable reference to the state of the list, to scroll and select the items
+    pub const fn list_state_mut(&mut self) -> &mut ListState {
+        &mut self.list
+    }
+
+    /// Returns whether each item is checked
+    pub fn checked(&self) -> &[bool] {
+        &self.checked
+    }
+
+    /// Returns whether the item at the given index is checked
+    ///
+    /// The items past the known number of items are unchecked.
+    pub fn is_checked(&self, index: usize) -> bool {
+        self.checked.get(index).copied().unwrap_or_default()
+    }
+
+    /// Checks or unchecks the item at the given index
+    ///
+    /// The list of checked flags grows to include the index when it is past the known items.
+    pub fn set_checked(&mut self, index: usize, checked: bool) {
+        if index >= self.checked.len() {
+            self.checked.resize(index + 1, false);
+        }
+        self.checked[index] = checked;
+    }
+
+    /// Toggles the item at the given index
+    pub fn toggle(&mut self, index: usize) {
+        self.set_checked(index, !self.is_checked(index));
+    }
+
+    /// Toggles the selected item, if any
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::CheckboxListState;
+    ///
+    /// let mut state = CheckboxListState::new(3);
+    /// state.list_state_mut().select(Some(2));
+    /// state.toggle_current();
+    /// assert!(state.is_checked(2));
+    /// state.toggle_current();
+    /// assert!(!state.is_checked(2));
+    /// ```
+    pub fn toggle_current(&mut self) {
+        if let Some(selected) = self.list.selected() {
+            self.toggle(selected);
+        }
+    }
+
+    /// Checks or unchecks all the items
+    pub fn set_all(&mut self, checked: bool) {
+        self.checked.fill(checked);
+    }
+
+    /// Returns the indices of the checked items, in order
+    pub fn checked_indices(&self) -> Vec<usize> {
+        self.checked
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &checked)| checked.then_some(i))
+            .collect()
+    }
+
+    /// Returns the number of checked items
+    pub fn checked_count(&self) -> usize {
+        self.checked.iter().filter(|&&checked| checked).count()
+    }
+}
+
+impl Widget for CheckboxList<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &CheckboxList<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = CheckboxListState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for CheckboxList<'_> {
+    type State = CheckboxListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &CheckboxList<'_> {
+    type State = CheckboxListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.checked.resize(self.list.len(), false);
+        let list = self.checked_list(&state.checked);
+        StatefulWidget::render(&list, area, buf, &mut state.list);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use pretty_assertions::assert_eq;
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+
+    fn render(list: &CheckboxList<'_>, state: &mut CheckboxListState, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, height));
+        StatefulWidget::render(list, buf.area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn render_checkboxes() {
+        let list = CheckboxList::new(List::new(["Eggs", "Milk", "Bread"]));
+        let mut state = CheckboxListState::new(3).with_checked(vec![false, true, false]);
+        assert_eq!(
+            render(&list, &mut state, 3),
+            Buffer::with_lines(["[ ] Eggs  ", "[x] Milk  ", "[ ] Bread "])
+        );
+    }
+
+    #[test]
+    fn render_does_not_change_items() {
+        let items = List::new(["Eggs", "Milk"]);
+        let list = CheckboxList::new(items.clone());
+        render(&list, &mut CheckboxListState::new(2), 2);
+        assert_eq!(list.list, items);
+    }
+
+    #[test]
+    fn render_with_highlight_symbol() {
+        let list = CheckboxList::new(List::new(["Eggs", "Milk"]).highlight_symbol(">"));
+        let mut state = CheckboxListState::new(2);
+        state.list_state_mut().select(Some(1));
+        state.toggle_current();
+        assert_eq!(
+            render(&list, &mut state, 2),
+            Buffer::with_lines([" [ ] Eggs ", ">[x] Milk "])
+        );
+    }
+
+    #[test]
+    fn render_indents_multiline_items() {
+        let list = CheckboxList::new(List::new(["Eggs\nfresh", "Milk"]));
+        let mut state = CheckboxListState::new(2).with_checked(vec![true, false]);
+        assert_eq!(
+            render(&list, &mut state, 3),
+            Buffer::with_lines(["[x] Eggs  ", "    fresh ", "[ ] Milk  "])
+        );
+    }
+
+    #[test]
+    fn render_headers_without_checkbox() {
+        let items = [ListItem::header("Dairy"), ListItem::new("Milk")];
+        let list = CheckboxList::new(List::new(items));
+        assert_eq!(
+            render(&list, &mut CheckboxListState::new(2), 2),
+            Buffer::with_lines(["Dairy     ", "[ ] Milk  "])
+        );
+    }
+
+    #[test]
+    fn render_custom_symbols_and_checked_style() {
+        let list = CheckboxList::new(List::new(["Eggs", "Milk"]))
+            .checked_symbol("◉ ".green())
+            .unchecked_symbol("○ ")
+            .checked_style(Style::new().bold());
+        let mut state = CheckboxListState::new(2).with_checked(vec![true, false]);
+        let mut expected = Buffer::with_lines(["◉ Eggs    ", "○ Milk    "]);
+        expected.set_style(Rect::new(0, 0, 10, 1), Style::new().bold());
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().green());
+        assert_eq!(render(&list, &mut state, 2), expected);
+    }
+
+    #[test]
+    fn render_virtual_items() {
+        let item = |i| ListItem::new(format!("Item {i}"));
+        let virtual_list = CheckboxList::new(List::default().items_from(20, item));
+        let list = CheckboxList::new(List::new((0..20).map(item)));
+        let mut state = CheckboxListState::default().with_checked(vec![false, true]);
+        state.list_state_mut().select(Some(12));
+        state.toggle_current();
+        let mut virtual_state = state.clone();
+
+        let buf = render(&list, &mut state, 3);
+        assert_eq!(render(&virtual_list, &mut virtual_state, 3), buf);
+        assert_eq!(virtual_state, state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["[ ] Item 1", "[ ] Item 1", "[x] Item 1"])
+        );
+    }
+
+    #[test]
+    fn render_resizes_checked_to_items() {
+        let list = CheckboxList::new(List::new(["Eggs", "Milk", "Bread"]));
+        let mut state = CheckboxListState::default().with_checked(vec![true; 5]);
+        render(&list, &mut state, 3);
+        assert_eq!(state.checked(), [true; 3]);
+        let mut state = CheckboxListState::default();
+        render(&list, &mut state, 3);
+        assert_eq!(state.checked(), [false; 3]);
+    }
+
+    #[test]
+    fn toggle_current() {
+        let mut state = CheckboxListState::new(3);
+        state.toggle_current();
+        assert_eq!(state.checked_count(), 0);
+
+        state.list_state_mut().select(Some(1));
+        state.toggle_current();
+        assert_eq!(state.checked_indices(), [1]);
+        state.list_state_mut().select_next();
+        state.toggle_current();
+        assert_eq!(state.checked_indices(), [1, 2]);
+        state.toggle_current();
+        assert_eq!(state.checked_indices(), [1]);
+    }
+
+    #[test]
+    fn set_all() {
+        let mut state = CheckboxListState::new(4);
+        state.set_all(true);
+        assert_eq!(state.checked_indices(), [0, 1, 2, 3]);
+        assert_eq!(state.checked_count(), 4);
+        state.set_all(false);
+        assert!(state.checked_indices().is_empty());
+    }
+
+    #[test]
+    fn set_checked_past_known_items() {
+        let mut state = CheckboxListState::new(2);
+        state.set_checked(4, true);
+        assert_eq!(state.checked(), [false, false, false, false, true]);
+        assert!(!state.is_checked(10));
+    }
+}
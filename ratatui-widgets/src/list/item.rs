@@ -1,5 +1,10 @@
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Alignment, Rect};
 use ratatui_core::style::Style;
-use ratatui_core::text::Text;
+use ratatui_core::text::{Line, StyledGrapheme, Text};
+
+use crate::paragraph::render_lines;
+use crate::reflow::{LineComposer, WordWrapper};
 
 /// A single item in a [`List`]
 ///
@@ -73,6 +78,14 @@ use ratatui_core::text::Text;
 pub struct ListItem<'a> {
     pub(crate) content: Text<'a>,
     pub(crate) style: Style,
+    /// Whether the item is a section header, which can't be selected
+    pub(crate) header: bool,
+    /// Whether the item is unavailable, which is skipped when navigating the list
+    pub(crate) disabled: bool,
+    /// The symbol shown before the item when it is selected, instead of the list's symbol
+    pub(crate) highlight_symbol: Option<Line<'a>>,
+    /// The style of the item when it is selected, instead of the list's highlight style
+    pub(crate) highlight_style: Option<Style>,
 }
 
 impl<'a> ListItem<'a> {
@@ -119,9 +132,122 @@ impl<'a> ListItem<'a> {
         Self {
             content: content.into(),
             style: Style::default(),
+            header: false,
+            disabled: false,
+            highlight_symbol: None,
+            highlight_style: None,
+        }
+    }
+
+    /// Creates a section header, an item that groups the items after it and can't be selected
+    ///
+    /// A header is displayed like any other item, but the selection skips it: it is never
+    /// highlighted and its highlight symbol column is left blank. Use [`List::select_next`] and
+    /// [`List::select_previous`] to move the selection over the headers, and
+    /// [`List::sticky_headers`] to keep the header of the displayed items pinned at the top of the
+    /// list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Stylize;
+    /// use ratatui::widgets::{List, ListItem};
+    ///
+    /// let list = List::new([
+    ///     ListItem::header("Appearance").bold(),
+    ///     ListItem::new("Theme"),
+    ///     ListItem::new("Font size"),
+    ///     ListItem::header("Network").bold(),
+    ///     ListItem::new("Proxy"),
+    /// ]);
+    /// ```
+    ///
+    /// [`List::select_next`]: crate::list::List::select_next
+    /// [`List::select_previous`]: crate::list::List::select_previous
+    /// [`List::sticky_headers`]: crate::list::List::sticky_headers
+    pub fn header<T>(content: T) -> Self
+    where
+        T: Into<Text<'a>>,
+    {
+        Self {
+            header: true,
+            ..Self::new(content)
         }
     }
 
+    /// Returns whether the item is a section header, see [`ListItem::header`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListItem;
+    ///
+    /// assert!(ListItem::header("Network").is_header());
+    /// assert!(!ListItem::new("Proxy").is_header());
+    /// ```
+    pub const fn is_header(&self) -> bool {
+        self.header
+    }
+
+    /// Sets whether the item is disabled
+    ///
+    /// A disabled item is an item that is currently unavailable. It is displayed with the
+    /// [`List::disabled_style`] and is skipped by [`List::navigate`], but it can still be selected
+    /// with [`ListState::select`]. Defaults to `false`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::{List, ListItem};
+    ///
+    /// let list = List::new([
+    ///     ListItem::new("Cut"),
+    ///     ListItem::new("Copy"),
+    ///     ListItem::new("Paste").disabled(true),
+    /// ])
+    /// .disabled_style(Style::new().dim());
+    /// ```
+    ///
+    /// [`List::disabled_style`]: crate::list::List::disabled_style
+    /// [`List::navigate`]: crate::list::List::navigate
+    /// [`ListState::select`]: crate::list::ListState::select
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Returns whether the item is disabled, see [`ListItem::disabled`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListItem;
+    ///
+    /// assert!(ListItem::new("Paste").disabled(true).is_disabled());
+    /// assert!(!ListItem::new("Copy").is_disabled());
+    /// ```
+    pub const fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Returns the content of the item
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::text::Text;
+    /// use ratatui::widgets::ListItem;
+    ///
+    /// assert_eq!(ListItem::new("Paste").content(), &Text::from("Paste"));
+    /// ```
+    pub const fn content(&self) -> &Text<'a> {
+        &self.content
+    }
+
     /// Sets the item style
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -160,6 +286,62 @@ impl<'a> ListItem<'a> {
         self
     }
 
+    /// Sets the symbol displayed in front of the item when it is selected
+    ///
+    /// This overrides the [`List::highlight_symbol`] for this item. The column of the highlight
+    /// symbols is as wide as the widest symbol of the displayed items, so that their content stays
+    /// aligned whichever item is selected.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListItem};
+    ///
+    /// let list = List::new([
+    ///     ListItem::new("src").highlight_symbol("▶ "),
+    ///     ListItem::new("Cargo.toml").highlight_symbol("• "),
+    /// ]);
+    /// ```
+    ///
+    /// [`List::highlight_symbol`]: crate::list::List::highlight_symbol
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_symbol<L: Into<Line<'a>>>(mut self, highlight_symbol: L) -> Self {
+        self.highlight_symbol = Some(highlight_symbol.into());
+        self
+    }
+
+    /// Sets the style of the item when it is selected
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This overrides the [`List::highlight_style`] for this item.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::{List, ListItem};
+    ///
+    /// let list = List::new([
+    ///     ListItem::new("notes.txt"),
+    ///     ListItem::new("broken.txt").highlight_style(Style::new().white().on_red()),
+    /// ])
+    /// .highlight_style(Style::new().reversed());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    /// [`List::highlight_style`]: crate::list::List::highlight_style
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = Some(style.into());
+        self
+    }
+
     /// Returns the item height
     ///
     /// # Examples
@@ -205,6 +387,42 @@ impl<'a> ListItem<'a> {
     pub fn width(&self) -> usize {
         self.content.width()
     }
+
+    /// Renders the content word-wrapped to the width of the area.
+    pub(crate) fn render_wrapped(&self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        buf.set_style(area, self.content.style);
+        let composer = WordWrapper::new(self.styled_lines(), area.width, true);
+        render_lines(composer, area, buf);
+    }
+
+    /// Returns the number of lines of the content once word-wrapped to `width`.
+    pub(crate) fn wrapped_height(&self, width: u16) -> usize {
+        if width == 0 {
+            return 0;
+        }
+        let mut composer = WordWrapper::new(self.styled_lines(), width, true);
+        let mut height = 0;
+        while composer.next_line().is_some() {
+            height += 1;
+        }
+        height
+    }
+
+    fn styled_lines(
+        &self,
+    ) -> impl Iterator<Item = (impl Iterator<Item = StyledGrapheme<'_>>, Alignment)> {
+        self.content.iter().map(move |line| {
+            let graphemes = line.styled_graphemes(self.content.style);
+            let alignment = line
+                .alignment
+                .or(self.content.alignment)
+                .unwrap_or_default();
+            (graphemes, alignment)
+        })
+    }
 }
 
 impl<'a, T> From<T> for ListItem<'a>
@@ -285,6 +503,32 @@ mod tests {
         assert_eq!(item.style, Style::default());
     }
 
+    #[test]
+    fn header() {
+        let item = ListItem::header("Network").red();
+        assert!(item.is_header());
+        assert_eq!(item.content, Text::from("Network"));
+        assert_eq!(item.style, Style::new().red());
+        assert!(!ListItem::new("Proxy").is_header());
+    }
+
+    #[test]
+    fn highlight_overrides() {
+        let item = ListItem::new("Item").highlight_symbol("> ");
+        assert_eq!(item.highlight_symbol, Some(Line::from("> ")));
+        assert_eq!(item.highlight_style, None);
+        let item = item.highlight_style(Style::new().red());
+        assert_eq!(item.highlight_style, Some(Style::new().red()));
+    }
+
+    #[test]
+    fn disabled() {
+        let item = ListItem::new("Paste").disabled(true);
+        assert!(item.is_disabled());
+        assert!(!item.is_header());
+        assert!(!item.disabled(false).is_disabled());
+    }
+
     #[test]
     fn str_into_list_item() {
         let s = "Test item";
@@ -350,4 +594,33 @@ mod tests {
                 .remove_modifier(Modifier::DIM)
         );
     }
+
+    #[test]
+    fn wrapped_height() {
+        let item = ListItem::new("a few words\nto wrap");
+        assert_eq!(item.wrapped_height(0), 0);
+        assert_eq!(item.wrapped_height(20), 2);
+        assert_eq!(item.wrapped_height(7), 3);
+        assert_eq!(item.wrapped_height(3), 7);
+        assert_eq!(ListItem::new("").wrapped_height(5), 1);
+    }
+
+    #[test]
+    fn render_wrapped() {
+        let item = ListItem::new("a few words");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 3));
+        item.render_wrapped(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["a few ", "words ", "      "]));
+    }
+
+    #[test]
+    fn render_wrapped_wide_characters() {
+        let item = ListItem::new("コンピュータ 🦀🦀");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 4));
+        item.render_wrapped(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["コン ", "ピュ ", "ータ ", "🦀🦀 "])
+        );
+    }
 }
@@ -1,10 +1,15 @@
+use alloc::vec::Vec;
+use core::ops::{Index, Range};
+
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
 use ratatui_core::text::{Line, ToLine};
 use ratatui_core::widgets::{StatefulWidget, Widget};
 
 use crate::block::BlockExt;
-use crate::list::{List, ListDirection, ListState};
+use crate::list::virtual_items::reordered_index;
+use crate::list::{List, ListAnchor, ListDirection, ListItem, ListState, VirtualItems};
+use crate::table::{HighlightSpacing, ScrollBehavior};
 
 impl Widget for List<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
@@ -31,14 +36,61 @@ impl StatefulWidget for &List<'_> {
     type State = ListState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if let Some(grabbed) = state.grabbed {
+            self.render_grabbed(grabbed, area, buf, state);
+            return;
+        }
+        self.render_list(area, buf, state, None);
+    }
+}
+
+impl<'a> List<'a> {
+    /// Renders the list in `area`, with the item at the original position of `grabbed` displayed
+    /// at its tentative position
+    fn render_list(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut ListState,
+        grabbed: Option<(usize, usize)>,
+    ) {
         buf.set_style(area, self.style);
         self.block.as_ref().render(area, buf);
         let list_area = self.block.inner_if_some(area);
+        state.item_areas.clear();
 
         if list_area.is_empty() {
             return;
         }
 
+        self.render_items(list_area, buf, state, grabbed);
+        if state.follow {
+            state.followed_len = self.len();
+        }
+    }
+
+    /// Renders the items of the list in `area`, inside the block
+    fn render_items(
+        &self,
+        list_area: Rect,
+        buf: &mut Buffer,
+        state: &mut ListState,
+        grabbed: Option<(usize, usize)>,
+    ) {
+        let items = DisplayedItems {
+            items: &self.items,
+            grabbed,
+        };
+        if let Some(filtered) = &self.filtered {
+            self.render_filtered(filtered, items, list_area, buf, state);
+            return;
+        }
+        if let Some(virtual_items) = &self.virtual_items {
+            self.render_virtual(virtual_items, list_area, buf, state);
+            return;
+        }
+
+        state.selected_set.split_off(&self.items.len());
         if self.items.is_empty() {
             state.select(None);
             return;
@@ -46,16 +98,11 @@ impl StatefulWidget for &List<'_> {
 
         // If the selected index is out of bounds, set it to the last item
         if state.selected.is_some_and(|s| s >= self.items.len()) {
-            state.select(Some(self.items.len().saturating_sub(1)));
+            state.selected = Some(self.items.len().saturating_sub(1));
         }
-
-        let list_height = list_area.height as usize;
-
-        let (first_visible_index, last_visible_index) =
-            self.get_items_bounds(state.selected, state.offset, list_height);
-
-        // Important: this changes the state's offset to be the beginning of the now viewable items
-        state.offset = first_visible_index;
+        state.selected = self.skip_headers(state.selected, |i| {
+            items.get(i).is_some_and(|item| item.header)
+        });
 
         // Get our set highlighted symbol (if one was set)
         let default_highlight_symbol = Line::default();
@@ -63,76 +110,645 @@ impl StatefulWidget for &List<'_> {
             .highlight_symbol
             .as_ref()
             .unwrap_or(&default_highlight_symbol);
-        let highlight_symbol_width = highlight_symbol.width() as u16;
-        let empty_symbol = " ".repeat(highlight_symbol_width as usize);
-        let empty_symbol = empty_symbol.to_line();
-
-        let mut current_height = 0;
         let selection_spacing = self.highlight_spacing.should_add(state.selected.is_some());
-        for (i, item) in self
-            .items
+
+        if self.direction == ListDirection::Horizontal {
+            self.render_horizontal(
+                items,
+                list_area,
+                buf,
+                state,
+                highlight_symbol,
+                selection_spacing,
+            );
+            return;
+        }
+
+        self.render_vertical(
+            items,
+            list_area,
+            buf,
+            state,
+            highlight_symbol,
+            selection_spacing,
+        );
+    }
+
+    /// Renders the list with the grabbed item moved from its original position to its tentative
+    /// position, and styled with the [`List::grabbed_style`].
+    fn render_grabbed(
+        &self,
+        (from, to): (usize, usize),
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut ListState,
+    ) {
+        let len = self
+            .virtual_items
+            .as_ref()
+            .map_or(self.items.len(), |v| v.len);
+        state.grabbed = None;
+        if from >= len {
+            self.render_list(area, buf, state, None);
+            return;
+        }
+        let to = to.min(len - 1);
+        state.selected = Some(to);
+        if let Some(virtual_items) = &self.virtual_items {
+            let reordered = List {
+                virtual_items: Some(virtual_items.reordered(from, to)),
+                ..self.clone()
+            };
+            reordered.render_list(area, buf, state, None);
+        } else {
+            self.render_list(area, buf, state, Some((from, to)));
+        }
+        state.grabbed = Some((from, to));
+        if let Some(&(_, item_area)) = state.item_areas.iter().find(|(i, _)| *i == to) {
+            buf.set_style(item_area, self.grabbed_style);
+        }
+    }
+
+    /// Renders a list with virtual items by creating its visible items and rendering them as a
+    /// list of their own.
+    fn render_virtual(
+        &self,
+        virtual_items: &VirtualItems<'a>,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut ListState,
+    ) {
+        let len = virtual_items.len;
+        state.selected_set.split_off(&len);
+        if len == 0 {
+            state.select(None);
+            return;
+        }
+        if state.selected.is_some_and(|s| s >= len) {
+            state.selected = Some(len - 1);
+        }
+
+        let (start, end) = if self.direction == ListDirection::Horizontal {
+            let has_selection = state.selected.is_some();
+            self.horizontal_bounds(len, state, area.width, |i| {
+                let item = virtual_items.item(i);
+                let symbol_width = self.horizontal_symbol_width(&item, has_selection);
+                symbol_width.saturating_add(first_line_width(&item))
+            })
+        } else {
+            self.vertical_bounds(len, state, area.height, |i| {
+                usize::from(virtual_items.height(i))
+            })
+        };
+        state.offset = start;
+
+        // The selected item may be outside of the window while scrolling to a target, so the
+        // spacing of the highlight symbol is decided by the selection of the whole list
+        let highlight_spacing = if self.highlight_spacing.should_add(state.selected.is_some()) {
+            HighlightSpacing::Always
+        } else {
+            HighlightSpacing::Never
+        };
+        let window = List {
+            block: None,
+            items: (start..end).map(|i| virtual_items.item(i)).collect(),
+            virtual_items: None,
+            sticky_headers: false,
+            highlight_spacing,
+            // keep the phase of the styles of the items, which are indexed from `start`
+            alternating_item_styles: self.alternating_item_styles.map(|(even, odd)| {
+                if start % 2 == 0 {
+                    (even, odd)
+                } else {
+                    (odd, even)
+                }
+            }),
+            ..self.without_items()
+        };
+        let window_selected = state
+            .selected
+            .and_then(|s| s.checked_sub(start))
+            .filter(|&s| s < window.items.len());
+        // The items are only checked for headers when a header is selected, so that they are not
+        // created more than once
+        if let Some(selected) = window_selected.filter(|&s| window.items[s].header) {
+            state.selected = self.skip_headers(Some(selected + start), |i| self.is_header(i));
+            self.render_virtual(virtual_items, area, buf, state);
+            return;
+        }
+        // the state of the window, where the items are indexed from the first visible item
+        let mut window_state = ListState::default().with_selected(window_selected);
+        window_state.selected_set = state
+            .selected_set
+            .range(start..end)
+            .map(|i| i - start)
+            .collect();
+        StatefulWidget::render(&window, area, buf, &mut window_state);
+        state.item_areas = window_state
+            .item_areas
+            .into_iter()
+            .map(|(i, item_area)| (i + start, item_area))
+            .collect();
+    }
+
+    /// Renders the items kept by the [filter](List::filter) as a list of their own, mapping the
+    /// indices of the state between the items of the list and the shown items.
+    fn render_filtered(
+        &self,
+        filtered: &[usize],
+        items: DisplayedItems<'_, 'a>,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut ListState,
+    ) {
+        state.selected_set.split_off(&self.len());
+        if filtered.is_empty() {
+            state.select(None);
+            return;
+        }
+        // the position of the item at `index` among the shown items, or of the next shown item
+        // when it is hidden
+        let position = |index: usize| filtered.partition_point(|&i| i < index);
+        let last = filtered.len() - 1;
+        let (items, virtual_items) = match &self.virtual_items {
+            Some(virtual_items) => (Vec::new(), Some(virtual_items.filtered(filtered))),
+            None => (filtered.iter().map(|&i| items[i].clone()).collect(), None),
+        };
+        let window = List {
+            block: None,
+            items,
+            virtual_items,
+            ..self.without_items()
+        };
+        let mut window_state = ListState::default()
+            .with_offset(position(state.offset).min(last))
+            .with_selected(state.selected.map(|s| position(s).min(last)));
+        window_state.selected_set = filtered
             .iter()
             .enumerate()
-            .skip(state.offset)
-            .take(last_visible_index - first_visible_index)
-        {
-            let (x, y) = if self.direction == ListDirection::BottomToTop {
-                current_height += item.height() as u16;
-                (list_area.left(), list_area.bottom() - current_height)
+            .filter(|(_, i)| state.selected_set.contains(i))
+            .map(|(position, _)| position)
+            .collect();
+        window_state.scroll_target = state
+            .scroll_target
+            .map(|(target, behavior)| (position(target).min(last), behavior));
+        window_state.follow = state.follow;
+        window_state.followed_len = position(state.followed_len);
+        StatefulWidget::render(&window, area, buf, &mut window_state);
+        state.offset = filtered[window_state.offset.min(last)];
+        state.selected = window_state.selected.map(|s| filtered[s]);
+        state.item_areas = window_state
+            .item_areas
+            .into_iter()
+            .map(|(i, item_area)| (filtered[i], item_area))
+            .collect();
+    }
+
+    /// Returns a list with the configuration of this list but no items, to render a window of its
+    /// items as a list of their own without copying all of them
+    fn without_items(&self) -> Self {
+        Self {
+            block: self.block.clone(),
+            items: Vec::new(),
+            style: self.style,
+            direction: self.direction,
+            highlight_style: self.highlight_style,
+            multi_highlight_style: self.multi_highlight_style,
+            disabled_style: self.disabled_style,
+            grabbed_style: self.grabbed_style,
+            alternating_item_styles: self.alternating_item_styles,
+            highlight_symbol: self.highlight_symbol.clone(),
+            repeat_highlight_symbol: self.repeat_highlight_symbol,
+            highlight_spacing: self.highlight_spacing.clone(),
+            scroll_padding: self.scroll_padding,
+            item_spacing: self.item_spacing,
+            item_separator: self.item_separator.clone(),
+            wrap_items: self.wrap_items,
+            max_item_height: self.max_item_height,
+            sticky_headers: self.sticky_headers,
+            anchor: self.anchor,
+            virtual_items: None,
+            filtered: None,
+        }
+    }
+
+    /// Renders the items top to bottom or bottom to top in `area`, pinning the header of the
+    /// displayed items at the top when [`List::sticky_headers`] is enabled
+    fn render_vertical(
+        &self,
+        items: DisplayedItems,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut ListState,
+        highlight_symbol: &Line<'_>,
+        selection_spacing: bool,
+    ) {
+        let (symbol_width, (mut first_visible_index, mut last_visible_index)) =
+            self.symbol_column_bounds(items, area, state, highlight_symbol, selection_spacing);
+        let content_width = area.width.saturating_sub(symbol_width);
+        let height = |i: usize| self.item_height(&items[i], content_width);
+        let symbol_column = Some((highlight_symbol, symbol_width)).filter(|_| selection_spacing);
+
+        // The items are rendered below the sticky header, which hides the items it would overlap
+        let mut items_area = area;
+        let sticky_header = self
+            .sticky_header(items, first_visible_index)
+            .and_then(|header| {
+                let separator_height = u16::from(self.item_separator.is_some());
+                let header_height = u16::try_from(height(header)).unwrap_or(u16::MAX);
+                let pinned_height = header_height.saturating_add(separator_height);
+                if pinned_height >= area.height {
+                    return None;
+                }
+                let below_header = Rect {
+                    y: area.y + pinned_height,
+                    height: area.height - pinned_height,
+                    ..area
+                };
+                // The selected item is not scrolled into view while scrolling to a target
+                let selected = state.selected.filter(|_| state.scroll_target.is_none());
+                let offset_state = ListState::default()
+                    .with_offset(first_visible_index)
+                    .with_selected(selected);
+                let (first, last) =
+                    self.vertical_bounds(items.len(), &offset_state, below_header.height, height);
+                // A header reaching the top replaces the sticky header instead of being rendered below
+                if items[first].header {
+                    let offset_state = ListState::default()
+                        .with_offset(first)
+                        .with_selected(selected);
+                    (first_visible_index, last_visible_index) =
+                        self.vertical_bounds(items.len(), &offset_state, area.height, height);
+                    return None;
+                }
+                (first_visible_index, last_visible_index) = (first, last);
+                items_area = below_header;
+                Some((header, header_height))
+            });
+
+        // Important: this changes the state's offset to be the beginning of the now viewable items
+        state.offset = first_visible_index;
+        if self.anchor == ListAnchor::Bottom && self.direction == ListDirection::TopToBottom {
+            let used_height = self.used_height(first_visible_index..last_visible_index, height);
+            let unused_height = items_area.height.saturating_sub(used_height);
+            items_area.y += unused_height;
+            items_area.height -= unused_height;
+        }
+
+        if let Some((header, header_height)) = sticky_header {
+            let header_area = Rect {
+                height: header_height,
+                ..area
+            };
+            let selection = (false, state.selected_set.contains(&header));
+            let item = &items[header];
+            self.render_item(header, item, header_area, buf, selection, symbol_column);
+            state.item_areas.push((header, header_area));
+            if let Some(separator) = &self.item_separator {
+                separator.render(Rect::new(area.x, header_area.bottom(), area.width, 1), buf);
+            }
+        }
+
+        let mut current_height = 0;
+        for (i, item) in items.range(first_visible_index..last_visible_index) {
+            if let Some(separator) = self
+                .item_separator
+                .as_ref()
+                .filter(|_| i > first_visible_index)
+            {
+                let y = if self.direction == ListDirection::BottomToTop {
+                    current_height += 1;
+                    items_area.bottom() - current_height
+                } else {
+                    let y = items_area.top() + current_height;
+                    current_height += 1;
+                    y
+                };
+                separator.render(Rect::new(items_area.left(), y, items_area.width, 1), buf);
+            }
+
+            let item_height = self.item_height(item, content_width) as u16;
+            let y = if self.direction == ListDirection::BottomToTop {
+                current_height += item_height;
+                items_area.bottom() - current_height
             } else {
-                let pos = (list_area.left(), list_area.top() + current_height);
-                current_height += item.height() as u16;
-                pos
+                let y = items_area.top() + current_height;
+                current_height += item_height;
+                y
             };
 
-            let row_area = Rect::new(x, y, list_area.width, item.height() as u16);
+            let row_area = Rect::new(items_area.left(), y, items_area.width, item_height);
+            let selection = (state.selected == Some(i), state.selected_set.contains(&i));
+            self.render_item(i, item, row_area, buf, selection, symbol_column);
+            state
+                .item_areas
+                .push((i, row_area.intersection(items_area)));
+        }
+    }
 
-            let item_style = self.style.patch(item.style);
-            buf.set_style(row_area, item_style);
+    /// Returns the height of the `items` of a vertical list and of the separators between them,
+    /// where `height` returns the height of the item at an index
+    fn used_height<F>(&self, items: Range<usize>, height: F) -> u16
+    where
+        F: Fn(usize) -> usize,
+    {
+        let separators = items.len().saturating_sub(1) * usize::from(self.item_separator.is_some());
+        let used_height = items.map(height).sum::<usize>() + separators;
+        u16::try_from(used_height).unwrap_or(u16::MAX)
+    }
 
-            let is_selected = state.selected == Some(i);
+    /// Returns the width of the highlight symbol column of a vertical list, along with the range
+    /// of the items displayed in `area`
+    ///
+    /// The column is as wide as the widest symbol of the displayed items. This changes the height
+    /// of wrapped items, so they are laid out again for the width of the column.
+    fn symbol_column_bounds(
+        &self,
+        items: DisplayedItems,
+        area: Rect,
+        state: &ListState,
+        highlight_symbol: &Line<'_>,
+        selection_spacing: bool,
+    ) -> (u16, (usize, usize)) {
+        let bounds = |symbol_width: u16| {
+            let content_width = area.width.saturating_sub(symbol_width);
+            self.vertical_bounds(items.len(), state, area.height, |i| {
+                self.item_height(&items[i], content_width)
+            })
+        };
+        if !selection_spacing {
+            return (0, bounds(0));
+        }
+        let symbol_width = highlight_symbol.width() as u16;
+        let (first, last) = bounds(symbol_width);
+        let visible_width = symbol_column_width(items.range(first..last), highlight_symbol);
+        if visible_width != symbol_width && self.wrap_items {
+            return (visible_width, bounds(visible_width));
+        }
+        (visible_width, (first, last))
+    }
 
-            let item_area = if selection_spacing {
-                Rect {
-                    x: row_area.x + highlight_symbol_width,
-                    width: row_area.width.saturating_sub(highlight_symbol_width),
-                    ..row_area
-                }
-            } else {
-                row_area
+    /// Renders an item of a vertical list in `area`
+    ///
+    /// The `selection` tells whether the item is selected and whether it is in the selection set.
+    /// The `symbol_column`, made of the highlight symbol of the list and the width of the column,
+    /// is rendered before the content of the item when it is set, with the symbol of the item (or
+    /// of the list) only shown when the item is selected.
+    fn render_item(
+        &self,
+        index: usize,
+        item: &ListItem<'_>,
+        area: Rect,
+        buf: &mut Buffer,
+        (is_selected, in_set): (bool, bool),
+        symbol_column: Option<(&Line<'_>, u16)>,
+    ) {
+        buf.set_style(area, self.item_style(index, item));
+
+        let highlight_symbol_width = symbol_column.map_or(0, |(_, width)| width);
+        let item_area = Rect {
+            x: area.x + highlight_symbol_width,
+            width: area.width.saturating_sub(highlight_symbol_width),
+            ..area
+        };
+        self.render_item_content(item, item_area, buf);
+
+        if item.disabled {
+            buf.set_style(area, self.disabled_style);
+        }
+        if in_set {
+            buf.set_style(area, self.multi_highlight_style);
+        }
+        if is_selected {
+            buf.set_style(area, item.highlight_style.unwrap_or(self.highlight_style));
+        }
+        if let Some((highlight_symbol, _)) = symbol_column {
+            let highlight_symbol = item.highlight_symbol.as_ref().unwrap_or(highlight_symbol);
+            let empty_symbol = " ".repeat(highlight_symbol_width as usize);
+            let empty_symbol = empty_symbol.to_line();
+            for j in 0..area.height {
+                // if the item is selected, we need to display the highlight symbol:
+                // - either for the first line of the item only,
+                // - or for each line of the item if the appropriate option is set
+                let line = if is_selected && (j == 0 || self.repeat_highlight_symbol) {
+                    highlight_symbol
+                } else {
+                    &empty_symbol
+                };
+                let highlight_area = Rect::new(area.x, area.y + j, highlight_symbol_width, 1);
+                line.render(highlight_area, buf);
+            }
+        }
+    }
+
+    /// Returns the index of the header pinned above the items displayed from
+    /// `first_visible_index`, which is the last header before them
+    ///
+    /// There is no sticky header when the displayed items start with a header.
+    fn sticky_header(&self, items: DisplayedItems, first_visible_index: usize) -> Option<usize> {
+        if !self.sticky_headers
+            || self.direction != ListDirection::TopToBottom
+            || items.get(first_visible_index)?.header
+        {
+            return None;
+        }
+        (0..first_visible_index).rev().find(|&i| items[i].header)
+    }
+
+    /// Moves a selected header to the next item that is not a header, or the previous one when
+    /// there is none after it, as headers can't be selected
+    ///
+    /// `is_header` returns whether the item at an index is a header.
+    fn skip_headers<F>(&self, selected: Option<usize>, is_header: F) -> Option<usize>
+    where
+        F: Fn(usize) -> bool,
+    {
+        let selected = selected?;
+        if !is_header(selected) {
+            return Some(selected);
+        }
+        (selected..self.len())
+            .find(|&i| !is_header(i))
+            .or_else(|| (0..selected).rev().find(|&i| !is_header(i)))
+    }
+
+    /// Renders the items left to right on the first line of `area`, see
+    /// [`ListDirection::Horizontal`]
+    fn render_horizontal(
+        &self,
+        items: DisplayedItems,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut ListState,
+        highlight_symbol: &Line<'_>,
+        selection_spacing: bool,
+    ) {
+        let has_selection = state.selected.is_some();
+        let spacing = self.item_spacing;
+        let item_width = |item: &ListItem<'_>| {
+            let symbol_width = self.horizontal_symbol_width(item, has_selection);
+            symbol_width.saturating_add(first_line_width(item))
+        };
+
+        let (first_visible_index, last_visible_index) =
+            self.horizontal_bounds(items.len(), state, area.width, |i| item_width(&items[i]));
+
+        // Important: this changes the state's offset to be the beginning of the now viewable items
+        state.offset = first_visible_index;
+
+        let mut x = area.left();
+        for (i, item) in items.range(first_visible_index..last_visible_index) {
+            let item_area = Rect::new(x, area.top(), item_width(item), 1).intersection(area);
+            x = item_area.right().saturating_add(spacing);
+            state.item_areas.push((i, item_area));
+
+            buf.set_style(item_area, self.item_style(i, item));
+
+            let highlight_symbol_width = self.horizontal_symbol_width(item, has_selection);
+            let content_area = Rect {
+                x: item_area.x + highlight_symbol_width,
+                width: item_area.width.saturating_sub(highlight_symbol_width),
+                ..item_area
             };
-            Widget::render(&item.content, item_area, buf);
+            Widget::render(&item.content, content_area, buf);
 
-            if is_selected {
-                buf.set_style(row_area, self.highlight_style);
+            if item.disabled {
+                buf.set_style(item_area, self.disabled_style);
             }
-            if selection_spacing {
-                for j in 0..item.content.height() {
-                    // if the item is selected, we need to display the highlight symbol:
-                    // - either for the first line of the item only,
-                    // - or for each line of the item if the appropriate option is set
-                    let line = if is_selected && (j == 0 || self.repeat_highlight_symbol) {
-                        highlight_symbol
-                    } else {
-                        &empty_symbol
+            if state.selected_set.contains(&i) {
+                buf.set_style(item_area, self.multi_highlight_style);
+            }
+            if state.selected == Some(i) {
+                buf.set_style(
+                    item_area,
+                    item.highlight_style.unwrap_or(self.highlight_style),
+                );
+                if selection_spacing {
+                    let highlight_area = Rect {
+                        width: highlight_symbol_width,
+                        ..item_area
                     };
-                    let highlight_area = Rect::new(x, y + j as u16, highlight_symbol_width, 1);
-                    line.render(highlight_area, buf);
+                    let highlight_symbol =
+                        item.highlight_symbol.as_ref().unwrap_or(highlight_symbol);
+                    highlight_symbol.render(highlight_area, buf);
                 }
             }
         }
     }
-}
 
-impl List<'_> {
-    /// Given an offset, calculate which items can fit in a given area
-    fn get_items_bounds(
+    /// Renders the content of an item, word-wrapped when [`List::wrap_items`] is enabled
+    fn render_item_content(&self, item: &ListItem<'_>, area: Rect, buf: &mut Buffer) {
+        if self.wrap_items {
+            item.render_wrapped(area, buf);
+        } else {
+            Widget::render(&item.content, area, buf);
+        }
+    }
+
+    /// Returns the height of an item of a vertical list whose content is `width` wide
+    ///
+    /// This is the number of wrapped lines of the item when [`List::wrap_items`] is enabled.
+    fn item_height(&self, item: &ListItem<'_>, width: u16) -> usize {
+        if !self.wrap_items {
+            return item.height();
+        }
+        let height = item.wrapped_height(width);
+        self.max_item_height
+            .map_or(height, |max| height.min(usize::from(max)))
+    }
+
+    /// Returns the width reserved for the highlight symbol before an item of a horizontal list,
+    /// which is the width of its own symbol or of the list's symbol
+    fn horizontal_symbol_width(&self, item: &ListItem<'_>, has_selection: bool) -> u16 {
+        if self.highlight_spacing.should_add(has_selection) {
+            item.highlight_symbol
+                .as_ref()
+                .or(self.highlight_symbol.as_ref())
+                .map_or(0, |symbol| symbol.width() as u16)
+        } else {
+            0
+        }
+    }
+
+    /// Returns the range of the items of a vertical list of `len` items that fit in
+    /// `area_height`, where `height` returns the height of the item at an index
+    fn vertical_bounds<F>(
         &self,
-        selected: Option<usize>,
-        offset: usize,
+        len: usize,
+        state: &ListState,
+        area_height: u16,
+        height: F,
+    ) -> (usize, usize)
+    where
+        F: Fn(usize) -> usize + Copy,
+    {
+        // Each item takes its height and the separator after it, so the separator after the last
+        // visible item is allowed to overflow the area
+        let separator_height = usize::from(self.item_separator.is_some());
+        self.get_items_bounds(
+            len,
+            state,
+            usize::from(area_height) + separator_height,
+            move |i| height(i) + separator_height,
+        )
+    }
+
+    /// Returns the range of the items of a horizontal list of `len` items that fit in
+    /// `area_width`, where `width` returns the width of the item at an index
+    fn horizontal_bounds<F>(
+        &self,
+        len: usize,
+        state: &ListState,
+        area_width: u16,
+        width: F,
+    ) -> (usize, usize)
+    where
+        F: Fn(usize) -> u16 + Copy,
+    {
+        // Each item takes its width and the spacing after it, so the spacing after the last
+        // visible item is allowed to overflow the area
+        let spacing = self.item_spacing;
+        self.get_items_bounds(
+            len,
+            state,
+            usize::from(area_width.saturating_add(spacing)),
+            move |i| usize::from(width(i).saturating_add(spacing)),
+        )
+    }
+
+    /// Given the offset of the state, calculate which of the `len` items can fit in a given area
+    ///
+    /// The size of each item along the direction of the list is given by `item_size`, which is
+    /// called with the index of the item. The offset is moved to the target of
+    /// [`ListState::scroll_to`] if there is one, or to the selected item otherwise.
+    fn get_items_bounds<F>(
+        &self,
+        len: usize,
+        state: &ListState,
         max_height: usize,
-    ) -> (usize, usize) {
-        let offset = offset.min(self.items.len().saturating_sub(1));
+        item_size: F,
+    ) -> (usize, usize)
+    where
+        F: Fn(usize) -> usize + Copy,
+    {
+        let (selected, offset) = match state.scroll_target {
+            Some((target, behavior)) => (
+                None,
+                behavior.offset(target, state.offset, len, max_height, item_size),
+            ),
+            None if state.follow && follows_last_item(state, len, max_height, item_size) => (
+                state.selected,
+                ScrollBehavior::Nearest.offset(
+                    len.saturating_sub(1),
+                    state.offset,
+                    len,
+                    max_height,
+                    item_size,
+                ),
+            ),
+            None => (state.selected, state.offset),
+        };
+        let offset = offset.min(len.saturating_sub(1));
 
         // Note: visible here implies visible in the given area
         let mut first_visible_index = offset;
@@ -143,12 +759,12 @@ impl List<'_> {
 
         // Calculate the last visible index and total height of the items
         // that will fit in the available space
-        for item in self.items.iter().skip(offset) {
-            if height_from_offset + item.height() > max_height {
+        for index in offset..len {
+            if height_from_offset + item_size(index) > max_height {
                 break;
             }
 
-            height_from_offset += item.height();
+            height_from_offset += item_size(index);
 
             last_visible_index += 1;
         }
@@ -158,10 +774,12 @@ impl List<'_> {
         // None.
         let index_to_display = self
             .apply_scroll_padding_to_selected_index(
+                len,
                 selected,
                 max_height,
                 first_visible_index,
                 last_visible_index,
+                item_size,
             )
             .unwrap_or(offset);
 
@@ -170,8 +788,7 @@ impl List<'_> {
         // If we have an item selected that is out of the viewable area (or
         // the offset is still set), we still need to show this item
         while index_to_display >= last_visible_index {
-            height_from_offset =
-                height_from_offset.saturating_add(self.items[last_visible_index].height());
+            height_from_offset = height_from_offset.saturating_add(item_size(last_visible_index));
 
             last_visible_index += 1;
 
@@ -179,7 +796,7 @@ impl List<'_> {
             // for the selected/offset item
             while height_from_offset > max_height {
                 height_from_offset =
-                    height_from_offset.saturating_sub(self.items[first_visible_index].height());
+                    height_from_offset.saturating_sub(item_size(first_visible_index));
 
                 // Remove this item to view by starting at the next item index
                 first_visible_index += 1;
@@ -191,15 +808,14 @@ impl List<'_> {
         while index_to_display < first_visible_index {
             first_visible_index -= 1;
 
-            height_from_offset =
-                height_from_offset.saturating_add(self.items[first_visible_index].height());
+            height_from_offset = height_from_offset.saturating_add(item_size(first_visible_index));
 
             // Don't show an item if it is beyond our viewable height
             while height_from_offset > max_height {
                 last_visible_index -= 1;
 
                 height_from_offset =
-                    height_from_offset.saturating_sub(self.items[last_visible_index].height());
+                    height_from_offset.saturating_sub(item_size(last_visible_index));
             }
         }
 
@@ -210,14 +826,19 @@ impl List<'_> {
     /// selected item on screen even with items of inconsistent sizes
     ///
     /// This function is sensitive to how the bounds checking function handles item height
-    fn apply_scroll_padding_to_selected_index(
+    fn apply_scroll_padding_to_selected_index<F>(
         &self,
+        len: usize,
         selected: Option<usize>,
         max_height: usize,
         first_visible_index: usize,
         last_visible_index: usize,
-    ) -> Option<usize> {
-        let last_valid_index = self.items.len().saturating_sub(1);
+        item_size: F,
+    ) -> Option<usize>
+    where
+        F: Fn(usize) -> usize,
+    {
+        let last_valid_index = len.saturating_sub(1);
         let selected = selected?.min(last_valid_index);
 
         // The bellow loop handles situations where the list item sizes may not be consistent,
@@ -232,7 +853,7 @@ impl List<'_> {
                     .saturating_add(scroll_padding)
                     .min(last_valid_index)
             {
-                height_around_selected += self.items[index].height();
+                height_around_selected += item_size(index);
             }
             if height_around_selected <= max_height {
                 break;
@@ -253,9 +874,87 @@ impl List<'_> {
     }
 }
 
+/// Returns the width of the widest highlight symbol of `items`, where the items without a symbol
+/// of their own use `highlight_symbol`
+fn symbol_column_width<'i, 'a: 'i>(
+    items: impl Iterator<Item = (usize, &'i ListItem<'a>)>,
+    highlight_symbol: &Line<'_>,
+) -> u16 {
+    items
+        .map(|(_, item)| item.highlight_symbol.as_ref().unwrap_or(highlight_symbol))
+        .map(|symbol| symbol.width() as u16)
+        .max()
+        .unwrap_or_else(|| highlight_symbol.width() as u16)
+}
+
+/// The items of a list in the order in which they are displayed
+///
+/// This is the order of the list, except while an item is grabbed: the grabbed item is displayed at
+/// its tentative position, shifting the items between its original and tentative positions, without
+/// reordering the items of the list.
+#[derive(Clone, Copy)]
+struct DisplayedItems<'l, 'a> {
+    items: &'l [ListItem<'a>],
+    /// The original and tentative positions of the grabbed item
+    grabbed: Option<(usize, usize)>,
+}
+
+impl<'l, 'a> DisplayedItems<'l, 'a> {
+    const fn len(self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns the index in the list of the item displayed at `index`
+    const fn list_index(self, index: usize) -> usize {
+        match self.grabbed {
+            Some((from, to)) => reordered_index(index, from, to),
+            None => index,
+        }
+    }
+
+    /// Returns the item displayed at `index`, if any
+    fn get(self, index: usize) -> Option<&'l ListItem<'a>> {
+        self.items.get(self.list_index(index))
+    }
+
+    /// Returns the items displayed at the indices of `range`, along with these indices
+    fn range(self, range: Range<usize>) -> impl Iterator<Item = (usize, &'l ListItem<'a>)> {
+        range.map(move |index| (index, &self.items[self.list_index(index)]))
+    }
+}
+
+impl<'a> Index<usize> for DisplayedItems<'_, 'a> {
+    type Output = ListItem<'a>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.items[self.list_index(index)]
+    }
+}
+
+/// Returns whether the list of `len` items follows its last item, which is when the last item of
+/// the previous render is displayed from the offset of the state, or when it wasn't rendered yet
+fn follows_last_item<F>(state: &ListState, len: usize, max_height: usize, item_size: F) -> bool
+where
+    F: Fn(usize) -> usize,
+{
+    let followed_len = state.followed_len.min(len);
+    followed_len == 0 || (state.offset..followed_len).map(item_size).sum::<usize>() <= max_height
+}
+
+/// Returns the width of the first line of the item, which is the only line rendered in a
+/// horizontal list
+fn first_line_width(item: &ListItem<'_>) -> u16 {
+    item.content
+        .lines
+        .first()
+        .map_or(0, |line| u16::try_from(line.width()).unwrap_or(u16::MAX))
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::borrow::ToOwned;
+    use alloc::format;
+    use alloc::string::ToString;
     use alloc::vec;
     use alloc::vec::Vec;
 
@@ -268,14 +967,26 @@ mod tests {
 
     use super::*;
     use crate::block::Block;
-    use crate::list::ListItem;
-    use crate::table::HighlightSpacing;
+    use crate::list::ListSeparator;
+    use crate::table::ScrollBehavior;
 
     #[fixture]
     fn single_line_buf() -> Buffer {
         Buffer::empty(Rect::new(0, 0, 10, 1))
     }
 
+    #[test]
+    fn without_items_keeps_configuration() {
+        let list = List::new(["Item 0", "Item 1"])
+            .block(Block::bordered())
+            .highlight_symbol(">>")
+            .highlight_style(Style::new().red())
+            .scroll_padding(2)
+            .wrap_items(true)
+            .filter(|item| item.height() > 0);
+        assert_eq!(list.without_items(), list.items(Vec::<ListItem>::new()));
+    }
+
     #[rstest]
     fn empty_list(mut single_line_buf: Buffer) {
         let mut state = ListState::default();
@@ -842,84 +1553,585 @@ mod tests {
     }
 
     #[test]
-    fn truncate_items() {
-        let list = List::new(["Item 0", "Item 1", "Item 2", "Item 3", "Item 4"]);
-        let buffer = widget(list, 10, 3);
-        #[rustfmt::skip]
-        let expected = Buffer::with_lines([
-            "Item 0    ",
-            "Item 1    ",
-            "Item 2    ",
-        ]);
+    fn horizontal() {
+        let list = List::new(["one", "two", "three", "four", "five"])
+            .direction(ListDirection::Horizontal)
+            .item_spacing(1);
+        let buffer = widget(list, 20, 2);
+        let expected = Buffer::with_lines(["one two three four  ", "                    "]);
         assert_eq!(buffer, expected);
     }
 
     #[test]
-    fn offset_renders_shifted() {
-        let list = List::new([
-            "Item 0", "Item 1", "Item 2", "Item 3", "Item 4", "Item 5", "Item 6",
-        ]);
-        let mut state = ListState::default().with_offset(3);
-        let buffer = stateful_widget(list, &mut state, 6, 3);
+    fn horizontal_scrolls_selected_item_into_view() {
+        let list = List::new(["one", "two", "three", "four", "five"])
+            .direction(ListDirection::Horizontal)
+            .item_spacing(1)
+            .highlight_symbol(">")
+            .highlight_style(Style::new().yellow());
+        let mut state = ListState::default().with_selected(Some(4));
+        let buffer = stateful_widget(list, &mut state, 20, 1);
+        assert_eq!(state.offset, 2);
+        let mut expected = Buffer::with_lines([" three  four >five  "]);
+        expected.set_style(Rect::new(13, 0, 5, 1), Style::new().yellow());
+        assert_eq!(buffer, expected);
+    }
 
-        let expected = Buffer::with_lines(["Item 3", "Item 4", "Item 5"]);
+    #[test]
+    fn horizontal_truncates_multi_line_items() {
+        let list = List::new(["one\ntwo", "three"])
+            .direction(ListDirection::Horizontal)
+            .item_spacing(2);
+        let buffer = widget(list, 12, 2);
+        let expected = Buffer::with_lines(["one  three  ", "            "]);
         assert_eq!(buffer, expected);
     }
 
     #[rstest]
-    #[case(None, [
-        "Item 0 with a v",
-        "Item 1         ",
-        "Item 2         ",
+    #[case::top_to_bottom(ListDirection::TopToBottom, [
+        "  Item 0  ",
+        "  line 2  ",
+        "~~~~~~~~~~",
+        ">>Item 1  ",
+        "  line 2  ",
+        "~~~~~~~~~~",
+        "  Item 2  ",
+        "  line 2  ",
+        "          ",
+        "          ",
     ])]
-    #[case(Some(0), [
-        ">>Item 0 with a",
-        "  Item 1       ",
-        "  Item 2       ",
+    #[case::bottom_to_top(ListDirection::BottomToTop, [
+        "          ",
+        "          ",
+        "  Item 2  ",
+        "  line 2  ",
+        "~~~~~~~~~~",
+        ">>Item 1  ",
+        "  line 2  ",
+        "~~~~~~~~~~",
+        "  Item 0  ",
+        "  line 2  ",
     ])]
-    fn long_lines<'line, Lines>(#[case] selected: Option<usize>, #[case] expected: Lines)
+    fn item_separator<'line, Lines>(#[case] direction: ListDirection, #[case] expected: Lines)
     where
         Lines: IntoIterator,
         Lines::Item: Into<Line<'line>>,
     {
-        let items = [
-            "Item 0 with a very long line that will be truncated",
-            "Item 1",
-            "Item 2",
-        ];
-        let list = List::new(items).highlight_symbol(">>");
-        let mut state = ListState::default().with_selected(selected);
-        let buffer = stateful_widget(list, &mut state, 15, 3);
-        assert_eq!(buffer, Buffer::with_lines(expected));
+        let list = List::new(["Item 0\nline 2", "Item 1\nline 2", "Item 2\nline 2"])
+            .direction(direction)
+            .item_separator(ListSeparator::new("~").style(Style::new().blue()))
+            .highlight_symbol(">>")
+            .highlight_style(Style::new().yellow());
+        let mut state = ListState::default().with_selected(Some(1));
+        let buffer = stateful_widget(list, &mut state, 10, 10);
+
+        let mut expected = Buffer::with_lines(expected);
+        let (selected_y, separator_ys) = if direction == ListDirection::TopToBottom {
+            (3, [2, 5])
+        } else {
+            (5, [4, 7])
+        };
+        expected.set_style(Rect::new(0, selected_y, 10, 2), Style::new().yellow());
+        for y in separator_ys {
+            expected.set_style(Rect::new(0, y, 10, 1), Style::new().blue());
+        }
+        assert_eq!(buffer, expected);
     }
 
     #[test]
-    fn selected_item_ensures_selected_item_is_visible_when_offset_is_before_visible_range() {
-        let items = [
-            "Item 0", "Item 1", "Item 2", "Item 3", "Item 4", "Item 5", "Item 6",
-        ];
-        let list = List::new(items).highlight_symbol(">>");
-        // Set the initial visible range to items 3, 4, and 5
-        let mut state = ListState::default().with_selected(Some(1)).with_offset(3);
-        let buffer = stateful_widget(list, &mut state, 10, 3);
+    fn item_separator_scrolls_selected_item_into_view() {
+        let list = List::new(["Item 0\nline 2", "Item 1\nline 2", "Item 2\nline 2"])
+            .item_separator(ListSeparator::new("-"));
+        let mut state = ListState::default().with_selected(Some(2));
+        let buffer = stateful_widget(list, &mut state, 10, 5);
+        assert_eq!(state.offset, 1);
+        let expected = Buffer::with_lines([
+            "Item 1    ",
+            "line 2    ",
+            "----------",
+            "Item 2    ",
+            "line 2    ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
 
-        #[rustfmt::skip]
+    #[test]
+    fn wrap_items() {
+        let list = List::new(["Item 0", "wrapped to three lines", "Item 2"])
+            .wrap_items(true)
+            .highlight_symbol(">>");
+        let mut state = ListState::default().with_selected(Some(1));
+        let buffer = stateful_widget(list, &mut state, 10, 5);
         let expected = Buffer::with_lines([
-            ">>Item 1  ",
+            "  Item 0  ",
+            ">>wrapped ",
+            "  to three",
+            "  lines   ",
             "  Item 2  ",
-            "  Item 3  ",
         ]);
+        assert_eq!(buffer, expected);
+    }
 
+    #[test]
+    fn wrap_items_scrolls_selected_item_into_view() {
+        let list = List::new(["Item 0", "wrapped to three lines", "Item 2", "Item 3"])
+            .wrap_items(true)
+            .highlight_symbol(">>");
+        let mut state = ListState::default().with_selected(Some(2));
+        let buffer = stateful_widget(list, &mut state, 10, 4);
+        // the wrapped item takes 3 rows, so the first item is scrolled off-screen
+        assert_eq!(state.offset, 1);
+        let expected = Buffer::with_lines(["  wrapped ", "  to three", "  lines   ", ">>Item 2  "]);
         assert_eq!(buffer, expected);
-        assert_eq!(state.selected, Some(1));
-        assert_eq!(
-            state.offset, 1,
-            "did not scroll the selected item into view"
-        );
     }
 
     #[test]
-    fn selected_item_ensures_selected_item_is_visible_when_offset_is_after_visible_range() {
+    fn wrap_items_with_max_item_height() {
+        let list = List::new(["wrapped to three lines", "Item 1"])
+            .wrap_items(true)
+            .max_item_height(2);
+        let buffer = widget(list, 8, 4);
+        let expected = Buffer::with_lines(["wrapped ", "to three", "Item 1  ", "        "]);
+        assert_eq!(buffer, expected);
+    }
+
+    fn grouped_items() -> [ListItem<'static>; 7] {
+        [
+            ListItem::header("Fruits"),
+            ListItem::new("Apple"),
+            ListItem::new("Banana"),
+            ListItem::new("Cherry"),
+            ListItem::header("Vegs"),
+            ListItem::new("Leek"),
+            ListItem::new("Okra"),
+        ]
+    }
+
+    #[test]
+    fn headers_have_blank_highlight_symbol() {
+        let list = List::new(grouped_items())
+            .highlight_symbol(">>")
+            .highlight_style(Style::new().yellow());
+        let mut state = ListState::default().with_selected(Some(1));
+        let buffer = stateful_widget(list, &mut state, 10, 3);
+        let mut expected = Buffer::with_lines(["  Fruits  ", ">>Apple   ", "  Banana  "]);
+        expected.set_style(Rect::new(0, 1, 10, 1), Style::new().yellow());
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::moves_to_next_item(Some(4), Some(5))]
+    #[case::moves_to_first_item(Some(0), Some(1))]
+    #[case::keeps_item(Some(2), Some(2))]
+    #[case::none(None, None)]
+    fn render_moves_selection_off_headers(
+        #[case] selected: Option<usize>,
+        #[case] expected: Option<usize>,
+    ) {
+        let list = List::new(grouped_items());
+        let mut state = ListState::default().with_selected(selected);
+        stateful_widget(list, &mut state, 10, 3);
+        assert_eq!(state.selected, expected);
+    }
+
+    #[test]
+    fn render_moves_selection_off_trailing_header() {
+        let list = List::new([ListItem::new("Apple"), ListItem::header("Vegs")]);
+        let mut state = ListState::default().with_selected(Some(1));
+        stateful_widget(list, &mut state, 10, 3);
+        assert_eq!(state.selected, Some(0));
+
+        let list = List::new([ListItem::header("Fruits"), ListItem::header("Vegs")]);
+        stateful_widget(list, &mut state, 10, 3);
+        assert_eq!(state.selected, None);
+    }
+
+    #[test]
+    fn sticky_headers() {
+        let list = List::new(grouped_items())
+            .highlight_symbol(">>")
+            .sticky_headers(true);
+        let mut state = ListState::default().with_selected(Some(3));
+        let buffer = stateful_widget(list, &mut state, 10, 3);
+        assert_eq!(state.offset, 2);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["  Fruits  ", "  Banana  ", ">>Cherry  "])
+        );
+    }
+
+    #[test]
+    fn sticky_headers_replaced_by_next_header() {
+        let list = List::new(grouped_items())
+            .highlight_symbol(">>")
+            .sticky_headers(true);
+        let mut state = ListState::default().with_selected(Some(5));
+        let buffer = stateful_widget(list, &mut state, 10, 3);
+        assert_eq!(state.offset, 4);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["  Vegs    ", ">>Leek    ", "  Okra    "])
+        );
+    }
+
+    #[test]
+    fn sticky_headers_with_separator() {
+        let list = List::new(grouped_items())
+            .item_separator(ListSeparator::new("-"))
+            .sticky_headers(true);
+        let mut state = ListState::default().with_selected(Some(3));
+        let buffer = stateful_widget(list, &mut state, 6, 5);
+        assert_eq!(state.offset, 2);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["Fruits", "------", "Banana", "------", "Cherry"])
+        );
+    }
+
+    #[test]
+    fn sticky_headers_only_top_to_bottom() {
+        let list = List::new(grouped_items())
+            .direction(ListDirection::BottomToTop)
+            .sticky_headers(true);
+        let mut state = ListState::default().with_selected(Some(3));
+        let buffer = stateful_widget(list, &mut state, 10, 3);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["Cherry    ", "Banana    ", "Apple     "])
+        );
+    }
+
+    #[rstest]
+    #[case::vertical(ListDirection::TopToBottom, ["Copy      ", "Paste     "], Rect::new(0, 1, 10, 1))]
+    #[case::horizontal(ListDirection::Horizontal, ["Copy Paste", "          "], Rect::new(5, 0, 5, 1))]
+    fn disabled_style<'line, Lines>(
+        #[case] direction: ListDirection,
+        #[case] expected: Lines,
+        #[case] disabled_area: Rect,
+    ) where
+        Lines: IntoIterator,
+        Lines::Item: Into<Line<'line>>,
+    {
+        let list = List::new([
+            ListItem::new("Copy"),
+            ListItem::new("Paste".red()).disabled(true),
+        ])
+        .direction(direction)
+        .item_spacing(1)
+        .disabled_style(Style::new().dark_gray());
+        let buffer = widget(list, 10, 2);
+        let mut expected = Buffer::with_lines(expected);
+        expected.set_style(disabled_area, Style::new().dark_gray());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn disabled_style_with_highlight_style() {
+        let list = List::new([ListItem::new("Paste").disabled(true)])
+            .disabled_style(Style::new().dark_gray())
+            .highlight_style(Style::new().on_blue());
+        let mut state = ListState::default().with_selected(Some(0));
+        let buffer = stateful_widget(list, &mut state, 6, 1);
+        let mut expected = Buffer::with_lines(["Paste "]);
+        expected.set_style(expected.area, Style::new().dark_gray().on_blue());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn multi_highlight_style() {
+        let list = List::new(["Item 0", "Item 1", "Item 2", "Item 3", "Item 4"])
+            .highlight_symbol(">>")
+            .highlight_style(Style::new().yellow())
+            .multi_highlight_style(Style::new().on_blue());
+        let mut state = ListState::default().with_selected(Some(3));
+        for index in [0, 3, 4] {
+            state.toggle(index);
+        }
+        let buffer = stateful_widget(list, &mut state, 8, 5);
+        let mut expected =
+            Buffer::with_lines(["  Item 0", "  Item 1", "  Item 2", ">>Item 3", "  Item 4"]);
+        expected.set_style(Rect::new(0, 0, 8, 1), Style::new().on_blue());
+        expected.set_style(Rect::new(0, 3, 8, 1), Style::new().yellow().on_blue());
+        expected.set_style(Rect::new(0, 4, 8, 1), Style::new().on_blue());
+        assert_eq!(buffer, expected);
+        assert!(state.selected_set().iter().eq(&[0, 3, 4]));
+    }
+
+    #[test]
+    fn multi_highlight_style_horizontal() {
+        let list = List::new(["a", "b", "c"])
+            .direction(ListDirection::Horizontal)
+            .item_spacing(1)
+            .multi_highlight_style(Style::new().on_blue());
+        let mut state = ListState::default();
+        state.toggle(1);
+        state.toggle(2);
+        let buffer = stateful_widget(list, &mut state, 6, 1);
+        let mut expected = Buffer::with_lines(["a b c "]);
+        expected.set_style(Rect::new(2, 0, 1, 1), Style::new().on_blue());
+        expected.set_style(Rect::new(4, 0, 1, 1), Style::new().on_blue());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_removes_set_items_beyond_the_end() {
+        let mut state = ListState::default();
+        for index in [1, 3, 7] {
+            state.toggle(index);
+        }
+        stateful_widget(List::new(["a", "b", "c", "d"]), &mut state, 3, 4);
+        assert!(state.selected_set().iter().eq(&[1, 3]));
+    }
+
+    fn fruits() -> List<'static> {
+        List::new(["Apple", "Banana", "Cherry", "Mango", "Orange"])
+            .filter(|item| item.content().to_string().contains("an"))
+    }
+
+    #[test]
+    fn filter() {
+        let mut state = ListState::default().with_selected(Some(3));
+        let buffer = stateful_widget(fruits().highlight_symbol(">>"), &mut state, 8, 4);
+        let expected = Buffer::with_lines(["  Banana", ">>Mango ", "  Orange", "        "]);
+        assert_eq!(buffer, expected);
+        assert_eq!(state.selected(), Some(3));
+        assert_eq!(state.index_at((0, 0)), Some(1));
+        assert_eq!(state.index_at((0, 2)), Some(4));
+    }
+
+    #[rstest]
+    #[case::shown(Some(1), Some(1))]
+    #[case::hidden(Some(2), Some(3))]
+    #[case::before_first_shown(Some(0), Some(1))]
+    #[case::past_end(Some(9), Some(4))]
+    #[case::none(None, None)]
+    fn filter_maps_selection_to_shown_item(
+        #[case] selected: Option<usize>,
+        #[case] expected: Option<usize>,
+    ) {
+        let mut state = ListState::default().with_selected(selected);
+        stateful_widget(fruits(), &mut state, 8, 4);
+        assert_eq!(state.selected(), expected);
+    }
+
+    #[test]
+    fn filter_without_matches() {
+        let list = List::new(["Apple", "Banana"]).filter(|_| false);
+        let mut state = ListState::default().with_selected(Some(1));
+        let buffer = stateful_widget(list, &mut state, 6, 2);
+        assert_eq!(buffer, Buffer::with_lines(["      ", "      "]));
+        assert_eq!(state.selected(), None);
+    }
+
+    #[test]
+    fn filter_offset_uses_original_indices() {
+        let list = List::new((0..10).map(|i| format!("Item {i}"))).filter(|item| {
+            item.content()
+                .to_string()
+                .ends_with(['0', '2', '4', '6', '8'])
+        });
+        let mut state = ListState::default().with_selected(Some(8));
+        let buffer = stateful_widget(list.clone(), &mut state, 6, 2);
+        assert_eq!(buffer, Buffer::with_lines(["Item 6", "Item 8"]));
+        assert_eq!((state.offset(), state.selected()), (6, Some(8)));
+
+        list.select_previous(&mut state);
+        list.select_previous(&mut state);
+        let buffer = stateful_widget(list, &mut state, 6, 2);
+        assert_eq!(buffer, Buffer::with_lines(["Item 4", "Item 6"]));
+        assert_eq!((state.offset(), state.selected()), (4, Some(4)));
+    }
+
+    #[test]
+    fn filter_keeps_hidden_items_in_selected_set() {
+        let list = List::new(["a", "b", "c", "d"])
+            .filter(|item| item.content().to_string() != "b")
+            .multi_highlight_style(Style::new().on_blue());
+        let mut state = ListState::default();
+        state.toggle(1);
+        state.toggle(2);
+        let buffer = stateful_widget(list, &mut state, 1, 3);
+        let mut expected = Buffer::with_lines(["a", "c", "d"]);
+        expected.set_style(Rect::new(0, 1, 1, 1), Style::new().on_blue());
+        assert_eq!(buffer, expected);
+        assert!(state.selected_set().iter().eq(&[1, 2]));
+    }
+
+    #[test]
+    fn alternating_item_styles() {
+        let list = List::new(["Item 0", "Item 1", "Item 2", "Item 3"])
+            .alternating_item_styles(Style::new().on_black(), Style::new().on_white());
+        let buffer = widget(list, 6, 6);
+        let mut expected =
+            Buffer::with_lines(["Item 0", "Item 1", "Item 2", "Item 3", "      ", "      "]);
+        expected.set_style(Rect::new(0, 0, 6, 1), Style::new().on_black());
+        expected.set_style(Rect::new(0, 1, 6, 1), Style::new().on_white());
+        expected.set_style(Rect::new(0, 2, 6, 1), Style::new().on_black());
+        expected.set_style(Rect::new(0, 3, 6, 1), Style::new().on_white());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn alternating_item_styles_under_item_and_highlight_styles() {
+        let list = List::new([
+            ListItem::new("a"),
+            ListItem::new("b").style(Style::new().on_blue()),
+            ListItem::new("c"),
+        ])
+        .alternating_item_styles(
+            Style::new().on_black().italic(),
+            Style::new().on_white().italic(),
+        )
+        .highlight_style(Style::new().on_red());
+        let mut state = ListState::default().with_offset(1).with_selected(Some(2));
+        let buffer = stateful_widget(list, &mut state, 3, 2);
+        let mut expected = Buffer::with_lines(["b  ", "c  "]);
+        expected.set_style(expected.area, Style::new().italic());
+        expected.set_style(Rect::new(0, 0, 3, 1), Style::new().on_blue());
+        expected.set_style(Rect::new(0, 1, 3, 1), Style::new().on_red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::first_item((1, 1), Some(0))]
+    #[case::highlight_symbol_column((2, 3), Some(1))]
+    #[case::second_line_of_wrapped_item((5, 4), Some(1))]
+    #[case::last_item((9, 6), Some(2))]
+    #[case::border((0, 1), None)]
+    #[case::separator((4, 2), None)]
+    #[case::padding((4, 7), None)]
+    fn index_at(#[case] position: (u16, u16), #[case] expected: Option<usize>) {
+        let list = List::new(["Item 0", "Item 1 wraps", "Item 2"])
+            .block(Block::bordered())
+            .wrap_items(true)
+            .item_separator(ListSeparator::new("-"))
+            .highlight_symbol(">>")
+            .highlight_spacing(HighlightSpacing::Always);
+        let mut state = ListState::default();
+        stateful_widget(list, &mut state, 12, 9);
+        assert_eq!(state.index_at(position), expected);
+    }
+
+    #[test]
+    fn index_at_scrolled_list() {
+        let list = List::new(["Item 0", "Item 1", "Item 2", "Item 3"]);
+        let mut state = ListState::default().with_selected(Some(3));
+        stateful_widget(list, &mut state, 10, 2);
+        assert_eq!(state.index_at((0, 0)), Some(2));
+        assert_eq!(state.index_at((0, 1)), Some(3));
+    }
+
+    #[test]
+    fn index_at_horizontal() {
+        let list = List::new(["one", "two"])
+            .direction(ListDirection::Horizontal)
+            .item_spacing(1);
+        let mut state = ListState::default();
+        stateful_widget(list, &mut state, 10, 2);
+        assert_eq!(state.index_at((2, 0)), Some(0));
+        assert_eq!(state.index_at((3, 0)), None);
+        assert_eq!(state.index_at((4, 0)), Some(1));
+        assert_eq!(state.index_at((4, 1)), None);
+    }
+
+    #[test]
+    fn index_at_sticky_header() {
+        let list = List::new(grouped_items()).sticky_headers(true);
+        let mut state = ListState::default().with_selected(Some(3));
+        stateful_widget(list, &mut state, 10, 3);
+        assert_eq!(state.index_at((0, 0)), Some(0));
+        assert_eq!(state.index_at((0, 1)), Some(2));
+    }
+
+    #[test]
+    fn index_at_cleared_when_not_displayed() {
+        let list = List::new(["Item 0"]);
+        let mut state = ListState::default();
+        stateful_widget(list.clone(), &mut state, 10, 1);
+        assert_eq!(state.index_at((0, 0)), Some(0));
+        StatefulWidget::render(list, Rect::ZERO, &mut Buffer::empty(Rect::ZERO), &mut state);
+        assert_eq!(state.index_at((0, 0)), None);
+    }
+
+    #[test]
+    fn truncate_items() {
+        let list = List::new(["Item 0", "Item 1", "Item 2", "Item 3", "Item 4"]);
+        let buffer = widget(list, 10, 3);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "Item 0    ",
+            "Item 1    ",
+            "Item 2    ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn offset_renders_shifted() {
+        let list = List::new([
+            "Item 0", "Item 1", "Item 2", "Item 3", "Item 4", "Item 5", "Item 6",
+        ]);
+        let mut state = ListState::default().with_offset(3);
+        let buffer = stateful_widget(list, &mut state, 6, 3);
+
+        let expected = Buffer::with_lines(["Item 3", "Item 4", "Item 5"]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case(None, [
+        "Item 0 with a v",
+        "Item 1         ",
+        "Item 2         ",
+    ])]
+    #[case(Some(0), [
+        ">>Item 0 with a",
+        "  Item 1       ",
+        "  Item 2       ",
+    ])]
+    fn long_lines<'line, Lines>(#[case] selected: Option<usize>, #[case] expected: Lines)
+    where
+        Lines: IntoIterator,
+        Lines::Item: Into<Line<'line>>,
+    {
+        let items = [
+            "Item 0 with a very long line that will be truncated",
+            "Item 1",
+            "Item 2",
+        ];
+        let list = List::new(items).highlight_symbol(">>");
+        let mut state = ListState::default().with_selected(selected);
+        let buffer = stateful_widget(list, &mut state, 15, 3);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn selected_item_ensures_selected_item_is_visible_when_offset_is_before_visible_range() {
+        let items = [
+            "Item 0", "Item 1", "Item 2", "Item 3", "Item 4", "Item 5", "Item 6",
+        ];
+        let list = List::new(items).highlight_symbol(">>");
+        // Set the initial visible range to items 3, 4, and 5
+        let mut state = ListState::default().with_selected(Some(1)).with_offset(3);
+        let buffer = stateful_widget(list, &mut state, 10, 3);
+
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            ">>Item 1  ",
+            "  Item 2  ",
+            "  Item 3  ",
+        ]);
+
+        assert_eq!(buffer, expected);
+        assert_eq!(state.selected, Some(1));
+        assert_eq!(
+            state.offset, 1,
+            "did not scroll the selected item into view"
+        );
+    }
+
+    #[test]
+    fn selected_item_ensures_selected_item_is_visible_when_offset_is_after_visible_range() {
         let items = [
             "Item 0", "Item 1", "Item 2", "Item 3", "Item 4", "Item 5", "Item 6",
         ];
@@ -1193,6 +2405,37 @@ mod tests {
         assert_eq!(offset_after_render, state.offset());
     }
 
+    #[test]
+    fn padding_kept_while_moving_selection() {
+        let list = List::new((0..10).map(|i| format!("Item {i}"))).scroll_padding(2);
+        let mut state = ListState::default();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 6));
+        // the first and last visible items for each selected item, moving down then up
+        let down = [
+            (0, 5),
+            (0, 5),
+            (0, 5),
+            (0, 5),
+            (1, 6),
+            (2, 7),
+            (3, 8),
+            (4, 9),
+            (4, 9),
+            (4, 9),
+        ];
+        for (selected, expected) in down.into_iter().enumerate() {
+            state.select(Some(selected));
+            StatefulWidget::render(&list, buffer.area, &mut buffer, &mut state);
+            assert_eq!((state.offset(), state.offset() + 5), expected);
+        }
+        let up = [(4, 9), (3, 8), (2, 7), (1, 6), (0, 5), (0, 5), (0, 5)];
+        for (selected, expected) in (0..7).rev().zip(up) {
+            state.select(Some(selected));
+            StatefulWidget::render(&list, buffer.area, &mut buffer, &mut state);
+            assert_eq!((state.offset(), state.offset() + 5), expected);
+        }
+    }
+
     #[test]
     fn padding_inconsistent_item_sizes() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
@@ -1269,4 +2512,714 @@ mod tests {
         StatefulWidget::render(list, single_line_buf.area, &mut single_line_buf, &mut state);
         assert_eq!(single_line_buf, Buffer::with_lines([expected]));
     }
+
+    #[rstest]
+    #[case::directory(0, ["▶  src    ", "   docs   ", "   main.rs"])]
+    #[case::file(2, ["   src    ", "   docs   ", "•  main.rs"])]
+    #[case::default_symbol(1, ["   src    ", ">> docs   ", "   main.rs"])]
+    fn item_highlight_symbols(#[case] selected: usize, #[case] expected: [&str; 3]) {
+        // the column is as wide as the widest symbol, whichever item is selected
+        let list = List::new([
+            ListItem::new("src").highlight_symbol("▶ "),
+            ListItem::new("docs"),
+            ListItem::new("main.rs").highlight_symbol("•"),
+        ])
+        .highlight_symbol(">> ");
+        let mut state = ListState::default().with_selected(Some(selected));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+        StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn item_highlight_symbols_of_visible_items() {
+        // the wide symbol of the hidden item doesn't widen the column
+        let list = List::new([
+            ListItem::new("Item 0"),
+            ListItem::new("Item 1"),
+            ListItem::new("Item 2").highlight_symbol(">>>> "),
+        ])
+        .highlight_symbol("> ")
+        .highlight_spacing(HighlightSpacing::Always);
+        let mut state = ListState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 2));
+        StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["  Item 0  ", "  Item 1  "]));
+    }
+
+    #[test]
+    fn item_highlight_symbol_wraps_items() {
+        let list = List::new([ListItem::new("aaa bbb").highlight_symbol(">>>> ")])
+            .highlight_symbol(">")
+            .wrap_items(true);
+        let mut state = ListState::default().with_selected(Some(0));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 2));
+        StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines([">>>> aaa ", "     bbb "]));
+    }
+
+    #[test]
+    fn item_highlight_style() {
+        let list = List::new([
+            ListItem::new("ok"),
+            ListItem::new("error").highlight_style(Style::new().red()),
+        ])
+        .highlight_style(Style::new().blue());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+
+        let mut state = ListState::default().with_selected(Some(1));
+        StatefulWidget::render(&list, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(["ok   ", "error"]);
+        expected.set_style(Rect::new(0, 1, 5, 1), Style::new().red());
+        assert_eq!(buf, expected);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        let mut state = ListState::default().with_selected(Some(0));
+        StatefulWidget::render(&list, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(["ok   ", "error"]);
+        expected.set_style(Rect::new(0, 0, 5, 1), Style::new().blue());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn item_highlight_symbol_horizontal() {
+        let list = List::new([
+            ListItem::new("a").highlight_symbol(">>"),
+            ListItem::new("b"),
+        ])
+        .direction(ListDirection::Horizontal)
+        .highlight_symbol(">")
+        .item_spacing(1);
+        let mut state = ListState::default().with_selected(Some(0));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 1));
+        StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines([">>a  b "]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn render_clamps_deserialized_state() -> Result<(), serde_json::Error> {
+        // the state was saved when the list had more items
+        let mut state: ListState = serde_json::from_str(r#"{"offset":50,"selected":60}"#)?;
+        let list = List::new(["Item 0", "Item 1", "Item 2"]).highlight_symbol(">>");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 2));
+        StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines([">>Item 2  ", "          "]));
+        assert_eq!(state.offset(), 2);
+        assert_eq!(state.selected(), Some(2));
+        Ok(())
+    }
+
+    mod scroll_to {
+        use alloc::format;
+
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        /// A list of 20 items, where the items at odd indexes are 2 lines high
+        fn list() -> List<'static> {
+            List::new((0..20).map(|i| {
+                if i % 2 == 0 {
+                    format!("Item {i}")
+                } else {
+                    format!("Item {i}\n  more")
+                }
+            }))
+        }
+
+        /// Renders the list in a 7 lines high area, scrolled from the offset 5 to the target
+        fn render(list: List<'_>, target: usize, behavior: ScrollBehavior) -> (Buffer, ListState) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 7));
+            let mut state = ListState::default().with_offset(5).with_selected(Some(6));
+            state.scroll_to(target, behavior);
+            StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+            (buf, state)
+        }
+
+        #[rstest]
+        #[case::top(ScrollBehavior::Top, 10, 10)]
+        #[case::top_past_end(ScrollBehavior::Top, 50, 19)]
+        #[case::center(ScrollBehavior::Center, 10, 8)]
+        #[case::center_tall_item(ScrollBehavior::Center, 11, 10)]
+        #[case::center_start(ScrollBehavior::Center, 1, 0)]
+        #[case::center_past_end(ScrollBehavior::Center, 50, 16)]
+        #[case::nearest_above(ScrollBehavior::Nearest, 2, 2)]
+        #[case::nearest_visible(ScrollBehavior::Nearest, 7, 5)]
+        #[case::nearest_below(ScrollBehavior::Nearest, 10, 6)]
+        #[case::nearest_past_end(ScrollBehavior::Nearest, 50, 16)]
+        fn offset(
+            #[case] behavior: ScrollBehavior,
+            #[case] target: usize,
+            #[case] expected: usize,
+        ) {
+            let (_, state) = render(list(), target, behavior);
+            assert_eq!(state.offset(), expected);
+            assert_eq!(state.selected(), Some(6));
+        }
+
+        #[test]
+        fn center() {
+            let (buf, _) = render(list(), 10, ScrollBehavior::Center);
+            let expected = Buffer::with_lines([
+                "Item 8    ",
+                "Item 9    ",
+                "  more    ",
+                "Item 10   ",
+                "Item 11   ",
+                "  more    ",
+                "Item 12   ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn selection_is_not_scrolled_into_view() {
+            let list = list().highlight_symbol(">>");
+            let (buf, state) = render(list, 19, ScrollBehavior::Nearest);
+            assert_eq!(state.offset(), 16);
+            assert_eq!(state.index_at((0, 0)), Some(16));
+            assert!(!buf.content().iter().any(|cell| cell.symbol() == ">"));
+        }
+
+        #[test]
+        fn target_is_kept_until_selection_changes() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+            let mut state = ListState::default().with_selected(Some(0));
+            state.scroll_to(10, ScrollBehavior::Top);
+            StatefulWidget::render(list(), buf.area, &mut buf, &mut state);
+            StatefulWidget::render(list(), buf.area, &mut buf, &mut state);
+            assert_eq!(state.offset(), 10);
+
+            state.select_next();
+            StatefulWidget::render(list(), buf.area, &mut buf, &mut state);
+            assert_eq!(state.offset(), 1);
+        }
+
+        #[test]
+        fn with_separator() {
+            // each item takes a line for the separator after it
+            let list = List::new((0..20).map(|i| format!("Item {i}")))
+                .item_separator(ListSeparator::new("-"));
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 5));
+            let mut state = ListState::default();
+            state.scroll_to(10, ScrollBehavior::Center);
+            StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+            assert_eq!(state.offset(), 9);
+        }
+
+        #[test]
+        fn horizontal() {
+            let list = List::new((0..20).map(|i| format!("{i:02}")))
+                .direction(ListDirection::Horizontal)
+                .item_spacing(1);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+            let mut state = ListState::default();
+            state.scroll_to(19, ScrollBehavior::Nearest);
+            StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+            assert_eq!(state.offset(), 17);
+            assert_eq!(buf, Buffer::with_lines(["17 18 19"]));
+        }
+    }
+
+    mod anchor {
+        use alloc::format;
+
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        fn render(list: List<'_>, state: &mut ListState) -> Buffer {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 4));
+            StatefulWidget::render(list, buf.area, &mut buf, state);
+            buf
+        }
+
+        #[test]
+        fn short_content_is_at_bottom() {
+            let list = List::new(["Hello", "Hi!"]).anchor(ListAnchor::Bottom);
+            let mut state = ListState::default();
+            assert_eq!(
+                render(list, &mut state),
+                Buffer::with_lines(["      ", "      ", "Hello ", "Hi!   "])
+            );
+            assert_eq!(state.index_at((0, 2)), Some(0));
+            assert_eq!(state.index_at((0, 0)), None);
+        }
+
+        #[test]
+        fn short_content_with_separator() {
+            let list = List::new(["Hello", "Hi!"])
+                .item_separator(ListSeparator::new("-"))
+                .anchor(ListAnchor::Bottom);
+            assert_eq!(
+                render(list, &mut ListState::default()),
+                Buffer::with_lines(["      ", "Hello ", "------", "Hi!   "])
+            );
+        }
+
+        #[test]
+        fn short_content_with_multiline_item() {
+            let list = List::new(["Hello\nyou"]).anchor(ListAnchor::Bottom);
+            assert_eq!(
+                render(list, &mut ListState::default()),
+                Buffer::with_lines(["      ", "      ", "Hello ", "you   "])
+            );
+        }
+
+        #[test]
+        fn content_filling_the_area_is_unchanged() {
+            let items = (0..6).map(|i| format!("Item {i}"));
+            let list = List::new(items).anchor(ListAnchor::Bottom);
+            assert_eq!(
+                render(list, &mut ListState::default()),
+                Buffer::with_lines(["Item 0", "Item 1", "Item 2", "Item 3"])
+            );
+        }
+
+        #[test]
+        fn bottom_to_top_is_unchanged() {
+            let list = List::new(["Hello", "Hi!"])
+                .direction(ListDirection::BottomToTop)
+                .anchor(ListAnchor::Bottom);
+            assert_eq!(
+                render(list, &mut ListState::default()),
+                Buffer::with_lines(["      ", "      ", "Hi!   ", "Hello "])
+            );
+        }
+    }
+
+    mod follow {
+        use alloc::format;
+        use alloc::string::String;
+
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        fn messages(len: usize) -> Vec<String> {
+            (0..len).map(|i| format!("Msg {i}")).collect()
+        }
+
+        fn render(list: List<'_>, state: &mut ListState) -> Buffer {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 4));
+            StatefulWidget::render(list, buf.area, &mut buf, state);
+            buf
+        }
+
+        #[test]
+        fn starts_at_last_item() {
+            let mut state = ListState::default();
+            state.follow(true);
+            assert!(state.is_following());
+            render(List::new(messages(10)), &mut state);
+            assert_eq!(state.offset(), 6);
+        }
+
+        #[test]
+        fn appended_items_are_shown_at_bottom() {
+            let mut state = ListState::default();
+            state.follow(true);
+            let list = |len| List::new(messages(len)).anchor(ListAnchor::Bottom);
+            assert_eq!(
+                render(list(1), &mut state),
+                Buffer::with_lines(["      ", "      ", "      ", "Msg 0 "])
+            );
+            render(list(3), &mut state);
+            assert_eq!(
+                render(list(5), &mut state),
+                Buffer::with_lines(["Msg 1 ", "Msg 2 ", "Msg 3 ", "Msg 4 "])
+            );
+            assert_eq!(state.offset(), 1);
+            render(list(7), &mut state);
+            assert_eq!(state.offset(), 3);
+        }
+
+        #[test]
+        fn scrolled_up_view_is_not_moved_by_appended_items() {
+            let mut state = ListState::default();
+            state.follow(true);
+            render(List::new(messages(10)), &mut state);
+            *state.offset_mut() = 2;
+            render(List::new(messages(10)), &mut state);
+            assert_eq!(state.offset(), 2);
+            assert_eq!(
+                render(List::new(messages(12)), &mut state),
+                Buffer::with_lines(["Msg 2 ", "Msg 3 ", "Msg 4 ", "Msg 5 "])
+            );
+
+            // scrolling back to the last item follows the appended items again
+            *state.offset_mut() = 8;
+            render(List::new(messages(12)), &mut state);
+            render(List::new(messages(15)), &mut state);
+            assert_eq!(state.offset(), 11);
+        }
+
+        #[test]
+        fn not_following_keeps_offset() {
+            let mut state = ListState::default();
+            render(List::new(messages(4)), &mut state);
+            render(List::new(messages(10)), &mut state);
+            assert_eq!(state.offset(), 0);
+        }
+
+        #[test]
+        fn virtual_items() {
+            let mut state = ListState::default();
+            state.follow(true);
+            let list = |len| List::default().items_from(len, |i| ListItem::new(format!("Msg {i}")));
+            render(list(4), &mut state);
+            assert_eq!(
+                render(list(6), &mut state),
+                Buffer::with_lines(["Msg 2 ", "Msg 3 ", "Msg 4 ", "Msg 5 "])
+            );
+        }
+    }
+
+    mod grab {
+        use alloc::format;
+
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        fn list() -> List<'static> {
+            List::new((0..8).map(|i| format!("Item {i}"))).grabbed_style(Style::new().blue())
+        }
+
+        fn render(list: &List<'_>, state: &mut ListState) -> Buffer {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 4));
+            StatefulWidget::render(list, buf.area, &mut buf, state);
+            buf
+        }
+
+        #[test]
+        fn grabbed_item_is_displayed_at_tentative_position() {
+            let list = list();
+            let mut state = ListState::default();
+            state.grab(2);
+            state.move_grabbed_down();
+            let mut expected = Buffer::with_lines(["Item 0", "Item 1", "Item 3", "Item 2"]);
+            expected.set_style(Rect::new(0, 3, 6, 1), Style::new().blue());
+            assert_eq!(render(&list, &mut state), expected);
+            assert_eq!(state.selected(), Some(3));
+            assert_eq!(state.grabbed(), Some((2, 3)));
+        }
+
+        #[test]
+        fn offset_follows_grabbed_item() {
+            let list = list();
+            let mut state = ListState::default();
+            state.grab(2);
+            for _ in 0..3 {
+                state.move_grabbed_down();
+                render(&list, &mut state);
+            }
+            assert_eq!(state.offset(), 2);
+            let mut expected = Buffer::with_lines(["Item 3", "Item 4", "Item 5", "Item 2"]);
+            expected.set_style(Rect::new(0, 3, 6, 1), Style::new().blue());
+            assert_eq!(render(&list, &mut state), expected);
+
+            assert_eq!(state.drop_grabbed(), Some((2, 5)));
+            assert_eq!(state.grabbed(), None);
+            assert_eq!(state.selected(), Some(5));
+            // the caller has not moved the item yet, so the original order is displayed
+            assert_eq!(
+                render(&list, &mut state),
+                Buffer::with_lines(["Item 2", "Item 3", "Item 4", "Item 5"])
+            );
+        }
+
+        #[test]
+        fn grabbed_item_moves_back_towards_start() {
+            let list = list();
+            let mut state = ListState::default().with_offset(4);
+            state.grab(6);
+            for _ in 0..4 {
+                state.move_grabbed_up();
+                render(&list, &mut state);
+            }
+            assert_eq!(state.offset(), 2);
+            let mut expected = Buffer::with_lines(["Item 6", "Item 2", "Item 3", "Item 4"]);
+            expected.set_style(Rect::new(0, 0, 6, 1), Style::new().blue());
+            assert_eq!(render(&list, &mut state), expected);
+            assert_eq!(state.drop_grabbed(), Some((6, 2)));
+        }
+
+        #[test]
+        fn horizontal_list() {
+            let list = List::new(["a", "bb", "c"])
+                .direction(ListDirection::Horizontal)
+                .item_spacing(1)
+                .grabbed_style(Style::new().blue());
+            let mut state = ListState::default();
+            state.grab(0);
+            state.move_grabbed_down();
+            let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+            StatefulWidget::render(&list, buf.area, &mut buf, &mut state);
+            let mut expected = Buffer::with_lines(["bb a c  "]);
+            expected.set_style(Rect::new(3, 0, 1, 1), Style::new().blue());
+            assert_eq!(buf, expected);
+            assert_eq!(state.selected(), Some(1));
+        }
+
+        #[test]
+        fn tentative_position_is_clamped_to_last_item() {
+            let list = list();
+            let mut state = ListState::default();
+            state.grab(6);
+            state.move_grabbed_down();
+            state.move_grabbed_down();
+            render(&list, &mut state);
+            assert_eq!(state.grabbed(), Some((6, 7)));
+            assert_eq!(state.selected(), Some(7));
+        }
+
+        #[test]
+        fn grabbed_item_out_of_bounds_is_released() {
+            let list = list();
+            let mut state = ListState::default();
+            state.grab(10);
+            assert_eq!(
+                render(&list, &mut state),
+                Buffer::with_lines(["Item 4", "Item 5", "Item 6", "Item 7"])
+            );
+            assert_eq!(state.grabbed(), None);
+            assert_eq!(state.selected(), Some(7));
+        }
+
+        #[test]
+        fn virtual_items() {
+            let items = list();
+            let virtual_list = List::default()
+                .items_from(8, |i| ListItem::new(format!("Item {i}")))
+                .grabbed_style(Style::new().blue());
+            let mut state = ListState::default();
+            state.grab(2);
+            let mut virtual_state = state.clone();
+            for _ in 0..3 {
+                state.move_grabbed_down();
+                virtual_state.move_grabbed_down();
+                assert_eq!(
+                    render(&virtual_list, &mut virtual_state),
+                    render(&items, &mut state)
+                );
+                assert_eq!(virtual_state, state);
+            }
+        }
+    }
+
+    mod virtual_items {
+        use alloc::format;
+        use core::cell::RefCell;
+
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        fn item(i: usize) -> ListItem<'static> {
+            ListItem::new(format!("Item {i}"))
+        }
+
+        fn render_both(list: List<'static>, len: usize, state: &ListState) -> Buffer {
+            let mut vec_buf = Buffer::empty(Rect::new(0, 0, 12, 6));
+            let mut vec_state = state.clone();
+            let vec_list = list.clone().items((0..len).map(item));
+            StatefulWidget::render(vec_list, vec_buf.area, &mut vec_buf, &mut vec_state);
+
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 6));
+            let mut virtual_state = state.clone();
+            let virtual_list = list.items_from(len, item);
+            StatefulWidget::render(virtual_list, buf.area, &mut buf, &mut virtual_state);
+
+            assert_eq!(buf, vec_buf);
+            assert_eq!(virtual_state, vec_state);
+            buf
+        }
+
+        #[rstest]
+        #[case::no_selection(ListState::default())]
+        #[case::offset(ListState::default().with_offset(50))]
+        #[case::offset_past_end(ListState::default().with_offset(5000))]
+        #[case::selected_before_offset(ListState::default().with_offset(50).with_selected(Some(20)))]
+        #[case::selected_after_offset(ListState::default().with_offset(50).with_selected(Some(80)))]
+        #[case::selected_past_end(ListState::default().with_selected(Some(5000)))]
+        fn render_matches_vec_items(
+            #[case] state: ListState,
+            #[values(ListDirection::TopToBottom, ListDirection::BottomToTop)]
+            direction: ListDirection,
+        ) {
+            let list = List::default()
+                .direction(direction)
+                .block(Block::bordered())
+                .highlight_symbol(">>")
+                .highlight_style(Style::new().red())
+                .scroll_padding(1);
+            render_both(list, 100, &state);
+        }
+
+        #[test]
+        fn render_selected_set_matches_vec_items() {
+            let list = List::default().multi_highlight_style(Style::new().on_blue());
+            let mut state = ListState::default().with_offset(50);
+            for index in [10, 51, 53, 99, 150] {
+                state.toggle(index);
+            }
+            render_both(list, 100, &state);
+        }
+
+        #[test]
+        fn render_filtered_matches_vec_items() {
+            let is_shown = |item: &ListItem| item.content().to_string().ends_with('7');
+            let state = ListState::default().with_offset(20).with_selected(Some(50));
+
+            let mut vec_buf = Buffer::empty(Rect::new(0, 0, 12, 6));
+            let mut vec_state = state.clone();
+            let vec_list = List::new((0..100).map(item)).filter(is_shown);
+            StatefulWidget::render(vec_list, vec_buf.area, &mut vec_buf, &mut vec_state);
+
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 6));
+            let mut virtual_state = state;
+            let virtual_list = List::default().items_from(100, item).filter(is_shown);
+            StatefulWidget::render(virtual_list, buf.area, &mut buf, &mut virtual_state);
+
+            assert_eq!(buf, vec_buf);
+            assert_eq!(virtual_state, vec_state);
+            assert_eq!((vec_state.offset(), vec_state.selected()), (27, Some(57)));
+        }
+
+        #[rstest]
+        #[case::even_offset(ListState::default().with_offset(50))]
+        #[case::odd_offset(ListState::default().with_offset(51))]
+        fn render_alternating_item_styles_matches_vec_items(#[case] state: ListState) {
+            let list = List::default()
+                .alternating_item_styles(Style::new().on_black(), Style::new().on_white());
+            render_both(list, 100, &state);
+        }
+
+        #[rstest]
+        #[case::offset(ListState::default().with_offset(50))]
+        #[case::selected_after_offset(ListState::default().with_offset(50).with_selected(Some(80)))]
+        fn render_with_separator_matches_vec_items(#[case] state: ListState) {
+            let list = List::default()
+                .item_separator(ListSeparator::new("-"))
+                .highlight_symbol(">>");
+            render_both(list, 100, &state);
+        }
+
+        #[rstest]
+        #[case::selected_in_view(ScrollBehavior::Center, 50, Some(51))]
+        #[case::selected_out_of_view(ScrollBehavior::Top, 50, Some(10))]
+        #[case::past_end(ScrollBehavior::Nearest, 5000, Some(10))]
+        fn render_scrolled_matches_vec_items(
+            #[case] behavior: ScrollBehavior,
+            #[case] target: usize,
+            #[case] selected: Option<usize>,
+            #[values(HighlightSpacing::WhenSelected, HighlightSpacing::Never)]
+            highlight_spacing: HighlightSpacing,
+        ) {
+            let list = List::default()
+                .highlight_symbol(">>")
+                .highlight_spacing(highlight_spacing);
+            let mut state = ListState::default().with_selected(selected);
+            state.scroll_to(target, behavior);
+            render_both(list, 100, &state);
+        }
+
+        #[test]
+        fn render_horizontal_matches_vec_items() {
+            let list = List::default()
+                .direction(ListDirection::Horizontal)
+                .item_spacing(1)
+                .highlight_symbol(">");
+            let state = ListState::default().with_selected(Some(7));
+            render_both(list, 10, &state);
+        }
+
+        #[test]
+        fn render_empty() {
+            let state = ListState::default().with_selected(Some(3));
+            render_both(List::default(), 0, &state);
+        }
+
+        #[test]
+        fn render_creates_only_visible_items() {
+            let created = RefCell::new(Vec::new());
+            let list = List::default().items_from(1_000_000, |i| {
+                created.borrow_mut().push(i);
+                item(i)
+            });
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+            let mut state = ListState::default()
+                .with_offset(500_000)
+                .with_selected(Some(700_000));
+            StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+            assert_eq!(created.into_inner(), [699_998, 699_999, 700_000]);
+            assert_eq!(state.offset(), 699_998);
+            assert_eq!(state.selected(), Some(700_000));
+        }
+
+        #[test]
+        fn select_last_uses_len() {
+            let list = List::default().items_from(1_000_000, item);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
+            let mut state = ListState::default();
+            state.select_last();
+            StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+            assert_eq!(state.selected(), Some(999_999));
+            assert_eq!(state.offset(), 999_998);
+            assert_eq!(buf, Buffer::with_lines(["Item 999998 ", "Item 999999 "]));
+        }
+
+        #[test]
+        fn render_moves_selection_off_headers() {
+            let list = List::default().items_from(10, |i| {
+                if i % 5 == 0 {
+                    ListItem::header(format!("Group {i}"))
+                } else {
+                    item(i)
+                }
+            });
+            let mut state = ListState::default().with_selected(Some(5));
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+            StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+            assert_eq!(state.selected, Some(6));
+        }
+
+        #[test]
+        fn index_at() {
+            let list = List::default().items_from(100, item);
+            let mut state = ListState::default().with_selected(Some(50));
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+            StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+            assert_eq!(state.index_at((0, 2)), Some(50));
+            assert_eq!(state.index_at((0, 0)), Some(48));
+        }
+
+        #[test]
+        fn render_with_item_heights() {
+            let list = List::default()
+                .items_from(100, |i| ListItem::new(format!("Item {i}\n  line 2")))
+                .item_heights_from(|_| 2);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+            let mut state = ListState::default().with_offset(10);
+            StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+            let expected =
+                Buffer::with_lines(["Item 10 ", "  line 2", "Item 11 ", "  line 2", "        "]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn items_replaces_virtual_items() {
+            let list = List::default().items_from(10, item);
+            assert!(list.virtual_items.is_some());
+            assert_eq!(list.len(), 10);
+            let list = list.items([item(0)]);
+            assert!(list.virtual_items.is_none());
+            assert_eq!(list.items, [item(0)]);
+            let list = list.items_from(10, item);
+            assert_eq!(list.items, []);
+            assert_eq!(list.len(), 10);
+        }
+    }
 }
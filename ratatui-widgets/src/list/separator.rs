@@ -0,0 +1,150 @@
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::{Style, Styled};
+use unicode_width::UnicodeWidthStr;
+
+/// A line drawn between the items of a [`List`], see [`List::item_separator`]
+///
+/// The symbol of the separator is repeated to fill the width of the list, and the whole line is
+/// styled with the separator's [`Style`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::style::{Style, Stylize};
+/// use ratatui::widgets::{List, ListSeparator};
+///
+/// let separator = ListSeparator::new("─").style(Style::new().dark_gray());
+/// let list = List::new(["Item 1", "Item 2"]).item_separator(separator);
+/// ```
+///
+/// A separator can also be created from a `&str`
+///
+/// ```rust
+/// use ratatui::widgets::{List, ListSeparator};
+///
+/// let separator: ListSeparator = "-".into();
+/// ```
+///
+/// [`List`]: crate::list::List
+/// [`List::item_separator`]: crate::list::List::item_separator
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct ListSeparator<'a> {
+    pub(crate) symbol: &'a str,
+    pub(crate) style: Style,
+}
+
+impl<'a> ListSeparator<'a> {
+    /// Creates a new [`ListSeparator`] repeating the given symbol
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListSeparator;
+    ///
+    /// let separator = ListSeparator::new("─");
+    /// ```
+    pub const fn new(symbol: &'a str) -> Self {
+        Self {
+            symbol,
+            style: Style::new(),
+        }
+    }
+
+    /// Sets the style of the separator
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This [`Style`] is combined with the base style of the [`List`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::ListSeparator;
+    ///
+    /// let separator = ListSeparator::new("─").style(Style::new().dark_gray());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    /// [`List`]: crate::list::List
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Renders the separator on the first line of `area`
+    pub(crate) fn render(&self, area: Rect, buf: &mut Buffer) {
+        let area = Rect { height: 1, ..area }.intersection(buf.area);
+        buf.set_style(area, self.style);
+        let symbol_width = self.symbol.width();
+        if symbol_width == 0 {
+            return;
+        }
+        let count = usize::from(area.width).div_ceil(symbol_width);
+        let line = self.symbol.repeat(count);
+        buf.set_stringn(area.x, area.y, line, usize::from(area.width), self.style);
+    }
+}
+
+impl<'a> From<&'a str> for ListSeparator<'a> {
+    fn from(symbol: &'a str) -> Self {
+        Self::new(symbol)
+    }
+}
+
+impl Styled for ListSeparator<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Stylize};
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let separator = ListSeparator::new("─");
+        assert_eq!(separator.symbol, "─");
+        assert_eq!(separator.style, Style::new());
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(ListSeparator::from("-"), ListSeparator::new("-"));
+    }
+
+    #[test]
+    fn style() {
+        let separator = ListSeparator::new("─").style(Style::new().red());
+        assert_eq!(separator.style, Style::new().red());
+    }
+
+    #[test]
+    fn can_be_stylized() {
+        assert_eq!(
+            ListSeparator::new("─").red().style,
+            Style::new().fg(Color::Red)
+        );
+    }
+
+    #[test]
+    fn render_repeats_symbol() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        ListSeparator::new("-=").render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["-=-=-", "     "]));
+    }
+}
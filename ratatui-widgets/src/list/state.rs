@@ -1,3 +1,10 @@
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+use ratatui_core::layout::{Position, Rect};
+
+use crate::table::ScrollBehavior;
+
 /// State of the [`List`] widget
 ///
 /// This state can be used to scroll through items and select one. When the list is rendered as a
@@ -5,12 +12,15 @@
 /// that the selected item is visible. This will modify the [`ListState`] object passed to the
 /// `Frame::render_stateful_widget` method.
 ///
-/// The state consists of two fields:
+/// The state consists of the following fields:
 /// - [`offset`]: the index of the first item to be displayed
 /// - [`selected`]: the index of the selected item, which can be `None` if no item is selected
+/// - [`selected_set`]: the indexes of the items selected for bulk operations, independently of the
+///   selected item which acts as a cursor (see [`ListState::toggle`])
 ///
 /// [`offset`]: ListState::offset()
 /// [`selected`]: ListState::selected()
+/// [`selected_set`]: ListState::selected_set()
 ///
 /// See the list in the [Examples] directory for a more in depth example of the various
 /// configuration options and for how to handle state.
@@ -45,6 +55,26 @@
 pub struct ListState {
     pub(crate) offset: usize,
     pub(crate) selected: Option<usize>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "BTreeSet::is_empty")
+    )]
+    pub(crate) selected_set: BTreeSet<usize>,
+    /// The original and tentative positions of the grabbed item
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) grabbed: Option<(usize, usize)>,
+    /// The item to scroll to and where to display it, until the selection changes
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) scroll_target: Option<(usize, ScrollBehavior)>,
+    /// Whether the list scrolls to show the items appended while the last item is displayed
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) follow: bool,
+    /// The number of items as of the last render, when following the last item
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) followed_len: usize,
+    /// The index and area of each displayed item, as of the last render
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) item_areas: Vec<(usize, Rect)>,
 }
 
 impl ListState {
@@ -144,7 +174,8 @@ impl ListState {
 
     /// Sets the index of the selected item
     ///
-    /// Set to `None` if no item is selected. This will also reset the offset to `0`.
+    /// Set to `None` if no item is selected. This will also reset the offset to `0`, and cancel a
+    /// pending [`scroll_to`](Self::scroll_to).
     ///
     /// # Examples
     ///
@@ -156,11 +187,90 @@ impl ListState {
     /// ```
     pub const fn select(&mut self, index: Option<usize>) {
         self.selected = index;
+        self.scroll_target = None;
         if index.is_none() {
             self.offset = 0;
         }
     }
 
+    /// Indexes of the items in the selection set, in ascending order
+    ///
+    /// The selection set is independent of the selected item, which acts as a cursor that can be
+    /// moved without changing the set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.toggle(3);
+    /// state.toggle(1);
+    /// assert!(state.selected_set().iter().eq(&[1, 3]));
+    /// ```
+    pub const fn selected_set(&self) -> &BTreeSet<usize> {
+        &self.selected_set
+    }
+
+    /// Adds an item to the selection set, or removes it if it is already in the set
+    ///
+    /// The selected item is not changed. The items of the set beyond the end of the list are
+    /// removed from the set when the list is rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.toggle(2);
+    /// assert!(state.selected_set().contains(&2));
+    /// state.toggle(2);
+    /// assert!(state.selected_set().is_empty());
+    /// ```
+    pub fn toggle(&mut self, index: usize) {
+        if !self.selected_set.remove(&index) {
+            self.selected_set.insert(index);
+        }
+    }
+
+    /// Adds the selected item to the selection set, or removes it if it is already in the set
+    ///
+    /// This does nothing if no item is selected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default().with_selected(Some(1));
+    /// state.toggle_selected();
+    /// state.select_next();
+    /// state.toggle_selected();
+    /// assert!(state.selected_set().iter().eq(&[1, 2]));
+    /// ```
+    pub fn toggle_selected(&mut self) {
+        if let Some(selected) = self.selected {
+            self.toggle(selected);
+        }
+    }
+
+    /// Removes all the items from the selection set
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.toggle(2);
+    /// state.clear_set();
+    /// assert!(state.selected_set().is_empty());
+    /// ```
+    pub fn clear_set(&mut self) {
+        self.selected_set.clear();
+    }
+
     /// Selects the next item or the first one if no item is selected
     ///
     /// Note: until the list is rendered, the number of items is not known, so the index is set to
@@ -268,6 +378,280 @@ impl ListState {
         let selected = self.selected.unwrap_or_default();
         self.select(Some(selected.saturating_sub(amount as usize)));
     }
+
+    /// Selects the next item, wrapping around to the first one after the last item
+    ///
+    /// The first item is selected if no item is selected. This does nothing when there are no
+    /// items. Unlike [`ListState::select_next`], this needs the number of items (`len`) to
+    /// know where to wrap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default().with_selected(Some(2));
+    /// state.select_next_wrapping(3);
+    /// assert_eq!(state.selected(), Some(0));
+    /// ```
+    pub fn select_next_wrapping(&mut self, len: usize) {
+        if len == 0 {
+            return;
+        }
+        let next = self
+            .selected
+            .map_or(0, |i| if i >= len - 1 { 0 } else { i + 1 });
+        self.select(Some(next));
+    }
+
+    /// Selects the previous item, wrapping around to the last one before the first item
+    ///
+    /// The last item is selected if no item is selected. This does nothing when there are no
+    /// items. Unlike [`ListState::select_previous`], this needs the number of items (`len`)
+    /// to know where to wrap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default().with_selected(Some(0));
+    /// state.select_previous_wrapping(3);
+    /// assert_eq!(state.selected(), Some(2));
+    /// ```
+    pub fn select_previous_wrapping(&mut self, len: usize) {
+        if len == 0 {
+            return;
+        }
+        let previous = self.selected.map_or(len - 1, |i| match i.min(len - 1) {
+            0 => len - 1,
+            i => i - 1,
+        });
+        self.select(Some(previous));
+    }
+
+    /// Scrolls down by a specified `amount`, wrapping around to the first item after the last
+    /// item
+    ///
+    /// This does nothing when there are no items. Unlike [`ListState::scroll_down_by`], this
+    /// needs the number of items (`len`) to know where to wrap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default().with_selected(Some(8));
+    /// state.scroll_down_by_wrapping(4, 10);
+    /// assert_eq!(state.selected(), Some(2));
+    /// ```
+    pub fn scroll_down_by_wrapping(&mut self, amount: u16, len: usize) {
+        if len == 0 {
+            return;
+        }
+        let selected = self.selected.unwrap_or_default().min(len - 1);
+        self.select(Some((selected + usize::from(amount) % len) % len));
+    }
+
+    /// Scrolls up by a specified `amount`, wrapping around to the last item before the first
+    /// item
+    ///
+    /// This does nothing when there are no items. Unlike [`ListState::scroll_up_by`], this needs
+    /// the number of items (`len`) to know where to wrap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default().with_selected(Some(1));
+    /// state.scroll_up_by_wrapping(4, 10);
+    /// assert_eq!(state.selected(), Some(7));
+    /// ```
+    pub fn scroll_up_by_wrapping(&mut self, amount: u16, len: usize) {
+        if len == 0 {
+            return;
+        }
+        let selected = self.selected.unwrap_or_default().min(len - 1);
+        self.select(Some((selected + len - usize::from(amount) % len) % len));
+    }
+
+    /// Grabs the item at the given index to move it to another position
+    ///
+    /// The grabbed item is selected and moved with [`ListState::move_grabbed_up`] and
+    /// [`ListState::move_grabbed_down`]. The list displays it at its tentative position with the
+    /// [`List::grabbed_style`], and scrolls to keep it visible, but the order of the items only
+    /// changes when the caller moves the item after [`ListState::drop_grabbed`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut items = vec!["Intro", "Verse", "Chorus", "Outro"];
+    /// let mut state = ListState::default();
+    /// state.grab(0);
+    /// state.move_grabbed_down();
+    /// state.move_grabbed_down();
+    /// if let Some((from, to)) = state.drop_grabbed() {
+    ///     let item = items.remove(from);
+    ///     items.insert(to, item);
+    /// }
+    /// assert_eq!(items, ["Verse", "Chorus", "Intro", "Outro"]);
+    /// assert_eq!(state.selected(), Some(2));
+    /// ```
+    ///
+    /// [`List::grabbed_style`]: crate::list::List::grabbed_style
+    pub const fn grab(&mut self, index: usize) {
+        self.grabbed = Some((index, index));
+        self.select(Some(index));
+    }
+
+    /// Returns the original and the tentative position of the grabbed item, if any
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.grab(2);
+    /// state.move_grabbed_up();
+    /// assert_eq!(state.grabbed(), Some((2, 1)));
+    /// ```
+    pub const fn grabbed(&self) -> Option<(usize, usize)> {
+        self.grabbed
+    }
+
+    /// Moves the grabbed item one position up, towards the first item
+    ///
+    /// This does nothing if no item is grabbed.
+    pub const fn move_grabbed_up(&mut self) {
+        if let Some((from, to)) = self.grabbed {
+            self.grab_at(from, to.saturating_sub(1));
+        }
+    }
+
+    /// Moves the grabbed item one position down, towards the last item
+    ///
+    /// This does nothing if no item is grabbed. Until the list is rendered, the number of items is
+    /// not known, so the position is corrected to the last item when the list is rendered.
+    pub const fn move_grabbed_down(&mut self) {
+        if let Some((from, to)) = self.grabbed {
+            self.grab_at(from, to.saturating_add(1));
+        }
+    }
+
+    /// Drops the grabbed item, returning its original and its new position
+    ///
+    /// The caller is responsible for moving the item in the list, by removing it from the original
+    /// position and inserting it at the new one. The new position stays selected. Returns `None`
+    /// if no item is grabbed.
+    pub const fn drop_grabbed(&mut self) -> Option<(usize, usize)> {
+        self.grabbed.take()
+    }
+
+    /// Sets the tentative position of the grabbed item and selects it
+    const fn grab_at(&mut self, from: usize, to: usize) {
+        self.grabbed = Some((from, to));
+        self.select(Some(to));
+    }
+
+    /// Scrolls the list to the item at the given index without changing the selection
+    ///
+    /// The offset is adjusted at the next render so that the item is displayed as described by the
+    /// [`ScrollBehavior`], accounting for the height of each item. An index past the end of the
+    /// list scrolls to the last item. The list keeps this position, instead of scrolling the
+    /// selected item into view, until the selection changes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::widgets::{List, ListState, ScrollBehavior, StatefulWidget};
+    ///
+    /// let list = List::new((0..20).map(|i| format!("Item {i}")));
+    /// let area = Rect::new(0, 0, 10, 5);
+    /// let mut state = ListState::default().with_selected(Some(0));
+    /// state.scroll_to(10, ScrollBehavior::Center);
+    /// StatefulWidget::render(list, area, &mut Buffer::empty(area), &mut state);
+    /// assert_eq!(state.offset(), 8);
+    /// assert_eq!(state.selected(), Some(0));
+    /// ```
+    pub const fn scroll_to(&mut self, index: usize, behavior: ScrollBehavior) {
+        self.scroll_target = Some((index, behavior));
+    }
+
+    /// Sets whether the list follows the items appended at its end, like the tail of a log
+    ///
+    /// While following, the list is scrolled to its last item when it is rendered, if the previous
+    /// last item was displayed by the previous render. Appending items then keeps the end of the
+    /// list in view, while the view stays where it is once it is scrolled up from the end. The
+    /// selected item, if any, is still scrolled into view.
+    ///
+    /// Enabling this scrolls the list to its last item on the next render.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::widgets::{List, ListState, StatefulWidget};
+    ///
+    /// let mut messages = vec!["Hello", "Hi!", "How are you?"];
+    /// let area = Rect::new(0, 0, 12, 3);
+    /// let mut buf = Buffer::empty(area);
+    /// let mut state = ListState::default();
+    /// state.follow(true);
+    /// StatefulWidget::render(List::new(messages.clone()), area, &mut buf, &mut state);
+    ///
+    /// messages.push("Fine");
+    /// StatefulWidget::render(List::new(messages), area, &mut buf, &mut state);
+    /// assert_eq!(state.offset(), 1);
+    /// ```
+    pub const fn follow(&mut self, follow: bool) {
+        self.follow = follow;
+        self.followed_len = 0;
+    }
+
+    /// Returns whether the list follows the items appended at its end
+    ///
+    /// See [`ListState::follow`].
+    pub const fn is_following(&self) -> bool {
+        self.follow
+    }
+
+    /// Index of the item displayed at the given position, as of the last render
+    ///
+    /// This can be used to hit-test the position of a mouse click. The area of an item covers all
+    /// of its lines, including the highlight symbol column, but not the borders of the block, the
+    /// separators between the items, or the area below the last item. Returns `None` if no item is
+    /// displayed at the position.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::widgets::{Block, List, ListState, StatefulWidget};
+    ///
+    /// let list = List::new(["Item 0", "Multi-line\nItem 1"]).block(Block::bordered());
+    /// let area = Rect::new(0, 0, 12, 6);
+    /// let mut state = ListState::default();
+    /// StatefulWidget::render(list, area, &mut Buffer::empty(area), &mut state);
+    /// assert_eq!(state.index_at((1, 1)), Some(0));
+    /// assert_eq!(state.index_at((1, 3)), Some(1));
+    /// assert_eq!(state.index_at((0, 1)), None);
+    /// assert_eq!(state.index_at((1, 4)), None);
+    /// ```
+    pub fn index_at<P: Into<Position>>(&self, position: P) -> Option<usize> {
+        let position = position.into();
+        self.item_areas
+            .iter()
+            .find(|(_, area)| area.contains(position))
+            .map(|&(index, _)| index)
+    }
 }
 
 #[cfg(test)]
@@ -354,4 +738,110 @@ mod tests {
         state.scroll_up_by(4);
         assert_eq!(state.selected, Some(0));
     }
+
+    #[rstest::rstest]
+    #[case::next_from_last(Some(4), ListState::select_next_wrapping, Some(0))]
+    #[case::next_from_middle(Some(2), ListState::select_next_wrapping, Some(3))]
+    #[case::next_past_end(Some(9), ListState::select_next_wrapping, Some(0))]
+    #[case::next_none_selected(None, ListState::select_next_wrapping, Some(0))]
+    #[case::previous_from_first(Some(0), ListState::select_previous_wrapping, Some(4))]
+    #[case::previous_from_middle(Some(2), ListState::select_previous_wrapping, Some(1))]
+    #[case::previous_past_end(Some(9), ListState::select_previous_wrapping, Some(3))]
+    #[case::previous_none_selected(None, ListState::select_previous_wrapping, Some(4))]
+    fn select_wrapping(
+        #[case] selected: Option<usize>,
+        #[case] select: fn(&mut ListState, usize),
+        #[case] expected: Option<usize>,
+    ) {
+        let mut state = ListState::default().with_selected(selected);
+        select(&mut state, 5);
+        assert_eq!(state.selected, expected);
+    }
+
+    #[test]
+    fn scroll_by_wrapping() {
+        let mut state = ListState::default().with_selected(Some(3));
+        state.scroll_down_by_wrapping(4, 5);
+        assert_eq!(state.selected, Some(2));
+        state.scroll_down_by_wrapping(12, 5);
+        assert_eq!(state.selected, Some(4));
+        state.scroll_up_by_wrapping(1, 5);
+        assert_eq!(state.selected, Some(3));
+        state.scroll_up_by_wrapping(4, 5);
+        assert_eq!(state.selected, Some(4));
+        state.scroll_up_by_wrapping(11, 5);
+        assert_eq!(state.selected, Some(3));
+    }
+
+    #[test]
+    fn wrapping_without_items_does_nothing() {
+        let mut state = ListState::default().with_offset(2).with_selected(Some(3));
+        state.select_next_wrapping(0);
+        state.select_previous_wrapping(0);
+        state.scroll_down_by_wrapping(1, 0);
+        state.scroll_up_by_wrapping(1, 0);
+        assert_eq!(
+            state,
+            ListState::default().with_offset(2).with_selected(Some(3))
+        );
+    }
+
+    #[test]
+    fn grab_move_and_drop() {
+        let mut state = ListState::default();
+        state.move_grabbed_down();
+        assert_eq!(state.grabbed(), None);
+        assert_eq!(state.selected(), None);
+
+        state.grab(2);
+        assert_eq!(state.grabbed(), Some((2, 2)));
+        state.move_grabbed_down();
+        state.move_grabbed_down();
+        state.move_grabbed_up();
+        assert_eq!(state.grabbed(), Some((2, 3)));
+        assert_eq!(state.selected(), Some(3));
+        for _ in 0..5 {
+            state.move_grabbed_up();
+        }
+        assert_eq!(state.grabbed(), Some((2, 0)));
+
+        assert_eq!(state.drop_grabbed(), Some((2, 0)));
+        assert_eq!(state.drop_grabbed(), None);
+        assert_eq!(state.selected(), Some(0));
+    }
+
+    #[test]
+    fn toggle() {
+        let mut state = ListState::default();
+        state.toggle(3);
+        state.toggle(0);
+        state.toggle(5);
+        state.toggle(3);
+        assert!(state.selected_set.iter().eq(&[0, 5]));
+        state.toggle_selected();
+        assert!(state.selected_set.iter().eq(&[0, 5]));
+        state.select(Some(2));
+        state.toggle_selected();
+        assert!(state.selected_set.iter().eq(&[0, 2, 5]));
+        assert_eq!(state.selected(), Some(2));
+        state.clear_set();
+        assert!(state.selected_set.is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_then_deserialize() -> Result<(), serde_json::Error> {
+        let state = ListState::default().with_offset(2).with_selected(Some(3));
+        let json = serde_json::to_string(&state)?;
+        assert_eq!(json, r#"{"offset":2,"selected":3}"#);
+        assert_eq!(serde_json::from_str::<ListState>(&json)?, state);
+
+        let mut state = state;
+        state.toggle(1);
+        state.toggle(4);
+        let json = serde_json::to_string(&state)?;
+        assert_eq!(json, r#"{"offset":2,"selected":3,"selected_set":[1,4]}"#);
+        assert_eq!(serde_json::from_str::<ListState>(&json)?, state);
+        Ok(())
+    }
 }
@@ -0,0 +1,210 @@
+use alloc::rc::Rc;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use super::ListItem;
+
+/// Items of a [`List`] that are created on demand, see [`List::items_from`].
+///
+/// Two instances are equal when they have the same length and share the same callbacks.
+///
+/// [`List`]: super::List
+/// [`List::items_from`]: super::List::items_from
+#[derive(Clone)]
+pub(crate) struct VirtualItems<'a> {
+    /// The number of items
+    pub(crate) len: usize,
+    /// Creates the item at the given index
+    item: Rc<dyn Fn(usize) -> ListItem<'a> + 'a>,
+    /// Returns the height of the item at the given index
+    height: Option<Rc<dyn Fn(usize) -> u16 + 'a>>,
+}
+
+impl<'a> VirtualItems<'a> {
+    pub(crate) fn new<F>(len: usize, item: F) -> Self
+    where
+        F: Fn(usize) -> ListItem<'a> + 'a,
+    {
+        Self {
+            len,
+            item: Rc::new(item),
+            height: None,
+        }
+    }
+
+    pub(crate) fn set_height<F>(&mut self, height: F)
+    where
+        F: Fn(usize) -> u16 + 'a,
+    {
+        self.height = Some(Rc::new(height));
+    }
+
+    /// Creates the item at `index`.
+    pub(crate) fn item(&self, index: usize) -> ListItem<'a> {
+        (self.item)(index)
+    }
+
+    /// Returns the height of the item at `index`, which is 1 by default.
+    pub(crate) fn height(&self, index: usize) -> u16 {
+        self.height.as_ref().map_or(1, |height| height(index))
+    }
+
+    /// Returns the items transformed by `map`, which is given each item and its index.
+    pub(crate) fn map<F>(&self, map: F) -> Self
+    where
+        F: Fn(usize, ListItem<'a>) -> ListItem<'a> + 'a,
+    {
+        let item = Rc::clone(&self.item);
+        Self {
+            len: self.len,
+            item: Rc::new(move |index| map(index, item(index))),
+            height: self.height.clone(),
+        }
+    }
+
+    /// Returns the items with the item at `from` moved to `to`.
+    pub(crate) fn reordered(&self, from: usize, to: usize) -> Self {
+        let item = Rc::clone(&self.item);
+        let height = self.height.clone().map(|height| {
+            Rc::new(move |index| height(reordered_index(index, from, to)))
+                as Rc<dyn Fn(usize) -> u16 + 'a>
+        });
+        Self {
+            len: self.len,
+            item: Rc::new(move |index| item(reordered_index(index, from, to))),
+            height,
+        }
+    }
+
+    /// Returns the items at the given indices, in their order.
+    pub(crate) fn filtered(&self, indices: &[usize]) -> Self {
+        let indices: Rc<[usize]> = Rc::from(indices);
+        let item = Rc::clone(&self.item);
+        let height = self.height.clone().map(|height| {
+            let indices = Rc::clone(&indices);
+            Rc::new(move |index: usize| height(indices[index])) as Rc<dyn Fn(usize) -> u16 + 'a>
+        });
+        Self {
+            len: indices.len(),
+            item: Rc::new(move |index| item(indices[index])),
+            height,
+        }
+    }
+}
+
+/// Returns the original index of the item displayed at `index` when the item at `from` is moved
+/// to `to`.
+pub(super) const fn reordered_index(index: usize, from: usize, to: usize) -> usize {
+    if index == to {
+        from
+    } else if from < to && from <= index && index < to {
+        index + 1
+    } else if to < from && to < index && index <= from {
+        index - 1
+    } else {
+        index
+    }
+}
+
+impl fmt::Debug for VirtualItems<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VirtualItems")
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for VirtualItems<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        let same_height = match (&self.height, &other.height) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+        self.len == other.len && Rc::ptr_eq(&self.item, &other.item) && same_height
+    }
+}
+
+impl Eq for VirtualItems<'_> {}
+
+impl Hash for VirtualItems<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        Rc::as_ptr(&self.item).cast::<()>().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use ratatui_core::style::{Color, Style};
+
+    use super::*;
+
+    fn items(len: usize) -> VirtualItems<'static> {
+        VirtualItems::new(len, |i| ListItem::new(i.to_string()))
+    }
+
+    #[test]
+    fn item() {
+        assert_eq!(items(10).item(3), ListItem::new("3"));
+    }
+
+    #[test]
+    fn height() {
+        let mut items = items(10);
+        assert_eq!(items.height(3), 1);
+        items.set_height(|i| if i % 2 == 0 { 2 } else { 1 });
+        assert_eq!(items.height(3), 1);
+        assert_eq!(items.height(4), 2);
+    }
+
+    #[test]
+    fn map() {
+        let mut items = items(10);
+        items.set_height(|i| u16::try_from(i).unwrap());
+        let mapped = items
+            .map(|i, item| item.style(Style::new().fg(Color::Indexed(u8::try_from(i).unwrap()))));
+        assert_eq!(
+            mapped.item(3),
+            ListItem::new("3").style(Style::new().fg(Color::Indexed(3)))
+        );
+        assert_eq!(mapped.height(3), 3);
+    }
+
+    #[test]
+    fn reordered() {
+        let mut items = items(6);
+        items.set_height(|i| u16::try_from(i).unwrap());
+        let moved_down = items.reordered(1, 4);
+        let order = (0..6).map(|i| moved_down.height(i)).collect::<Vec<_>>();
+        assert_eq!(order, [0, 2, 3, 4, 1, 5]);
+        assert_eq!(moved_down.item(4), ListItem::new("1"));
+        let moved_up = items.reordered(4, 1);
+        let order = (0..6).map(|i| moved_up.height(i)).collect::<Vec<_>>();
+        assert_eq!(order, [0, 4, 1, 2, 3, 5]);
+    }
+
+    #[test]
+    fn filtered() {
+        let mut items = items(6);
+        items.set_height(|i| u16::try_from(i).unwrap());
+        let filtered = items.filtered(&[1, 3, 4]);
+        assert_eq!(filtered.len, 3);
+        assert_eq!(filtered.item(1), ListItem::new("3"));
+        let heights = (0..3).map(|i| filtered.height(i)).collect::<Vec<_>>();
+        assert_eq!(heights, [1, 3, 4]);
+    }
+
+    #[test]
+    fn eq() {
+        let a = items(10);
+        assert_eq!(a, a.clone());
+        assert_ne!(a, items(10));
+        let mut b = a.clone();
+        b.set_height(|_| 1);
+        assert_ne!(a, b);
+    }
+}
@@ -1,15 +1,26 @@
 //! The [`Paragraph`] widget and related types allows displaying a block of text with optional
 //! wrapping, alignment, and block styling.
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter;
+use core::ops::Range;
+
 use ratatui_core::buffer::Buffer;
-use ratatui_core::layout::{Alignment, Position, Rect};
+use ratatui_core::layout::{Alignment, Position, Rect, VerticalAlignment};
 use ratatui_core::style::{Style, Styled};
-use ratatui_core::text::{Line, StyledGrapheme, Text};
-use ratatui_core::widgets::Widget;
+use ratatui_core::text::{Line, Span, StyledGrapheme, Text};
+use ratatui_core::widgets::{StatefulWidget, Widget};
+use strum::{Display, EnumString};
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
+pub use self::state::ParagraphState;
 use crate::block::{Block, BlockExt};
 use crate::reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine};
 
+mod state;
+
 /// A widget to display some text.
 ///
 /// It is used to display a block of text. The text can be styled and aligned. It can also be
@@ -20,13 +31,29 @@ use crate::reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine};
 ///
 /// The text can be wrapped to the next line if it is too long to fit in the given area. The
 /// wrapping can be configured with the [`wrap`] method. For more complex wrapping, consider using
-/// the [Textwrap crate].
+/// the [Textwrap crate]. When the text is not wrapped, the lines that don't fit can end with an
+/// ellipsis with the [`overflow`] method.
 ///
 /// The text can be aligned to the left, right, or center. The alignment can be configured with the
 /// [`alignment`] method or with the [`left_aligned`], [`right_aligned`], and [`centered`] methods.
+/// Wrapped text can also be justified with the [`justified`] method. The alignment of a [`Line`]
+/// overrides that of the paragraph for all the rows the line is wrapped into, and the alignment of
+/// the [`Text`] is the default alignment of the paragraph. The text can be moved to the center or
+/// the bottom of the area with the [`vertical_alignment`] method.
 ///
 /// The text can be scrolled to show a specific part of the text. The scroll offset can be set with
-/// the [`scroll`] method.
+/// the [`scroll`] method, or held by a [`ParagraphState`] when the paragraph is rendered as a
+/// [`StatefulWidget`], which can also keep the last line in view as the text grows.
+///
+/// The text can be hidden behind a mask character, e.g. for password fields, with the [`mask`]
+/// method.
+///
+/// Tab characters can be expanded to the next tab stop with the [`tab_width`] method.
+///
+/// A range of the text can be highlighted with the [`selection`] and [`selection_style`]
+/// methods, and [`text_position`] returns the position in the text of a rendered cell, e.g. to
+/// select the text under the mouse. Conversely, [`cursor_cell`] returns the cell where a cursor in
+/// the text is displayed.
 ///
 /// The text can be surrounded by a [`Block`] with a title and borders. The block can be configured
 /// with the [`block`] method.
@@ -40,11 +67,20 @@ use crate::reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine};
 ///
 /// [Textwrap crate]: https://crates.io/crates/textwrap
 /// [`wrap`]: Self::wrap
+/// [`overflow`]: Self::overflow
 /// [`alignment`]: Self::alignment
 /// [`left_aligned`]: Self::left_aligned
 /// [`right_aligned`]: Self::right_aligned
 /// [`centered`]: Self::centered
+/// [`justified`]: Self::justified
+/// [`vertical_alignment`]: Self::vertical_alignment
 /// [`scroll`]: Self::scroll
+/// [`mask`]: Self::mask
+/// [`tab_width`]: Self::tab_width
+/// [`selection`]: Self::selection
+/// [`selection_style`]: Self::selection_style
+/// [`text_position`]: Self::text_position
+/// [`cursor_cell`]: Self::cursor_cell
 /// [`block`]: Self::block
 /// [`style`]: Self::style
 ///
@@ -87,6 +123,24 @@ pub struct Paragraph<'a> {
     scroll: Position,
     /// Alignment of the text
     alignment: Alignment,
+    /// Whether the wrapped lines are justified
+    justify: bool,
+    /// Vertical alignment of the text
+    vertical_alignment: VerticalAlignment,
+    /// Character displayed in place of each grapheme of the text
+    mask: Option<char>,
+    /// Distance between tab stops
+    tab_width: Option<u16>,
+    /// Where the lines are broken when the text is wrapped
+    wrap_mode: WrapMode,
+    /// How the lines that don't fit are displayed when the text is not wrapped
+    overflow: Overflow,
+    /// The string that replaces the end of the lines that don't fit
+    ellipsis: &'a str,
+    /// The start and the end of the selected text, as (line, grapheme) positions
+    selection: Option<((usize, usize), (usize, usize))>,
+    /// Style of the selected text
+    selection_style: Style,
 }
 
 /// Describes how to wrap text across lines.
@@ -125,14 +179,122 @@ pub struct Wrap {
     pub trim: bool,
 }
 
+/// Describes where the lines of a wrapped [`Paragraph`] are broken.
+///
+/// See [`Paragraph::wrap_mode`]. The graphemes are never split, so a double-width character that
+/// doesn't fit at the end of a line is moved to the next one.
+///
+/// ## Examples
+///
+/// ```
+/// use ratatui::widgets::{Paragraph, Wrap, WrapMode};
+///
+/// let text = "see https://ratatui.rs/examples/widgets/paragraph";
+///
+/// // In a 20 columns wide area:
+/// Paragraph::new(text).wrap(Wrap { trim: true });
+/// // see
+/// // https://ratatui.rs/e
+/// // xamples/widgets/para
+/// // graph
+///
+/// Paragraph::new(text)
+///     .wrap(Wrap { trim: true })
+///     .wrap_mode(WrapMode::WordBreak);
+/// // see https://ratatui.
+/// // rs/examples/widgets/
+/// // paragraph
+///
+/// Paragraph::new("Hello world")
+///     .wrap(Wrap { trim: true })
+///     .wrap_mode(WrapMode::BreakAnywhere);
+/// // In a 8 columns wide area:
+/// // Hello wo
+/// // rld
+///
+/// Paragraph::new("Hyphenation works")
+///     .wrap(Wrap { trim: true })
+///     .wrap_mode(WrapMode::Hyphenate);
+/// // In a 8 columns wide area:
+/// // Hyphena-
+/// // tion wo-
+/// // rks
+/// ```
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WrapMode {
+    /// Break the lines between words (default)
+    ///
+    /// A word that doesn't fit on the rest of a line is moved to the next line. A word longer than
+    /// a whole line is also moved to the next line, where it is broken at the width of the line.
+    #[default]
+    Word,
+    /// Break the lines between words, and break the words that are too long for a line
+    ///
+    /// Like [`WrapMode::Word`], but a word longer than a whole line starts on the current line,
+    /// right after the text before it, and is broken at the end of each line. This avoids leaving
+    /// the rest of a line empty before a long URL or token.
+    WordBreak,
+    /// Break the lines at the width of the area, between any graphemes
+    ///
+    /// Every line is filled with as many graphemes as fit, whether they are in a word or not.
+    BreakAnywhere,
+    /// Break the lines between words, and hyphenate the words that don't fit on a line
+    ///
+    /// Like [`WrapMode::BreakAnywhere`], but a word broken at the end of a line is followed by a
+    /// hyphen, which takes one column of the line and has the style of the text before it. A word
+    /// with a hyphen on the line is broken after that hyphen instead, and a word is moved to the
+    /// next line when less than two of its graphemes would fit before the hyphen. This gives
+    /// narrow areas a less ragged right edge than [`WrapMode::Word`].
+    Hyphenate,
+}
+
+/// Describes how the lines of a [`Paragraph`] that don't fit in its width are displayed.
+///
+/// See [`Paragraph::overflow`]. This only applies to text that is not wrapped.
+///
+/// ## Examples
+///
+/// ```
+/// use ratatui::widgets::{Overflow, Paragraph};
+///
+/// Paragraph::new("Hello world").overflow(Overflow::Clip);
+/// // In a 8 columns wide area:
+/// // Hello wo
+///
+/// Paragraph::new("Hello world").overflow(Overflow::Ellipsis);
+/// // In a 8 columns wide area:
+/// // Hello w…
+/// ```
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Overflow {
+    /// Cut the lines at the edge of the area (default)
+    #[default]
+    Clip,
+    /// Replace the end of the lines that are cut with an ellipsis
+    ///
+    /// The ellipsis is [`Paragraph::DEFAULT_ELLIPSIS`] unless it is set with
+    /// [`Paragraph::ellipsis`]. When the text is scrolled horizontally, the start of the lines
+    /// that are cut on the left is replaced as well.
+    Ellipsis,
+}
+
 type Horizontal = u16;
 type Vertical = u16;
 
 impl<'a> Paragraph<'a> {
+    /// The character usually used to mask the text of password fields, see [`Paragraph::mask`].
+    pub const DEFAULT_MASK: char = '•';
+
+    /// The string displayed by default where a line is truncated, see [`Paragraph::overflow`].
+    pub const DEFAULT_ELLIPSIS: &'static str = "…";
+
     /// Creates a new [`Paragraph`] widget with the given text.
     ///
     /// The `text` parameter can be a [`Text`] or any type that can be converted into a [`Text`]. By
-    /// default, the text is styled with [`Style::default()`], not wrapped, and aligned to the left.
+    /// default, the text is styled with [`Style::default()`], not wrapped, and aligned with the
+    /// alignment of the [`Text`], or to the left if it has none.
     ///
     /// # Examples
     ///
@@ -151,13 +313,23 @@ impl<'a> Paragraph<'a> {
     where
         T: Into<Text<'a>>,
     {
+        let text = text.into();
         Self {
             block: None,
             style: Style::default(),
             wrap: None,
-            text: text.into(),
+            alignment: text.alignment.unwrap_or(Alignment::Left),
+            text,
             scroll: Position::ORIGIN,
-            alignment: Alignment::Left,
+            justify: false,
+            vertical_alignment: VerticalAlignment::Top,
+            mask: None,
+            tab_width: None,
+            wrap_mode: WrapMode::Word,
+            overflow: Overflow::Clip,
+            ellipsis: Self::DEFAULT_ELLIPSIS,
+            selection: None,
+            selection_style: Style::new(),
         }
     }
 
@@ -217,11 +389,78 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Sets where the lines are broken when the text is wrapped.
+    ///
+    /// This only has an effect when the text is wrapped with [`Paragraph::wrap`]. Defaults to
+    /// [`WrapMode::Word`]. See [`WrapMode`] for more information on the different options.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Paragraph, Wrap, WrapMode};
+    ///
+    /// let paragraph = Paragraph::new("token: 8f14e45fceea167a5a36dedd4bea2543")
+    ///     .wrap(Wrap { trim: true })
+    ///     .wrap_mode(WrapMode::WordBreak);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn wrap_mode(mut self, wrap_mode: WrapMode) -> Self {
+        self.wrap_mode = wrap_mode;
+        self
+    }
+
+    /// Sets how the lines that don't fit in the width of the paragraph are displayed.
+    ///
+    /// This only has an effect when the text is not wrapped. Defaults to [`Overflow::Clip`], which
+    /// cuts the lines at the edge of the paragraph. With [`Overflow::Ellipsis`], the last columns
+    /// of a line that is cut are replaced by an ellipsis (see [`Paragraph::ellipsis`]), so that it
+    /// is visible that the line goes on. A line that exactly fits is not changed. A wide character
+    /// that would be split by the ellipsis is removed instead, and the ellipsis has the style of
+    /// the text it replaces. When the text is scrolled horizontally with [`Paragraph::scroll`], the
+    /// lines that are cut on the left start with an ellipsis too.
+    ///
+    /// To truncate a [`Line`] that is rendered directly, use [`Line::truncate_with_ellipsis`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Overflow, Paragraph};
+    ///
+    /// let paragraph = Paragraph::new("A long line of text").overflow(Overflow::Ellipsis);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Sets the string that replaces the cut part of the lines with [`Overflow::Ellipsis`].
+    ///
+    /// Defaults to [`Paragraph::DEFAULT_ELLIPSIS`] (`"…"`). The lines are cut without an ellipsis
+    /// when it is wider than the paragraph.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Overflow, Paragraph};
+    ///
+    /// let paragraph = Paragraph::new("A long line of text")
+    ///     .overflow(Overflow::Ellipsis)
+    ///     .ellipsis("...");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn ellipsis(mut self, ellipsis: &'a str) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
     /// Set the scroll offset for the given paragraph
     ///
     /// The scroll offset is a tuple of (y, x) offset. The y offset is the number of lines to
-    /// scroll, and the x offset is the number of characters to scroll. The scroll offset is applied
-    /// after the text is wrapped and aligned.
+    /// scroll, and the x offset is the number of columns to scroll. The scroll offset is applied
+    /// after the text is wrapped and aligned. When the x offset splits a wide character (e.g. a CJK
+    /// character or an emoji), its remaining column is displayed as a space so that the following
+    /// characters keep their columns.
     ///
     /// Note: the order of the tuple is (y, x) instead of (x, y), which is different from general
     /// convention across the crate.
@@ -240,7 +479,12 @@ impl<'a> Paragraph<'a> {
     /// Set the text alignment for the given paragraph
     ///
     /// The alignment is a variant of the [`Alignment`] enum which can be one of Left, Right, or
-    /// Center. If no alignment is specified, the text in a paragraph will be left-aligned.
+    /// Center. If no alignment is specified, the text in a paragraph will be aligned with the
+    /// alignment of its [`Text`], or left-aligned if it has none. This replaces the justification
+    /// set with [`Paragraph::justified`].
+    ///
+    /// The lines that have their own alignment (see [`Line::alignment`]) keep it. When the text is
+    /// wrapped, every row of a line is aligned with the alignment of the line.
     ///
     /// # Example
     ///
@@ -253,6 +497,7 @@ impl<'a> Paragraph<'a> {
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn alignment(mut self, alignment: Alignment) -> Self {
         self.alignment = alignment;
+        self.justify = false;
         self
     }
 
@@ -304,6 +549,279 @@ impl<'a> Paragraph<'a> {
         self.alignment(Alignment::Right)
     }
 
+    /// Sets the vertical alignment of the text in the given paragraph.
+    ///
+    /// By default, the text starts at the top of the paragraph. When the text takes fewer rows than
+    /// the height of the paragraph, [`VerticalAlignment::Center`] and [`VerticalAlignment::Bottom`]
+    /// move it down to the center or the bottom of the area inside the block. The rows are counted
+    /// after wrapping, and the rows scrolled past with [`Paragraph::scroll`] are not counted, so
+    /// the rows that are displayed are aligned. Text that is taller than the paragraph is rendered
+    /// from the top, as without alignment.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::layout::VerticalAlignment;
+    /// use ratatui::widgets::{Block, Paragraph};
+    ///
+    /// let paragraph = Paragraph::new("Are you sure?")
+    ///     .block(Block::bordered().title("Confirm"))
+    ///     .centered()
+    ///     .vertical_alignment(VerticalAlignment::Center);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn vertical_alignment(mut self, vertical_alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = vertical_alignment;
+        self
+    }
+
+    /// Justifies the text in the given paragraph.
+    ///
+    /// When the text is wrapped with [`Paragraph::wrap`], the spaces between the words of each
+    /// wrapped row are widened so that the row spans the full width of the paragraph. The extra
+    /// columns are spread evenly between the words, starting from the left, and take the style of
+    /// the space they widen. The last row of each line, which ends the line rather than being
+    /// broken, is left-aligned, as are the rows without spaces between words and the text that
+    /// isn't wrapped. Lines with their own center or right alignment keep it.
+    ///
+    /// This replaces the alignment set with [`Paragraph::alignment`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Paragraph, Wrap};
+    ///
+    /// let paragraph = Paragraph::new("Hello World, how are you?")
+    ///     .wrap(Wrap { trim: true })
+    ///     .justified();
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn justified(mut self) -> Self {
+        self.alignment = Alignment::Left;
+        self.justify = true;
+        self
+    }
+
+    /// Displays every grapheme of the text as the `mask` character.
+    ///
+    /// This is useful for password fields. The text itself is not modified, only the way it is
+    /// displayed. Each grapheme (e.g. a character, or an emoji made of several characters) is
+    /// replaced by a single mask character, regardless of its width, so the position of the cursor
+    /// in a masked field is the number of graphemes before it multiplied by the width of the mask.
+    /// Wrapping, alignment and scrolling apply to the masked text. Whitespace is masked as well.
+    ///
+    /// [`Paragraph::DEFAULT_MASK`] (`'•'`) is the usual mask character.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let paragraph = Paragraph::new("hunter2").mask(Paragraph::DEFAULT_MASK);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn mask(mut self, mask: char) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Expands tab characters to the next tab stop, every `tab_width` columns.
+    ///
+    /// By default, tab characters are not expanded. When a tab width is set, each `\t` is
+    /// replaced by as many spaces as needed to reach the next multiple of `tab_width` columns from
+    /// the start of the line. A width of 0 removes tab characters. Tab stops are computed from the
+    /// start of the lines of the text, before wrapping. The expanded spaces are wrapped like any
+    /// other whitespace, so a tab that would overflow the line moves the next word to a new line.
+    /// The tabs of a [`Text`] or a [`Line`] that is rendered directly can be expanded with
+    /// [`Text::expand_tabs`] and [`Line::expand_tabs`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let paragraph = Paragraph::new("name\tvalue").tab_width(8);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn tab_width(mut self, tab_width: u16) -> Self {
+        self.tab_width = Some(tab_width);
+        self
+    }
+
+    /// Selects the text between two positions, which is displayed with the
+    /// [`Paragraph::selection_style`].
+    ///
+    /// The positions are (line, grapheme) pairs, where `line` is the index of a line of the text
+    /// and `grapheme` the index of a grapheme in that line, counting the graphemes of all its
+    /// spans. The selection starts at the first position and ends before the second one, and the
+    /// positions can be given in any order. The selected graphemes are highlighted wherever they
+    /// are displayed, as the selection follows the text through wrapping, scrolling and masking.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// // selects "World" and the first line break
+    /// let paragraph = Paragraph::new("Hello World\nfoo")
+    ///     .selection((0, 6), (1, 0))
+    ///     .selection_style(Style::new().reversed());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn selection(mut self, start: (usize, usize), end: (usize, usize)) -> Self {
+        self.selection = Some((start, end));
+        self
+    }
+
+    /// Sets the style of the text selected with [`Paragraph::selection`].
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The style is patched over the style of the selected text, and it is empty by default.
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selection_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selection_style = style.into();
+        self
+    }
+
+    /// Returns the position in the text of the grapheme rendered at `position`.
+    ///
+    /// `area` is the area the paragraph is rendered in, including its block. The position is a
+    /// (line, grapheme) pair as used by [`Paragraph::selection`]. The wrapping, the alignment and
+    /// the [`Paragraph::scroll`] offset are taken into account, so this maps the position of the
+    /// mouse to the text, e.g. to select the text between the positions of a mouse drag.
+    ///
+    /// A position to the left or the right of the text of a row maps to the start or the end of
+    /// the text of that row, and `None` is returned outside of the text area of the paragraph or
+    /// below the last line. When the paragraph is rendered with a [`ParagraphState`], set its
+    /// offset with [`Paragraph::scroll`] to map the positions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::layout::{Position, Rect};
+    /// use ratatui::widgets::{Paragraph, Wrap};
+    ///
+    /// let paragraph = Paragraph::new("Hello World").wrap(Wrap { trim: true });
+    /// let area = Rect::new(0, 0, 8, 2);
+    /// // "World" is displayed on the second row
+    /// assert_eq!(
+    ///     paragraph.text_position(area, Position::new(1, 1)),
+    ///     Some((0, 7))
+    /// );
+    /// ```
+    pub fn text_position(&self, area: Rect, position: Position) -> Option<(usize, usize)> {
+        let text_area = self.aligned_text_area(self.block.inner_if_some(area), self.scroll.y);
+        if !text_area.contains(position) {
+            return None;
+        }
+        let mut rows_above = usize::from(position.y - text_area.y) + usize::from(self.scroll.y);
+        let column = position.x - text_area.x;
+        let mut mask = [0; 4];
+        let mask = self
+            .mask
+            .map(|mask_char| &*mask_char.encode_utf8(&mut mask));
+        for (line_index, line) in self.text.iter().enumerate() {
+            let rows = self.line_rows(line, text_area.width, mask);
+            if rows_above >= rows.len() {
+                rows_above -= rows.len();
+                continue;
+            }
+            // the index of the grapheme after those of the previous rows
+            let previous = rows[..rows_above].iter().flat_map(|row| &row.cells);
+            let mut next_index = previous
+                .rev()
+                .find_map(|cell| cell.index)
+                .map_or(0, |index| index + 1);
+            for cell in &rows[rows_above].cells {
+                if cell.columns == 0 {
+                    continue;
+                }
+                if cell.x >= 0 && i32::from(column) < cell.end() {
+                    return Some((line_index, cell.index.unwrap_or(next_index)));
+                }
+                if let Some(index) = cell.index {
+                    next_index = index + 1;
+                }
+            }
+            return Some((line_index, next_index));
+        }
+        None
+    }
+
+    /// Returns the position of the cell where the cursor is displayed when it is at `byte_offset`
+    /// in the text.
+    ///
+    /// `area` is the area the paragraph is rendered in, including its block. The byte offset is
+    /// that of the cursor in the content of the text with its lines joined by newlines, e.g. the
+    /// value of a text input. The cursor is displayed on the grapheme the offset is in, or after
+    /// the last grapheme of its line at the end of the line. The wrapping, the alignment and the
+    /// [`Paragraph::scroll`] offset are taken into account, so the position can be passed to
+    /// `Frame::set_cursor_position` to show the cursor in the paragraph.
+    ///
+    /// When the cursor is after a row that fills the width of the text area, it is displayed at
+    /// the start of the next row. `None` is returned when the offset is past the end of the text
+    /// or when the cursor is scrolled out of the text area.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::layout::{Position, Rect};
+    /// use ratatui::widgets::{Paragraph, Wrap};
+    ///
+    /// let paragraph = Paragraph::new("Hello World").wrap(Wrap { trim: true });
+    /// let area = Rect::new(0, 0, 8, 2);
+    /// // the "r" of "World" is displayed on the second row
+    /// assert_eq!(paragraph.cursor_cell(8, area), Some(Position::new(2, 1)));
+    /// ```
+    pub fn cursor_cell(&self, byte_offset: usize, area: Rect) -> Option<Position> {
+        let text_area = self.aligned_text_area(self.block.inner_if_some(area), self.scroll.y);
+        if text_area.is_empty() {
+            return None;
+        }
+        let (line_index, grapheme_index) = self.grapheme_at(byte_offset)?;
+        let mut mask = [0; 4];
+        let mask = self
+            .mask
+            .map(|mask_char| &*mask_char.encode_utf8(&mut mask));
+        let rows_above: usize = self.text.lines[..line_index]
+            .iter()
+            .map(|line| self.line_rows(line, text_area.width, mask).len())
+            .sum();
+        let rows = self.line_rows(&self.text.lines[line_index], text_area.width, mask);
+        // the cell of the grapheme, or of the next displayed grapheme when it is not displayed
+        // (e.g. a space trimmed at the end of a row)
+        let cell = rows.iter().enumerate().find_map(|(row_index, row)| {
+            row.cells
+                .iter()
+                .find(|cell| cell.columns > 0 && cell.index.is_some_and(|i| i >= grapheme_index))
+                .map(|cell| (row_index, cell.x))
+        });
+        // otherwise the cursor is at the end of the line, after its last displayed grapheme
+        let (mut row_index, mut x) = cell.unwrap_or_else(|| {
+            let row_index = rows.len().saturating_sub(1);
+            let x = rows.last().map_or(0, |row| {
+                let mut cells = row.cells.iter().rev();
+                cells
+                    .find(|cell| cell.columns > 0)
+                    .map_or(row.x, RowCell::end)
+            });
+            (row_index, x)
+        });
+        if self.wrap.is_some() && x >= i32::from(text_area.width) {
+            row_index += 1;
+            x = 0;
+        }
+        let y = (rows_above + row_index).checked_sub(usize::from(self.scroll.y))?;
+        let x = u16::try_from(x).ok().filter(|&x| x < text_area.width)?;
+        let y = u16::try_from(y).ok().filter(|&y| y < text_area.height)?;
+        Some(Position::new(text_area.x + x, text_area.y + y))
+    }
+
     /// Calculates the number of lines needed to fully render.
     ///
     /// Given a max line width, this method calculates the number of lines that a paragraph will
@@ -339,16 +857,25 @@ impl<'a> Paragraph<'a> {
             .map(Block::vertical_space)
             .unwrap_or_default();
 
-        let count = if let Some(Wrap { trim }) = self.wrap {
+        self.text_line_count(width)
+            .saturating_add(top as usize)
+            .saturating_add(bottom as usize)
+    }
+
+    /// Returns the number of lines of the text once wrapped to `width`, without the block.
+    fn text_line_count(&self, width: u16) -> usize {
+        if let Some(Wrap { trim }) = self.wrap {
+            let mut mask = [0; 4];
+            let mask = self
+                .mask
+                .map(|mask_char| &*mask_char.encode_utf8(&mut mask));
             let styled = self.text.iter().map(|line| {
-                let graphemes = line
-                    .spans
-                    .iter()
-                    .flat_map(|span| span.styled_graphemes(self.style));
+                let graphemes = self.displayed_graphemes(&line.spans, self.style, mask);
                 let alignment = line.alignment.unwrap_or(self.alignment);
                 (graphemes, alignment)
             });
             let mut line_composer = WordWrapper::new(styled, width, trim);
+            line_composer.set_mode(self.wrap_mode);
             let mut count = 0;
             while line_composer.next_line().is_some() {
                 count += 1;
@@ -356,11 +883,7 @@ impl<'a> Paragraph<'a> {
             count
         } else {
             self.text.height()
-        };
-
-        count
-            .saturating_add(top as usize)
-            .saturating_add(bottom as usize)
+        }
     }
 
     /// Calculates the shortest line width needed to avoid any word being wrapped or truncated.
@@ -385,7 +908,16 @@ impl<'a> Paragraph<'a> {
         issue = "https://github.com/ratatui/ratatui/issues/293"
     )]
     pub fn line_width(&self) -> usize {
-        let width = self.text.iter().map(Line::width).max().unwrap_or_default();
+        let mut mask = [0; 4];
+        let mask = self
+            .mask
+            .map(|mask_char| &*mask_char.encode_utf8(&mut mask));
+        let line_width = |line: &Line| {
+            self.displayed_graphemes(&line.spans, Style::new(), mask)
+                .map(|grapheme| grapheme.symbol.width())
+                .sum::<usize>()
+        };
+        let width = self.text.iter().map(line_width).max().unwrap_or_default();
         let (left, right) = self
             .block
             .as_ref()
@@ -398,6 +930,34 @@ impl<'a> Paragraph<'a> {
     }
 }
 
+/// An extension trait for [`Text`] that measures the text as it is rendered by a [`Paragraph`].
+pub trait TextExt {
+    /// Returns the number of lines of the text once wrapped to `width` as by a [`Paragraph`].
+    ///
+    /// This runs the same wrapping as a paragraph with the given [`Wrap`] and the default
+    /// [`WrapMode`], so it can be used to size the area of the paragraph before rendering it. The
+    /// height is 0 when `width` is 0, and the count saturates at [`u16::MAX`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::text::Text;
+    /// use ratatui::widgets::{TextExt, Wrap};
+    ///
+    /// let text = Text::from("Hello World");
+    /// assert_eq!(text.height_for_width(20, Wrap { trim: true }), 1);
+    /// assert_eq!(text.height_for_width(8, Wrap { trim: true }), 2);
+    /// ```
+    fn height_for_width(&self, width: u16, wrap: Wrap) -> u16;
+}
+
+impl TextExt for Text<'_> {
+    fn height_for_width(&self, width: u16, wrap: Wrap) -> u16 {
+        let paragraph = Paragraph::new(self.clone()).wrap(wrap);
+        u16::try_from(paragraph.text_line_count(width)).unwrap_or(u16::MAX)
+    }
+}
+
 impl Widget for Paragraph<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         Widget::render(&self, area, buf);
@@ -409,46 +969,412 @@ impl Widget for &Paragraph<'_> {
         buf.set_style(area, self.style);
         self.block.as_ref().render(area, buf);
         let inner = self.block.inner_if_some(area);
-        self.render_paragraph(inner, buf);
+        self.render_paragraph(inner, buf, self.scroll);
+    }
+}
+
+// Only implemented for references, so that calling `render` on a paragraph isn't ambiguous when
+// both widget traits are in scope.
+impl StatefulWidget for &Paragraph<'_> {
+    type State = ParagraphState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() {
+            return;
+        }
+        let line_count = self.text_line_count(inner.width);
+        let offset = state.update_offset(line_count, inner.height);
+        let scroll = Position {
+            y: u16::try_from(offset).unwrap_or(u16::MAX),
+            ..self.scroll
+        };
+        self.render_paragraph(inner, buf, scroll);
     }
 }
 
 impl Paragraph<'_> {
-    fn render_paragraph(&self, text_area: Rect, buf: &mut Buffer) {
+    /// Returns the graphemes of the spans of a line as they are displayed, with the mask applied
+    /// and the tabs expanded.
+    fn displayed_graphemes<'b>(
+        &self,
+        spans: &'b [Span<'_>],
+        base_style: Style,
+        mask: Option<&'b str>,
+    ) -> impl Iterator<Item = StyledGrapheme<'b>> {
+        self.indexed_graphemes(spans, base_style, mask)
+            .map(|(_, grapheme)| grapheme)
+    }
+
+    /// Returns the graphemes of the spans of a line as they are displayed, with the index of the
+    /// grapheme of the spans they are displayed for.
+    fn indexed_graphemes<'b>(
+        &self,
+        spans: &'b [Span<'_>],
+        base_style: Style,
+        mask: Option<&'b str>,
+    ) -> impl Iterator<Item = (usize, StyledGrapheme<'b>)> {
+        let tab_width = self.tab_width;
+        let graphemes = spans
+            .iter()
+            .flat_map(move |span| {
+                let style = base_style.patch(span.style);
+                let hyperlink = span.hyperlink.as_deref();
+                span.content
+                    .graphemes(true)
+                    .map(move |grapheme| StyledGrapheme::new(grapheme, style).hyperlink(hyperlink))
+            })
+            .enumerate()
+            // control characters are not displayed, except for tabs that are expanded
+            .filter(move |(_, grapheme)| {
+                (tab_width.is_some() && grapheme.symbol == "\t")
+                    || !grapheme.symbol.contains(char::is_control)
+            });
+        let mut column = 0;
+        graphemes.flat_map(move |(index, grapheme)| {
+            let grapheme = mask_grapheme(grapheme, mask);
+            match tab_width {
+                Some(tab_width) if grapheme.symbol == "\t" => {
+                    let tab_width = usize::from(tab_width);
+                    let spaces = tab_width.saturating_sub(column % tab_width.max(1));
+                    column += spaces;
+                    let space = StyledGrapheme {
+                        symbol: " ",
+                        ..grapheme
+                    };
+                    iter::repeat_n((index, space), spaces)
+                }
+                _ => {
+                    column += grapheme.symbol.width();
+                    iter::repeat_n((index, grapheme), 1)
+                }
+            }
+        })
+    }
+
+    /// Returns the range of the graphemes of the line at `index` that are selected.
+    fn selected_graphemes(&self, index: usize) -> Range<usize> {
+        let Some((start, end)) = self.selection else {
+            return 0..0;
+        };
+        let (start, end) = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        if index < start.0 || index > end.0 {
+            return 0..0;
+        }
+        let first = if index == start.0 { start.1 } else { 0 };
+        let last = if index == end.0 { end.1 } else { usize::MAX };
+        first..last
+    }
+
+    /// Returns the part of `text_area` where the text is rendered with the vertical alignment,
+    /// when the text is scrolled down by `scroll_y` rows.
+    fn aligned_text_area(&self, text_area: Rect, scroll_y: u16) -> Rect {
+        if self.vertical_alignment == VerticalAlignment::Top || text_area.is_empty() {
+            return text_area;
+        }
+        let rows = self
+            .text_line_count(text_area.width)
+            .saturating_sub(usize::from(scroll_y));
+        let free_rows = usize::from(text_area.height).saturating_sub(rows);
+        let offset = match self.vertical_alignment {
+            VerticalAlignment::Top => 0,
+            VerticalAlignment::Center => free_rows / 2,
+            VerticalAlignment::Bottom => free_rows,
+        };
+        let offset = u16::try_from(offset).unwrap_or_default();
+        Rect {
+            y: text_area.y + offset,
+            height: text_area.height - offset,
+            ..text_area
+        }
+    }
+
+    /// Returns the (line, grapheme) position of the grapheme that contains `byte_offset` in the
+    /// text with its lines joined by newlines.
+    ///
+    /// An offset at the end of a line maps to the grapheme after the last one of the line, and
+    /// `None` is returned for an offset past the end of the text.
+    fn grapheme_at(&self, byte_offset: usize) -> Option<(usize, usize)> {
+        let mut offset = byte_offset;
+        for (line_index, line) in self.text.iter().enumerate() {
+            let mut line_len = 0;
+            let mut count = 0;
+            for span in line {
+                for grapheme in span.content.graphemes(true) {
+                    line_len += grapheme.len();
+                    if line_len > offset {
+                        return Some((line_index, count));
+                    }
+                    count += 1;
+                }
+            }
+            if offset == line_len {
+                return Some((line_index, count));
+            }
+            // skip the line and its newline
+            offset -= line_len + 1;
+        }
+        None
+    }
+
+    /// Returns the rows a line of the text is displayed in when the text area is `width` columns
+    /// wide, with the column of each of their graphemes.
+    ///
+    /// Without wrapping, the line is displayed in a single row that is shifted by the horizontal
+    /// [`Paragraph::scroll`] offset, so its cells can be on either side of the text area.
+    fn line_rows(&self, line: &Line, width: u16, mask: Option<&str>) -> Vec<Row> {
+        let alignment = line.alignment.unwrap_or(self.alignment);
+        let Some(Wrap { trim }) = self.wrap else {
+            let graphemes: Vec<_> = self
+                .indexed_graphemes(&line.spans, Style::new(), mask)
+                .collect();
+            let line_width = graphemes
+                .iter()
+                .map(|(_, grapheme)| grapheme.symbol.width())
+                .sum::<usize>();
+            let line_width = u16::try_from(line_width).unwrap_or(u16::MAX).min(width);
+            let mut x = i32::from(get_line_offset(line_width, width, alignment));
+            if alignment == Alignment::Left {
+                x -= i32::from(self.scroll.x);
+            }
+            let row_x = x;
+            let cells = graphemes
+                .iter()
+                .map(|(index, grapheme)| {
+                    let cell = RowCell {
+                        index: Some(*index),
+                        x,
+                        columns: grapheme.symbol.width() as u16,
+                    };
+                    x = cell.end();
+                    cell
+                })
+                .collect();
+            return vec![Row { x: row_x, cells }];
+        };
+        // the symbols of the line are sliced from a single string, so that the offset of a
+        // wrapped symbol in that string identifies the grapheme it was sliced from
+        let mut symbols = String::new();
+        // the offset of each symbol in `symbols`, and the index of its grapheme
+        let mut starts = Vec::new();
+        for (index, grapheme) in self.indexed_graphemes(&line.spans, Style::new(), mask) {
+            starts.push((symbols.len(), index));
+            symbols.push_str(grapheme.symbol);
+        }
+        let ends = starts.iter().skip(1).map(|&(start, _)| start);
+        let ends = ends.chain(iter::once(symbols.len()));
+        let graphemes = starts.iter().zip(ends).map(|(&(start, _), end)| {
+            #[expect(clippy::string_slice)] // the bounds are those of the pushed symbols
+            StyledGrapheme::new(&symbols[start..end], Style::new())
+        });
+        let grapheme_index = |symbol: &str| {
+            let offset = (symbol.as_ptr() as usize).wrapping_sub(symbols.as_ptr() as usize);
+            (offset < symbols.len()).then(|| {
+                let start = starts.partition_point(|&(start, _)| start <= offset);
+                starts[start - 1].1
+            })
+        };
+        let mut composer = WordWrapper::new(iter::once((graphemes, alignment)), width, trim);
+        composer.set_mode(self.wrap_mode);
+        let mut rows = Vec::new();
+        while let Some(wrapped) = composer.next_line() {
+            let row_x = i32::from(get_line_offset(wrapped.width, width, wrapped.alignment));
+            let mut x = row_x;
+            let cells = grapheme_columns(&wrapped, width, self.justify)
+                .map(|(grapheme, columns)| {
+                    let cell = RowCell {
+                        index: grapheme_index(grapheme.symbol),
+                        x,
+                        columns,
+                    };
+                    x = cell.end();
+                    cell
+                })
+                .collect();
+            rows.push(Row { x: row_x, cells });
+        }
+        rows
+    }
+
+    fn render_paragraph(&self, text_area: Rect, buf: &mut Buffer, scroll: Position) {
         if text_area.is_empty() {
             return;
         }
 
         buf.set_style(text_area, self.style);
-        let styled = self.text.iter().map(|line| {
-            let graphemes = line.styled_graphemes(self.text.style);
+        let text_area = self.aligned_text_area(text_area, scroll.y);
+        let mut mask = [0; 4];
+        let mask = self
+            .mask
+            .map(|mask_char| &*mask_char.encode_utf8(&mut mask));
+        let styled = self.text.iter().enumerate().map(|(index, line)| {
+            let selected = self.selected_graphemes(index);
+            let base_style = self.text.style.patch(line.style);
+            let graphemes = self.indexed_graphemes(&line.spans, base_style, mask).map(
+                move |(index, mut grapheme)| {
+                    if selected.contains(&index) {
+                        grapheme.style = grapheme.style.patch(self.selection_style);
+                    }
+                    grapheme
+                },
+            );
             let alignment = line.alignment.unwrap_or(self.alignment);
             (graphemes, alignment)
         });
 
         if let Some(Wrap { trim }) = self.wrap {
             let mut line_composer = WordWrapper::new(styled, text_area.width, trim);
+            line_composer.set_mode(self.wrap_mode);
             // compute the lines iteratively until we reach the desired scroll offset.
-            for _ in 0..self.scroll.y {
+            for _ in 0..scroll.y {
                 if line_composer.next_line().is_none() {
                     return;
                 }
             }
-            render_lines(line_composer, text_area, buf);
+            render_rows(line_composer, text_area, buf, self.justify);
+        } else if self.overflow == Overflow::Ellipsis {
+            let ellipsis = if self.ellipsis.width() <= usize::from(text_area.width) {
+                self.ellipsis
+            } else {
+                ""
+            };
+            // the horizontal offset is applied with the ellipsis
+            let lines = styled
+                .skip(scroll.y as usize)
+                .map(|(graphemes, alignment)| {
+                    let offset = if alignment == Alignment::Left {
+                        scroll.x
+                    } else {
+                        0
+                    };
+                    let graphemes = ellipsize(graphemes, text_area.width, offset, ellipsis);
+                    (graphemes.into_iter(), alignment)
+                });
+            render_lines(LineTruncator::new(lines, text_area.width), text_area, buf);
         } else {
             // avoid unnecessary work by skipping directly to the relevant line before rendering
-            let lines = styled.skip(self.scroll.y as usize);
+            let lines = styled.skip(scroll.y as usize);
             let mut line_composer = LineTruncator::new(lines, text_area.width);
-            line_composer.set_horizontal_offset(self.scroll.x);
+            line_composer.set_horizontal_offset(scroll.x);
             render_lines(line_composer, text_area, buf);
         }
     }
 }
 
-fn render_lines<'a, C: LineComposer<'a>>(mut composer: C, area: Rect, buf: &mut Buffer) {
+/// Returns the graphemes of a line that are displayed in `width` columns after skipping `offset`
+/// columns, with the graphemes that are cut on each side replaced by the `ellipsis`.
+///
+/// The graphemes keep their columns, so when a wide grapheme is removed next to an ellipsis, the
+/// ellipsis is moved next to the remaining graphemes and leaves an empty column at the edge.
+fn ellipsize<'b>(
+    graphemes: impl Iterator<Item = StyledGrapheme<'b>>,
+    width: u16,
+    offset: u16,
+    ellipsis: &'b str,
+) -> Vec<StyledGrapheme<'b>> {
+    let (width, offset) = (usize::from(width), usize::from(offset));
+    let ellipsis_width = ellipsis.width();
+    // the graphemes with the column they start at
+    let mut line_width = 0;
+    let graphemes: Vec<_> = graphemes
+        .map(|grapheme| {
+            let column = line_width;
+            line_width += grapheme.symbol.width();
+            (column, grapheme)
+        })
+        .collect();
+    if line_width <= offset {
+        return Vec::new();
+    }
+    let cut_left = offset > 0;
+    let cut_right = line_width - offset > width;
+    let start = if cut_left {
+        offset + ellipsis_width
+    } else {
+        offset
+    };
+    let end = if cut_right {
+        offset + width - ellipsis_width
+    } else {
+        line_width
+    };
+    let is_visible = |(column, grapheme): &(usize, StyledGrapheme)| {
+        *column >= start && column + grapheme.symbol.width() <= end
+    };
+    let first = graphemes
+        .iter()
+        .position(is_visible)
+        .unwrap_or(graphemes.len());
+    let last = graphemes
+        .iter()
+        .rposition(is_visible)
+        .map_or(first, |last| last + 1);
+    let ellipsis_graphemes = |style| {
+        ellipsis
+            .graphemes(true)
+            .map(move |symbol| StyledGrapheme::new(symbol, style))
+    };
+
+    let mut displayed = Vec::with_capacity(last - first + 2 * ellipsis_width);
+    if cut_left {
+        // the ellipsis takes the place of the last graphemes cut on the left, right before the
+        // first visible grapheme
+        let first_column = graphemes.get(first).map_or(start, |(column, _)| *column);
+        let padding = first_column.saturating_sub(start);
+        displayed.extend(iter::repeat_n(
+            StyledGrapheme::new(" ", Style::new()),
+            padding,
+        ));
+        let style = first
+            .checked_sub(1)
+            .map_or_else(Style::new, |cut| graphemes[cut].1.style);
+        displayed.extend(ellipsis_graphemes(style));
+    }
+    displayed.extend(
+        graphemes[first..last]
+            .iter()
+            .map(|(_, grapheme)| grapheme.clone()),
+    );
+    if cut_right {
+        let style = graphemes
+            .get(last)
+            .map_or_else(Style::new, |(_, grapheme)| grapheme.style);
+        displayed.extend(ellipsis_graphemes(style));
+    }
+    displayed
+}
+
+/// Replaces the symbol of a visible grapheme with the mask, if any.
+fn mask_grapheme<'a>(grapheme: StyledGrapheme<'a>, mask: Option<&'a str>) -> StyledGrapheme<'a> {
+    match mask {
+        Some(mask) if grapheme.symbol.width() > 0 => StyledGrapheme {
+            symbol: mask,
+            ..grapheme
+        },
+        _ => grapheme,
+    }
+}
+
+pub(crate) fn render_lines<'a, C: LineComposer<'a>>(composer: C, area: Rect, buf: &mut Buffer) {
+    render_rows(composer, area, buf, false);
+}
+
+/// Renders the rows of `composer`, justifying them to the width of `area` if `justify` is set.
+fn render_rows<'a, C: LineComposer<'a>>(
+    mut composer: C,
+    area: Rect,
+    buf: &mut Buffer,
+    justify: bool,
+) {
     let mut y = 0;
     while let Some(ref wrapped) = composer.next_line() {
-        render_line(wrapped, area, buf, y);
+        render_line(wrapped, area, buf, y, justify);
         y += 1;
         if y >= area.height {
             break;
@@ -456,21 +1382,119 @@ fn render_lines<'a, C: LineComposer<'a>>(mut composer: C, area: Rect, buf: &mut
     }
 }
 
-fn render_line(wrapped: &WrappedLine<'_, '_>, area: Rect, buf: &mut Buffer, y: u16) {
+fn render_line(wrapped: &WrappedLine<'_, '_>, area: Rect, buf: &mut Buffer, y: u16, justify: bool) {
     let mut x = get_line_offset(wrapped.width, area.width, wrapped.alignment);
-    for StyledGrapheme { symbol, style } in wrapped.graphemes {
-        let width = symbol.width();
-        if width == 0 {
+    for (
+        StyledGrapheme {
+            symbol,
+            style,
+            hyperlink,
+        },
+        columns,
+    ) in grapheme_columns(wrapped, area.width, justify)
+    {
+        if columns == 0 {
             continue;
         }
         // Make sure to overwrite any previous character with a space (rather than a zero-width)
         let symbol = if symbol.is_empty() { " " } else { symbol };
         let position = Position::new(area.left() + x, area.top() + y);
-        buf[position].set_symbol(symbol).set_style(*style);
-        x += u16::try_from(width).unwrap_or(u16::MAX);
+        buf[position]
+            .set_symbol(symbol)
+            .set_style(*style)
+            .set_hyperlink(*hyperlink);
+        // the columns added to justify the row are spaces in the style of the widened space
+        for padding_x in x + symbol.width() as u16..x + columns {
+            let position = Position::new(area.left() + padding_x, area.top() + y);
+            buf[position]
+                .set_symbol(" ")
+                .set_style(*style)
+                .set_hyperlink(*hyperlink);
+        }
+        x += columns;
+    }
+}
+
+/// Returns the graphemes of a row with the number of columns they are displayed in.
+///
+/// When the row is justified to `width`, the extra columns are added to the last space of each
+/// gap between words, and the spaces at the end of the row are not displayed. A row is only
+/// justified if `justify` is set and it is a left-aligned row that doesn't end its line.
+fn grapheme_columns<'b, 'a>(
+    wrapped: &WrappedLine<'b, 'a>,
+    width: u16,
+    justify: bool,
+) -> impl Iterator<Item = (&'b StyledGrapheme<'a>, u16)> {
+    let graphemes = wrapped.graphemes;
+    // a gap between words ends at a space that follows a word and is followed by a word
+    let is_gap_end = move |index: usize, after_word: bool| {
+        after_word
+            && graphemes[index].is_whitespace()
+            && graphemes.get(index + 1).is_some_and(|g| !g.is_whitespace())
+    };
+    let justify = justify && !wrapped.last_of_input && wrapped.alignment == Alignment::Left;
+    let (mut gaps, mut extra) = (0, 0);
+    // the width of the row up to its last word
+    let mut content_width = 0;
+    if justify {
+        let mut row_width = 0;
+        for (index, grapheme) in graphemes.iter().enumerate() {
+            row_width += grapheme.symbol.width() as u16;
+            if grapheme.is_whitespace() {
+                gaps += u16::from(is_gap_end(index, content_width > 0));
+            } else {
+                content_width = row_width;
+            }
+        }
+        extra = width.saturating_sub(content_width);
     }
+    let justify = justify && gaps > 0;
+    let mut gap = 0;
+    let mut x = 0;
+    let mut after_word = false;
+    graphemes.iter().enumerate().map(move |(index, grapheme)| {
+        let mut columns = grapheme.symbol.width() as u16;
+        #[expect(clippy::else_if_without_else)]
+        if justify {
+            if x >= content_width {
+                // the spaces at the end of the row
+                columns = 0;
+            } else if is_gap_end(index, after_word) {
+                // the first gaps get one more column when the extra columns don't divide evenly
+                columns += extra / gaps + u16::from(gap < extra % gaps);
+                gap += 1;
+            }
+        }
+        x += grapheme.symbol.width() as u16;
+        after_word |= !grapheme.is_whitespace();
+        (grapheme, columns)
+    })
+}
+
+/// A row of a line of the text, as returned by [`Paragraph::line_rows`].
+struct Row {
+    /// The column the row starts at, which is negative when it is scrolled to the left.
+    x: i32,
+    /// The cells of the graphemes of the row.
+    cells: Vec<RowCell>,
 }
 
+/// A grapheme of a [`Row`] and the cells it is displayed in.
+struct RowCell {
+    /// The index of the grapheme of the line, or `None` for the symbols added by the composer.
+    index: Option<usize>,
+    /// The column the grapheme starts at.
+    x: i32,
+    /// The number of columns the grapheme is displayed in, which is 0 when it isn't displayed.
+    columns: u16,
+}
+
+impl RowCell {
+    /// Returns the column after the grapheme.
+    fn end(&self) -> i32 {
+        self.x + i32::from(self.columns)
+    }
+}
 const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Alignment) -> u16 {
     match alignment {
         Alignment::Center => (text_area_width / 2).saturating_sub(line_width / 2),
@@ -493,16 +1517,18 @@ impl Styled for Paragraph<'_> {
 
 #[cfg(test)]
 mod tests {
-    use alloc::vec;
+    use alloc::string::ToString;
+    use alloc::{format, vec};
 
     use ratatui_core::buffer::Buffer;
-    use ratatui_core::layout::{Alignment, Rect};
+    use ratatui_core::layout::{self, Alignment, Rect};
     use ratatui_core::style::{Color, Modifier, Style, Stylize};
     use ratatui_core::text::{Line, Span, Text};
     use ratatui_core::widgets::Widget;
+    use rstest::rstest;
 
     use super::*;
-    use crate::block::Position;
+    use crate::block::{Padding, Position};
     use crate::borders::Borders;
 
     /// Tests the [`Paragraph`] widget against the expected [`Buffer`] by rendering it onto an equal
@@ -512,10 +1538,42 @@ mod tests {
     #[track_caller]
     fn test_case(paragraph: &Paragraph, expected: &Buffer) {
         let mut buffer = Buffer::empty(Rect::new(0, 0, expected.area.width, expected.area.height));
-        paragraph.render(buffer.area, &mut buffer);
+        Widget::render(paragraph, buffer.area, &mut buffer);
         assert_eq!(buffer, *expected);
     }
 
+    /// The link of the first span is kept in the first `linked` cells, whether the line is
+    /// wrapped or scrolled
+    #[rstest]
+    #[case::unwrapped(None, (0, 0), Rect::new(0, 0, 5, 1), 2)]
+    #[case::wrapped(Some(Wrap { trim: true }), (0, 0), Rect::new(0, 0, 3, 2), 2)]
+    #[case::scrolled(None, (0, 1), Rect::new(0, 0, 5, 1), 1)]
+    fn render_hyperlink(
+        #[case] wrap: Option<Wrap>,
+        #[case] scroll: (u16, u16),
+        #[case] area: Rect,
+        #[case] linked: usize,
+    ) {
+        let line = Line::from(vec![
+            Span::raw("ab").hyperlink("https://ratatui.rs"),
+            Span::raw(" cd"),
+        ]);
+        let mut paragraph = Paragraph::new(line).scroll(scroll);
+        if let Some(wrap) = wrap {
+            paragraph = paragraph.wrap(wrap);
+        }
+        let mut buffer = Buffer::empty(area);
+        paragraph.render(area, &mut buffer);
+        let links: Vec<_> = buffer
+            .content
+            .iter()
+            .map(ratatui_core::buffer::Cell::hyperlink)
+            .collect();
+        let mut expected = vec![None; links.len()];
+        expected[..linked].fill(Some("https://ratatui.rs"));
+        assert_eq!(links, expected);
+    }
+
     #[test]
     fn zero_width_char_at_end_of_line() {
         let line = "foo\u{200B}";
@@ -881,6 +1939,70 @@ mod tests {
         );
     }
 
+    /// The text mixing the alignments of its lines used to test the alignment of wrapped lines
+    fn mixed_alignment_text() -> Text<'static> {
+        Text::from(vec![
+            Line::from("left aligned text"),
+            Line::from("  centered     text here").centered(),
+            Line::from("right   aligned text").right_aligned(),
+        ])
+    }
+
+    #[rstest]
+    #[case::trimmed(true, [
+        "left      ",
+        "aligned   ",
+        "text      ",
+        " centered ",
+        " text here",
+        "     right",
+        "   aligned",
+        "      text",
+    ])]
+    #[case::untrimmed(false, [
+        "left      ",
+        "aligned   ",
+        "text      ",
+        "  centered",
+        "   text   ",
+        "   here   ",
+        "     right",
+        "   aligned",
+    ])]
+    fn wrapped_line_alignments(#[case] trim: bool, #[case] expected: [&str; 8]) {
+        let paragraph = Paragraph::new(mixed_alignment_text()).wrap(Wrap { trim });
+        test_case(&paragraph, &Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn wrapped_line_alignments_override_paragraph_alignment() {
+        let paragraph = Paragraph::new(mixed_alignment_text())
+            .right_aligned()
+            .wrap(Wrap { trim: true });
+        test_case(
+            &paragraph,
+            &Buffer::with_lines([
+                "      left",
+                "   aligned",
+                "      text",
+                " centered ",
+                " text here",
+                "     right",
+                "   aligned",
+                "      text",
+            ]),
+        );
+    }
+
+    #[test]
+    fn text_alignment_is_default_alignment() {
+        let text = Text::from(vec![Line::from("ab"), Line::from("cd").left_aligned()]).centered();
+        let paragraph = Paragraph::new(text.clone());
+        test_case(&paragraph, &Buffer::with_lines(["  ab  ", "cd    "]));
+        let paragraph = Paragraph::new(text).right_aligned();
+        test_case(&paragraph, &Buffer::with_lines(["    ab", "cd    "]));
+    }
+
     #[test]
     fn test_render_paragraph_with_scroll_offset() {
         let text = "This is a\ncool\nmultiline\nparagraph.";
@@ -906,6 +2028,21 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::cjk_even_offset("你好世界", 2, "好世界")]
+    #[case::cjk_odd_offset("你好世界", 1, " 好世 ")]
+    #[case::cjk_odd_offset_after_grapheme("你好世界", 3, " 世界 ")]
+    #[case::emoji_odd_offset("a🦀b🦀c", 2, " b🦀c ")]
+    #[case::emoji_even_offset("a🦀b🦀c", 1, "🦀b🦀c")]
+    fn horizontal_scroll_wide_graphemes(
+        #[case] text: &str,
+        #[case] offset: u16,
+        #[case] expected: &str,
+    ) {
+        let paragraph = Paragraph::new(text).scroll((0, offset));
+        test_case(&paragraph, &Buffer::with_lines([expected]));
+    }
+
     #[test]
     fn test_render_paragraph_with_zero_width_area() {
         let text = "Hello, world!";
@@ -1015,6 +2152,687 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mask() {
+        let paragraph = Paragraph::new("hello").mask('*');
+        assert_eq!(paragraph.mask, Some('*'));
+        assert_eq!(paragraph.text, Text::from("hello"));
+    }
+
+    #[test]
+    fn test_render_masked_paragraph() {
+        let paragraph = Paragraph::new("p4ss!").mask(Paragraph::DEFAULT_MASK);
+        test_case(&paragraph, &Buffer::with_lines(["•••••   "]));
+    }
+
+    #[test]
+    fn test_render_masked_paragraph_with_wide_graphemes() {
+        // each grapheme is replaced by a single mask, whatever its width
+        let paragraph = Paragraph::new("日本 😃").mask('*');
+        test_case(&paragraph, &Buffer::with_lines(["****  "]));
+    }
+
+    #[test]
+    fn test_render_masked_paragraph_with_styled_text() {
+        let paragraph = Paragraph::new(Line::from(vec!["ab".red(), "c".blue()])).mask('*');
+        let mut expected = Buffer::with_lines(["*** "]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(2, 0, 1, 1), Style::new().blue());
+        test_case(&paragraph, &expected);
+    }
+
+    #[test]
+    fn test_render_masked_paragraph_with_wrap() {
+        let paragraph = Paragraph::new("secret pass")
+            .mask('*')
+            .wrap(Wrap { trim: true });
+        test_case(&paragraph, &Buffer::with_lines(["******", "***** "]));
+        assert_eq!(paragraph.line_count(6), 2);
+        assert_eq!(paragraph.line_width(), 11);
+    }
+
+    #[test]
+    fn tab_width() {
+        let paragraph = Paragraph::new("a\tb").tab_width(4);
+        assert_eq!(paragraph.tab_width, Some(4));
+        assert_eq!(paragraph.text, Text::from("a\tb"));
+    }
+
+    #[rstest]
+    #[case::start_of_line("\tx", "    x     ")]
+    #[case::after_one_column("a\tx", "a   x     ")]
+    #[case::before_stop("abc\tx", "abc x     ")]
+    #[case::at_stop("abcd\tx", "abcd    x ")]
+    #[case::consecutive_tabs("a\t\tx", "a       x ")]
+    #[case::wide_characters("日本\tx", "日本    x ")]
+    #[case::wide_character_before_stop("字\tx", "字  x     ")]
+    fn test_render_paragraph_with_tabs(#[case] text: &str, #[case] expected: &str) {
+        let paragraph = Paragraph::new(text).tab_width(4);
+        test_case(&paragraph, &Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn test_render_paragraph_with_tab_stops_per_line() {
+        let paragraph = Paragraph::new("a\tb\nabcdef\tc").tab_width(4);
+        test_case(&paragraph, &Buffer::with_lines(["a   b    ", "abcdef  c"]));
+    }
+
+    #[test]
+    fn test_render_paragraph_with_zero_tab_width() {
+        let paragraph = Paragraph::new("a\tb").tab_width(0);
+        test_case(&paragraph, &Buffer::with_lines(["ab  "]));
+    }
+
+    #[rstest]
+    #[case::exact_fit("abcdef", (0, 0), "abcdef")]
+    #[case::one_over("abcdefg", (0, 0), "abcde…")]
+    #[case::wide_character_at_boundary("abcd界x", (0, 0), "abcd… ")]
+    #[case::scrolled("abcdefghij", (0, 2), "…defg…")]
+    #[case::scrolled_to_end("abcdefgh", (0, 2), "…defgh")]
+    #[case::wide_character_scrolled("ab界cdefgh", (0, 2), " …cde…")]
+    #[case::scrolled_out("abc", (0, 3), "      ")]
+    #[case::short("abc", (0, 0), "abc   ")]
+    fn overflow_ellipsis(#[case] text: &str, #[case] scroll: (u16, u16), #[case] expected: &str) {
+        let paragraph = Paragraph::new(text)
+            .overflow(Overflow::Ellipsis)
+            .scroll(scroll);
+        test_case(&paragraph, &Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn overflow_ellipsis_keeps_style_of_cut_span() {
+        let line = Line::from(vec!["abc".red(), "defghij".blue()]);
+        let paragraph = Paragraph::new(line)
+            .overflow(Overflow::Ellipsis)
+            .scroll((0, 2));
+        let mut expected = Buffer::with_lines(["…defg…"]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red());
+        expected.set_style(Rect::new(1, 0, 5, 1), Style::new().blue());
+        test_case(&paragraph, &expected);
+    }
+
+    #[rstest]
+    #[case::custom_ellipsis(
+        Paragraph::new("abcdefgh").overflow(Overflow::Ellipsis).ellipsis("..."),
+        &["abc..."],
+    )]
+    #[case::ellipsis_wider_than_area(
+        Paragraph::new("abcdefgh").overflow(Overflow::Ellipsis).ellipsis("[more]>"),
+        &["abcdef"],
+    )]
+    #[case::clip(Paragraph::new("abcdefgh").overflow(Overflow::Clip), &["abcdef"])]
+    #[case::centered(
+        Paragraph::new("abcdefgh\nabc").overflow(Overflow::Ellipsis).centered(),
+        &["abcde…", "  abc "],
+    )]
+    #[case::wrapped(
+        Paragraph::new("abcdefgh").overflow(Overflow::Ellipsis).wrap(Wrap { trim: true }),
+        &["abcdef", "gh    "],
+    )]
+    fn overflow(#[case] paragraph: Paragraph, #[case] expected: &[&str]) {
+        test_case(&paragraph, &Buffer::with_lines(expected.iter().copied()));
+    }
+
+    #[test]
+    fn overflow_from_str() {
+        assert_eq!("Ellipsis".parse::<Overflow>(), Ok(Overflow::Ellipsis));
+        assert_eq!(Overflow::Clip.to_string(), "Clip");
+    }
+
+    #[test]
+    fn test_render_paragraph_with_tabs_and_wrap() {
+        // the tab overflows the line so the next word is moved to a new line
+        let paragraph = Paragraph::new("abcd\tef")
+            .tab_width(4)
+            .wrap(Wrap { trim: true });
+        test_case(&paragraph, &Buffer::with_lines(["abcd  ", "ef    "]));
+        assert_eq!(paragraph.line_count(6), 2);
+        assert_eq!(paragraph.line_width(), 10);
+    }
+
+    #[rstest]
+    fn wrap_mode_breaks_long_token(
+        #[values(WrapMode::Word, WrapMode::WordBreak, WrapMode::BreakAnywhere)] mode: WrapMode,
+        #[values(true, false)] trim: bool,
+    ) {
+        let token = "0123456789".repeat(20);
+        let paragraph = Paragraph::new(token).wrap(Wrap { trim }).wrap_mode(mode);
+        test_case(&paragraph, &Buffer::with_lines(["0123456789"; 20]));
+        assert_eq!(paragraph.line_count(10), 20);
+    }
+
+    #[rstest]
+    #[case::word(WrapMode::Word, &["see", "abcdefghij", "abcdefghij", "abcde"])]
+    #[case::word_break(WrapMode::WordBreak, &["see abcdef", "ghijabcdef", "ghijabcde"])]
+    #[case::break_anywhere(WrapMode::BreakAnywhere, &["see abcdef", "ghijabcdef", "ghijabcde"])]
+    #[case::hyphenate(WrapMode::Hyphenate, &["see abcde-", "fghijabcd-", "efghijabc-", "de"])]
+    fn wrap_mode_long_token_after_word(#[case] mode: WrapMode, #[case] expected: &[&str]) {
+        let text = format!("see {}", "abcdefghij".repeat(2) + "abcde");
+        let paragraph = Paragraph::new(text)
+            .wrap(Wrap { trim: true })
+            .wrap_mode(mode);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 4));
+        Widget::render(&paragraph, buffer.area, &mut buffer);
+        let mut lines = expected.to_vec();
+        lines.resize(4, "");
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(lines.iter().map(|l| format!("{l:10}")))
+        );
+        assert_eq!(paragraph.line_count(10), expected.len());
+    }
+
+    #[rstest]
+    #[case::word(WrapMode::Word, &["Hello", "world"])]
+    #[case::word_break(WrapMode::WordBreak, &["Hello", "world"])]
+    #[case::break_anywhere(WrapMode::BreakAnywhere, &["Hello wo", "rld"])]
+    #[case::hyphenate(WrapMode::Hyphenate, &["Hello", "world"])]
+    fn wrap_mode_short_words(#[case] mode: WrapMode, #[case] expected: &[&str]) {
+        let paragraph = Paragraph::new("Hello world")
+            .wrap(Wrap { trim: true })
+            .wrap_mode(mode);
+        let lines = expected.iter().map(|line| format!("{line:8}"));
+        test_case(&paragraph, &Buffer::with_lines(lines));
+        assert_eq!(paragraph.line_count(8), expected.len());
+    }
+
+    #[rstest]
+    #[case::word(WrapMode::Word, &["ab", "界界", "界界"])]
+    #[case::word_break(WrapMode::WordBreak, &["ab 界", "界界", "界"])]
+    #[case::break_anywhere(WrapMode::BreakAnywhere, &["ab 界", "界界", "界"])]
+    #[case::hyphenate(WrapMode::Hyphenate, &["ab", "界界-", "界界"])]
+    fn wrap_mode_keeps_wide_characters_whole(#[case] mode: WrapMode, #[case] expected: &[&str]) {
+        let paragraph = Paragraph::new("ab 界界界界")
+            .wrap(Wrap { trim: true })
+            .wrap_mode(mode);
+        let lines = expected.iter().map(|line| {
+            let padding = 5 - line.width();
+            format!("{line}{}", " ".repeat(padding))
+        });
+        test_case(&paragraph, &Buffer::with_lines(lines));
+        assert_eq!(paragraph.line_count(5), expected.len());
+    }
+
+    #[test]
+    fn wrap_mode_without_trim_keeps_leading_whitespace() {
+        let paragraph = Paragraph::new("  ab   cdefgh")
+            .wrap(Wrap { trim: false })
+            .wrap_mode(WrapMode::BreakAnywhere);
+        test_case(&paragraph, &Buffer::with_lines(["  ab   c", "defgh   "]));
+        assert_eq!(paragraph.line_count(8), 2);
+    }
+
+    #[rstest]
+    #[case::long_words("Hyphenation works", &["Hyphena-", "tion wo-", "rks"])]
+    #[case::existing_hyphen("a well-known fact", &["a well-", "known", "fact"])]
+    #[case::whitespace("abcdefgh ijk", &["abcdefgh", "ijk"])]
+    #[case::short_prefix("Hello abc", &["Hello", "abc"])]
+    fn wrap_mode_hyphenate(#[case] text: &str, #[case] expected: &[&str]) {
+        let paragraph = Paragraph::new(text)
+            .wrap(Wrap { trim: true })
+            .wrap_mode(WrapMode::Hyphenate);
+        let lines = expected.iter().map(|line| format!("{line:8}"));
+        test_case(&paragraph, &Buffer::with_lines(lines));
+        assert_eq!(paragraph.line_count(8), expected.len());
+    }
+
+    #[test]
+    fn wrap_mode_hyphenate_keeps_styles() {
+        let line = Line::from(vec!["Hyphen".red(), "ation".blue()]);
+        let paragraph = Paragraph::new(line)
+            .wrap(Wrap { trim: true })
+            .wrap_mode(WrapMode::Hyphenate);
+        let mut expected = Buffer::with_lines(["Hyphena-", "tion    "]);
+        expected.set_style(Rect::new(0, 0, 6, 1), Style::new().red());
+        expected.set_style(Rect::new(6, 0, 2, 1), Style::new().blue());
+        expected.set_style(Rect::new(0, 1, 4, 1), Style::new().blue());
+        test_case(&paragraph, &expected);
+        assert_eq!(paragraph.line_count(8), 2);
+    }
+
+    #[test]
+    fn wrap_mode_hyphenate_without_room_for_hyphen() {
+        let paragraph = Paragraph::new("abc")
+            .wrap(Wrap { trim: true })
+            .wrap_mode(WrapMode::Hyphenate);
+        test_case(&paragraph, &Buffer::with_lines(["a", "b", "c"]));
+        assert_eq!(paragraph.line_count(1), 3);
+    }
+
+    #[rstest]
+    #[case::short("Hello", 10, true)]
+    #[case::wrapped("Hello World, how are you?", 8, true)]
+    #[case::leading_whitespace("   indented text that wraps", 6, false)]
+    #[case::several_lines("first line\nsecond line\nthird", 7, true)]
+    #[case::long_word("supercalifragilistic is long", 5, true)]
+    #[case::wide_characters("界界界 界界界界", 5, true)]
+    fn height_for_width_matches_rendered_rows(
+        #[case] text: &str,
+        #[case] width: u16,
+        #[case] trim: bool,
+    ) {
+        let text = Text::from(text);
+        let height = text.height_for_width(width, Wrap { trim });
+
+        let paragraph = Paragraph::new(text).wrap(Wrap { trim });
+        let mut buffer = Buffer::empty(Rect::new(0, 0, width, 20));
+        Widget::render(&paragraph, buffer.area, &mut buffer);
+        let rendered_rows = (0..20)
+            .rev()
+            .find(|&y| (0..width).any(|x| buffer[(x, y)].symbol() != " "))
+            .map_or(0, |y| y + 1);
+        assert_eq!(height, rendered_rows);
+    }
+
+    #[test]
+    fn height_for_width_without_text() {
+        assert_eq!(Text::from("abc").height_for_width(0, Wrap::default()), 0);
+        assert_eq!(Text::default().height_for_width(10, Wrap::default()), 0);
+        // an empty line takes a row
+        assert_eq!(Text::from("").height_for_width(10, Wrap::default()), 1);
+    }
+
+    /// Returns the positions of the cells of the buffer that are reversed
+    fn reversed_cells(buffer: &Buffer) -> Vec<(u16, u16)> {
+        buffer
+            .area
+            .positions()
+            .filter(|&position| buffer[position].modifier.contains(Modifier::REVERSED))
+            .map(|position| (position.x, position.y))
+            .collect()
+    }
+
+    #[test]
+    fn selection_across_wrap_boundary() {
+        // the space where the line is broken is not displayed
+        let paragraph = Paragraph::new("Hello World")
+            .wrap(Wrap { trim: true })
+            .selection((0, 3), (0, 8))
+            .selection_style(Style::new().reversed());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 2));
+        Widget::render(&paragraph, buffer.area, &mut buffer);
+        assert_eq!(buffer[(0, 1)].symbol(), "W");
+        assert_eq!(reversed_cells(&buffer), [(3, 0), (4, 0), (0, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn selection_with_scroll_and_wide_characters() {
+        let paragraph = Paragraph::new("ab 界界界界")
+            .wrap(Wrap { trim: true })
+            .scroll((1, 0))
+            .selection((0, 4), (0, 6))
+            .selection_style(Style::new().reversed());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 2));
+        Widget::render(&paragraph, buffer.area, &mut buffer);
+        assert_eq!(reversed_cells(&buffer), [(2, 0), (0, 1)]);
+    }
+
+    #[rstest]
+    #[case::forward((0, 1), (1, 1))]
+    #[case::backward((1, 1), (0, 1))]
+    fn selection_across_lines(#[case] start: (usize, usize), #[case] end: (usize, usize)) {
+        let paragraph = Paragraph::new("abc\ndef")
+            .selection(start, end)
+            .selection_style(Style::new().reversed());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        Widget::render(&paragraph, buffer.area, &mut buffer);
+        assert_eq!(reversed_cells(&buffer), [(1, 0), (2, 0), (0, 1)]);
+    }
+
+    #[rstest]
+    #[case::first_row((1, 0), Some((0, 1)))]
+    #[case::second_wrapped_row((1, 1), Some((0, 7)))]
+    #[case::after_first_row((7, 0), Some((0, 5)))]
+    #[case::last_line((2, 2), Some((1, 2)))]
+    #[case::below_text((0, 3), None)]
+    #[case::outside_area((9, 0), None)]
+    fn text_position(#[case] position: (u16, u16), #[case] expected: Option<(usize, usize)>) {
+        let paragraph = Paragraph::new("Hello World\nfoo").wrap(Wrap { trim: true });
+        let area = Rect::new(0, 0, 8, 4);
+        let position = layout::Position::new(position.0, position.1);
+        assert_eq!(paragraph.text_position(area, position), expected);
+    }
+
+    #[test]
+    fn text_position_with_block_and_scroll() {
+        let paragraph = Paragraph::new("Hello World\nfoo")
+            .block(Block::bordered())
+            .wrap(Wrap { trim: true })
+            .scroll((1, 0));
+        let area = Rect::new(0, 0, 10, 4);
+        // the second wrapped row is displayed on the first row of the text area
+        assert_eq!(
+            paragraph.text_position(area, layout::Position::new(3, 1)),
+            Some((0, 8))
+        );
+        assert_eq!(
+            paragraph.text_position(area, layout::Position::new(1, 2)),
+            Some((1, 0))
+        );
+        assert_eq!(
+            paragraph.text_position(area, layout::Position::new(0, 1)),
+            None
+        );
+    }
+
+    #[test]
+    fn text_position_with_trimming_alignment_and_tabs() {
+        let area = Rect::new(0, 0, 8, 1);
+        let trimmed = Paragraph::new("  ab").wrap(Wrap { trim: true });
+        assert_eq!(
+            trimmed.text_position(area, layout::Position::new(0, 0)),
+            Some((0, 2))
+        );
+        let centered = Paragraph::new("ab").centered();
+        assert_eq!(
+            centered.text_position(area, layout::Position::new(0, 0)),
+            Some((0, 0))
+        );
+        assert_eq!(
+            centered.text_position(area, layout::Position::new(4, 0)),
+            Some((0, 1))
+        );
+        let tabs = Paragraph::new("a\tb").tab_width(4);
+        assert_eq!(
+            tabs.text_position(area, layout::Position::new(2, 0)),
+            Some((0, 1))
+        );
+        assert_eq!(
+            tabs.text_position(area, layout::Position::new(4, 0)),
+            Some((0, 2))
+        );
+        let scrolled = Paragraph::new("abcdef").scroll((0, 2));
+        assert_eq!(
+            scrolled.text_position(area, layout::Position::new(0, 0)),
+            Some((0, 2))
+        );
+    }
+
+    #[rstest]
+    #[case::first_grapheme(0, Some((0, 0)))]
+    #[case::wide_grapheme(3, Some((2, 0)))]
+    #[case::inside_wide_grapheme(4, Some((2, 0)))]
+    #[case::second_wrapped_row(6, Some((0, 1)))]
+    #[case::end_of_line(12, Some((4, 1)))]
+    #[case::next_line(13, Some((0, 2)))]
+    #[case::end_of_text(15, Some((2, 2)))]
+    #[case::past_end_of_text(16, None)]
+    fn cursor_cell_cjk(#[case] byte_offset: usize, #[case] expected: Option<(u16, u16)>) {
+        let paragraph = Paragraph::new(
+            "你好世界
+ab",
+        )
+        .wrap(Wrap { trim: true });
+        let area = Rect::new(0, 0, 5, 3);
+        let expected = expected.map(|(x, y)| layout::Position::new(x, y));
+        assert_eq!(paragraph.cursor_cell(byte_offset, area), expected);
+    }
+
+    #[rstest]
+    #[case::end_of_first_row(5, Some((0, 1)))]
+    #[case::second_wrapped_row(8, Some((2, 1)))]
+    #[case::end_of_line(11, Some((5, 1)))]
+    fn cursor_cell_wrapped(#[case] byte_offset: usize, #[case] expected: Option<(u16, u16)>) {
+        let paragraph = Paragraph::new("Hello World").wrap(Wrap { trim: true });
+        let area = Rect::new(0, 0, 8, 2);
+        let expected = expected.map(|(x, y)| layout::Position::new(x, y));
+        assert_eq!(paragraph.cursor_cell(byte_offset, area), expected);
+    }
+
+    #[test]
+    fn cursor_cell_after_full_row() {
+        let paragraph = Paragraph::new("abcd").wrap(Wrap { trim: false });
+        let area = Rect::new(0, 0, 4, 2);
+        assert_eq!(
+            paragraph.cursor_cell(4, area),
+            Some(layout::Position::new(0, 1))
+        );
+        // without wrapping, the cursor is outside of the text area
+        let paragraph = Paragraph::new("abcd");
+        assert_eq!(paragraph.cursor_cell(4, area), None);
+    }
+
+    #[test]
+    fn cursor_cell_with_block_and_scroll() {
+        let paragraph = Paragraph::new("Hello World\nfoo")
+            .block(Block::bordered())
+            .wrap(Wrap { trim: true })
+            .scroll((1, 0));
+        let area = Rect::new(0, 0, 10, 4);
+        // the first wrapped row is scrolled out
+        assert_eq!(paragraph.cursor_cell(1, area), None);
+        assert_eq!(
+            paragraph.cursor_cell(8, area),
+            Some(layout::Position::new(3, 1))
+        );
+        assert_eq!(
+            paragraph.cursor_cell(13, area),
+            Some(layout::Position::new(2, 2))
+        );
+    }
+
+    #[test]
+    fn cursor_cell_with_alignment_and_horizontal_scroll() {
+        let area = Rect::new(0, 0, 8, 1);
+        let centered = Paragraph::new("ab").centered();
+        assert_eq!(
+            centered.cursor_cell(1, area),
+            Some(layout::Position::new(4, 0))
+        );
+        let empty = Paragraph::new("").centered();
+        assert_eq!(
+            empty.cursor_cell(0, area),
+            Some(layout::Position::new(4, 0))
+        );
+        let scrolled = Paragraph::new("abcdef").scroll((0, 2));
+        assert_eq!(scrolled.cursor_cell(1, area), None);
+        assert_eq!(
+            scrolled.cursor_cell(3, area),
+            Some(layout::Position::new(1, 0))
+        );
+    }
+
+    #[test]
+    fn vertical_alignment_center() {
+        let paragraph =
+            Paragraph::new("first\nsecond").vertical_alignment(VerticalAlignment::Center);
+        let mut expected = Buffer::empty(Rect::new(0, 0, 6, 10));
+        expected.set_string(0, 4, "first", Style::new());
+        expected.set_string(0, 5, "second", Style::new());
+        test_case(&paragraph, &expected);
+    }
+
+    #[test]
+    fn vertical_alignment_center_with_titled_block() {
+        let paragraph = Paragraph::new("first\nsecond")
+            .block(Block::bordered().title("Title"))
+            .vertical_alignment(VerticalAlignment::Center);
+        test_case(
+            &paragraph,
+            &Buffer::with_lines([
+                "┌Title───┐",
+                "│        │",
+                "│        │",
+                "│        │",
+                "│first   │",
+                "│second  │",
+                "│        │",
+                "│        │",
+                "│        │",
+                "└────────┘",
+            ]),
+        );
+    }
+
+    #[rstest]
+    #[case::top(VerticalAlignment::Top, ["abc de", "f     ", "      ", "      ", "      "])]
+    #[case::center(VerticalAlignment::Center, ["      ", "abc de", "f     ", "      ", "      "])]
+    #[case::bottom(VerticalAlignment::Bottom, ["      ", "      ", "      ", "abc de", "f     "])]
+    fn vertical_alignment_with_wrap(
+        #[case] vertical_alignment: VerticalAlignment,
+        #[case] expected: [&str; 5],
+    ) {
+        let paragraph = Paragraph::new("abc de f")
+            .wrap(Wrap { trim: true })
+            .vertical_alignment(vertical_alignment);
+        test_case(&paragraph, &Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn vertical_alignment_with_padding_and_scroll() {
+        // the scrolled rows are not counted, so the last two rows are aligned to the bottom
+        let paragraph = Paragraph::new("a\nb\nc")
+            .block(Block::new().padding(Padding::vertical(1)))
+            .scroll((1, 0))
+            .vertical_alignment(VerticalAlignment::Bottom);
+        test_case(&paragraph, &Buffer::with_lines([" ", " ", "b", "c", " "]));
+        let area = Rect::new(0, 0, 1, 5);
+        assert_eq!(
+            paragraph.text_position(area, layout::Position::new(0, 3)),
+            Some((2, 0))
+        );
+        assert_eq!(
+            paragraph.text_position(area, layout::Position::new(0, 1)),
+            None
+        );
+    }
+
+    #[test]
+    fn vertical_alignment_with_taller_text() {
+        let paragraph = Paragraph::new("a\nb\nc\nd").vertical_alignment(VerticalAlignment::Bottom);
+        test_case(&paragraph, &Buffer::with_lines(["a", "b", "c"]));
+    }
+
+    #[test]
+    fn justified() {
+        let text = "Ratatui is the crate for cooking up terminal user interfaces in Rust. It is \
+                    lightweight.\nA short line.";
+        let paragraph = Paragraph::new(text).wrap(Wrap { trim: true }).justified();
+        let expected = [
+            "Ratatui   is   the  crate  for",
+            "cooking   up   terminal   user",
+            "interfaces   in  Rust.  It  is",
+            "lightweight.                  ",
+            "A short line.                 ",
+        ];
+        test_case(&paragraph, &Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn justified_keeps_span_styles() {
+        let line = Line::from(vec![
+            "ab".red(),
+            " ".on_blue(),
+            "cd".green(),
+            " ef gh".into(),
+        ]);
+        let paragraph = Paragraph::new(line).wrap(Wrap { trim: true }).justified();
+        let mut expected = Buffer::with_lines(["ab   cd", "ef gh  "]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(2, 0, 3, 1), Style::new().on_blue());
+        expected.set_style(Rect::new(5, 0, 2, 1), Style::new().green());
+        test_case(&paragraph, &expected);
+        let area = Rect::new(0, 0, 7, 2);
+        assert_eq!(
+            paragraph.text_position(area, layout::Position::new(4, 0)),
+            Some((0, 2))
+        );
+        assert_eq!(
+            paragraph.text_position(area, layout::Position::new(5, 0)),
+            Some((0, 3))
+        );
+    }
+
+    #[rstest]
+    #[case::not_wrapped(Paragraph::new("ab cd ef").justified(), &["ab cd "])]
+    #[case::single_word(
+        Paragraph::new("abcdefgh").wrap(Wrap { trim: true }).justified(),
+        &["abcdef", "gh    "],
+    )]
+    #[case::centered_line(
+        Paragraph::new(Line::from("ab cd ef").centered()).wrap(Wrap { trim: true }).justified(),
+        &[" ab cd", "  ef  "],
+    )]
+    #[case::replaced_by_alignment(
+        Paragraph::new("ab cd ef").wrap(Wrap { trim: true }).justified().right_aligned(),
+        &[" ab cd", "    ef"],
+    )]
+    fn justified_rows_not_widened(#[case] paragraph: Paragraph, #[case] expected: &[&str]) {
+        test_case(&paragraph, &Buffer::with_lines(expected.iter().copied()));
+    }
+
+    /// Renders the lines in a bordered paragraph with room for two lines of six columns
+    fn render_with_state(lines: &[&str], wrap: bool, state: &mut ParagraphState) -> Buffer {
+        let mut paragraph = Paragraph::new(lines.join("\n")).block(Block::bordered());
+        if wrap {
+            paragraph = paragraph.wrap(Wrap { trim: true });
+        }
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 4));
+        StatefulWidget::render(&paragraph, buffer.area, &mut buffer, state);
+        buffer
+    }
+
+    #[rstest]
+    #[case::no_wrap(false, ["│two   │", "│three │"])]
+    #[case::wrap(true, ["│three │", "│four  │"])]
+    fn state_follows_appended_lines(#[case] wrap: bool, #[case] expected: [&str; 2]) {
+        let mut lines = vec!["one", "two"];
+        let mut state = ParagraphState::default().with_follow(true);
+        let buffer = render_with_state(&lines, wrap, &mut state);
+        let expected_before = ["┌──────┐", "│one   │", "│two   │", "└──────┘"];
+        assert_eq!(buffer, Buffer::with_lines(expected_before));
+        assert_eq!(state.offset(), 0);
+
+        lines.push("three four");
+        let buffer = render_with_state(&lines, wrap, &mut state);
+        let [first, second] = expected;
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["┌──────┐", first, second, "└──────┘"])
+        );
+        assert_eq!(state.offset(), if wrap { 2 } else { 1 });
+        assert!(state.is_following());
+    }
+
+    #[test]
+    fn state_scrolling_stops_following() {
+        let mut lines = vec!["1", "2", "3", "4", "5"];
+        let mut state = ParagraphState::default().with_follow(true);
+        render_with_state(&lines, false, &mut state);
+        assert_eq!(state.offset(), 3);
+
+        state.scroll_up_by(1);
+        lines.push("6");
+        let buffer = render_with_state(&lines, false, &mut state);
+        assert_eq!(state.offset(), 2);
+        assert_eq!(buffer[(1, 1)].symbol(), "3");
+
+        state.scroll_to_bottom();
+        render_with_state(&lines, false, &mut state);
+        assert_eq!(state.offset(), 4);
+
+        state.follow(true);
+        lines.push("7");
+        let buffer = render_with_state(&lines, false, &mut state);
+        assert_eq!(state.offset(), 5);
+        assert_eq!(buffer[(1, 2)].symbol(), "7");
+    }
+
+    #[test]
+    fn state_ignores_vertical_scroll_of_paragraph() {
+        let paragraph = Paragraph::new("abc\ndef").scroll((1, 1));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        let mut state = ParagraphState::default();
+        StatefulWidget::render(&paragraph, buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(["bc ", "ef "]));
+    }
+
+    #[test]
+    fn wrap_mode_from_str() {
+        assert_eq!("WordBreak".parse::<WrapMode>(), Ok(WrapMode::WordBreak));
+        assert_eq!(WrapMode::BreakAnywhere.to_string(), "BreakAnywhere");
+    }
+
     #[test]
     fn can_be_stylized() {
         assert_eq!(
@@ -1183,7 +3001,7 @@ mod tests {
         let paragraph = Paragraph::new(text).block(Block::bordered());
 
         let mut buf = Buffer::empty(Rect::new(0, 0, 20, 3));
-        paragraph.render(Rect::new(0, 0, 20, 3), &mut buf);
+        Widget::render(paragraph, Rect::new(0, 0, 20, 3), &mut buf);
 
         let mut expected = Buffer::with_lines([
             "┌──────────────────┐",
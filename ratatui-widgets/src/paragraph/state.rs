@@ -0,0 +1,254 @@
+/// State of the [`Paragraph`] widget
+///
+/// This state holds the vertical scroll offset of a paragraph rendered as a stateful widget, in
+/// lines of wrapped text. When [following], the paragraph is scrolled to its last line on each
+/// render, so the lines appended to a log or a chat stay visible. Scrolling manually stops
+/// following until [`ParagraphState::follow`] is called again.
+///
+/// The offset is clamped when the paragraph is rendered, so that the last line of the text is not
+/// scrolled above the bottom of the area. The vertical offset of [`Paragraph::scroll`] is ignored
+/// when the paragraph is rendered with a state, and its horizontal offset still applies. A
+/// reference to the paragraph is rendered with the state.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::widgets::{Block, Paragraph, ParagraphState, Wrap};
+///
+/// # fn ui(frame: &mut Frame, logs: &[String]) {
+/// // This should be stored outside of the function in your application state.
+/// let mut state = ParagraphState::default().with_follow(true);
+///
+/// let text = logs
+///     .iter()
+///     .map(String::as_str)
+///     .collect::<Vec<_>>()
+///     .join("\n");
+/// let paragraph = Paragraph::new(text)
+///     .block(Block::bordered().title("Logs"))
+///     .wrap(Wrap { trim: false });
+/// frame.render_stateful_widget(&paragraph, frame.area(), &mut state);
+/// # }
+/// ```
+///
+/// [`Paragraph`]: super::Paragraph
+/// [`Paragraph::scroll`]: super::Paragraph::scroll
+/// [following]: ParagraphState::is_following
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParagraphState {
+    pub(crate) offset: usize,
+    pub(crate) follow: bool,
+}
+
+impl ParagraphState {
+    /// Sets the index of the first line to be displayed
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ParagraphState;
+    ///
+    /// let state = ParagraphState::default().with_offset(1);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets whether the paragraph is scrolled to its last line on each render
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ParagraphState;
+    ///
+    /// let state = ParagraphState::default().with_follow(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_follow(mut self, follow: bool) -> Self {
+        self.follow = follow;
+        self
+    }
+
+    /// Index of the first line to be displayed
+    ///
+    /// This is the offset as of the last render when following.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ParagraphState;
+    ///
+    /// let state = ParagraphState::default();
+    /// assert_eq!(state.offset(), 0);
+    /// ```
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Scrolls the paragraph to its last line on each render, or stops following
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::widgets::{Paragraph, ParagraphState, StatefulWidget};
+    ///
+    /// let area = Rect::new(0, 0, 10, 2);
+    /// let mut buf = Buffer::empty(area);
+    /// let mut state = ParagraphState::default();
+    /// state.follow(true);
+    /// StatefulWidget::render(&Paragraph::new("a\nb\nc"), area, &mut buf, &mut state);
+    /// assert_eq!(state.offset(), 1);
+    /// ```
+    pub const fn follow(&mut self, follow: bool) {
+        self.follow = follow;
+    }
+
+    /// Returns whether the paragraph is scrolled to its last line on each render
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ParagraphState;
+    ///
+    /// let mut state = ParagraphState::default().with_follow(true);
+    /// assert!(state.is_following());
+    /// state.scroll_up_by(1);
+    /// assert!(!state.is_following());
+    /// ```
+    pub const fn is_following(&self) -> bool {
+        self.follow
+    }
+
+    /// Scrolls down by `amount` lines and stops following
+    ///
+    /// The offset is clamped to the last line of the text when the paragraph is rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ParagraphState;
+    ///
+    /// let mut state = ParagraphState::default();
+    /// state.scroll_down_by(4);
+    /// assert_eq!(state.offset(), 4);
+    /// ```
+    pub const fn scroll_down_by(&mut self, amount: u16) {
+        self.offset = self.offset.saturating_add(amount as usize);
+        self.follow = false;
+    }
+
+    /// Scrolls up by `amount` lines and stops following
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ParagraphState;
+    ///
+    /// let mut state = ParagraphState::default().with_offset(4);
+    /// state.scroll_up_by(3);
+    /// assert_eq!(state.offset(), 1);
+    /// ```
+    pub const fn scroll_up_by(&mut self, amount: u16) {
+        self.offset = self.offset.saturating_sub(amount as usize);
+        self.follow = false;
+    }
+
+    /// Scrolls to the first line and stops following
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ParagraphState;
+    ///
+    /// let mut state = ParagraphState::default().with_offset(4);
+    /// state.scroll_to_top();
+    /// assert_eq!(state.offset(), 0);
+    /// ```
+    pub const fn scroll_to_top(&mut self) {
+        self.offset = 0;
+        self.follow = false;
+    }
+
+    /// Scrolls to the last line and stops following
+    ///
+    /// The last line is displayed at the bottom of the area on the next render, which doesn't
+    /// scroll further when lines are appended. Use [`ParagraphState::follow`] to keep the last
+    /// line in view.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ParagraphState;
+    ///
+    /// let mut state = ParagraphState::default();
+    /// state.scroll_to_bottom();
+    /// ```
+    pub const fn scroll_to_bottom(&mut self) {
+        self.offset = usize::MAX;
+        self.follow = false;
+    }
+
+    /// Returns the offset at which `line_count` lines are displayed in a viewport of `height`
+    /// lines, and stores it.
+    pub(crate) fn update_offset(&mut self, line_count: usize, height: u16) -> usize {
+        let max_offset = line_count.saturating_sub(usize::from(height));
+        self.offset = if self.follow {
+            max_offset
+        } else {
+            self.offset.min(max_offset)
+        };
+        self.offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn scrolling_stops_following() {
+        let mut state = ParagraphState::default().with_offset(2).with_follow(true);
+        state.scroll_down_by(3);
+        assert_eq!(state, ParagraphState::default().with_offset(5));
+        state.follow(true);
+        state.scroll_up_by(10);
+        assert_eq!(state, ParagraphState::default());
+        state.follow(true);
+        state.scroll_to_bottom();
+        assert_eq!(state, ParagraphState::default().with_offset(usize::MAX));
+        state.follow(true);
+        state.scroll_to_top();
+        assert_eq!(state, ParagraphState::default());
+    }
+
+    #[rstest]
+    #[case::top(0, false, 0)]
+    #[case::middle(3, false, 3)]
+    #[case::clamped(usize::MAX, false, 6)]
+    #[case::following(0, true, 6)]
+    fn update_offset(#[case] offset: usize, #[case] follow: bool, #[case] expected: usize) {
+        let mut state = ParagraphState::default()
+            .with_offset(offset)
+            .with_follow(follow);
+        assert_eq!(state.update_offset(10, 4), expected);
+        assert_eq!(state.offset(), expected);
+    }
+
+    #[test]
+    fn update_offset_with_few_lines() {
+        let mut state = ParagraphState::default().with_follow(true);
+        assert_eq!(state.update_offset(3, 4), 0);
+    }
+}
@@ -6,9 +6,10 @@ use core::mem;
 
 use ratatui_core::layout::Alignment;
 use ratatui_core::text::StyledGrapheme;
-use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
+use crate::paragraph::WrapMode;
+
 /// A state machine to pack styled symbols into lines.
 /// Cannot implement it as Iterator since it yields slices of the internal buffer (need streaming
 /// iterators for that).
@@ -24,6 +25,8 @@ pub struct WrappedLine<'lend, 'text> {
     pub width: u16,
     /// Whether the line was aligned left or right
     pub alignment: Alignment,
+    /// Whether the line is the last one of its input line
+    pub last_of_input: bool,
 }
 
 /// A state machine that wraps lines on word boundaries.
@@ -44,6 +47,8 @@ where
     current_line: Vec<StyledGrapheme<'a>>,
     /// Removes the leading whitespace from lines
     trim: bool,
+    /// Where the lines are broken
+    mode: WrapMode,
 
     // These are cached allocations that hold no state across next_line invocations
     pending_word: Vec<StyledGrapheme<'a>>,
@@ -65,6 +70,7 @@ where
             current_alignment: Alignment::Left,
             current_line: vec![],
             trim,
+            mode: WrapMode::Word,
 
             pending_word: Vec::new(),
             pending_line_pool: Vec::new(),
@@ -72,9 +78,18 @@ where
         }
     }
 
+    /// Set where the lines are broken, which is between words by default.
+    pub const fn set_mode(&mut self, mode: WrapMode) {
+        self.mode = mode;
+    }
+
     /// Split an input line (`line_symbols`) into wrapped lines
     /// and cache them to be emitted later
     fn process_input(&mut self, line_symbols: impl IntoIterator<Item = StyledGrapheme<'a>>) {
+        if self.mode != WrapMode::Word {
+            self.process_input_breaking_words(line_symbols);
+            return;
+        }
         let mut pending_line = self.pending_line_pool.pop().unwrap_or_default();
         let mut line_width = 0;
         let mut word_width = 0;
@@ -191,6 +206,121 @@ where
         }
     }
 
+    /// Split an input line (`line_symbols`) into wrapped lines, breaking the words that don't fit
+    /// in the rest of a line, and cache them to be emitted later
+    ///
+    /// With [`WrapMode::WordBreak`], only the words longer than a line are broken, and the other
+    /// words are moved to the next line. With [`WrapMode::BreakAnywhere`], every word is broken.
+    /// With [`WrapMode::Hyphenate`], every word is broken too, see [`hyphenate`]. The whitespace
+    /// where a line is broken is removed.
+    fn process_input_breaking_words(
+        &mut self,
+        line_symbols: impl IntoIterator<Item = StyledGrapheme<'a>>,
+    ) {
+        let max_width = self.max_line_width;
+        let mut pending_line = self.pending_line_pool.pop().unwrap_or_default();
+        pending_line.clear();
+        let mut line_width: u16 = 0;
+        // whether the pending line continues a line that was broken
+        let mut wrapped = false;
+
+        // ignore symbols wider than line limit
+        let mut graphemes = line_symbols
+            .into_iter()
+            .filter(|grapheme| grapheme.symbol.width() as u16 <= max_width)
+            .peekable();
+        while let Some(first) = graphemes.next() {
+            // group the graphemes into words and runs of whitespace
+            let is_whitespace = first.is_whitespace();
+            self.pending_word.clear();
+            self.pending_word.push(first);
+            while let Some(grapheme) =
+                graphemes.next_if(|grapheme| grapheme.is_whitespace() == is_whitespace)
+            {
+                self.pending_word.push(grapheme);
+            }
+            let word_width = self
+                .pending_word
+                .iter()
+                .map(|grapheme| grapheme.symbol.width() as u16)
+                .sum::<u16>();
+
+            if is_whitespace {
+                if pending_line.is_empty() && (self.trim || wrapped) {
+                    continue;
+                }
+                for grapheme in self.pending_word.drain(..) {
+                    let symbol_width = grapheme.symbol.width() as u16;
+                    if line_width + symbol_width > max_width {
+                        // the rest of the whitespace is where the line is broken
+                        break;
+                    }
+                    line_width += symbol_width;
+                    pending_line.push(grapheme);
+                }
+                continue;
+            }
+
+            let fits_on_next_line = self.mode == WrapMode::WordBreak && word_width <= max_width;
+            if line_width + word_width > max_width && fits_on_next_line {
+                trim_trailing_whitespace(&mut pending_line);
+                self.wrapped_lines.push_back(mem::take(&mut pending_line));
+                line_width = 0;
+                wrapped = true;
+            }
+            // the number of graphemes of the word on the pending line
+            let mut on_line = 0;
+            let mut index = 0;
+            while let Some(grapheme) = self.pending_word.get(index) {
+                let symbol_width = grapheme.symbol.width() as u16;
+                if line_width + symbol_width > max_width {
+                    if self.mode == WrapMode::Hyphenate {
+                        // the graphemes moved off the line are processed again on the next line
+                        index -= hyphenate(&mut pending_line, on_line, max_width);
+                    }
+                    trim_trailing_whitespace(&mut pending_line);
+                    self.wrapped_lines.push_back(mem::take(&mut pending_line));
+                    line_width = 0;
+                    on_line = 0;
+                    wrapped = true;
+                    continue;
+                }
+                line_width += symbol_width;
+                pending_line.push(grapheme.clone());
+                on_line += 1;
+                index += 1;
+            }
+        }
+
+        #[expect(clippy::else_if_without_else)]
+        if !pending_line.is_empty() || self.wrapped_lines.is_empty() {
+            self.wrapped_lines.push_back(pending_line);
+        } else if pending_line.capacity() > 0 {
+            self.pending_line_pool.push(pending_line);
+        }
+    }
+
+    /// Removes the whitespace around the breaks of the wrapped lines of a centered or right-aligned
+    /// input line, so that the whitespace where the line is broken doesn't shift the wrapped lines.
+    ///
+    /// The leading whitespace of the first wrapped line and the trailing whitespace of the last
+    /// one are kept, as they are when the input line is not wrapped.
+    fn trim_breaks(&mut self) {
+        if self.current_alignment == Alignment::Left {
+            return;
+        }
+        let last = self.wrapped_lines.len().saturating_sub(1);
+        for (index, line) in self.wrapped_lines.iter_mut().enumerate() {
+            if index < last {
+                trim_trailing_whitespace(line);
+            }
+            if index > 0 {
+                let leading = line.iter().take_while(|g| g.is_whitespace()).count();
+                line.drain(..leading);
+            }
+        }
+    }
+
     fn replace_current_line(&mut self, line: Vec<StyledGrapheme<'a>>) {
         let cache = mem::replace(&mut self.current_line, line);
         if cache.capacity() > 0 {
@@ -222,6 +352,7 @@ where
                     graphemes: &self.current_line,
                     width: line_width,
                     alignment: self.current_alignment,
+                    last_of_input: self.wrapped_lines.is_empty(),
                 });
             }
 
@@ -229,10 +360,59 @@ where
             let (line_symbols, line_alignment) = self.input_lines.next()?;
             self.current_alignment = line_alignment;
             self.process_input(line_symbols);
+            self.trim_breaks();
         }
     }
 }
 
+/// The minimum number of graphemes of a word before an added hyphen, unless the word starts the
+/// line
+const MIN_HYPHENATED_PREFIX: usize = 2;
+
+/// Breaks the word at the end of a full `line`, whose last `word_len` graphemes start the word,
+/// and returns the number of graphemes moved off the line.
+///
+/// The word is broken after its last hyphen on the line if there is one. Otherwise a hyphen with
+/// the style of the preceding grapheme is added, and the graphemes that don't leave room for it
+/// are moved off the line. The whole word is moved off the line when fewer than
+/// [`MIN_HYPHENATED_PREFIX`] graphemes would remain before the hyphen, unless the word starts the
+/// line.
+fn hyphenate(line: &mut Vec<StyledGrapheme<'_>>, word_len: usize, max_width: u16) -> usize {
+    let word_start = line.len() - word_len;
+    if let Some(hyphen) = line[word_start..].iter().rposition(|g| g.symbol == "-") {
+        let moved = word_len - hyphen - 1;
+        line.truncate(line.len() - moved);
+        return moved;
+    }
+    let starts_line = line[..word_start].iter().all(StyledGrapheme::is_whitespace);
+    let mut width = line.iter().map(|g| g.symbol.width() as u16).sum::<u16>();
+    let mut kept = word_len;
+    while kept > 0 && width + 1 > max_width {
+        width -= line[word_start + kept - 1].symbol.width() as u16;
+        kept -= 1;
+    }
+    if kept >= MIN_HYPHENATED_PREFIX || (starts_line && kept > 0) {
+        line.truncate(word_start + kept);
+        let last = &line[line.len() - 1];
+        let hyphen = StyledGrapheme::new("-", last.style).hyperlink(last.hyperlink);
+        line.push(hyphen);
+        word_len - kept
+    } else if starts_line {
+        // there is no room for a hyphen
+        0
+    } else {
+        line.truncate(word_start);
+        word_len
+    }
+}
+
+/// Removes the whitespace at the end of a line that is broken
+fn trim_trailing_whitespace(line: &mut Vec<StyledGrapheme<'_>>) {
+    while line.last().is_some_and(StyledGrapheme::is_whitespace) {
+        line.pop();
+    }
+}
+
 /// A state machine that truncates overhanging lines.
 #[derive(Debug, Default, Clone)]
 pub struct LineTruncator<'a, O, I>
@@ -267,6 +447,9 @@ where
     }
 
     /// Set the horizontal offset to skip render.
+    ///
+    /// The offset is a number of columns, and the columns left by a wide grapheme that it splits
+    /// are filled with spaces.
     pub const fn set_horizontal_offset(&mut self, horizontal_offset: u16) {
         self.horizontal_offset = horizontal_offset;
     }
@@ -292,7 +475,7 @@ where
             lines_exhausted = false;
             current_alignment = *alignment;
 
-            for StyledGrapheme { symbol, style } in current_line {
+            for grapheme @ StyledGrapheme { symbol, .. } in current_line {
                 // Ignore characters wider that the total max width.
                 if symbol.width() as u16 > self.max_line_width {
                     continue;
@@ -303,21 +486,31 @@ where
                     break;
                 }
 
-                let symbol = if horizontal_offset == 0 || Alignment::Left != *alignment {
-                    symbol
-                } else {
+                if horizontal_offset > 0 && *alignment == Alignment::Left {
                     let w = symbol.width();
-                    if w > horizontal_offset {
-                        let t = trim_offset(symbol, horizontal_offset);
-                        horizontal_offset = 0;
-                        t
-                    } else {
+                    if w <= horizontal_offset {
                         horizontal_offset -= w;
-                        ""
+                        self.current_line.push(StyledGrapheme {
+                            symbol: "",
+                            ..grapheme
+                        });
+                        continue;
                     }
-                };
+                    // The offset splits a wide grapheme: its remaining columns are padded with
+                    // spaces so that the following graphemes stay in their columns.
+                    let padding = (w - horizontal_offset) as u16;
+                    horizontal_offset = 0;
+                    for _ in 0..padding {
+                        self.current_line.push(StyledGrapheme {
+                            symbol: " ",
+                            ..grapheme
+                        });
+                    }
+                    current_line_width += padding;
+                    continue;
+                }
                 current_line_width += symbol.width() as u16;
-                self.current_line.push(StyledGrapheme { symbol, style });
+                self.current_line.push(grapheme);
             }
         }
 
@@ -328,35 +521,20 @@ where
                 graphemes: &self.current_line,
                 width: current_line_width,
                 alignment: current_alignment,
+                last_of_input: true,
             })
         }
     }
 }
 
-/// This function will return a str slice which start at specified offset.
-/// As src is a unicode str, start offset has to be calculated with each character.
-fn trim_offset(src: &str, mut offset: usize) -> &str {
-    let mut start = 0;
-    for c in UnicodeSegmentation::graphemes(src, true) {
-        let w = c.width();
-        if w <= offset {
-            offset -= w;
-            start += c.len();
-        } else {
-            break;
-        }
-    }
-    #[expect(clippy::string_slice)] // Is safe as it comes from UnicodeSegmentation
-    &src[start..]
-}
-
 #[cfg(test)]
 mod tests {
     use alloc::boxed::Box;
     use alloc::string::String;
 
-    use ratatui_core::style::Style;
+    use ratatui_core::style::{Style, Stylize};
     use ratatui_core::text::{Line, Text};
+    use unicode_segmentation::UnicodeSegmentation;
 
     use super::*;
 
@@ -393,6 +571,7 @@ mod tests {
             graphemes,
             width,
             alignment,
+            ..
         }) = composer.next_line()
         {
             let line = graphemes
@@ -726,4 +905,40 @@ mod tests {
         let (word_wrapper, _, _) = run_composer(Composer::WordWrapper { trim: true }, line, width);
         assert_eq!(word_wrapper, ["foo", "bar"]);
     }
+
+    #[test]
+    fn line_composer_word_wrapper_last_of_input() {
+        let text = Text::from("foo bar baz\nqux");
+        let lines = text.iter().map(|line| {
+            let graphemes = line
+                .iter()
+                .flat_map(|span| span.styled_graphemes(Style::default()));
+            (graphemes, Alignment::Left)
+        });
+        let mut composer = WordWrapper::new(lines, 7, true);
+        let mut last_of_input = vec![];
+        while let Some(wrapped) = composer.next_line() {
+            last_of_input.push(wrapped.last_of_input);
+        }
+        assert_eq!(last_of_input, [false, true, true]);
+    }
+
+    #[test]
+    fn line_composer_truncator_horizontal_offset_splits_wide_grapheme() {
+        let text = Text::from("你好世界".red());
+        let lines = text.iter().map(|line| {
+            let graphemes = line
+                .iter()
+                .flat_map(|span| span.styled_graphemes(Style::default()));
+            (graphemes, Alignment::Left)
+        });
+        let mut composer = LineTruncator::new(lines, 6);
+        composer.set_horizontal_offset(3);
+        let wrapped = composer.next_line().unwrap();
+        let symbols: Vec<_> = wrapped.graphemes.iter().map(|g| g.symbol).collect();
+        // the remaining half of the split grapheme is a space in its style
+        assert_eq!(symbols, ["", " ", "世", "界"]);
+        assert_eq!(wrapped.graphemes[1].style, Style::new().red());
+        assert_eq!(wrapped.width, 5);
+    }
 }
@@ -1089,4 +1089,34 @@ mod tests {
         let mut state = ScrollbarState::new(10);
         scrollbar.render(zero_width_area, &mut buffer, &mut state);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_then_deserialize() -> Result<(), serde_json::Error> {
+        let state = ScrollbarState::new(10)
+            .position(3)
+            .viewport_content_length(4);
+        let json = serde_json::to_string(&state)?;
+        assert_eq!(serde_json::from_str::<ScrollbarState>(&json)?, state);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn render_clamps_deserialized_position() -> Result<(), serde_json::Error> {
+        // the state was saved when the content was longer
+        let json = r#"{"content_length":10,"position":50,"viewport_content_length":0}"#;
+        let mut state: ScrollbarState = serde_json::from_str(json)?;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 5));
+        Scrollbar::new(ScrollbarOrientation::VerticalRight).render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::empty(buf.area);
+        let mut last = ScrollbarState::new(10).position(9);
+        Scrollbar::new(ScrollbarOrientation::VerticalRight).render(
+            expected.area,
+            &mut expected,
+            &mut last,
+        );
+        assert_eq!(buf, expected);
+        Ok(())
+    }
 }
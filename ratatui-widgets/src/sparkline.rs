@@ -29,7 +29,8 @@ use crate::block::{Block, BlockExt};
 /// entire widget or for individual bars by setting individual [`SparklineBar::style`].
 ///
 /// The bars are rendered using a set of symbols. The default set is [`symbols::bar::NINE_LEVELS`].
-/// You can change the set using [`Sparkline::bar_set`].
+/// You can change the set using [`Sparkline::bar_set`], or render the bars with Braille dots using
+/// [`Sparkline::sparkline_style`].
 ///
 /// If the data provided is a slice of `u64` or `Option<u64>`, the bars will be styled with the
 /// style of the sparkline. If the data is a slice of [`SparklineBar`], the bars will be
@@ -44,7 +45,11 @@ use crate::block::{Block, BlockExt};
 /// - [`Sparkline::block`] wraps the sparkline in a [`Block`]
 /// - [`Sparkline::data`] defines the dataset, you'll almost always want to use it
 /// - [`Sparkline::max`] sets the maximum value of bars
+/// - [`Sparkline::absolute_max`] sets the maximum value of bars, or scales them to the displayed
+///   values
 /// - [`Sparkline::direction`] sets the render direction
+/// - [`Sparkline::sparkline_style`] sets whether the bars are drawn with blocks or Braille dots
+/// - [`Sparkline::baseline`] highlights the row of a reference value
 ///
 /// # Examples
 ///
@@ -63,6 +68,7 @@ use crate::block::{Block, BlockExt};
 ///     .absent_value_symbol(symbols::shade::FULL);
 /// ```
 #[derive(Debug, Default, Clone, Eq, PartialEq)]
+#[expect(clippy::struct_field_names)] // sparkline_style needs to be differentiated to style
 pub struct Sparkline<'a> {
     /// A block to wrap the widget in
     block: Option<Block<'a>>,
@@ -77,10 +83,16 @@ pub struct Sparkline<'a> {
     /// The maximum value to take to compute the maximum bar height (if nothing is specified, the
     /// widget uses the max of the dataset)
     max: Option<u64>,
+    /// Whether the bars are scaled between the minimum and the maximum of the displayed values
+    auto_scale: bool,
     /// A set of bar symbols used to represent the give data
     bar_set: symbols::bar::Set,
     /// The direction to render the sparkline, either from left to right, or from right to left
     direction: RenderDirection,
+    /// Whether the bars are drawn with the bar set or with Braille dots
+    sparkline_style: SparklineStyle,
+    /// The reference value whose row is highlighted, and the style of the row
+    baseline: Option<(u64, Style)>,
 }
 
 /// Defines the direction in which sparkline will be rendered.
@@ -96,6 +108,22 @@ pub enum RenderDirection {
     RightToLeft,
 }
 
+/// Defines how the bars of a sparkline are drawn.
+///
+/// See [`Sparkline::sparkline_style`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SparklineStyle {
+    /// Each column is a bar drawn with the symbols of the bar set, with 8 levels per row
+    #[default]
+    Bar,
+    /// Each column holds two bars drawn with Braille dots, with 4 levels per row
+    ///
+    /// Twice as many values fit in the width of the sparkline, and each bar is one dot wide, so
+    /// the changes between consecutive values look smoother than with the block symbols.
+    Braille,
+}
+
 impl<'a> Sparkline<'a> {
     /// Wraps the sparkline with the given `block`.
     #[must_use = "method moves the value of self and returns the modified value"]
@@ -222,6 +250,32 @@ impl<'a> Sparkline<'a> {
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn max(mut self, max: u64) -> Self {
         self.max = Some(max);
+        self.auto_scale = false;
+        self
+    }
+
+    /// Sets the maximum value of bars, or scales the bars to the displayed values.
+    ///
+    /// `Some(max)` is the same as [`Sparkline::max`]. With `None`, the bars are scaled between
+    /// the minimum and the maximum of the values that fit in the width of the sparkline, instead
+    /// of between 0 and the maximum of the whole dataset. The lowest displayed value has an empty
+    /// bar and the highest a full one, so that small fluctuations of live data remain visible.
+    /// When all the displayed values are equal, the bars are scaled from 0 as usual.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::Sparkline;
+    ///
+    /// // the bars range from empty for 100 to full for 104
+    /// let sparkline = Sparkline::default()
+    ///     .data(&[100, 102, 101, 104, 103])
+    ///     .absolute_max(None);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn absolute_max(mut self, max: Option<u64>) -> Self {
+        self.max = max;
+        self.auto_scale = max.is_none();
         self
     }
 
@@ -243,6 +297,56 @@ impl<'a> Sparkline<'a> {
         self.direction = direction;
         self
     }
+
+    /// Sets whether the bars are drawn with blocks or Braille dots.
+    ///
+    /// [`SparklineStyle::Bar`] by default. With [`SparklineStyle::Braille`], the sparkline displays
+    /// two values per column, one in each column of dots. The two bars of a column share its
+    /// style, which is patched with the styles of both bars. Absent values have no dots and the
+    /// [`Sparkline::absent_value_symbol`] is not used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{Sparkline, SparklineStyle};
+    ///
+    /// let sparkline = Sparkline::default()
+    ///     .data(&[0, 1, 2, 3, 4, 5, 6, 7, 8])
+    ///     .sparkline_style(SparklineStyle::Braille);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn sparkline_style(mut self, sparkline_style: SparklineStyle) -> Self {
+        self.sparkline_style = sparkline_style;
+        self
+    }
+
+    /// Highlights the row of a reference value with the given style.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The row is the one where a bar of the given value ends, and it is highlighted across the
+    /// whole width of the sparkline, over the bars. This shows which values are above a threshold
+    /// or a target. The row isn't highlighted when the value is above the maximum of the bars.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::Sparkline;
+    ///
+    /// let sparkline = Sparkline::default()
+    ///     .data(&[2, 5, 9, 4, 7])
+    ///     .max(10)
+    ///     .baseline(6, Style::new().on_dark_gray());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn baseline<S: Into<Style>>(mut self, value: u64, style: S) -> Self {
+        self.baseline = Some((value, style.into()));
+        self
+    }
 }
 
 /// An bar in a `Sparkline`.
@@ -340,6 +444,25 @@ impl Widget for &Sparkline<'_> {
     }
 }
 
+/// Highlights the row of the sparkline where a bar of the given height, in `levels` per row, ends.
+fn render_baseline(spark_area: Rect, buf: &mut Buffer, height: u64, levels: u64, style: Style) {
+    let area_height = u64::from(spark_area.height);
+    if height > area_height * levels {
+        return;
+    }
+    // the row of the last level of the bar, counted from the bottom
+    let row = u16::try_from(height.saturating_sub(1) / levels).unwrap_or(u16::MAX);
+    let y = spark_area.bottom() - 1 - row;
+    buf.set_style(
+        Rect {
+            y,
+            height: 1,
+            ..spark_area
+        },
+        style,
+    );
+}
+
 /// A newtype wrapper for the symbol to use for absent values.
 #[derive(Debug, Clone, Eq, PartialEq)]
 struct AbsentValueSymbol(String);
@@ -355,16 +478,51 @@ impl Sparkline<'_> {
         if spark_area.is_empty() {
             return;
         }
-        // determine the maximum height across all bars
-        let max_height = self
-            .max
-            .unwrap_or_else(|| self.data.iter().filter_map(|s| s.value).max().unwrap_or(1));
+        // determine the number of bars in a column, and of levels in a row
+        let (bars_per_column, levels) = match self.sparkline_style {
+            SparklineStyle::Bar => (1, 8),
+            SparklineStyle::Braille => (2, 4),
+        };
 
         // determine the maximum index to render
-        let max_index = min(spark_area.width as usize, self.data.len());
+        let max_index = min(spark_area.width as usize * bars_per_column, self.data.len());
 
+        // determine the range of values scaled to the height of the spark area
+        let (min_value, max_height) = self.scale(max_index);
+        let max_height = max_height - min_value;
+        let scaled_height = |value: u64| {
+            if max_height == 0 {
+                0
+            } else {
+                value
+                    .saturating_sub(min_value)
+                    .saturating_mul(u64::from(spark_area.height) * levels)
+                    / max_height
+            }
+        };
+
+        match self.sparkline_style {
+            SparklineStyle::Bar => self.render_bars(spark_area, buf, max_index, &scaled_height),
+            SparklineStyle::Braille => {
+                self.render_braille(spark_area, buf, max_index, &scaled_height);
+            }
+        }
+
+        if let Some((value, style)) = self.baseline {
+            render_baseline(spark_area, buf, scaled_height(value), levels, style);
+        }
+    }
+
+    /// Renders the first `len` items of the data as bars of the bar set.
+    fn render_bars(
+        &self,
+        spark_area: Rect,
+        buf: &mut Buffer,
+        len: usize,
+        scaled_height: &impl Fn(u64) -> u64,
+    ) {
         // render each item in the data
-        for (i, item) in self.data.iter().take(max_index).enumerate() {
+        for (i, item) in self.data.iter().take(len).enumerate() {
             let x = match self.direction {
                 RenderDirection::LeftToRight => spark_area.left() + i as u16,
                 RenderDirection::RightToLeft => spark_area.right() - i as u16 - 1,
@@ -385,14 +543,7 @@ impl Sparkline<'_> {
                 SparklineBar {
                     value: Some(value),
                     style,
-                } => {
-                    let height = if max_height == 0 {
-                        0
-                    } else {
-                        *value * u64::from(spark_area.height) * 8 / max_height
-                    };
-                    (height, None, *style)
-                }
+                } => (scaled_height(*value), None, *style),
                 _ => (
                     u64::from(spark_area.height) * 8,
                     Some(self.absent_value_symbol.0.as_str()),
@@ -421,6 +572,77 @@ impl Sparkline<'_> {
         }
     }
 
+    /// Renders the first `len` items of the data as bars of Braille dots, two per column.
+    fn render_braille(
+        &self,
+        spark_area: Rect,
+        buf: &mut Buffer,
+        len: usize,
+        scaled_height: &impl Fn(u64) -> u64,
+    ) {
+        for (i, items) in self.data[..len].chunks(2).enumerate() {
+            let x = match self.direction {
+                RenderDirection::LeftToRight => spark_area.left() + i as u16,
+                RenderDirection::RightToLeft => spark_area.right() - i as u16 - 1,
+            };
+
+            // the heights of the bars in the left and right columns of dots
+            let mut heights = [0; 2];
+            let mut style = self.style;
+            for (j, item) in items.iter().enumerate() {
+                let column = match self.direction {
+                    RenderDirection::LeftToRight => j,
+                    RenderDirection::RightToLeft => 1 - j,
+                };
+                match item {
+                    SparklineBar {
+                        value: Some(value),
+                        style: bar_style,
+                    } => {
+                        heights[column] = scaled_height(*value);
+                        style = style.patch(bar_style.unwrap_or_default());
+                    }
+                    _ => style = style.patch(self.absent_value_style),
+                }
+            }
+
+            // render the bars from bottom to top, filling the dots of each row from the bottom
+            for y in (spark_area.top()..spark_area.bottom()).rev() {
+                let mut code_point = symbols::braille::BLANK;
+                for (column, height) in heights.iter_mut().enumerate() {
+                    for dots in symbols::braille::DOTS
+                        .iter()
+                        .rev()
+                        .take(min(*height, 4) as usize)
+                    {
+                        code_point |= dots[column];
+                    }
+                    *height = height.saturating_sub(4);
+                }
+                let symbol = char::from_u32(u32::from(code_point)).unwrap_or(' ');
+                buf[(x, y)].set_char(symbol).set_style(style);
+            }
+        }
+    }
+
+    /// Returns the values of an empty bar and of a full bar, when the first `len` bars are
+    /// displayed.
+    fn scale(&self, len: usize) -> (u64, u64) {
+        if self.auto_scale {
+            let values = self.data[..len].iter().filter_map(|bar| bar.value);
+            if let Some((min, max)) = values.clone().min().zip(values.max()) {
+                if min < max {
+                    return (min, max);
+                }
+                return (0, max);
+            }
+        }
+        let max = self
+            .max
+            .unwrap_or_else(|| self.data.iter().filter_map(|s| s.value).max().unwrap_or(1));
+        (0, max)
+    }
+
     const fn symbol_for_height(&self, height: u64) -> &str {
         match height {
             0 => self.bar_set.empty,
@@ -442,6 +664,7 @@ mod tests {
 
     use ratatui_core::buffer::Cell;
     use ratatui_core::style::{Color, Modifier, Stylize};
+    use rstest::rstest;
     use strum::ParseError;
 
     use super::*;
@@ -468,6 +691,12 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sparkline_style_to_string_and_from_str() {
+        assert_eq!(SparklineStyle::Braille.to_string(), "Braille");
+        assert_eq!("Bar".parse::<SparklineStyle>(), Ok(SparklineStyle::Bar));
+    }
+
     #[test]
     fn it_can_be_created_from_vec_of_u64() {
         let data = vec![1_u64, 2, 3];
@@ -598,6 +827,179 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(["xxx█▇▆▅▄▃▂▁ "]));
     }
 
+    #[test]
+    fn right_to_left_mirrors_left_to_right() {
+        let data = [3, 0, 8, 5, 1, 6, 2];
+        let area = Rect::new(0, 0, 7, 2);
+        let mut left_to_right = Buffer::empty(area);
+        Sparkline::default()
+            .data(data)
+            .render(area, &mut left_to_right);
+        let mut right_to_left = Buffer::empty(area);
+        Sparkline::default()
+            .data(data)
+            .direction(RenderDirection::RightToLeft)
+            .render(area, &mut right_to_left);
+
+        assert_eq!(left_to_right, Buffer::with_lines(["  █▂ ▄ ", "▆ ██▂█▄"]));
+        let mirrored = (0..area.height).map(|y| {
+            (0..area.width)
+                .rev()
+                .map(|x| left_to_right[(x, y)].symbol())
+                .collect::<String>()
+        });
+        assert_eq!(right_to_left, Buffer::with_lines(mirrored));
+    }
+
+    #[test]
+    fn it_renders_baseline() {
+        let widget = Sparkline::default()
+            .data([0, 2, 4, 6, 8])
+            .max(8)
+            .baseline(5, Style::new().red());
+        let area = Rect::new(0, 0, 6, 2);
+        let mut buffer = Buffer::filled(area, Cell::new("x"));
+        widget.render(area, &mut buffer);
+        let mut expected = Buffer::with_lines(["   ▄█x", " ▄███x"]);
+        expected.set_style(Rect::new(0, 0, 6, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::zero(0, 1)]
+    #[case::middle_of_bottom_row(2, 1)]
+    #[case::top_of_bottom_row(4, 1)]
+    #[case::top_row(5, 0)]
+    #[case::max(8, 0)]
+    fn baseline_row(#[case] value: u64, #[case] y: u16) {
+        let widget = Sparkline::default()
+            .data([8])
+            .max(8)
+            .direction(RenderDirection::RightToLeft)
+            .baseline(value, Style::new().red());
+        let area = Rect::new(0, 0, 2, 2);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+        let mut expected = Buffer::with_lines([" █", " █"]);
+        expected.set_style(Rect::new(0, y, 2, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn baseline_above_max_is_not_rendered() {
+        let widget = Sparkline::default()
+            .data([8])
+            .max(8)
+            .baseline(9, Style::new().red());
+        let buffer = render(widget, 2);
+        assert_eq!(buffer, Buffer::with_lines(["█x"]));
+    }
+
+    #[test]
+    fn absolute_max() {
+        let sparkline = Sparkline::default().absolute_max(None);
+        assert_eq!((sparkline.max, sparkline.auto_scale), (None, true));
+        let sparkline = sparkline.absolute_max(Some(8));
+        assert_eq!((sparkline.max, sparkline.auto_scale), (Some(8), false));
+        let sparkline = Sparkline::default().absolute_max(None).max(8);
+        assert_eq!((sparkline.max, sparkline.auto_scale), (Some(8), false));
+    }
+
+    #[rstest]
+    #[case::fixed_max(Some(104), "▇▇▇█▇x")]
+    #[case::auto_scaled(None, " ▄▂█▆x")]
+    fn it_scales_to_absolute_max(#[case] max: Option<u64>, #[case] expected: &str) {
+        let widget = Sparkline::default()
+            .data([100, 102, 101, 104, 103])
+            .absolute_max(max);
+        let buffer = render(widget, 6);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn auto_scaling_ignores_values_outside_the_area() {
+        let widget = Sparkline::default()
+            .data([Some(2), None, Some(4), Some(6), Some(0), Some(100)])
+            .absolute_max(None);
+        let buffer = render(widget, 4);
+        assert_eq!(buffer, Buffer::with_lines(["  ▄█"]));
+    }
+
+    #[test]
+    fn auto_scaling_equal_values() {
+        let widget = Sparkline::default()
+            .data([5, 5, 5])
+            .absolute_max(None)
+            .baseline(5, Style::new().red());
+        let buffer = render(widget, 4);
+        let mut expected = Buffer::with_lines(["███x"]);
+        expected.set_style(Rect::new(0, 0, 4, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn auto_scaling_without_values() {
+        let widget = Sparkline::default().data([None, None]).absolute_max(None);
+        let buffer = render(widget, 3);
+        assert_eq!(buffer, Buffer::with_lines(["  x"]));
+    }
+
+    #[rstest]
+    #[case::bar(SparklineStyle::Bar, ["     ", " ▂▄▆█"])]
+    // twice as many values are displayed, in the columns of dots
+    #[case::braille(SparklineStyle::Braille, ["⠀⠀⢀⣴⡇", "⢀⣴⣿⣿⡇"])]
+    fn it_renders_sparkline_style(#[case] style: SparklineStyle, #[case] expected: [&str; 2]) {
+        let widget = Sparkline::default()
+            .data([0, 1, 2, 3, 4, 5, 6, 7, 8])
+            .max(8)
+            .sparkline_style(style);
+        let area = Rect::new(0, 0, 5, 2);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[rstest]
+    #[case::left_to_right(RenderDirection::LeftToRight, "⢀⣴")]
+    #[case::right_to_left(RenderDirection::RightToLeft, "⣦⡀")]
+    fn it_renders_braille_direction(#[case] direction: RenderDirection, #[case] expected: &str) {
+        let widget = Sparkline::default()
+            .data([0, 1, 2, 3])
+            .max(4)
+            .direction(direction)
+            .sparkline_style(SparklineStyle::Braille);
+        let buffer = render(widget, 2);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn it_renders_braille_with_absent_value_style() {
+        let widget = Sparkline::default()
+            .data([None, Some(4), Some(2)])
+            .max(4)
+            .absent_value_style(Style::new().red())
+            .sparkline_style(SparklineStyle::Braille);
+        let buffer = render(widget, 3);
+        let mut expected = Buffer::with_lines(["⢸⡄x"]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn it_renders_braille_baseline() {
+        let widget = Sparkline::default()
+            .data([8, 8])
+            .max(8)
+            .baseline(5, Style::new().red())
+            .sparkline_style(SparklineStyle::Braille);
+        let area = Rect::new(0, 0, 2, 2);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+        let mut expected = Buffer::with_lines(["⣿ ", "⣿ "]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn it_renders_with_absent_value_style() {
         let widget = Sparkline::default()
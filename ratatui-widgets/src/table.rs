@@ -3,24 +3,41 @@
 
 use alloc::vec;
 use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::mem;
+use core::ops::Range;
 
 use itertools::Itertools;
 use ratatui_core::buffer::Buffer;
-use ratatui_core::layout::{Constraint, Flex, Layout, Rect};
+use ratatui_core::layout::{Alignment, Constraint, Flex, Layout, Rect};
 use ratatui_core::style::{Style, Styled};
-use ratatui_core::text::Text;
+use ratatui_core::symbols;
+use ratatui_core::text::{Span, Text};
 use ratatui_core::widgets::{StatefulWidget, Widget};
 
 pub use self::cell::Cell;
+use self::column_highlight_fn::ColumnHighlightFn;
+pub use self::column_width::ColumnWidth;
 pub use self::highlight_spacing::HighlightSpacing;
 pub use self::row::Row;
+use self::row_style_fn::RowStyleFn;
+pub use self::scroll_behavior::ScrollBehavior;
+pub use self::sort_direction::SortDirection;
 pub use self::state::TableState;
+use self::virtual_rows::VirtualRows;
 use crate::block::{Block, BlockExt};
 
 mod cell;
+mod column_highlight_fn;
+mod column_width;
 mod highlight_spacing;
 mod row;
+mod row_style_fn;
+mod scroll_behavior;
+mod sort_direction;
 mod state;
+mod virtual_rows;
+mod visible_rows;
 
 /// A widget to display data in formatted columns.
 ///
@@ -42,7 +59,10 @@ mod state;
 /// not visible (based on the offset), the table will be scrolled to make the selected row visible.
 ///
 /// Note: if the `widths` field is empty, the table will be rendered with equal widths.
-/// Note: Highlight styles are applied in the following order: Row, Column, Cell.
+/// Note: Highlight styles are applied in the following order: Row, Column (the
+/// [`Table::column_highlight_style`] then the [`Table::column_highlight_style_fn`]), Range, Cell.
+/// The header cell of the selected column is only styled with the
+/// [`Table::selected_column_header_style`].
 ///
 /// See the table example and the recipe and traceroute tabs in the demo2 example in the [Examples]
 /// directory for a more in depth example of the various configuration options and for how to handle
@@ -62,15 +82,44 @@ mod state;
 /// - [`Table::rows`] sets the rows of the [`Table`].
 /// - [`Table::header`] sets the header row of the [`Table`].
 /// - [`Table::footer`] sets the footer row of the [`Table`].
+/// - [`Table::rows_from`] sets rows that are created on demand, for huge datasets.
+/// - [`Table::row_heights_from`] sets the height of the rows created on demand.
 /// - [`Table::widths`] sets the width constraints of each column.
+/// - [`Table::column_widths`] sets the width of each column, which can be sized to its content.
+/// - [`Table::measure_all_rows`] sizes the columns to the content of all the rows instead of the
+///   visible ones.
 /// - [`Table::column_spacing`] sets the spacing between each column.
+/// - [`Table::column_separator`] sets the symbol drawn between the columns.
+/// - [`Table::column_separator_style`] sets the style of the column separators.
+/// - [`Table::column_alignments`] sets the default alignment of the cells of each column.
+/// - [`Table::column_styles`] sets the default style of the cells of each column.
 /// - [`Table::block`] wraps the table in a [`Block`] widget.
 /// - [`Table::style`] sets the base style of the widget.
+/// - [`Table::row_style_fn`] computes the style of each row from its index.
+/// - [`Table::alternating_row_styles`] alternates the style of the rows.
 /// - [`Table::row_highlight_style`] sets the style of the selected row.
 /// - [`Table::column_highlight_style`] sets the style of the selected column.
+/// - [`Table::column_highlight_style_fn`] computes the style of the cells of the selected column
+///   from the index of their row.
+/// - [`Table::selected_column_header_style`] sets the style of the header cell of the selected
+///   column.
 /// - [`Table::cell_highlight_style`] sets the style of the selected cell.
+/// - [`Table::range_highlight_style`] sets the style of the selected range of cells.
+/// - [`Table::multi_highlight_style`] sets the style of the rows in the selection set.
 /// - [`Table::highlight_symbol`] sets the symbol to be displayed in front of the selected row.
+/// - [`Table::multi_highlight_symbol`] sets the symbol to be displayed in front of the rows in the
+///   selection set.
 /// - [`Table::highlight_spacing`] sets when to show the highlight spacing.
+/// - [`Table::scroll_padding`] sets the number of rows kept visible around the selected row.
+/// - [`Table::horizontal_scroll`] allows the columns to be scrolled horizontally instead of being
+///   shrunk to fit the available width.
+/// - [`Table::frozen_columns`] keeps the leading columns visible when scrolling horizontally.
+/// - [`Table::wrap_cells`] wraps the content of the cells and grows the rows to fit it.
+/// - [`Table::max_row_height`] limits the height of rows with wrapped cells.
+/// - [`Table::sort_indicator_symbols`] sets the symbols displayed in the header of the sorted
+///   column.
+/// - [`Table::sort_indicator`] displays the sort indicator of a column without a [`TableState`].
+/// - [`Table::sorted_by`] sorts the rows by the column set in the [`TableState`].
 ///
 /// # Example
 ///
@@ -230,10 +279,17 @@ mod state;
 ///
 /// [`Stylize`]: ratatui_core::style::Stylize
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "the flags are independent settings"
+)]
 pub struct Table<'a> {
     /// Data to display in each row
     rows: Vec<Row<'a>>,
 
+    /// Rows created on demand instead of the `rows` when set
+    virtual_rows: Option<VirtualRows<'a>>,
+
     /// Optional header
     header: Option<Row<'a>>,
 
@@ -243,50 +299,135 @@ pub struct Table<'a> {
     /// Width constraints for each column
     widths: Vec<Constraint>,
 
+    /// Widths of the columns when some of them are sized to their content, empty otherwise
+    column_widths: Vec<ColumnWidth>,
+
+    /// Whether the columns sized to their content are measured on all the rows
+    measure_all_rows: bool,
+
     /// Space between each column
     column_spacing: u16,
 
+    /// Symbol drawn in the spacing between the columns
+    column_separator: Option<&'a str>,
+
+    /// Style of the column separators
+    column_separator_style: Style,
+
+    /// Default alignment of the cells of each column
+    column_alignments: Vec<Alignment>,
+
+    /// Default style of the cells of each column
+    column_styles: Vec<Style>,
+
     /// A block to wrap the widget in
     block: Option<Block<'a>>,
 
     /// Base style for the widget
     style: Style,
 
+    /// Computes the style of each row from its index
+    row_style_fn: Option<RowStyleFn<'a>>,
+
     /// Style used to render the selected row
     row_highlight_style: Style,
 
     /// Style used to render the selected column
     column_highlight_style: Style,
 
+    /// Computes the style of the cells of the selected column from the index of their row
+    column_highlight_style_fn: Option<ColumnHighlightFn<'a>>,
+
+    /// Style used to render the header cell of the selected column
+    selected_column_header_style: Style,
+
     /// Style used to render the selected cell
     cell_highlight_style: Style,
 
+    /// Style used to render the cells of the selected range
+    range_highlight_style: Style,
+
+    /// Style used to render the rows of the selection set
+    multi_highlight_style: Style,
+
     /// Symbol in front of the selected row
     highlight_symbol: Text<'a>,
 
+    /// Symbol in front of the rows of the selection set
+    multi_highlight_symbol: Text<'a>,
+
     /// Decides when to allocate spacing for the row selection
     highlight_spacing: HighlightSpacing,
 
+    /// Number of rows to keep visible around the selected row
+    scroll_padding: usize,
+
     /// Controls how to distribute extra space among the columns
     flex: Flex,
+
+    /// Whether columns that don't fit are scrolled horizontally instead of shrunk
+    horizontal_scroll: bool,
+
+    /// Number of leading columns that are not scrolled horizontally
+    frozen_columns: u16,
+
+    /// Whether the content of the cells is wrapped to the width of their column
+    wrap_cells: bool,
+
+    /// Maximum height of a row when its height is increased to fit wrapped cells
+    max_row_height: Option<u16>,
+
+    /// Whether the details of the selected row are displayed without expanding it
+    expand_selected: bool,
+
+    /// Number of columns the details of the rows are indented by
+    details_indent: u16,
+
+    /// Symbols appended to the header cell of the sorted column, for each sort direction
+    sort_indicator_symbols: (Span<'a>, Span<'a>),
+
+    /// Column whose header displays a sort indicator when the state has no sorted column
+    sort_indicator: Option<(usize, SortDirection)>,
 }
 
 impl Default for Table<'_> {
     fn default() -> Self {
         Self {
             rows: Vec::new(),
+            virtual_rows: None,
             header: None,
             footer: None,
             widths: Vec::new(),
+            column_widths: Vec::new(),
+            measure_all_rows: false,
             column_spacing: 1,
+            column_separator: None,
+            column_separator_style: Style::new(),
+            column_alignments: Vec::new(),
+            column_styles: Vec::new(),
             block: None,
             style: Style::new(),
+            row_style_fn: None,
             row_highlight_style: Style::new(),
             column_highlight_style: Style::new(),
+            column_highlight_style_fn: None,
+            selected_column_header_style: Style::new(),
             cell_highlight_style: Style::new(),
+            range_highlight_style: Style::new(),
+            multi_highlight_style: Style::new(),
             highlight_symbol: Text::default(),
+            multi_highlight_symbol: Text::default(),
             highlight_spacing: HighlightSpacing::default(),
+            scroll_padding: 0,
             flex: Flex::Start,
+            horizontal_scroll: false,
+            frozen_columns: 0,
+            wrap_cells: false,
+            max_row_height: None,
+            expand_selected: false,
+            details_indent: 0,
+            sort_indicator_symbols: (Span::raw(" ▲"), Span::raw(" ▼")),
+            sort_indicator: None,
         }
     }
 }
@@ -362,6 +503,79 @@ impl<'a> Table<'a> {
         T: IntoIterator<Item = Row<'a>>,
     {
         self.rows = rows.into_iter().collect();
+        self.virtual_rows = None;
+        self
+    }
+
+    /// Set the rows to be created on demand from their index
+    ///
+    /// Instead of building every [`Row`] up front, the table calls `row` only for the indexes of
+    /// the rows it displays, which are computed from `len` and the offset and selection of the
+    /// [`TableState`]. This makes it possible to display huge datasets without creating a row for
+    /// each item on every frame. The selection and offset are clamped to `len`, which is also the
+    /// content length to use for a [`Scrollbar`].
+    ///
+    /// The rows are assumed to be 1 line high, unless [`Table::row_heights_from`] is used. Their
+    /// height when [wrapping cells](Table::wrap_cells) is not taken into account, and
+    /// [`Table::sorted_by`] leaves virtual rows unchanged, so the data should be sorted instead.
+    ///
+    /// This replaces the rows set with [`Table::new`] or [`Table::rows`], and setting them again
+    /// replaces the virtual rows.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// let numbers: Vec<u64> = (0..1_000_000).collect();
+    /// let table = Table::default()
+    ///     .widths([Constraint::Length(10)])
+    ///     .rows_from(numbers.len(), |i| Row::new([numbers[i].to_string()]));
+    /// ```
+    ///
+    /// [`Scrollbar`]: crate::scrollbar::Scrollbar
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn rows_from<F>(mut self, len: usize, row: F) -> Self
+    where
+        F: Fn(usize) -> Row<'a> + 'a,
+    {
+        self.rows = Vec::new();
+        self.virtual_rows = Some(VirtualRows::new(len, row));
+        self
+    }
+
+    /// Set the height of each of the rows created with [`Table::rows_from`]
+    ///
+    /// `height` is called with the index of a row and returns the number of lines it takes,
+    /// including its top and bottom margins. It should match the rows created by
+    /// [`Table::rows_from`]. This has no effect if the rows are not virtual.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// let table = Table::default()
+    ///     .widths([Constraint::Length(10)])
+    ///     .rows_from(1000, |i| {
+    ///         Row::new([i.to_string()]).height(if i % 10 == 0 { 2 } else { 1 })
+    ///     })
+    ///     .row_heights_from(|i| if i % 10 == 0 { 2 } else { 1 });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn row_heights_from<F>(mut self, height: F) -> Self
+    where
+        F: Fn(usize) -> u16 + 'a,
+    {
+        if let Some(virtual_rows) = &mut self.virtual_rows {
+            virtual_rows.set_height(height);
+        }
         self
     }
 
@@ -369,6 +583,9 @@ impl<'a> Table<'a> {
     ///
     /// The `header` parameter is a [`Row`] which will be displayed at the top of the [`Table`]
     ///
+    /// The header is as tall as its tallest cell, so that multi-line labels are displayed in
+    /// full, unless its height is set with [`Row::height`].
+    ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
     /// # Examples
@@ -392,6 +609,12 @@ impl<'a> Table<'a> {
     ///
     /// The `footer` parameter is a [`Row`] which will be displayed at the bottom of the [`Table`]
     ///
+    /// Like the header, the footer stays visible when the rows are scrolled, and its cells are
+    /// aligned with the columns of the rows. The height and margins of the footer are reserved at
+    /// the bottom of the area, which reduces the height available to the rows. When the area is too
+    /// short to display the header, the footer and at least one line of rows, the footer is not
+    /// displayed.
+    ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
     /// # Examples
@@ -444,6 +667,79 @@ impl<'a> Table<'a> {
         let widths = widths.into_iter().map(Into::into).collect_vec();
         ensure_percentages_less_than_100(&widths);
         self.widths = widths;
+        self.column_widths = Vec::new();
+        self
+    }
+
+    /// Set the widths of the columns, allowing columns to be sized to their content.
+    ///
+    /// The `widths` parameter accepts any type that implements `IntoIterator<Item =
+    /// Into<ColumnWidth>>`. [`ColumnWidth`] is created from a [`Constraint`], which is used in the
+    /// same way as with [`Table::widths`], or from a u16 for a fixed width.
+    ///
+    /// A [`ColumnWidth::Auto`] column is as wide as the widest of its cells in the header, the
+    /// footer and the visible rows, clamped between its minimum and maximum width. Use
+    /// [`Table::measure_all_rows`] to measure all the rows instead, so that the width doesn't
+    /// change when the table is scrolled. The width of a cell is its display width, so wide
+    /// characters count as two columns. Cells spanning several columns are not measured. The
+    /// space left is distributed to the other columns according to their constraints, e.g. to the
+    /// [`Constraint::Fill`] columns.
+    ///
+    /// This replaces the widths set with [`Table::widths`]. This is a fluent setter method which
+    /// must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::widgets::{ColumnWidth, Row, Table};
+    ///
+    /// let rows = [Row::new(["1", "Alice", "Likes to read"])];
+    /// let table = Table::new(rows, [0; 3]).column_widths([
+    ///     ColumnWidth::from(3),
+    ///     ColumnWidth::Auto { min: 4, max: 20 },
+    ///     ColumnWidth::from(Constraint::Fill(1)),
+    /// ]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_widths<I>(mut self, widths: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<ColumnWidth>,
+    {
+        let column_widths = widths.into_iter().map(Into::into).collect_vec();
+        let widths = column_widths
+            .iter()
+            .map(|width| width.constraint(0))
+            .collect_vec();
+        ensure_percentages_less_than_100(&widths);
+        self.widths = widths;
+        self.column_widths = column_widths;
+        self
+    }
+
+    /// Set whether the columns sized to their content are measured on all the rows
+    ///
+    /// By default, the [`ColumnWidth::Auto`] columns set with [`Table::column_widths`] are sized
+    /// to the header, the footer and the visible rows only, which is fast for large tables but
+    /// makes the widths change when the table is scrolled. When `true`, all the rows are measured
+    /// on each render. This has no effect on the rows created with [`Table::rows_from`], which are
+    /// always measured on the visible rows.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{ColumnWidth, Table};
+    ///
+    /// let table = Table::default()
+    ///     .column_widths([ColumnWidth::Auto { min: 0, max: 30 }])
+    ///     .measure_all_rows(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn measure_all_rows(mut self, measure_all_rows: bool) -> Self {
+        self.measure_all_rows = measure_all_rows;
         self
     }
 
@@ -467,6 +763,134 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Set the symbol drawn between the columns
+    ///
+    /// The separator is drawn in the spacing between each column of the header, the rows and the
+    /// footer, except inside of cells spanning several columns. When the spacing is wider than one
+    /// cell, the separator is drawn in the middle of it. When the column spacing is 0, one cell is
+    /// still reserved between the columns for the separator.
+    ///
+    /// When the table is wrapped in a [`Block`] with plain, rounded, thick or double borders, the
+    /// separators are joined to the top and bottom borders of the block with tees.
+    ///
+    /// Defaults to no separator.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Block, Row, Table};
+    ///
+    /// let rows = [Row::new(["Cell1", "Cell2"])];
+    /// let table = Table::new(rows, [5, 5])
+    ///     .block(Block::bordered())
+    ///     .column_separator("│");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_separator<T: Into<Option<&'a str>>>(mut self, separator: T) -> Self {
+        self.column_separator = separator.into();
+        self
+    }
+
+    /// Set the style of the column separators
+    ///
+    /// See [`Table::column_separator`].
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// let rows = [Row::new(["Cell1", "Cell2"])];
+    /// let table = Table::new(rows, [5, 5])
+    ///     .column_separator("│")
+    ///     .column_separator_style(Style::new().dark_gray());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_separator_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.column_separator_style = style.into();
+        self
+    }
+
+    /// Set the default alignment of the cells of each column
+    ///
+    /// The alignments are given in column order, and apply to the cells of the header, the rows
+    /// and the footer. A cell whose [`Text`] or [`Line`] has an alignment of its own keeps it.
+    /// Columns without an alignment in the list use the default (left) alignment. A cell spanning
+    /// several columns uses the alignment of its first column.
+    ///
+    /// Lines are aligned by their display width, so wide characters such as CJK are aligned
+    /// correctly.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Alignment;
+    /// use ratatui::text::Line;
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// let rows = [
+    ///     Row::new(["Apples", "12"]),
+    ///     Row::new(["Bananas", "1234"]),
+    ///     Row::new([Line::from("Total"), Line::from("n/a").centered()]),
+    /// ];
+    /// let table = Table::new(rows, [10, 6]).column_alignments([Alignment::Left, Alignment::Right]);
+    /// ```
+    ///
+    /// [`Line`]: ratatui_core::text::Line
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_alignments<I>(mut self, alignments: I) -> Self
+    where
+        I: IntoIterator<Item = Alignment>,
+    {
+        self.column_alignments = alignments.into_iter().collect();
+        self
+    }
+
+    /// Set the default style of the cells of each column
+    ///
+    /// The styles are given in column order, and apply to the cells of the header, the rows and
+    /// the footer. The style of a column is applied over the style of the [`Row`], and the style
+    /// of a [`Cell`] is applied over it. A cell spanning several columns uses the style of its
+    /// first column.
+    ///
+    /// `styles` accepts any iterator of types that are convertible to [`Style`] (e.g. [`Style`],
+    /// [`Color`], or your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// let rows = [Row::new(["Apples", "12"])];
+    /// let table = Table::new(rows, [10, 6]).column_styles([Style::new(), Style::new().yellow()]);
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_styles<I>(mut self, styles: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Style>,
+    {
+        self.column_styles = styles.into_iter().map(Into::into).collect();
+        self
+    }
+
     /// Wraps the table with a custom [`Block`] widget.
     ///
     /// The `block` parameter is of type [`Block`]. This holds the specified block to be
@@ -534,6 +958,99 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Set a function computing the style of each row
+    ///
+    /// The function is called when the table is rendered, for each visible row, with the index of
+    /// the row in the table (not on the screen) and the row itself, so that the styles don't change
+    /// when the table is scrolled. The computed style is overridden by [`Row::style`],
+    /// [`Cell::style`], the styles of the cell's content, and the highlight styles.
+    ///
+    /// When the rows are sorted with [`Table::sorted_by`], the index is the position of the row in
+    /// the sorted order.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// // highlight every fifth row
+    /// let table = Table::new(rows, widths).row_style_fn(|i, _row| {
+    ///     if i % 5 == 4 {
+    ///         Style::new().underlined()
+    ///     } else {
+    ///         Style::new()
+    ///     }
+    /// });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn row_style_fn<F>(mut self, style: F) -> Self
+    where
+        F: Fn(usize, &Row<'_>) -> Style + 'a,
+    {
+        self.row_style_fn = Some(RowStyleFn::new(style));
+        self
+    }
+
+    /// Alternate the style of the rows between `even` and `odd`
+    ///
+    /// The first row of the table uses `even`, the second row `odd` and so on, in the order the
+    /// rows are rendered, so the styles don't change when the table is scrolled. This is a shortcut
+    /// for [`Table::row_style_fn`], and the styles are layered in the same way, under the highlight
+    /// styles. The space below the rows is not striped.
+    ///
+    /// `even` and `odd` accept any type that is convertible to [`Style`] (e.g. [`Style`],
+    /// [`Color`], or your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .alternating_row_styles(Style::new(), Style::new().on_dark_gray());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn alternating_row_styles<S: Into<Style>, T: Into<Style>>(self, even: S, odd: T) -> Self {
+        let (even, odd) = (even.into(), odd.into());
+        self.row_style_fn(move |i, _| if i % 2 == 0 { even } else { odd })
+    }
+
+    /// Alternate the style of the rows between `even` and `odd`
+    ///
+    /// This is the same as [`Table::alternating_row_styles`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).zebra(Style::new(), Style::new().on_dark_gray());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn zebra<S: Into<Style>, T: Into<Style>>(self, even: S, odd: T) -> Self {
+        self.alternating_row_styles(even, odd)
+    }
+
     /// Set the style of the selected row
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -613,41 +1130,159 @@ impl<'a> Table<'a> {
         self
     }
 
-    /// Set the style of the selected cell
+    /// Set a callback computing the style of the cells of the selected column
     ///
-    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
-    /// your own type that implements [`Into<Style>`]).
-    ///
-    /// This style will be applied to the selected cell, and will override any style set on the
-    /// row or on the individual cells.
+    /// The callback is called with the index of the row of each visible cell of the selected
+    /// column, and the style it returns is applied on top of the
+    /// [`Table::column_highlight_style`]. The row highlight is applied before it, and the range and
+    /// cell highlights after it, so the style of the selected cell can also be emphasized by
+    /// returning a different style for the selected row.
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use ratatui::{layout::Constraint, style::{Style, Stylize}, widgets::{Row, Table}};
-    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
-    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
-    /// let table = Table::new(rows, widths).cell_highlight_style(Style::new().red().italic());
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::{Row, Table, TableState};
+    ///
+    /// let state = TableState::new().with_selected_cell((1, 0));
+    /// let selected_row = state.selected();
+    /// let rows = [Row::new(["Cell1", "Cell2"]), Row::new(["Cell3", "Cell4"])];
+    /// let table = Table::new(rows, [5, 5]).column_highlight_style_fn(move |i| {
+    ///     if Some(i) == selected_row {
+    ///         Style::new().bold()
+    ///     } else {
+    ///         Style::new().dim()
+    ///     }
+    /// });
     /// ```
-    /// [`Color`]: ratatui_core::style::Color
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn cell_highlight_style<S: Into<Style>>(mut self, highlight_style: S) -> Self {
-        self.cell_highlight_style = highlight_style.into();
+    pub fn column_highlight_style_fn<F>(mut self, style: F) -> Self
+    where
+        F: Fn(usize) -> Style + 'a,
+    {
+        self.column_highlight_style_fn = Some(ColumnHighlightFn::new(style));
         self
     }
 
-    /// Set the symbol to be displayed in front of the selected row
+    /// Set the style of the header cell of the selected column
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The [`Table::column_highlight_style`] only applies to the rows of the table, so this style
+    /// allows the header of the selected column to be styled differently from its cells. It is
+    /// applied on top of the style of the header.
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use ratatui::layout::Constraint;
-    /// use ratatui::widgets::{Cell, Row, Table};
-    ///
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// let rows = [Row::new(["Cell1", "Cell2"])];
+    /// let table = Table::new(rows, [5, 5])
+    ///     .header(Row::new(["Head1", "Head2"]))
+    ///     .selected_column_header_style(Style::new().bold())
+    ///     .column_highlight_style(Style::new().dim());
+    /// ```
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selected_column_header_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selected_column_header_style = style.into();
+        self
+    }
+
+    /// Set the style of the selected cell
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style will be applied to the selected cell, and will override any style set on the
+    /// row or on the individual cells.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{layout::Constraint, style::{Style, Stylize}, widgets::{Row, Table}};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).cell_highlight_style(Style::new().red().italic());
+    /// ```
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn cell_highlight_style<S: Into<Style>>(mut self, highlight_style: S) -> Self {
+        self.cell_highlight_style = highlight_style.into();
+        self
+    }
+
+    /// Set the style of the cells of the selected range
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style will be applied to each visible cell of the range selected with
+    /// [`TableState::select_range`]. It overrides the styles of the selected row and column, and
+    /// is overridden by the style of the selected cell.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{layout::Constraint, style::{Style, Stylize}, widgets::{Row, Table}};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).range_highlight_style(Style::new().on_blue());
+    /// ```
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn range_highlight_style<S: Into<Style>>(mut self, highlight_style: S) -> Self {
+        self.range_highlight_style = highlight_style.into();
+        self
+    }
+
+    /// Set the style of the rows in the selection set
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style will be applied to each visible row of the set of rows selected with
+    /// [`TableState::toggle`] and [`TableState::select_range_to`]. The style of the selected row is
+    /// applied on top of it, so the selected row stands out when it is also in the set.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{layout::Constraint, style::{Style, Stylize}, widgets::{Row, Table}};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).multi_highlight_style(Style::new().on_blue());
+    /// ```
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn multi_highlight_style<S: Into<Style>>(mut self, highlight_style: S) -> Self {
+        self.multi_highlight_style = highlight_style.into();
+        self
+    }
+
+    /// Set the symbol to be displayed in front of the selected row
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::widgets::{Cell, Row, Table};
+    ///
     /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
     /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
     /// let table = Table::new(rows, widths).highlight_symbol(">>");
@@ -658,6 +1293,33 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Set the symbol to be displayed in front of the rows in the selection set
+    ///
+    /// The symbol is displayed in the same column as the [`Table::highlight_symbol`], which is
+    /// displayed instead for the selected row. The column is as wide as the widest of the two
+    /// symbols, and is shown when a row is selected or the selection set is not empty (see
+    /// [`Table::highlight_spacing`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .highlight_symbol("> ")
+    ///     .multi_highlight_symbol("* ");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn multi_highlight_symbol<T: Into<Text<'a>>>(mut self, highlight_symbol: T) -> Self {
+        self.multi_highlight_symbol = highlight_symbol.into();
+        self
+    }
+
     /// Set when to show the highlight spacing
     ///
     /// The highlight spacing is the spacing that is allocated for the selection symbol column (if
@@ -692,6 +1354,31 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Set the number of rows around the selected row that should be kept visible
+    ///
+    /// When the selection moves, the table is scrolled so that `padding` rows stay visible above
+    /// and below the selected row, except at the start and the end of the table. When the area is
+    /// too short to display the padding on both sides, the padding is reduced, which keeps the
+    /// selected row near the middle of the area.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// A padding value of 1 will keep 1 row above and 1 row below visible if possible
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// let rows = [Row::new(["Cell1"])];
+    /// let table = Table::new(rows, [5]).scroll_padding(1);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn scroll_padding(mut self, padding: usize) -> Self {
+        self.scroll_padding = padding;
+        self
+    }
+
     /// Set how extra space is distributed amongst columns.
     ///
     /// This determines how the space is distributed when the constraints are satisfied. By default,
@@ -720,6 +1407,301 @@ impl<'a> Table<'a> {
         self.flex = flex;
         self
     }
+
+    /// Set whether the columns can be scrolled horizontally
+    ///
+    /// By default, when the columns need more space than is available, they are shrunk to fit the
+    /// width of the table. When horizontal scrolling is enabled, the columns keep the width they
+    /// need instead, and the table only displays the columns starting at
+    /// [`TableState::column_offset`]. Columns that don't fit in the table are cut off at the right
+    /// edge.
+    ///
+    /// When a column is selected, the column offset is adjusted when rendering so that the
+    /// selected column is visible.
+    ///
+    /// The width a column needs is given by its constraint: the value of [`Constraint::Length`],
+    /// [`Constraint::Min`] and [`Constraint::Max`], the proportion of the table width for
+    /// [`Constraint::Percentage`] and [`Constraint::Ratio`], and nothing for [`Constraint::Fill`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::widgets::{Row, Table, TableState};
+    ///
+    /// let rows = [Row::new((0..20).map(|i| format!("Cell{i}")))];
+    /// let table = Table::new(rows, [Constraint::Length(10); 20]).horizontal_scroll(true);
+    ///
+    /// // display the columns starting at the fifth one
+    /// let mut state = TableState::new().with_column_offset(4);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn horizontal_scroll(mut self, horizontal_scroll: bool) -> Self {
+        self.horizontal_scroll = horizontal_scroll;
+        self
+    }
+
+    /// Set the number of leading columns that stay visible when scrolling horizontally
+    ///
+    /// This only has an effect when [`Table::horizontal_scroll`] is enabled. The frozen columns
+    /// are always displayed at the left of the table, followed by a `│` separator, and the other
+    /// columns scroll in the remaining width. [`TableState::column_offset`] is then the index of
+    /// the first displayed column after the frozen columns. When the frozen columns are wider than
+    /// the table, they are cut off at the right edge and the other columns are hidden.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// let rows = [Row::new((0..20).map(|i| format!("Cell{i}")))];
+    /// // the first column stays visible while the others scroll
+    /// let table = Table::new(rows, [Constraint::Length(10); 20])
+    ///     .horizontal_scroll(true)
+    ///     .frozen_columns(1);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn frozen_columns(mut self, frozen_columns: u16) -> Self {
+        self.frozen_columns = frozen_columns;
+        self
+    }
+
+    /// Set whether the content of the cells is word-wrapped to the width of their column
+    ///
+    /// By default, the lines of a cell that are wider than its column are truncated. When wrapping
+    /// is enabled, the lines are wrapped on word boundaries, or on grapheme boundaries for words
+    /// that are wider than the column, and the height of each row grows to the number of lines of
+    /// its tallest cell. The height set with [`Row::height`] is used as the minimum height of the
+    /// row, and [`Table::max_row_height`] can be used to limit how tall a row can grow. Scrolling
+    /// and selection take the resulting row heights into account.
+    ///
+    /// The cells of the header are wrapped too, except for the cell of the sorted column, and the
+    /// header grows to fit them unless its height is set with [`Row::height`]. The footer is not
+    /// wrapped.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// let rows = [Row::new(["Name", "A long description that wraps"])];
+    /// let table = Table::new(rows, [Constraint::Length(5), Constraint::Length(10)])
+    ///     .wrap_cells(true)
+    ///     .max_row_height(3);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn wrap_cells(mut self, wrap_cells: bool) -> Self {
+        self.wrap_cells = wrap_cells;
+        self
+    }
+
+    /// Set the maximum height of rows whose cells are wrapped
+    ///
+    /// This only has an effect when [`Table::wrap_cells`] is enabled. The lines of a cell that
+    /// exceed the maximum height are not displayed. `None` (the default) lets rows grow to fit all
+    /// of their lines.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Table;
+    ///
+    /// let table = Table::default().wrap_cells(true).max_row_height(2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn max_row_height<T: Into<Option<u16>>>(mut self, max_row_height: T) -> Self {
+        self.max_row_height = max_row_height.into();
+        self
+    }
+
+    /// Set whether the details of the selected row are displayed below it
+    ///
+    /// By default, the details of a row (see [`Row::details`]) are only displayed when the row is
+    /// expanded with [`TableState::toggle_expanded`]. When this is enabled, the selected row is
+    /// expanded too, which gives a master-detail table where the details follow the selection and
+    /// push the following rows down. Scrolling keeps the selected row and its details visible.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// let rows = [
+    ///     Row::new(["notes.txt", "4 KiB"]).details("Modified yesterday"),
+    ///     Row::new(["todo.md", "1 KiB"]).details("Modified today"),
+    /// ];
+    /// let table = Table::new(rows, [10, 5])
+    ///     .expand_selected(true)
+    ///     .details_indent(2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn expand_selected(mut self, expand_selected: bool) -> Self {
+        self.expand_selected = expand_selected;
+        self
+    }
+
+    /// Set the number of columns the details of the rows are indented by
+    ///
+    /// The details of an expanded row start below its first column, and span the columns up to
+    /// the right edge of the table. The indent moves their start to the right, so that they stand
+    /// out from the cells of the rows. The default indent is `0`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Table;
+    ///
+    /// let table = Table::default().details_indent(2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn details_indent(mut self, indent: u16) -> Self {
+        self.details_indent = indent;
+        self
+    }
+
+    /// Set the symbols appended to the header cell of the sorted column
+    ///
+    /// The `ascending` symbol is displayed when the rows are sorted in [`SortDirection::Ascending`]
+    /// order and the `descending` symbol when they are sorted in [`SortDirection::Descending`]
+    /// order. The sorted column is set with [`TableState::sort_column`] or
+    /// [`Table::sort_indicator`]. The symbols are appended to the last line of the header cell, so
+    /// they should usually start with a space. The default symbols are `" ▲"` and `" ▼"`.
+    ///
+    /// Both parameters accept any value that can be converted into a [`Span`] (e.g. `&str`,
+    /// `String`, or a styled [`Span`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Row, Table, TableState};
+    ///
+    /// let table = Table::default()
+    ///     .header(Row::new(["Name", "Size"]))
+    ///     .sort_indicator_symbols(" ↑", " ↓");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn sort_indicator_symbols<A, D>(mut self, ascending: A, descending: D) -> Self
+    where
+        A: Into<Span<'a>>,
+        D: Into<Span<'a>>,
+    {
+        self.sort_indicator_symbols = (ascending.into(), descending.into());
+        self
+    }
+
+    /// Set the column whose header displays the sort indicator, and its sort direction
+    ///
+    /// The symbol of the direction (see [`Table::sort_indicator_symbols`]) is appended to the
+    /// header cell of the column, and the content of the cell is truncated so that the symbol fits
+    /// in the width of the cell. This is for tables whose rows are already sorted by the
+    /// application, e.g. tables rendered without a [`TableState`]. Only one column displays the
+    /// indicator: the sorted column of the state ([`TableState::sort_column`]) takes precedence
+    /// over this column when it is set.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Row, SortDirection, Table};
+    ///
+    /// let rows = [Row::new(["b", "20"]), Row::new(["a", "30"])];
+    /// let table = Table::new(rows, [5, 5])
+    ///     .header(Row::new(["Name", "Size"]))
+    ///     .sort_indicator(1, SortDirection::Ascending);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn sort_indicator(mut self, column: usize, direction: SortDirection) -> Self {
+        self.sort_indicator = Some((column, direction));
+        self
+    }
+
+    /// Sorts the rows by the column set in [`TableState::sort_column`]
+    ///
+    /// Rows don't give access to their content, so `compare` is called with the index of the
+    /// sorted column and the indexes of two rows, in the order they were added to the table, and
+    /// should compare the application data these rows are built from. The comparison is reversed
+    /// for [`SortDirection::Descending`]. The sort is stable, and the rows are moved rather than
+    /// cloned. The rows are left in their original order if no column is sorted.
+    ///
+    /// When [`TableState::selection_follows_sort`] is enabled, the order of the rows is remembered
+    /// in the state so that the selection stays on the same row when the sort changes. In that
+    /// case, the table should be built from the data in the same order each time it is sorted.
+    ///
+    /// This should be called after the rows are set, and has no effect on rows created with
+    /// [`Table::rows_from`]. This is a fluent setter method which must be chained or used as it
+    /// consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::widgets::{Row, Table, TableState};
+    ///
+    /// let files = [("b.txt", 20), ("a.txt", 30), ("c.txt", 10)];
+    /// let mut state = TableState::new().with_selection_follows_sort(true);
+    /// state.toggle_sort(0);
+    ///
+    /// let rows = files
+    ///     .iter()
+    ///     .map(|(name, size)| Row::new([name.to_string(), size.to_string()]));
+    /// let table = Table::new(rows, [Constraint::Length(5); 2])
+    ///     .header(Row::new(["Name", "Size"]))
+    ///     .sorted_by(&mut state, |column, a, b| match column {
+    ///         0 => files[a].0.cmp(files[b].0),
+    ///         _ => files[a].1.cmp(&files[b].1),
+    ///     });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn sorted_by<F>(mut self, state: &mut TableState, mut compare: F) -> Self
+    where
+        F: FnMut(usize, usize, usize) -> Ordering,
+    {
+        if self.virtual_rows.is_some() {
+            return self;
+        }
+        let mut order = (0..self.rows.len()).collect_vec();
+        if let Some((column, direction)) = state.sort_column {
+            order.sort_by(|&a, &b| match direction {
+                SortDirection::Ascending => compare(column, a, b),
+                SortDirection::Descending => compare(column, a, b).reverse(),
+            });
+        }
+
+        if state.selection_follows_sort {
+            if let Some(selected) = state.selected {
+                let selected_row = state.sort_order.get(selected).copied().unwrap_or(selected);
+                state.selected = order
+                    .iter()
+                    .position(|&row| row == selected_row)
+                    .or(Some(selected));
+            }
+            state.sort_order.clone_from(&order);
+        }
+
+        let mut rows = mem::take(&mut self.rows)
+            .into_iter()
+            .map(Some)
+            .collect_vec();
+        self.rows = order.iter().filter_map(|&row| rows[row].take()).collect();
+        self
+    }
 }
 
 impl Widget for Table<'_> {
@@ -747,20 +1729,27 @@ impl StatefulWidget for &Table<'_> {
     type State = TableState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        buf.set_style(area, self.style);
+        if let Some(virtual_rows) = &self.virtual_rows {
+            self.render_virtual(virtual_rows, area, buf, state);
+            return;
+        }
+        buf.set_style(area, self.style);
         self.block.as_ref().render(area, buf);
         let table_area = self.block.inner_if_some(area);
+        state.column_positions.clear();
+        state.row_areas.clear();
         if table_area.is_empty() {
             return;
         }
 
         if state.selected.is_some_and(|s| s >= self.rows.len()) {
-            state.select(Some(self.rows.len().saturating_sub(1)));
+            state.selected = Some(self.rows.len().saturating_sub(1));
         }
 
         if self.rows.is_empty() {
             state.select(None);
         }
+        state.selected_set.split_off(&self.rows.len());
 
         let column_count = self.column_count();
         if state.selected_column.is_some_and(|s| s >= column_count) {
@@ -769,29 +1758,189 @@ impl StatefulWidget for &Table<'_> {
         if column_count == 0 {
             state.select_column(None);
         }
+        if let Some(((top, left), (bottom, right))) = state.selected_range {
+            state.selected_range = (top < self.rows.len() && left < column_count).then(|| {
+                let bottom = bottom.min(self.rows.len() - 1);
+                ((top, left), (bottom, right.min(column_count - 1)))
+            });
+        }
+        // a selected cell is clamped to the cells of its row, which can be fewer than the columns
+        if let Some((row, column)) = state.selected_cell() {
+            let row_columns: usize = self.rows[row].cells.iter().map(Cell::column_span).sum();
+            if row_columns > 0 && column >= row_columns {
+                state.select_column(Some(row_columns.min(column_count).saturating_sub(1)));
+            }
+        }
 
         let selection_width = self.selection_width(state);
-        let column_widths = self.get_column_widths(table_area.width, selection_width, column_count);
-        let (header_area, rows_area, footer_area) = self.layout(table_area);
+        let ((header_area, rows_area, footer_area), mut column_widths) =
+            self.layout_columns(table_area, state, column_count);
+        let separator_x = if self.horizontal_scroll {
+            self.scroll_columns(&mut column_widths, table_area.width, state)
+        } else {
+            None
+        };
+        state.column_positions = column_widths
+            .iter()
+            .map(|&(x, width)| (table_area.x.saturating_add(x), width))
+            .collect();
 
-        self.render_header(header_area, buf, &column_widths);
+        self.render_header(header_area, buf, state, &column_widths);
 
         self.render_rows(rows_area, buf, state, selection_width, &column_widths);
 
         self.render_footer(footer_area, buf, &column_widths);
+
+        self.join_column_separators(area, table_area, buf, &column_widths);
+
+        if let Some(x) = separator_x {
+            for y in table_area.top()..table_area.bottom() {
+                buf[(table_area.x + x, y)].set_symbol(symbols::line::VERTICAL);
+            }
+        }
     }
 }
 
 // private methods for rendering
 impl Table<'_> {
-    /// Splits the table area into a header, rows area and a footer
-    fn layout(&self, area: Rect) -> (Rect, Rect, Rect) {
+    /// Renders a table with virtual rows by creating its visible rows and rendering them as a
+    /// table of their own.
+    fn render_virtual(
+        &self,
+        virtual_rows: &VirtualRows,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut TableState,
+    ) {
+        let len = virtual_rows.len;
+        if state.selected.is_some_and(|s| s >= len) {
+            state.selected = Some(len.saturating_sub(1));
+        }
+        if len == 0 {
+            state.select(None);
+        }
+        state.selected_set.split_off(&len);
+        if let Some(((top, left), (bottom, right))) = state.selected_range {
+            state.selected_range = (top < len).then(|| ((top, left), (bottom.min(len - 1), right)));
+        }
+
+        let table_area = self.block.inner_if_some(area);
+        let ((_, rows_area, _), _) = self.layout_columns(table_area, state, self.column_count());
+        let (start, end) =
+            visible_rows::visible_rows(len, state, self.scroll_padding, rows_area.height, |i| {
+                let height = virtual_rows.height(i);
+                (height, height)
+            });
+        state.offset = start;
+
+        let window = Table {
+            rows: (start..end).map(|i| virtual_rows.row(i)).collect(),
+            virtual_rows: None,
+            row_style_fn: self
+                .row_style_fn
+                .as_ref()
+                .map(|style| style.with_offset(start)),
+            column_highlight_style_fn: self
+                .column_highlight_style_fn
+                .as_ref()
+                .map(|style| style.with_offset(start)),
+            // the selected row may be outside of the window while scrolling to a target, so the
+            // spacing of the selection symbol is decided by the selection of the whole table
+            highlight_spacing: if self.selection_width(state) > 0 {
+                HighlightSpacing::Always
+            } else {
+                HighlightSpacing::Never
+            },
+            ..self.clone()
+        };
+        // the state of the window, where the rows are indexed from the first visible row
+        let mut window_state = TableState {
+            offset: 0,
+            selected: state
+                .selected
+                .and_then(|selected| selected.checked_sub(start))
+                .filter(|&selected| selected < end - start),
+            scroll_target: None,
+            selected_range: state
+                .selected_range
+                .and_then(|((top, left), (bottom, right))| {
+                    let (top, bottom) = (top.max(start), bottom.min(end.saturating_sub(1)));
+                    (top <= bottom).then(|| ((top - start, left), (bottom - start, right)))
+                }),
+            selected_set: state
+                .selected_set
+                .range(start..end)
+                .map(|index| index - start)
+                .collect(),
+            expanded: state
+                .expanded
+                .range(start..end)
+                .map(|index| index - start)
+                .collect(),
+            sort_order: Vec::new(),
+            ..state.clone()
+        };
+        StatefulWidget::render(&window, area, buf, &mut window_state);
+
+        state.selected_column = window_state.selected_column;
+        state.column_offset = window_state.column_offset;
+        state.column_positions = window_state.column_positions;
+        state.row_areas = window_state
+            .row_areas
+            .into_iter()
+            .map(|(i, row_area)| (i + start, row_area))
+            .collect();
+        if let (Some(((top, _), (bottom, _))), Some(((_, left), (_, right)))) =
+            (state.selected_range, window_state.selected_range)
+        {
+            state.selected_range = Some(((top, left), (bottom, right)));
+        }
+    }
+
+    /// Lays out the header, rows and footer areas of the table and the columns in `table_area`.
+    ///
+    /// The wrapped header cells can only be measured once the columns are laid out, so the areas
+    /// are laid out again when the wrapped header has another height.
+    fn layout_columns(
+        &self,
+        table_area: Rect,
+        state: &TableState,
+        column_count: usize,
+    ) -> ((Rect, Rect, Rect), Vec<(u16, u16)>) {
+        let header_height = self.header_height(&[]);
+        let mut areas = self.layout(table_area, header_height);
+        let selection_width = self.selection_width(state);
+        let column_widths = match self.resized_widths(state, table_area.width, areas.1) {
+            Some(widths) => self.split_columns(widths, table_area.width, selection_width),
+            None => self.get_column_widths(table_area.width, selection_width, column_count),
+        };
+        let wrapped_header_height = self.header_height(&column_widths);
+        if wrapped_header_height != header_height {
+            areas = self.layout(table_area, wrapped_header_height);
+        }
+        (areas, column_widths)
+    }
+
+    /// Splits the area into the header, rows and footer areas, given the height of the header.
+    ///
+    /// The footer is dropped when the area is too short to display it along with the header and
+    /// at least one line of rows.
+    fn layout(&self, area: Rect, header_height: u16) -> (Rect, Rect, Rect) {
         let header_top_margin = self.header.as_ref().map_or(0, |h| h.top_margin);
-        let header_height = self.header.as_ref().map_or(0, |h| h.height);
         let header_bottom_margin = self.header.as_ref().map_or(0, |h| h.bottom_margin);
-        let footer_top_margin = self.footer.as_ref().map_or(0, |h| h.top_margin);
-        let footer_height = self.footer.as_ref().map_or(0, |f| f.height);
-        let footer_bottom_margin = self.footer.as_ref().map_or(0, |h| h.bottom_margin);
+        let header_total = header_top_margin
+            .saturating_add(header_height)
+            .saturating_add(header_bottom_margin);
+        let footer = self.footer.as_ref().filter(|footer| {
+            let footer_total = footer
+                .top_margin
+                .saturating_add(footer.height)
+                .saturating_add(footer.bottom_margin);
+            header_total.saturating_add(footer_total) < area.height
+        });
+        let footer_top_margin = footer.map_or(0, |h| h.top_margin);
+        let footer_height = footer.map_or(0, |f| f.height);
+        let footer_bottom_margin = footer.map_or(0, |h| h.bottom_margin);
         let layout = Layout::vertical([
             Constraint::Length(header_top_margin),
             Constraint::Length(header_height),
@@ -806,21 +1955,92 @@ impl Table<'_> {
         (header_area, rows_area, footer_area)
     }
 
-    fn render_header(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
+    /// Highlights the visible cells of the selected range, given the areas of its visible rows.
+    fn render_range_highlight(
+        &self,
+        buf: &mut Buffer,
+        state: &TableState,
+        columns_widths: &[(u16, u16)],
+        row_areas: &[Rect],
+    ) {
+        let Some(((_, left), (_, right))) = state.selected_range else {
+            return;
+        };
+        // the columns scrolled out of view have a width of 0
+        let range_columns = columns_widths
+            .iter()
+            .take(right + 1)
+            .skip(left)
+            .filter(|(_, width)| *width > 0);
+        for &(x, width) in range_columns {
+            for row_area in row_areas {
+                let cell_area = Rect {
+                    x: row_area.x + x,
+                    width,
+                    ..*row_area
+                };
+                buf.set_style(cell_area, self.range_highlight_style);
+            }
+        }
+    }
+
+    /// Applies the default style of `column` to the area of one of its cells and returns the
+    /// default alignment of the column.
+    fn apply_column_defaults(
+        &self,
+        column: usize,
+        area: Rect,
+        buf: &mut Buffer,
+    ) -> Option<Alignment> {
+        if let Some(&style) = self.column_styles.get(column) {
+            buf.set_style(area, style);
+        }
+        self.column_alignments.get(column).copied()
+    }
+
+    fn render_header(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &TableState,
+        column_widths: &[(u16, u16)],
+    ) {
         if let Some(ref header) = self.header {
             buf.set_style(area, header.style);
-            for ((x, width), cell) in column_widths.iter().zip(header.cells.iter()) {
-                cell.render(Rect::new(area.x + x, area.y, *width, area.height), buf);
+            for (columns, x, width, cell) in cell_positions(&header.cells, column_widths) {
+                let cell_area = Rect::new(area.x + x, area.y, width, area.height);
+                let alignment = self.apply_column_defaults(columns.start, cell_area, buf);
+                match self.sort_column(state) {
+                    Some((column, direction)) if columns.contains(&column) => {
+                        let symbol = match direction {
+                            SortDirection::Ascending => &self.sort_indicator_symbols.0,
+                            SortDirection::Descending => &self.sort_indicator_symbols.1,
+                        };
+                        cell.render_with_suffix(symbol, cell_area, buf, alignment);
+                    }
+                    _ if self.wrap_cells => cell.render_wrapped(cell_area, buf, alignment),
+                    _ => cell.render(cell_area, buf, alignment),
+                }
+                if state
+                    .selected_column
+                    .is_some_and(|column| columns.contains(&column))
+                {
+                    buf.set_style(cell_area, self.selected_column_header_style);
+                }
             }
+            self.render_column_separators(area, buf, &header.cells, column_widths);
         }
     }
 
     fn render_footer(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
         if let Some(ref footer) = self.footer {
             buf.set_style(area, footer.style);
-            for ((x, width), cell) in column_widths.iter().zip(footer.cells.iter()) {
-                cell.render(Rect::new(area.x + x, area.y, *width, area.height), buf);
+            for (columns, x, width, cell) in cell_positions(&footer.cells, column_widths) {
+                let cell_area = Rect::new(area.x + x, area.y, width, area.height);
+                let alignment = self.apply_column_defaults(columns.start, cell_area, buf);
+                cell.render(cell_area, buf, alignment);
             }
+            self.render_column_separators(area, buf, &footer.cells, column_widths);
         }
     }
 
@@ -836,12 +2056,20 @@ impl Table<'_> {
             return;
         }
 
-        let (start_index, end_index) = self.visible_rows(state, area);
+        let (start_index, end_index) = self.visible_rows(state, area, columns_widths);
         state.offset = start_index;
 
         let mut y_offset = 0;
 
         let mut selected_row_area = None;
+        // the cells spanning the selected column along with other columns, which are highlighted
+        // as a whole
+        let mut spanned_column_areas = Vec::new();
+        let mut selected_cell_area = None;
+        let mut range_row_areas = Vec::new();
+        let mut row_areas = Vec::new();
+        let (details_x, details_width) =
+            details_columns(area.width, columns_widths, self.details_indent);
         for (i, row) in self
             .rows
             .iter()
@@ -849,30 +2077,65 @@ impl Table<'_> {
             .skip(start_index)
             .take(end_index - start_index)
         {
+            let row_height = self.row_height(row, columns_widths);
             let y = area.y + y_offset + row.top_margin;
-            let height = (y + row.height).min(area.bottom()).saturating_sub(y);
+            let height = (y + row_height).min(area.bottom()).saturating_sub(y);
             let row_area = Rect { y, height, ..area };
-            buf.set_style(row_area, row.style);
+            let row_style = self.row_style(i, row);
+            buf.set_style(row_area, row_style);
 
             let is_selected = state.selected.is_some_and(|index| index == i);
-            if selection_width > 0 && is_selected {
-                let selection_area = Rect {
-                    width: selection_width,
-                    ..row_area
-                };
-                buf.set_style(selection_area, row.style);
-                (&self.highlight_symbol).render(selection_area, buf);
+            let in_set = state.selected_set.contains(&i);
+            if selection_width > 0 && (is_selected || in_set) {
+                let selection_area = Rect::new(area.x, y, selection_width, height);
+                self.render_selection_symbol(selection_area, buf, row_style, is_selected);
             }
-            for ((x, width), cell) in columns_widths.iter().zip(row.cells.iter()) {
-                cell.render(
-                    Rect::new(row_area.x + x, row_area.y, *width, row_area.height),
-                    buf,
-                );
+            for (columns, x, width, cell) in cell_positions(&row.cells, columns_widths) {
+                let cell_area = Rect::new(row_area.x + x, row_area.y, width, row_area.height);
+                let alignment = self.apply_column_defaults(columns.start, cell_area, buf);
+                if self.wrap_cells {
+                    cell.render_wrapped(cell_area, buf, alignment);
+                } else {
+                    cell.render(cell_area, buf, alignment);
+                }
+                if state
+                    .selected_column
+                    .is_some_and(|column| columns.len() > 1 && columns.contains(&column))
+                {
+                    spanned_column_areas.push((i, cell_area));
+                    if is_selected {
+                        selected_cell_area = Some(cell_area);
+                    }
+                }
+            }
+            self.render_column_separators(row_area, buf, &row.cells, columns_widths);
+            let details_height = self.details_height(i, row, state, details_width);
+            if let Some(details) = row.details.as_ref().filter(|_| details_height > 0) {
+                let (x, y) = (area.x + details_x, y + row_height);
+                let details_area =
+                    Rect::new(x, y, details_width, details_height).intersection(area);
+                buf.set_style(details_area, row_style);
+                details.render_wrapped(details_area, buf, None);
+            }
+            if in_set {
+                buf.set_style(row_area, self.multi_highlight_style);
             }
             if is_selected {
                 selected_row_area = Some(row_area);
             }
-            y_offset += row.height_with_margin();
+            row_areas.push((i, row_area));
+            let hit_area = Rect {
+                height: row_area.height.saturating_add(details_height),
+                ..row_area
+            };
+            state.row_areas.push((i, hit_area.intersection(area)));
+            if state
+                .selected_range
+                .is_some_and(|((top, _), (bottom, _))| (top..=bottom).contains(&i))
+            {
+                range_row_areas.push(row_area);
+            }
+            y_offset += row.height_with_margin(row_height + details_height);
         }
 
         let selected_column_area = state.selected_column.and_then(|s| {
@@ -885,70 +2148,240 @@ impl Table<'_> {
             })
         });
 
-        match (selected_row_area, selected_column_area) {
-            (Some(row_area), Some(col_area)) => {
-                buf.set_style(row_area, self.row_highlight_style);
-                buf.set_style(col_area, self.column_highlight_style);
-                let cell_area = row_area.intersection(col_area);
-                buf.set_style(cell_area, self.cell_highlight_style);
-            }
-            (Some(row_area), None) => {
-                buf.set_style(row_area, self.row_highlight_style);
+        if let Some(row_area) = selected_row_area {
+            buf.set_style(row_area, self.row_highlight_style);
+        }
+        if let Some(col_area) = selected_column_area {
+            self.render_column_highlight(buf, col_area, &row_areas, &spanned_column_areas);
+        }
+
+        self.render_range_highlight(buf, state, columns_widths, &range_row_areas);
+
+        if let (Some(row_area), Some(col_area)) = (selected_row_area, selected_column_area) {
+            let cell_area = selected_cell_area.unwrap_or_else(|| row_area.intersection(col_area));
+            buf.set_style(cell_area, self.cell_highlight_style);
+        }
+    }
+
+    /// Draws the column separators in the area of a row, except between the columns spanned by one
+    /// of its cells.
+    fn render_column_separators(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        cells: &[Cell],
+        column_widths: &[(u16, u16)],
+    ) {
+        let Some(separator) = self.column_separator else {
+            return;
+        };
+        let spans: Vec<Range<usize>> = cell_positions(cells, column_widths)
+            .map(|(columns, ..)| columns)
+            .filter(|columns| columns.len() > 1)
+            .collect();
+        for (column, x) in separator_positions(column_widths) {
+            let x = area.x.saturating_add(x);
+            if x >= area.right()
+                || spans
+                    .iter()
+                    .any(|columns| columns.start < column && column < columns.end)
+            {
+                continue;
             }
-            (None, Some(col_area)) => {
-                buf.set_style(col_area, self.column_highlight_style);
+            for y in area.top()..area.bottom() {
+                buf[(x, y)]
+                    .set_symbol(separator)
+                    .set_style(self.column_separator_style);
             }
-            (None, None) => (),
         }
     }
 
-    /// Return the indexes of the visible rows.
+    /// Replaces the top and bottom borders of the block with tees where the column separators
+    /// meet them.
     ///
-    /// The algorithm works as follows:
-    /// - start at the offset and calculate the height of the rows that can be displayed within the
-    ///   area.
-    /// - if the selected row is not visible, scroll the table to ensure it is visible.
-    /// - if there is still space to fill then there's a partial row at the end which should be
-    ///   included in the view.
-    fn visible_rows(&self, state: &TableState, area: Rect) -> (usize, usize) {
-        let last_row = self.rows.len().saturating_sub(1);
-        let mut start = state.offset.min(last_row);
+    /// Only plain, thick and double borders are joined, and the borders covered by a title are left
+    /// as they are.
+    fn join_column_separators(
+        &self,
+        area: Rect,
+        table_area: Rect,
+        buf: &mut Buffer,
+        column_widths: &[(u16, u16)],
+    ) {
+        let Some(separator) = self.column_separator.filter(|_| self.block.is_some()) else {
+            return;
+        };
+        let mut edges = Vec::with_capacity(2);
+        if table_area.top() > area.top() {
+            edges.push((table_area.top(), table_area.top() - 1, true));
+        }
+        if table_area.bottom() < area.bottom() {
+            edges.push((table_area.bottom() - 1, table_area.bottom(), false));
+        }
+        for (_, x) in separator_positions(column_widths) {
+            let x = table_area.x.saturating_add(x);
+            if x >= table_area.right() {
+                continue;
+            }
+            for &(inner_y, border_y, down) in &edges {
+                if buf[(x, inner_y)].symbol() != separator {
+                    continue;
+                }
+                let tee = [
+                    symbols::line::NORMAL,
+                    symbols::line::THICK,
+                    symbols::line::DOUBLE,
+                ]
+                .into_iter()
+                .find(|set| set.horizontal == buf[(x, border_y)].symbol())
+                .map(|set| {
+                    if down {
+                        set.horizontal_down
+                    } else {
+                        set.horizontal_up
+                    }
+                });
+                if let Some(tee) = tee {
+                    buf[(x, border_y)].set_symbol(tee);
+                }
+            }
+        }
+    }
 
-        if let Some(selected) = state.selected {
-            start = start.min(selected);
+    /// Highlights the selected column, given the areas of the visible rows and of the cells
+    /// spanning the selected column along with other columns, which are highlighted as a whole.
+    fn render_column_highlight(
+        &self,
+        buf: &mut Buffer,
+        column_area: Rect,
+        row_areas: &[(usize, Rect)],
+        spanned_column_areas: &[(usize, Rect)],
+    ) {
+        buf.set_style(column_area, self.column_highlight_style);
+        for &(_, cell_area) in spanned_column_areas {
+            buf.set_style(cell_area, self.column_highlight_style);
+        }
+        let Some(style_fn) = &self.column_highlight_style_fn else {
+            return;
+        };
+        for &(i, row_area) in row_areas {
+            let cell_area = spanned_column_areas
+                .iter()
+                .find(|&&(row, _)| row == i)
+                .map_or_else(|| row_area.intersection(column_area), |&(_, area)| area);
+            buf.set_style(cell_area, style_fn.style(i));
         }
+    }
 
-        let mut end = start;
-        let mut height = 0;
+    /// Returns the style of a row, which is patched over the style computed by the row style
+    /// callback.
+    fn row_style(&self, index: usize, row: &Row) -> Style {
+        self.row_style_fn
+            .as_ref()
+            .map_or(row.style, |style| style.style(index, row).patch(row.style))
+    }
 
-        for item in self.rows.iter().skip(start) {
-            if height + item.height > area.height {
-                break;
-            }
-            height += item.height_with_margin();
-            end += 1;
+    /// Renders the symbol of the selected row, or of a row of the selection set.
+    fn render_selection_symbol(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        style: Style,
+        is_selected: bool,
+    ) {
+        buf.set_style(area, style);
+        if is_selected {
+            (&self.highlight_symbol).render(area, buf);
+        } else {
+            (&self.multi_highlight_symbol).render(area, buf);
         }
+    }
 
-        if let Some(selected) = state.selected {
-            let selected = selected.min(last_row);
+    /// Return the indexes of the visible rows, see [`visible_rows::visible_rows`].
+    fn visible_rows(
+        &self,
+        state: &TableState,
+        area: Rect,
+        column_widths: &[(u16, u16)],
+    ) -> (usize, usize) {
+        let (_, details_width) = details_columns(area.width, column_widths, self.details_indent);
+        visible_rows::visible_rows(
+            self.rows.len(),
+            state,
+            self.scroll_padding,
+            area.height,
+            |index| {
+                // the height of a row includes its details when it is expanded
+                let row = &self.rows[index];
+                let details_height = self.details_height(index, row, state, details_width);
+                let height = self
+                    .row_height(row, column_widths)
+                    .saturating_add(details_height);
+                (height, row.height_with_margin(height))
+            },
+        )
+    }
 
-            // scroll down until the selected row is visible
-            while selected >= end {
-                height = height.saturating_add(self.rows[end].height_with_margin());
-                end += 1;
-                while height > area.height {
-                    height = height.saturating_sub(self.rows[start].height_with_margin());
-                    start += 1;
-                }
-            }
+    /// Returns the height of a row, without its margins.
+    ///
+    /// When cells are wrapped, this is the number of lines of the tallest wrapped cell, limited by
+    /// the maximum row height, or the height of the row if that is taller.
+    fn row_height(&self, row: &Row, column_widths: &[(u16, u16)]) -> u16 {
+        if !self.wrap_cells {
+            return row.height;
         }
+        let wrapped_height = cell_positions(&row.cells, column_widths)
+            .map(|(_, _, width, cell)| cell.wrapped_height(width))
+            .max()
+            .unwrap_or_default();
+        let wrapped_height = self
+            .max_row_height
+            .map_or(wrapped_height, |max| wrapped_height.min(max));
+        row.height.max(wrapped_height)
+    }
 
-        // Include a partial row if there is space
-        if height < area.height && end < self.rows.len() {
-            end += 1;
+    /// Returns the height of the header, without its margins.
+    ///
+    /// Unless its height is fixed, the header is as tall as its tallest cell. When cells are
+    /// wrapped and the columns are laid out, the cells are measured once wrapped to the width of
+    /// their columns.
+    fn header_height(&self, column_widths: &[(u16, u16)]) -> u16 {
+        let Some(header) = &self.header else {
+            return 0;
+        };
+        if header.fixed_height {
+            header.height
+        } else if self.wrap_cells && !column_widths.is_empty() {
+            self.row_height(header, column_widths)
+        } else {
+            header
+                .cells
+                .iter()
+                .map(Cell::height)
+                .fold(header.height, u16::max)
         }
+    }
+
+    /// Returns the column whose header displays the sort indicator, and its sort direction.
+    fn sort_column(&self, state: &TableState) -> Option<(usize, SortDirection)> {
+        state.sort_column.or(self.sort_indicator)
+    }
 
-        (start, end)
+    /// Returns the height of the details of a row when it is expanded, or 0 if it is not expanded
+    /// or has no details.
+    ///
+    /// A row is expanded when its index is in [`TableState::expanded`], or when it is selected and
+    /// [`Table::expand_selected`] is enabled.
+    fn details_height(&self, index: usize, row: &Row, state: &TableState, width: u16) -> u16 {
+        let is_expanded = state.expanded.contains(&index)
+            || (self.expand_selected && state.selected == Some(index));
+        match &row.details {
+            Some(details) if is_expanded => {
+                let height = details.wrapped_height(width);
+                self.max_row_height.map_or(height, |max| height.min(max))
+            }
+            _ => 0,
+        }
     }
 
     /// Get all offsets and widths of all user specified columns.
@@ -961,23 +2394,232 @@ impl Table<'_> {
         selection_width: u16,
         col_count: usize,
     ) -> Vec<(u16, u16)> {
-        let widths = if self.widths.is_empty() {
+        let widths = self.column_constraints(max_width, col_count);
+        self.split_columns(widths, max_width, selection_width)
+    }
+
+    /// Returns the constraints of the columns, which are equal widths when `.widths()` has not
+    /// been called.
+    fn column_constraints(&self, max_width: u16, col_count: usize) -> Vec<Constraint> {
+        if self.widths.is_empty() {
             // Divide the space between each column equally
             vec![Constraint::Length(max_width / col_count.max(1) as u16); col_count]
         } else {
             self.widths.clone()
+        }
+    }
+
+    /// Returns the constraints of the columns with the columns resized by the user and the columns
+    /// sized to their content replaced by their width, or `None` if there are no such columns.
+    fn resized_widths(
+        &self,
+        state: &TableState,
+        max_width: u16,
+        rows_area: Rect,
+    ) -> Option<Vec<Constraint>> {
+        let overrides = &state.column_width_overrides;
+        let mut widths = match self.measured_widths(state, rows_area) {
+            Some(widths) => widths,
+            None if overrides.iter().any(Option::is_some) => {
+                self.column_constraints(max_width, self.column_count())
+            }
+            None => return None,
+        };
+        // the columns resized by the user take precedence over the widths of the table
+        for (width, width_override) in widths.iter_mut().zip(overrides) {
+            if let Some(width_override) = *width_override {
+                *width = Constraint::Length(width_override);
+            }
+        }
+        Some(widths)
+    }
+
+    /// Returns the constraints of the columns with the columns sized to their content replaced by
+    /// their measured width, or `None` if no column is sized to its content.
+    fn measured_widths(&self, state: &TableState, rows_area: Rect) -> Option<Vec<Constraint>> {
+        if !self
+            .column_widths
+            .iter()
+            .any(|width| matches!(width, ColumnWidth::Auto { .. }))
+        {
+            return None;
+        }
+        let rows = if self.measure_all_rows {
+            0..self.rows.len()
+        } else {
+            let (start, end) = self.visible_rows(state, rows_area, &[]);
+            start..end
+        };
+        let sort_indicator = self.sort_column(state).map(|(column, direction)| {
+            let symbol = match direction {
+                SortDirection::Ascending => &self.sort_indicator_symbols.0,
+                SortDirection::Descending => &self.sort_indicator_symbols.1,
+            };
+            (column, symbol.width() as u16)
+        });
+
+        let mut content_widths = vec![0_u16; self.column_widths.len()];
+        let mut measure = |row: &Row, sort_indicator: Option<(usize, u16)>| {
+            let mut column = 0;
+            for cell in &row.cells {
+                if cell.column_span() == 1 {
+                    let suffix_width = sort_indicator
+                        .filter(|&(sorted, _)| sorted == column)
+                        .map_or(0, |(_, width)| width);
+                    let width = cell.content_width().saturating_add(suffix_width);
+                    if let Some(content_width) = content_widths.get_mut(column) {
+                        *content_width = (*content_width).max(width);
+                    }
+                }
+                column += cell.column_span();
+            }
         };
+        if let Some(header) = &self.header {
+            measure(header, sort_indicator);
+        }
+        for row in self.footer.iter().chain(&self.rows[rows]) {
+            measure(row, None);
+        }
+
+        let widths = self
+            .column_widths
+            .iter()
+            .zip(content_widths)
+            .map(|(width, content_width)| width.constraint(content_width))
+            .collect();
+        Some(widths)
+    }
+
+    /// Lays out the columns with the given constraints, after the selection area.
+    ///
+    /// Returns (x, width) of each column.
+    fn split_columns(
+        &self,
+        widths: Vec<Constraint>,
+        max_width: u16,
+        selection_width: u16,
+    ) -> Vec<(u16, u16)> {
         // this will always allocate a selection area
-        let [_selection_area, columns_area] =
+        let [_selection_area, mut columns_area] =
             Layout::horizontal([Constraint::Length(selection_width), Constraint::Fill(0)])
                 .areas(Rect::new(0, 0, max_width, 1));
+        if self.horizontal_scroll {
+            // lay the columns out over a virtual area wide enough to not shrink any of them
+            let required_width = self.required_width(&widths, columns_area.width);
+            columns_area.width = columns_area
+                .width
+                .max(required_width)
+                .min(u16::MAX - columns_area.x);
+        }
         let rects = Layout::horizontal(widths)
             .flex(self.flex)
-            .spacing(self.column_spacing)
+            .spacing(self.spacing())
             .split(columns_area);
         rects.iter().map(|c| (c.x, c.width)).collect()
     }
 
+    /// Returns the width needed to display the columns without shrinking them.
+    ///
+    /// See [`Table::horizontal_scroll`] for the width needed by each constraint.
+    fn required_width(&self, widths: &[Constraint], max_width: u16) -> u16 {
+        let spacing = self
+            .spacing()
+            .saturating_mul(widths.len().saturating_sub(1) as u16);
+        widths
+            .iter()
+            .map(|constraint| match *constraint {
+                Constraint::Length(width) | Constraint::Min(width) | Constraint::Max(width) => {
+                    width
+                }
+                Constraint::Percentage(percentage) => {
+                    (u32::from(max_width) * u32::from(percentage) / 100) as u16
+                }
+                Constraint::Ratio(numerator, denominator) => {
+                    (u64::from(max_width) * u64::from(numerator) / u64::from(denominator.max(1)))
+                        .min(u64::from(u16::MAX)) as u16
+                }
+                Constraint::Bounded {
+                    ratio: (numerator, denominator),
+                    min,
+                    max,
+                } => (u64::from(max_width) * u64::from(numerator) / u64::from(denominator.max(1)))
+                    .min(u64::from(max))
+                    .max(u64::from(min)) as u16,
+                Constraint::Fill(_) => 0,
+            })
+            .fold(spacing, u16::saturating_add)
+    }
+
+    /// Scrolls the columns horizontally based on the column offset of the state.
+    ///
+    /// The column offset is first adjusted to ensure that the selected column is visible. The
+    /// columns before the offset and the columns that start beyond `max_width` are hidden by
+    /// setting their width to 0, and the column at the right edge is cut off to fit. The frozen
+    /// columns are never hidden.
+    ///
+    /// Returns the position of the separator after the frozen columns, if it is visible.
+    fn scroll_columns(
+        &self,
+        column_widths: &mut [(u16, u16)],
+        max_width: u16,
+        state: &mut TableState,
+    ) -> Option<u16> {
+        let Some(&(columns_x, _)) = column_widths.first() else {
+            state.column_offset = 0;
+            return None;
+        };
+        let frozen = usize::from(self.frozen_columns).min(column_widths.len());
+        // the separator is only needed if there are columns to scroll after the frozen ones
+        let separator_x = (frozen > 0 && frozen < column_widths.len()).then(|| {
+            let (x, width) = column_widths[frozen - 1];
+            x.saturating_add(width).saturating_add(self.spacing())
+        });
+        let scroll_start = separator_x.map_or(columns_x, |x| {
+            x.saturating_add(1).saturating_add(self.spacing())
+        });
+
+        let viewport_width = max_width.saturating_sub(scroll_start);
+        let mut offset = state
+            .column_offset
+            .clamp(frozen, column_widths.len().max(frozen + 1) - 1);
+        if let Some(selected) = state.selected_column.filter(|&selected| selected >= frozen) {
+            if let Some(&(x, width)) = column_widths.get(selected) {
+                offset = offset.min(selected);
+                // scroll right until the selected column fits
+                while offset < selected
+                    && (x + width).saturating_sub(column_widths[offset].0) > viewport_width
+                {
+                    offset += 1;
+                }
+            }
+        }
+        state.column_offset = offset;
+
+        let offset_x = column_widths.get(offset).map_or(0, |&(x, _)| x);
+        for (i, (x, width)) in column_widths.iter_mut().enumerate() {
+            if i >= frozen {
+                if i < offset {
+                    (*x, *width) = (scroll_start, 0);
+                } else {
+                    *x = (*x - offset_x).saturating_add(scroll_start);
+                }
+            }
+            *x = (*x).min(max_width);
+            *width = (*width).min(max_width - *x);
+        }
+        separator_x.filter(|&x| x < max_width)
+    }
+
+    /// Returns the spacing between the columns, which is at least one cell when the columns are
+    /// separated by a symbol.
+    fn spacing(&self) -> u16 {
+        if self.column_separator.is_some() {
+            self.column_spacing.max(1)
+        } else {
+            self.column_spacing
+        }
+    }
+
     fn column_count(&self) -> usize {
         self.rows
             .iter()
@@ -988,18 +2630,64 @@ impl Table<'_> {
             .unwrap_or_default()
     }
 
-    /// Returns the width of the selection column if a row is selected, or the `highlight_spacing`
-    /// is set to show the column always, otherwise 0.
+    /// Returns the width of the selection column if a row is selected or the selection set is not
+    /// empty, or the `highlight_spacing` is set to show the column always, otherwise 0.
     fn selection_width(&self, state: &TableState) -> u16 {
-        let has_selection = state.selected.is_some();
+        let has_selection = state.selected.is_some() || !state.selected_set.is_empty();
         if self.highlight_spacing.should_add(has_selection) {
-            self.highlight_symbol.width() as u16
+            let symbol_width = self.highlight_symbol.width();
+            symbol_width.max(self.multi_highlight_symbol.width()) as u16
         } else {
             0
         }
     }
 }
 
+/// Returns the columns spanned by each cell of a row, along with the x position and width of the
+/// cell.
+///
+/// A cell spanning several columns covers the columns and the spacing between them, and the cells
+/// after the last column are dropped.
+fn cell_positions<'r, 'a>(
+    cells: &'r [Cell<'a>],
+    column_widths: &'r [(u16, u16)],
+) -> impl Iterator<Item = (Range<usize>, u16, u16, &'r Cell<'a>)> {
+    let mut column = 0;
+    cells.iter().map_while(move |cell| {
+        let &(x, _) = column_widths.get(column)?;
+        let end = (column + cell.column_span()).min(column_widths.len());
+        let (last_x, last_width) = column_widths[end - 1];
+        let columns = column..end;
+        column = end;
+        Some((columns, x, (last_x + last_width).saturating_sub(x), cell))
+    })
+}
+
+/// Returns the position of the separator between each pair of visible columns, along with the
+/// index of the column after it.
+///
+/// The separator is in the middle of the spacing between the columns.
+fn separator_positions(column_widths: &[(u16, u16)]) -> impl Iterator<Item = (usize, u16)> + '_ {
+    column_widths
+        .windows(2)
+        .enumerate()
+        .filter_map(|(i, pair)| {
+            let [(left_x, left_width), (right_x, right_width)] = [pair[0], pair[1]];
+            let left_end = left_x.saturating_add(left_width);
+            let gap = right_x.saturating_sub(left_end);
+            (left_width > 0 && right_width > 0 && gap > 0)
+                .then(|| (i + 1, left_end + (gap - 1) / 2))
+        })
+}
+
+/// Returns the x position and width of the details of the rows, which span all the columns up to
+/// the right edge of the table, after the `indent`.
+fn details_columns(max_width: u16, column_widths: &[(u16, u16)], indent: u16) -> (u16, u16) {
+    let x = column_widths.first().map_or(0, |&(x, _)| x);
+    let x = x.saturating_add(indent).min(max_width);
+    (x, max_width - x)
+}
+
 fn ensure_percentages_less_than_100(widths: &[Constraint]) {
     for w in widths {
         if let Constraint::Percentage(p) = w {
@@ -1060,12 +2748,22 @@ mod tests {
         assert_eq!(table.footer, None);
         assert_eq!(table.widths, widths);
         assert_eq!(table.column_spacing, 1);
+        assert_eq!(table.column_alignments, []);
+        assert_eq!(table.column_styles, []);
         assert_eq!(table.block, None);
         assert_eq!(table.style, Style::default());
         assert_eq!(table.row_highlight_style, Style::default());
         assert_eq!(table.highlight_symbol, Text::default());
         assert_eq!(table.highlight_spacing, HighlightSpacing::WhenSelected);
         assert_eq!(table.flex, Flex::Start);
+        assert!(!table.horizontal_scroll);
+        assert_eq!(table.frozen_columns, 0);
+        assert!(!table.wrap_cells);
+        assert_eq!(table.max_row_height, None);
+        assert_eq!(
+            table.sort_indicator_symbols,
+            (Span::raw(" ▲"), Span::raw(" ▼"))
+        );
     }
 
     #[test]
@@ -1076,12 +2774,22 @@ mod tests {
         assert_eq!(table.footer, None);
         assert_eq!(table.widths, []);
         assert_eq!(table.column_spacing, 1);
+        assert_eq!(table.column_alignments, []);
+        assert_eq!(table.column_styles, []);
         assert_eq!(table.block, None);
         assert_eq!(table.style, Style::default());
         assert_eq!(table.row_highlight_style, Style::default());
         assert_eq!(table.highlight_symbol, Text::default());
         assert_eq!(table.highlight_spacing, HighlightSpacing::WhenSelected);
         assert_eq!(table.flex, Flex::Start);
+        assert!(!table.horizontal_scroll);
+        assert_eq!(table.frozen_columns, 0);
+        assert!(!table.wrap_cells);
+        assert_eq!(table.max_row_height, None);
+        assert_eq!(
+            table.sort_indicator_symbols,
+            (Span::raw(" ▲"), Span::raw(" ▼"))
+        );
     }
 
     #[test]
@@ -1139,6 +2847,36 @@ mod tests {
         assert_eq!(table.column_spacing, 2);
     }
 
+    #[test]
+    fn selected_column_header_style() {
+        let table = Table::default().selected_column_header_style(Style::new().bold());
+        assert_eq!(table.selected_column_header_style, Style::new().bold());
+    }
+
+    #[test]
+    fn column_separator() {
+        let table = Table::default()
+            .column_separator("│")
+            .column_separator_style(Style::new().red());
+        assert_eq!(table.column_separator, Some("│"));
+        assert_eq!(table.column_separator_style, Style::new().red());
+        assert_eq!(table.column_separator(None).column_separator, None);
+    }
+
+    #[test]
+    fn column_alignments() {
+        let table = Table::default().column_alignments([Alignment::Left, Alignment::Right]);
+        assert_eq!(table.column_alignments, [Alignment::Left, Alignment::Right]);
+    }
+
+    #[test]
+    fn column_styles() {
+        let table = Table::default().column_styles([Style::new(), Style::new().red()]);
+        assert_eq!(table.column_styles, [Style::new(), Style::new().red()]);
+        let table = Table::default().column_styles([Color::Blue]);
+        assert_eq!(table.column_styles, [Style::new().fg(Color::Blue)]);
+    }
+
     #[test]
     fn block() {
         let block = Block::bordered().title("Table");
@@ -1202,43 +2940,155 @@ mod tests {
     }
 
     #[test]
-    #[should_panic = "Percentages should be between 0 and 100 inclusively"]
-    fn table_invalid_percentages() {
-        let _ = Table::default().widths([Constraint::Percentage(110)]);
+    fn horizontal_scroll() {
+        let table = Table::default().horizontal_scroll(true);
+        assert!(table.horizontal_scroll);
     }
 
     #[test]
-    fn widths_conversions() {
-        let array = [Constraint::Percentage(100)];
-        let table = Table::new(Vec::<Row>::new(), array);
-        assert_eq!(table.widths, [Constraint::Percentage(100)], "array");
+    fn frozen_columns() {
+        let table = Table::default().frozen_columns(2);
+        assert_eq!(table.frozen_columns, 2);
+    }
 
-        let array_ref = &[Constraint::Percentage(100)];
-        let table = Table::new(Vec::<Row>::new(), array_ref);
-        assert_eq!(table.widths, [Constraint::Percentage(100)], "array ref");
+    #[test]
+    fn wrap_cells() {
+        let table = Table::default().wrap_cells(true);
+        assert!(table.wrap_cells);
+    }
 
-        let vec = vec![Constraint::Percentage(100)];
-        let slice = vec.as_slice();
-        let table = Table::new(Vec::<Row>::new(), slice);
-        assert_eq!(table.widths, [Constraint::Percentage(100)], "slice");
+    #[test]
+    fn max_row_height() {
+        let table = Table::default().max_row_height(3);
+        assert_eq!(table.max_row_height, Some(3));
+        let table = table.max_row_height(None);
+        assert_eq!(table.max_row_height, None);
+    }
 
-        let vec = vec![Constraint::Percentage(100)];
-        let table = Table::new(Vec::<Row>::new(), vec);
-        assert_eq!(table.widths, [Constraint::Percentage(100)], "vec");
+    #[test]
+    fn expand_selected() {
+        let table = Table::default().expand_selected(true);
+        assert!(table.expand_selected);
+    }
 
-        let vec_ref = &vec![Constraint::Percentage(100)];
-        let table = Table::new(Vec::<Row>::new(), vec_ref);
-        assert_eq!(table.widths, [Constraint::Percentage(100)], "vec ref");
+    #[test]
+    fn details_indent() {
+        let table = Table::default().details_indent(2);
+        assert_eq!(table.details_indent, 2);
     }
 
-    #[cfg(test)]
-    mod state {
-        use ratatui_core::buffer::Buffer;
-        use ratatui_core::layout::{Constraint, Rect};
-        use ratatui_core::widgets::StatefulWidget;
+    #[test]
+    fn sort_indicator_symbols() {
+        let table = Table::default().sort_indicator_symbols("+", "-");
+        assert_eq!(
+            table.sort_indicator_symbols,
+            (Span::raw("+"), Span::raw("-"))
+        );
+    }
 
-        use super::*;
-        use crate::table::{Row, Table, TableState};
+    #[test]
+    fn sort_indicator() {
+        let table = Table::default().sort_indicator(1, SortDirection::Descending);
+        assert_eq!(table.sort_indicator, Some((1, SortDirection::Descending)));
+    }
+
+    /// The names and sizes of the rows of the tables used to test sorting
+    const FILES: [(&str, u16); 4] = [("b", 20), ("a", 30), ("d", 10), ("c", 30)];
+
+    fn sorted_files_table(state: &mut TableState) -> Table<'static> {
+        let rows = FILES
+            .iter()
+            .map(|&(name, size)| Row::new([name.to_string(), size.to_string()]));
+        Table::new(rows, [Constraint::Length(2); 2]).sorted_by(state, |column, a, b| match column {
+            0 => FILES[a].0.cmp(FILES[b].0),
+            _ => FILES[a].1.cmp(&FILES[b].1),
+        })
+    }
+
+    #[rstest]
+    #[case::unsorted(None, ["b", "a", "d", "c"])]
+    #[case::by_name(Some((0, SortDirection::Ascending)), ["a", "b", "c", "d"])]
+    #[case::by_name_descending(Some((0, SortDirection::Descending)), ["d", "c", "b", "a"])]
+    #[case::stable(Some((1, SortDirection::Ascending)), ["d", "b", "a", "c"])]
+    #[case::stable_descending(Some((1, SortDirection::Descending)), ["a", "c", "b", "d"])]
+    fn sorted_by(#[case] sort_column: Option<(usize, SortDirection)>, #[case] expected: [&str; 4]) {
+        let mut state = TableState::new().with_sort_column(sort_column);
+        let table = sorted_files_table(&mut state);
+        let expected = expected.map(|name| {
+            let (_, size) = FILES.iter().find(|(file, _)| *file == name).unwrap();
+            Row::new([name.to_string(), size.to_string()])
+        });
+        assert_eq!(table.rows, expected);
+    }
+
+    #[test]
+    fn sorted_by_keeps_selected_index_by_default() {
+        let mut state = TableState::new()
+            .with_selected(0)
+            .with_sort_column(Some((0, SortDirection::Ascending)));
+        let _ = sorted_files_table(&mut state);
+        state.toggle_sort(0);
+        let _ = sorted_files_table(&mut state);
+        assert_eq!(state.selected, Some(0));
+    }
+
+    #[test]
+    fn sorted_by_selection_follows_sort() {
+        let mut state = TableState::new()
+            .with_selection_follows_sort(true)
+            .with_sort_column(Some((0, SortDirection::Ascending)));
+        let _ = sorted_files_table(&mut state);
+        // select "b", which is the second row when sorted by name
+        state.select(Some(1));
+        state.toggle_sort(0);
+        let table = sorted_files_table(&mut state);
+        assert_eq!(state.selected, Some(2));
+        assert_eq!(table.rows[2], Row::new(["b", "20"]));
+        // sorted by size, "b" is the second row
+        state.toggle_sort(1);
+        let table = sorted_files_table(&mut state);
+        assert_eq!(state.selected, Some(1));
+        assert_eq!(table.rows[1], Row::new(["b", "20"]));
+    }
+
+    #[test]
+    #[should_panic = "Percentages should be between 0 and 100 inclusively"]
+    fn table_invalid_percentages() {
+        let _ = Table::default().widths([Constraint::Percentage(110)]);
+    }
+
+    #[test]
+    fn widths_conversions() {
+        let array = [Constraint::Percentage(100)];
+        let table = Table::new(Vec::<Row>::new(), array);
+        assert_eq!(table.widths, [Constraint::Percentage(100)], "array");
+
+        let array_ref = &[Constraint::Percentage(100)];
+        let table = Table::new(Vec::<Row>::new(), array_ref);
+        assert_eq!(table.widths, [Constraint::Percentage(100)], "array ref");
+
+        let vec = vec![Constraint::Percentage(100)];
+        let slice = vec.as_slice();
+        let table = Table::new(Vec::<Row>::new(), slice);
+        assert_eq!(table.widths, [Constraint::Percentage(100)], "slice");
+
+        let vec = vec![Constraint::Percentage(100)];
+        let table = Table::new(Vec::<Row>::new(), vec);
+        assert_eq!(table.widths, [Constraint::Percentage(100)], "vec");
+
+        let vec_ref = &vec![Constraint::Percentage(100)];
+        let table = Table::new(Vec::<Row>::new(), vec_ref);
+        assert_eq!(table.widths, [Constraint::Percentage(100)], "vec ref");
+    }
+
+    #[cfg(test)]
+    mod state {
+        use ratatui_core::buffer::Buffer;
+        use ratatui_core::layout::{Constraint, Rect};
+        use ratatui_core::widgets::StatefulWidget;
+
+        use super::*;
+        use crate::table::{Row, Table, TableState};
 
         #[fixture]
         fn table_buf() -> Buffer {
@@ -1308,6 +3158,28 @@ mod tests {
             assert_eq!(state.selected_column, Some(0));
             assert_eq!(state.selected, None);
         }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn render_clamps_deserialized_state() -> Result<(), serde_json::Error> {
+            // the state was saved when the table had more rows and columns
+            let json = r#"{"offset":50,"selected":60,"selected_column":9,"selected_set":[1,70]}"#;
+            let mut state: TableState = serde_json::from_str(json)?;
+            let rows = [
+                Row::new(["a0", "b0"]),
+                Row::new(["a1", "b1"]),
+                Row::new(["a2", "b2"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(2); 2]);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["a2 b2", "     "]));
+            assert_eq!(state.offset, 2);
+            assert_eq!(state.selected, Some(2));
+            assert_eq!(state.selected_column, Some(1));
+            assert_eq!(state.selected_set, [1].into());
+            Ok(())
+        }
     }
 
     #[cfg(test)]
@@ -1371,6 +3243,76 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_multi_line_header() {
+            let header = Row::new(["Requests\n/ sec\n(p99)", "Host"]);
+            let rows = [Row::new(["120", "a"]), Row::new(["42", "b"])];
+            let table = Table::new(rows, [8, 4]).header(header);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 13, 5));
+            Widget::render(table, buf.area, &mut buf);
+            let expected = Buffer::with_lines([
+                "Requests Host",
+                "/ sec        ",
+                "(p99)        ",
+                "120      a   ",
+                "42       b   ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_fixed_header_height() {
+            let header = Row::new(["Requests\n/ sec\n(p99)", "Host"]).height(2);
+            let rows = [Row::new(["120", "a"]), Row::new(["42", "b"])];
+            let table = Table::new(rows, [8, 4]).header(header);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 13, 4));
+            Widget::render(table, buf.area, &mut buf);
+            let expected = Buffer::with_lines([
+                "Requests Host",
+                "/ sec        ",
+                "120      a   ",
+                "42       b   ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_wrapped_header() {
+            let header = Row::new(["Requests / sec (p99)", "Host"]);
+            let rows = [Row::new(["120", "a"])];
+            let table = Table::new(rows, [8, 4]).header(header).wrap_cells(true);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 13, 4));
+            Widget::render(table, buf.area, &mut buf);
+            let expected = Buffer::with_lines([
+                "Requests Host",
+                "/ sec        ",
+                "(p99)        ",
+                "120      a   ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_multi_line_header_and_pinned_footer() {
+            let header = Row::new(["Requests\n/ sec", "Host"]);
+            let rows = (0..10).map(|i| Row::new([format!("{i}"), format!("h{i}")]));
+            let table = Table::new(rows, [8, 4])
+                .header(header)
+                .footer(Row::new(["Total", "10"]));
+            let mut state = TableState::new().with_selected(5);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 13, 5));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            let expected = Buffer::with_lines([
+                "Requests Host",
+                "/ sec        ",
+                "4        h4  ",
+                "5        h5  ",
+                "Total    10  ",
+            ]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.offset(), 4);
+        }
+
         #[test]
         fn render_with_footer() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
@@ -1444,6 +3386,69 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[rstest]
+        #[case::no_selection(None, [
+            "  Head1 Head2  ",
+            "  Cell0 Val0   ",
+            "  Cell1 Val1   ",
+            "  Total 10     ",
+        ])]
+        #[case::selected_near_bottom(Some(8), [
+            "  Head1 Head2  ",
+            "  Cell7 Val7   ",
+            ">>Cell8 Val8   ",
+            "  Total 10     ",
+        ])]
+        fn render_with_pinned_footer(
+            #[case] selected: Option<usize>,
+            #[case] expected: [&'static str; 4],
+        ) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 4));
+            let rows = (0..10).map(|i| Row::new([format!("Cell{i}"), format!("Val{i}")]));
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .header(Row::new(["Head1", "Head2"]))
+                .footer(Row::new(["Total", "10"]))
+                .highlight_symbol(">>")
+                .highlight_spacing(HighlightSpacing::Always);
+            let mut state = TableState::new().with_selected(selected);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(expected));
+        }
+
+        #[rstest]
+        #[case::fits(3, ["Head1 Head2    ", "Cell0 Val0     ", "Total 10       "])]
+        #[case::too_short(2, ["Head1 Head2    ", "Cell0 Val0     "])]
+        #[case::only_header(1, ["Head1 Head2    "])]
+        fn render_drops_footer_when_too_short<const N: usize>(
+            #[case] height: u16,
+            #[case] expected: [&'static str; N],
+        ) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, height));
+            let rows = (0..10).map(|i| Row::new([format!("Cell{i}"), format!("Val{i}")]));
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .header(Row::new(["Head1", "Head2"]))
+                .footer(Row::new(["Total", "10"]));
+            Widget::render(table, buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines(expected));
+        }
+
+        #[test]
+        fn render_drops_footer_with_its_margin() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let rows = [Row::new(["Cell1", "Cell2"]), Row::new(["Cell3", "Cell4"])];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .header(Row::new(["Head1", "Head2"]))
+                .footer(Row::new(["Foot1", "Foot2"]).top_margin(1));
+            Widget::render(table, buf.area, &mut buf);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Head1 Head2    ",
+                "Cell1 Cell2    ",
+                "Cell3 Cell4    ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
         #[test]
         fn render_with_row_margin() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
@@ -1484,6 +3489,160 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        fn wrapping_table() -> Table<'static> {
+            let rows = [
+                Row::new(["Cell1", "a cell that wraps"]),
+                Row::new(["Cell2", "short"]),
+                Row::new(["Cell3", "another wrapped cell"]),
+            ];
+            Table::new(rows, [Constraint::Length(5), Constraint::Length(7)]).wrap_cells(true)
+        }
+
+        #[test]
+        fn render_with_wrapped_cells() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 13, 6));
+            Widget::render(wrapping_table(), buf.area, &mut buf);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Cell1 a cell ",
+                "      that   ",
+                "      wraps  ",
+                "Cell2 short  ",
+                "Cell3 another",
+                "      wrapped",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_wrapped_cells_and_max_row_height() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 13, 6));
+            let table = wrapping_table().max_row_height(2);
+            Widget::render(table, buf.area, &mut buf);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Cell1 a cell ",
+                "      that   ",
+                "Cell2 short  ",
+                "Cell3 another",
+                "      wrapped",
+                "             ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_wrapped_cells_scrolls_to_selected_row() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 13, 4));
+            let mut state = TableState::new().with_selected(2);
+            StatefulWidget::render(wrapping_table(), buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Cell2 short  ",
+                "Cell3 another",
+                "      wrapped",
+                "      cell   ",
+            ]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.offset, 1);
+        }
+
+        #[rstest]
+        #[case::ascending(SortDirection::Ascending, "Name Size ▲")]
+        #[case::descending(SortDirection::Descending, "Name Size ▼")]
+        fn render_with_sort_indicator(#[case] direction: SortDirection, #[case] header: &str) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 2));
+            let table = Table::new(
+                [Row::new(["a", "1"])],
+                [Constraint::Length(4), Constraint::Length(6)],
+            )
+            .header(Row::new(["Name", "Size"]));
+            let mut state = TableState::new().with_sort_column(Some((1, direction)));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            let expected = Buffer::with_lines([header, "a    1     "]);
+            assert_eq!(buf, expected);
+        }
+
+        #[rstest]
+        #[case::ascending(SortDirection::Ascending, "Name Size ▲")]
+        #[case::descending(SortDirection::Descending, "Name Size ▼")]
+        fn render_with_table_sort_indicator(
+            #[case] direction: SortDirection,
+            #[case] header: &str,
+        ) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 2));
+            let table = Table::new(
+                [Row::new(["a", "1"])],
+                [Constraint::Length(4), Constraint::Length(6)],
+            )
+            .header(Row::new(["Name", "Size"]))
+            .sort_indicator(1, direction);
+            Widget::render(table, buf.area, &mut buf);
+            let expected = Buffer::with_lines([header, "a    1     "]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_table_sort_indicator_truncates_header() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 2));
+            let table = Table::new(
+                [Row::new(["a", "1"])],
+                [Constraint::Length(6), Constraint::Length(4)],
+            )
+            .header(Row::new(["Filename", "Size"]))
+            .sort_indicator(0, SortDirection::Ascending);
+            Widget::render(table, buf.area, &mut buf);
+            let expected = Buffer::with_lines(["File ▲ Size", "a      1   "]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_sort_indicator_keeps_hyperlink_of_truncated_header() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+            let header = Span::raw("Filename").hyperlink("https://ratatui.rs");
+            let table = Table::default()
+                .widths([6])
+                .header(Row::new([header]))
+                .sort_indicator(0, SortDirection::Ascending);
+            Widget::render(table, buf.area, &mut buf);
+            let mut expected = Buffer::with_lines(["File ▲"]);
+            for x in 0..4 {
+                expected[(x, 0)].set_hyperlink(Some("https://ratatui.rs"));
+            }
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_state_sort_column_replaces_table_sort_indicator() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 2));
+            let table = Table::new(
+                [Row::new(["a", "1"])],
+                [Constraint::Length(4), Constraint::Length(6)],
+            )
+            .header(Row::new(["Name", "Size"]))
+            .sort_indicator(0, SortDirection::Ascending);
+            let mut state =
+                TableState::new().with_sort_column(Some((1, SortDirection::Descending)));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            let expected = Buffer::with_lines(["Name Size ▼", "a    1     "]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_custom_sort_indicator_symbols() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 2));
+            let table = Table::new(
+                [Row::new(["a", "1"])],
+                [Constraint::Length(6), Constraint::Length(4)],
+            )
+            .header(Row::new(["Name", "Size"]))
+            .sort_indicator_symbols("+", "-");
+            let mut state = TableState::new().with_sort_column(Some((0, SortDirection::Ascending)));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            let expected = Buffer::with_lines(["Name+  Size", "a      1   "]);
+            assert_eq!(buf, expected);
+        }
+
         #[test]
         fn render_with_alignment() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
@@ -1637,6 +3796,32 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_selected_cell_in_short_row() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 20, 3));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2", "Cell3"]),
+                Row::new(vec!["Cell4"]),
+                Row::new(vec!["Cell7", "Cell8", "Cell9"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 3])
+                .highlight_symbol(">>")
+                .row_highlight_style(Style::new().red())
+                .column_highlight_style(Style::new().blue())
+                .cell_highlight_style(Style::new().green());
+            let mut state = TableState::new().with_selected_cell((1, 2));
+            state.select_next_cell_in_row();
+            StatefulWidget::render(table, Rect::new(0, 0, 20, 3), &mut buf, &mut state);
+            // the selection is clamped to the only cell of the row
+            assert_eq!(state.selected_cell(), Some((1, 0)));
+            let expected = Buffer::with_lines::<[Line; 3]>([
+                Line::from(vec!["  ".into(), "Cell1".blue(), " Cell2 Cell3".into()]),
+                Line::from(vec![">>".red(), "Cell4".green(), "             ".red()]),
+                Line::from(vec!["  ".into(), "Cell7".blue(), " Cell8 Cell9".into()]),
+            ]);
+            assert_eq!(buf, expected);
+        }
+
         /// Note that this includes a regression test for a bug where the table would not render the
         /// correct rows when there is no selection.
         /// <https://github.com/ratatui/ratatui/issues/1179>
@@ -1666,6 +3851,1579 @@ mod tests {
             assert_eq!(buf, Buffer::with_lines(expected_items));
             assert_eq!(state.offset, expected_offset);
         }
+
+        /// A horizontally scrollable table with 10 columns of width 5
+        fn wide_table() -> Table<'static> {
+            let header = Row::new((0..10).map(|i| format!("Col{i}")));
+            let rows = [Row::new((0..10).map(|i| format!("v{i}")))];
+            Table::new(rows, [Constraint::Length(5); 10])
+                .header(header)
+                .horizontal_scroll(true)
+        }
+
+        #[test]
+        fn render_with_column_offset() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 30, 2));
+            let mut state = TableState::new().with_column_offset(7);
+            StatefulWidget::render(wide_table(), buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Col7  Col8  Col9              ",
+                "v7    v8    v9                ",
+            ]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.column_offset, 7);
+        }
+
+        #[test]
+        fn render_with_column_offset_cuts_off_partial_column() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 32, 2));
+            let mut state = TableState::new().with_column_offset(1);
+            StatefulWidget::render(wide_table(), buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Col1  Col2  Col3  Col4  Col5  Co",
+                "v1    v2    v3    v4    v5    v6",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_column_offset_out_of_bounds() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 30, 2));
+            let mut state = TableState::new().with_column_offset(20);
+            StatefulWidget::render(wide_table(), buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Col9                          ",
+                "v9                            ",
+            ]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.column_offset, 9);
+        }
+
+        #[test]
+        fn render_with_selected_column_scrolls_into_view() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 30, 2));
+            let table = wide_table().column_highlight_style(Style::new().bold());
+            let mut state = TableState::new().with_selected_column(7);
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            let mut expected = Buffer::with_lines([
+                "Col3  Col4  Col5  Col6  Col7  ",
+                "v3    v4    v5    v6    v7    ",
+            ]);
+            expected.set_style(Rect::new(24, 1, 5, 1), Style::new().bold());
+            assert_eq!(buf, expected);
+            assert_eq!(state.column_offset, 3);
+
+            // selecting a column before the offset scrolls back to it
+            state.select_column(Some(1));
+            let mut buf = Buffer::empty(Rect::new(0, 0, 30, 2));
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            let mut expected = Buffer::with_lines([
+                "Col1  Col2  Col3  Col4  Col5  ",
+                "v1    v2    v3    v4    v5    ",
+            ]);
+            expected.set_style(Rect::new(0, 1, 5, 1), Style::new().bold());
+            assert_eq!(buf, expected);
+            assert_eq!(state.column_offset, 1);
+        }
+
+        #[test]
+        fn render_with_column_offset_and_highlight_symbol() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 30, 2));
+            let table = wide_table().highlight_symbol(">>");
+            let mut state = TableState::new().with_selected(0).with_selected_column(9);
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "  Col6  Col7  Col8  Col9      ",
+                ">>v6    v7    v8    v9        ",
+            ]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.column_offset, 6);
+        }
+    }
+
+    mod colspan {
+        use super::*;
+
+        fn table_with_section_header() -> Table<'static> {
+            let rows = [
+                Row::new([Cell::new("── Production ──").colspan(3)]),
+                Row::new(["web", "up", "3"]),
+                Row::new([Cell::new("db (primary)").colspan(2), Cell::new("1")]),
+            ];
+            Table::new(
+                rows,
+                [
+                    Constraint::Length(5),
+                    Constraint::Length(6),
+                    Constraint::Length(3),
+                ],
+            )
+        }
+
+        #[test]
+        fn render_with_colspan() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 18, 3));
+            Widget::render(table_with_section_header(), buf.area, &mut buf);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "── Production ──  ",
+                "web   up     3    ",
+                "db (primary) 1    ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_colspan_beyond_last_column() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+            let rows = [Row::new([
+                Cell::new("a"),
+                Cell::new("spanned cell").colspan(5),
+                Cell::new("dropped"),
+            ])];
+            let table = Table::new(rows, [Constraint::Length(3); 3]);
+            Widget::render(table, buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines(["a   spanned"]));
+        }
+
+        #[test]
+        fn render_header_with_colspan_and_sort_indicator() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 1));
+            let table = Table::new(Vec::<Row>::new(), [Constraint::Length(3); 3])
+                .header(Row::new([Cell::new("Name").colspan(2), Cell::new("Age")]));
+            let mut state = TableState::new().with_sort_column(Some((1, SortDirection::Ascending)));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["Name ▲  Age    "]));
+        }
+
+        #[test]
+        fn render_with_selected_column_inside_span() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 18, 3));
+            let table = table_with_section_header()
+                .column_highlight_style(Style::new().blue())
+                .cell_highlight_style(Style::new().bold());
+            let mut state = TableState::new().with_selected_cell((2, 1));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let mut expected = Buffer::with_lines([
+                "── Production ──  ",
+                "web   up     3    ",
+                "db (primary) 1    ",
+            ]);
+            // the selected column
+            expected.set_style(Rect::new(6, 0, 6, 3), Style::new().blue());
+            // the cells spanning the selected column
+            expected.set_style(Rect::new(0, 0, 16, 1), Style::new().blue());
+            expected.set_style(Rect::new(0, 2, 12, 1), Style::new().blue());
+            // the selected cell spans the first two columns
+            expected.set_style(Rect::new(0, 2, 12, 1), Style::new().bold());
+            assert_eq!(buf, expected);
+        }
+    }
+
+    mod column_defaults {
+        use super::*;
+
+        fn inventory() -> Table<'static> {
+            let rows = [
+                Row::new(["Apples", "12"]),
+                Row::new(["Bananas", "1234"]),
+                Row::new(["面包", "日本"]),
+            ];
+            Table::new(rows, [Constraint::Length(7), Constraint::Length(6)])
+                .header(Row::new(["Item", "Qty"]))
+                .footer(Row::new(["Total", "1246"]))
+                .column_alignments([Alignment::Left, Alignment::Right])
+        }
+
+        #[test]
+        fn render_with_right_aligned_numeric_column() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 14, 5));
+            Widget::render(inventory(), buf.area, &mut buf);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Item       Qty",
+                "Apples      12",
+                "Bananas   1234",
+                "面包      日本",
+                "Total     1246",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_cell_alignment_override() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 14, 2));
+            let rows = [
+                Row::new([Line::from("Apples"), Line::from("12").centered()]),
+                Row::new([
+                    Cell::new("Pears"),
+                    Cell::new(Text::from("7").left_aligned()),
+                ]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(7), Constraint::Length(6)])
+                .column_alignments([Alignment::Right, Alignment::Right]);
+            Widget::render(table, buf.area, &mut buf);
+            assert_eq!(
+                buf,
+                Buffer::with_lines([" Apples   12  ", "  Pears 7     "])
+            );
+        }
+
+        #[test]
+        fn render_with_column_styles() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 14, 5));
+            let table = inventory()
+                .column_styles([Style::new(), Style::new().yellow()])
+                .rows([
+                    Row::new(["Apples", "12"]).style(Style::new().on_blue()),
+                    Row::new([Cell::new("Bananas"), Cell::new("1234").red()]),
+                    Row::new(["Pears", "7"]),
+                ]);
+            Widget::render(table, buf.area, &mut buf);
+            #[rustfmt::skip]
+            let mut expected = Buffer::with_lines([
+                "Item       Qty",
+                "Apples      12",
+                "Bananas   1234",
+                "Pears        7",
+                "Total     1246",
+            ]);
+            expected.set_style(Rect::new(0, 1, 14, 1), Style::new().on_blue());
+            expected.set_style(Rect::new(8, 0, 6, 5), Style::new().yellow());
+            // the style of the cell is applied over the style of the column
+            expected.set_style(Rect::new(8, 2, 6, 1), Style::new().red());
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_sort_indicator_in_aligned_column() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 14, 5));
+            let mut state =
+                TableState::new().with_sort_column(Some((1, SortDirection::Descending)));
+            StatefulWidget::render(inventory(), buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Item     Qty ▼",
+                "Apples      12",
+                "Bananas   1234",
+                "面包      日本",
+                "Total     1246",
+            ]);
+            assert_eq!(buf, expected);
+        }
+    }
+
+    mod range_selection {
+        use super::*;
+
+        /// A table of 5 rows and 4 columns, where each cell contains its row and column indexes
+        fn grid_table() -> Table<'static> {
+            let rows = (0..5).map(|row| Row::new((0..4).map(|column| format!("{row}{column}"))));
+            Table::new(rows, [Constraint::Length(2); 4])
+                .range_highlight_style(Style::new().on_blue())
+        }
+
+        #[test]
+        fn render_range() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 3));
+            let mut state = TableState::new().with_selected_range(((1, 2), (0, 1)));
+            StatefulWidget::render(grid_table(), buf.area, &mut buf, &mut state);
+            let mut expected = Buffer::with_lines(["00 01 02 03", "10 11 12 13", "20 21 22 23"]);
+            for (x, y) in [(3, 0), (6, 0), (3, 1), (6, 1)] {
+                expected.set_style(Rect::new(x, y, 2, 1), Style::new().on_blue());
+            }
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_range_partially_scrolled_out_of_view() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+            let table = grid_table().horizontal_scroll(true);
+            let mut state = TableState::new()
+                .with_offset(1)
+                .with_column_offset(1)
+                .with_selected_range(((0, 0), (1, 2)));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            let mut expected = Buffer::with_lines(["11 12 13", "21 22 23"]);
+            // row 0 is scrolled out above and column 0 is scrolled out to the left
+            expected.set_style(Rect::new(0, 0, 2, 1), Style::new().on_blue());
+            expected.set_style(Rect::new(3, 0, 2, 1), Style::new().on_blue());
+            assert_eq!(buf, expected);
+            assert_eq!(state.selected_range(), Some(((0, 0), (1, 2))));
+        }
+
+        #[test]
+        fn render_range_with_selected_cell() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 2));
+            let table = grid_table()
+                .row_highlight_style(Style::new().red())
+                .cell_highlight_style(Style::new().on_green());
+            let mut state = TableState::new()
+                .with_selected_range(((0, 0), (1, 1)))
+                .with_selected_cell((1, 1));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            let mut expected = Buffer::with_lines(["00 01 02 03", "10 11 12 13"]);
+            expected.set_style(Rect::new(0, 1, 11, 1), Style::new().red());
+            expected.set_style(Rect::new(0, 0, 2, 2), Style::new().on_blue());
+            expected.set_style(Rect::new(3, 0, 2, 2), Style::new().on_blue());
+            expected.set_style(Rect::new(3, 1, 2, 1), Style::new().on_green());
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_clamps_range_to_table() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 5));
+            let mut state = TableState::new().with_selected_range(((3, 2), (10, 10)));
+            StatefulWidget::render(grid_table(), buf.area, &mut buf, &mut state);
+            assert_eq!(state.selected_range(), Some(((3, 2), (4, 3))));
+            assert_eq!(
+                state.selected_range_cells().collect::<Vec<_>>(),
+                [(3, 2), (3, 3), (4, 2), (4, 3)]
+            );
+
+            let mut state = TableState::new().with_selected_range(((5, 0), (6, 1)));
+            StatefulWidget::render(grid_table(), buf.area, &mut buf, &mut state);
+            assert_eq!(state.selected_range(), None);
+        }
+    }
+
+    mod virtual_rows {
+        use core::cell::RefCell;
+
+        use super::*;
+
+        fn number_row(i: usize) -> Row<'static> {
+            Row::new([i.to_string(), (i * i).to_string()])
+        }
+
+        fn render_both(table: Table<'static>, len: usize, state: &TableState) -> Buffer {
+            let mut vec_buf = Buffer::empty(Rect::new(0, 0, 15, 6));
+            let mut vec_state = state.clone();
+            let vec_table = table.clone().rows((0..len).map(number_row));
+            StatefulWidget::render(vec_table, vec_buf.area, &mut vec_buf, &mut vec_state);
+
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 6));
+            let mut virtual_state = state.clone();
+            let virtual_table = table.rows_from(len, number_row);
+            StatefulWidget::render(virtual_table, buf.area, &mut buf, &mut virtual_state);
+
+            assert_eq!(buf, vec_buf);
+            assert_eq!(virtual_state, vec_state);
+            buf
+        }
+
+        #[rstest]
+        #[case::no_selection(TableState::new())]
+        #[case::offset(TableState::new().with_offset(50))]
+        #[case::offset_past_end(TableState::new().with_offset(5000))]
+        #[case::selected_before_offset(TableState::new().with_offset(50).with_selected(20))]
+        #[case::selected_after_offset(TableState::new().with_offset(50).with_selected(80))]
+        #[case::selected_past_end(TableState::new().with_selected(5000))]
+        #[case::selected_cell(TableState::new().with_selected_cell((60, 1)))]
+        #[case::range(TableState::new().with_offset(40).with_selected_range(((38, 0), (41, 5))))]
+        fn render_matches_vec_rows(#[case] state: TableState) {
+            let table = Table::default()
+                .widths([Constraint::Length(3), Constraint::Length(5)])
+                .header(Row::new(["n", "n²"]))
+                .highlight_symbol(">>")
+                .row_highlight_style(Style::new().red())
+                .range_highlight_style(Style::new().on_blue());
+            render_both(table, 100, &state);
+        }
+
+        #[test]
+        fn render_empty() {
+            let state = TableState::new().with_selected(3);
+            render_both(Table::default().widths([3, 5]), 0, &state);
+        }
+
+        #[rstest]
+        #[case::selected_in_view(ScrollBehavior::Center, 50, 51)]
+        #[case::selected_out_of_view(ScrollBehavior::Top, 50, 10)]
+        #[case::past_end(ScrollBehavior::Nearest, 5000, 10)]
+        fn render_scrolled_matches_vec_rows(
+            #[case] behavior: ScrollBehavior,
+            #[case] target: usize,
+            #[case] selected: usize,
+            #[values(HighlightSpacing::WhenSelected, HighlightSpacing::Never)]
+            highlight_spacing: HighlightSpacing,
+        ) {
+            let table = Table::default()
+                .widths([Constraint::Length(3), Constraint::Length(5)])
+                .highlight_symbol(">>")
+                .highlight_spacing(highlight_spacing);
+            let mut state = TableState::new().with_selected(selected);
+            state.scroll_to(target, behavior);
+            render_both(table, 100, &state);
+        }
+
+        #[test]
+        fn render_with_wrapped_header() {
+            let table = Table::default()
+                .widths([8, 4])
+                .header(Row::new(["Requests / sec (p99)", "Host"]))
+                .wrap_cells(true);
+            let state = TableState::new().with_selected(4);
+            render_both(table.clone(), 20, &state);
+
+            let mut buf = Buffer::empty(Rect::new(0, 0, 13, 6));
+            let mut state = state;
+            StatefulWidget::render(
+                table.rows_from(20, number_row),
+                buf.area,
+                &mut buf,
+                &mut state,
+            );
+            assert_eq!(state.offset(), 2);
+        }
+
+        #[test]
+        fn render_creates_only_visible_rows() {
+            let created = RefCell::new(Vec::new());
+            let table = Table::default().widths([3, 5]).rows_from(1_000_000, |i| {
+                created.borrow_mut().push(i);
+                number_row(i)
+            });
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let mut state = TableState::new()
+                .with_offset(500_000)
+                .with_selected(700_000);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(created.into_inner(), [699_998, 699_999, 700_000]);
+            assert_eq!(state.offset(), 699_998);
+            assert_eq!(state.selected(), Some(700_000));
+        }
+
+        #[test]
+        fn render_with_row_heights() {
+            let table = Table::default()
+                .widths([3, 5])
+                .rows_from(100, |i| number_row(i).height(2))
+                .row_heights_from(|_| 2);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 9, 5));
+            let mut state = TableState::new().with_offset(10);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "10  100  ",
+                "         ",
+                "11  121  ",
+                "         ",
+                "12  144  ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn rows_replaces_virtual_rows() {
+            let table = Table::default().rows_from(10, number_row);
+            assert!(table.virtual_rows.is_some());
+            let table = table.rows([number_row(0)]);
+            assert!(table.virtual_rows.is_none());
+            assert_eq!(table.rows, [number_row(0)]);
+            let table = table.rows_from(10, number_row);
+            assert_eq!(table.rows, []);
+        }
+
+        #[test]
+        fn render_with_scroll_padding_matches_vec_rows() {
+            let table = Table::default()
+                .widths([Constraint::Length(3), Constraint::Length(5)])
+                .scroll_padding(2);
+            for state in [
+                TableState::new().with_offset(50).with_selected(80),
+                TableState::new().with_offset(50).with_selected(51),
+                TableState::new().with_selected(99),
+            ] {
+                render_both(table.clone(), 100, &state);
+            }
+        }
+
+        #[test]
+        fn render_zebra_matches_vec_rows() {
+            let table = Table::default()
+                .widths([Constraint::Length(3), Constraint::Length(5)])
+                .zebra(Style::new().on_black(), Style::new().on_white());
+            let state = TableState::new().with_offset(51);
+            let buf = render_both(table, 100, &state);
+            assert_eq!(buf[(0, 0)].bg, Color::White);
+            assert_eq!(buf[(0, 1)].bg, Color::Black);
+        }
+
+        #[test]
+        fn render_selection_set_matches_vec_rows() {
+            let table = Table::default()
+                .widths([Constraint::Length(3), Constraint::Length(5)])
+                .multi_highlight_style(Style::new().on_blue())
+                .multi_highlight_symbol("*");
+            let mut state = TableState::new().with_offset(50);
+            for index in [10, 51, 53, 99, 150] {
+                state.toggle(index);
+            }
+            let buf = render_both(table, 100, &state);
+            assert_eq!(buf[(0, 1)].symbol(), "*");
+            assert_eq!(buf[(0, 1)].bg, Color::Blue);
+        }
+    }
+
+    mod column_separator {
+        use super::*;
+        use crate::borders::BorderType;
+
+        fn table() -> Table<'static> {
+            let rows = [Row::new(["a1", "b1", "c1"]), Row::new(["a2", "b2", "c2"])];
+            Table::new(rows, [Constraint::Length(3); 3])
+                .header(Row::new(["A", "B", "C"]))
+                .column_separator("│")
+        }
+
+        fn render(table: Table, area: Rect) -> Buffer {
+            let mut buf = Buffer::empty(area);
+            Widget::render(table, area, &mut buf);
+            buf
+        }
+
+        #[test]
+        fn render_without_block() {
+            let expected =
+                Buffer::with_lines(["A  │B  │C  ", "a1 │b1 │c1 ", "a2 │b2 │c2 ", "           "]);
+            assert_eq!(render(table(), Rect::new(0, 0, 11, 4)), expected);
+        }
+
+        #[test]
+        fn render_with_block() {
+            let table = table().block(Block::bordered());
+            let expected = Buffer::with_lines([
+                "┌───┬───┬───┐",
+                "│A  │B  │C  │",
+                "│a1 │b1 │c1 │",
+                "│a2 │b2 │c2 │",
+                "└───┴───┴───┘",
+            ]);
+            assert_eq!(render(table, Rect::new(0, 0, 13, 5)), expected);
+        }
+
+        #[test]
+        fn render_with_block_taller_than_rows() {
+            // the separators only join the borders they touch
+            let table = table().block(Block::bordered().border_type(BorderType::Double));
+            let expected = Buffer::with_lines([
+                "╔═══╦═══╦═══╗",
+                "║A  │B  │C  ║",
+                "║a1 │b1 │c1 ║",
+                "║a2 │b2 │c2 ║",
+                "║           ║",
+                "╚═══════════╝",
+            ]);
+            assert_eq!(render(table, Rect::new(0, 0, 13, 6)), expected);
+        }
+
+        #[test]
+        fn render_with_block_title() {
+            let table = table().block(Block::bordered().title("Title"));
+            let expected = Buffer::with_lines([
+                "┌Title──┬───┐",
+                "│A  │B  │C  │",
+                "│a1 │b1 │c1 │",
+                "│a2 │b2 │c2 │",
+                "└───┴───┴───┘",
+            ]);
+            assert_eq!(render(table, Rect::new(0, 0, 13, 5)), expected);
+        }
+
+        #[test]
+        fn render_without_column_spacing() {
+            let table = table().column_spacing(0);
+            let expected = Buffer::with_lines(["A  │B  │C  ", "a1 │b1 │c1 ", "a2 │b2 │c2 "]);
+            assert_eq!(render(table, Rect::new(0, 0, 11, 3)), expected);
+        }
+
+        #[test]
+        fn render_in_middle_of_column_spacing() {
+            let table = table().column_spacing(3);
+            let expected =
+                Buffer::with_lines(["A   │ B   │ C  ", "a1  │ b1  │ c1 ", "a2  │ b2  │ c2 "]);
+            assert_eq!(render(table, Rect::new(0, 0, 15, 3)), expected);
+        }
+
+        #[test]
+        fn render_with_colspan() {
+            let rows = [
+                Row::new([Cell::from("spanned").colspan(2), Cell::from("c1")]),
+                Row::new(["a2", "b2", "c2"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(3); 3]).column_separator("│");
+            let expected = Buffer::with_lines(["spanned│c1 ", "a2 │b2 │c2 "]);
+            assert_eq!(render(table, Rect::new(0, 0, 11, 2)), expected);
+        }
+
+        #[test]
+        fn render_with_style() {
+            let table = table().column_separator_style(Style::new().red());
+            let mut expected = Buffer::with_lines(["A  │B  │C  ", "a1 │b1 │c1 ", "a2 │b2 │c2 "]);
+            for y in 0..3 {
+                expected.set_style(Rect::new(3, y, 1, 1), Style::new().red());
+                expected.set_style(Rect::new(7, y, 1, 1), Style::new().red());
+            }
+            assert_eq!(render(table, Rect::new(0, 0, 11, 3)), expected);
+        }
+    }
+
+    mod expanded_rows {
+        use super::*;
+
+        fn table() -> Table<'static> {
+            let rows = (0..5).map(|i| {
+                let details = "details of the row";
+                Row::new([format!("Row{i}")]).details(details)
+            });
+            Table::new(rows, [Constraint::Length(8)])
+                .row_highlight_style(Style::new().red())
+                .highlight_symbol(">>")
+        }
+
+        fn render(table: Table, state: &mut TableState) -> Buffer {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 5));
+            StatefulWidget::render(table, buf.area, &mut buf, state);
+            buf
+        }
+
+        #[test]
+        fn render_expanded_row() {
+            let mut state = TableState::new();
+            state.toggle_expanded(1);
+            let expected = Buffer::with_lines([
+                "Row0      ",
+                "Row1      ",
+                "details of",
+                "the row   ",
+                "Row2      ",
+            ]);
+            assert_eq!(render(table(), &mut state), expected);
+        }
+
+        #[test]
+        fn render_collapsed_rows() {
+            let mut state = TableState::new();
+            let expected = Buffer::with_lines([
+                "Row0      ",
+                "Row1      ",
+                "Row2      ",
+                "Row3      ",
+                "Row4      ",
+            ]);
+            assert_eq!(render(table(), &mut state), expected);
+        }
+
+        #[test]
+        fn render_expanded_row_without_details() {
+            let rows = (0..5).map(|i| Row::new([format!("Row{i}")]));
+            let mut state = TableState::new();
+            state.toggle_expanded(1);
+            let expected = Buffer::with_lines([
+                "Row0      ",
+                "Row1      ",
+                "Row2      ",
+                "Row3      ",
+                "Row4      ",
+            ]);
+            assert_eq!(render(Table::new(rows, [8]), &mut state), expected);
+        }
+
+        #[test]
+        fn render_scrolls_to_row_after_expanded_row() {
+            let mut state = TableState::new().with_selected(2);
+            state.toggle_expanded(1);
+            let expected = Buffer::with_lines([
+                "  Row1    ".into(),
+                "  details ".into(),
+                "  of the  ".into(),
+                "  row     ".into(),
+                ">>Row2    ".red(),
+            ]);
+            assert_eq!(render(table(), &mut state), expected);
+            assert_eq!(state.offset(), 1);
+        }
+
+        #[test]
+        fn render_selected_expanded_row() {
+            let mut state = TableState::new().with_selected(1).with_offset(3);
+            state.toggle_expanded(1);
+            let expected = Buffer::with_lines([
+                ">>Row1    ".red(),
+                "  details ".into(),
+                "  of the  ".into(),
+                "  row     ".into(),
+                "  Row2    ".into(),
+            ]);
+            assert_eq!(render(table(), &mut state), expected);
+            assert_eq!(state.selected(), Some(1));
+        }
+
+        #[test]
+        fn render_details_of_selected_row() {
+            let table = table().expand_selected(true).details_indent(2);
+            let mut state = TableState::new().with_selected(1);
+            let expected = Buffer::with_lines([
+                ">>Row1    ".red(),
+                "    detail".into(),
+                "    s of  ".into(),
+                "    the   ".into(),
+                "    row   ".into(),
+            ]);
+            assert_eq!(render(table, &mut state), expected);
+            assert!(state.expanded().is_empty());
+        }
+
+        #[test]
+        fn render_scrolls_to_details_of_selected_row() {
+            let table = table().expand_selected(true);
+            let mut state = TableState::new().with_selected(4);
+            let expected = Buffer::with_lines([
+                "  Row3    ".into(),
+                ">>Row4    ".red(),
+                "  details ".into(),
+                "  of the  ".into(),
+                "  row     ".into(),
+            ]);
+            assert_eq!(render(table, &mut state), expected);
+            assert_eq!(state.offset(), 3);
+        }
+    }
+
+    mod column_highlight {
+        use super::*;
+
+        fn table() -> Table<'static> {
+            let rows = [
+                Row::new(["Cell1", "Cell2"]),
+                Row::new(["Cell3", "Cell4"]),
+                Row::new(["Cell5", "Cell6"]),
+            ];
+            Table::new(rows, [5, 5]).header(Row::new(["Head1", "Head2"]))
+        }
+
+        #[test]
+        fn render_header_and_body_styles() {
+            let table = table()
+                .selected_column_header_style(Style::new().bold())
+                .column_highlight_style(Style::new().dim());
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 4));
+            let mut state = TableState::new().with_selected_column(1);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf[(6, 0)].modifier, Modifier::BOLD);
+            assert_eq!(buf[(6, 1)].modifier, Modifier::DIM);
+            assert_eq!(buf[(0, 0)].modifier, Modifier::empty());
+            assert_eq!(buf[(0, 1)].modifier, Modifier::empty());
+        }
+
+        #[test]
+        fn render_with_style_fn() {
+            let table = table()
+                .row_highlight_style(Style::new().red())
+                .column_highlight_style(Style::new().blue())
+                .column_highlight_style_fn(|i| {
+                    if i == 1 {
+                        Style::new().bold()
+                    } else {
+                        Style::new().italic()
+                    }
+                })
+                .cell_highlight_style(Style::new().green());
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 4));
+            let mut state = TableState::new().with_selected_cell((1, 1));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            let expected = Buffer::with_lines([
+                Line::from("Head1 Head2"),
+                Line::from(vec!["Cell1 ".into(), "Cell2".blue().italic()]),
+                Line::from(vec!["Cell3 ".red(), "Cell4".green().bold()]),
+                Line::from(vec!["Cell5 ".into(), "Cell6".blue().italic()]),
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_style_fn_and_virtual_rows() {
+            let table = Table::default()
+                .rows_from(100, |i| Row::new([format!("Row{i}")]))
+                .widths([5])
+                .column_highlight_style_fn(|i| {
+                    if i == 50 {
+                        Style::new().bold()
+                    } else {
+                        Style::new()
+                    }
+                });
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+            let mut state = TableState::new().with_offset(49).with_selected_column(0);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            let expected = Buffer::with_lines([Line::from("Row49"), Line::from("Row50".bold())]);
+            assert_eq!(buf, expected);
+        }
+    }
+
+    mod selection_set {
+        use super::*;
+
+        fn table() -> Table<'static> {
+            let rows = (0..6).map(|i| Row::new([format!("Row{i}")]));
+            Table::new(rows, [Constraint::Length(4)])
+                .row_highlight_style(Style::new().red())
+                .multi_highlight_style(Style::new().on_blue())
+                .highlight_symbol(">>")
+                .multi_highlight_symbol("*")
+        }
+
+        fn render(state: &mut TableState) -> Buffer {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+            StatefulWidget::render(table(), buf.area, &mut buf, state);
+            buf
+        }
+
+        #[test]
+        fn render_set_without_cursor() {
+            let mut state = TableState::new();
+            state.toggle(1);
+            let expected =
+                Buffer::with_lines(["  Row0 ".into(), "* Row1 ".on_blue(), "  Row2 ".into()]);
+            assert_eq!(render(&mut state), expected);
+        }
+
+        #[test]
+        fn render_cursor_in_set() {
+            let mut state = TableState::new().with_selected(1);
+            state.select_range_to(2);
+            let expected = Buffer::with_lines([
+                "  Row0 ".into(),
+                "* Row1 ".on_blue(),
+                ">>Row2 ".red().on_blue(),
+            ]);
+            assert_eq!(render(&mut state), expected);
+        }
+
+        #[test]
+        fn render_set_scrolled_out_of_view() {
+            let mut state = TableState::new().with_selected(5);
+            for index in [0, 3, 4] {
+                state.toggle(index);
+            }
+            let expected =
+                Buffer::with_lines(["* Row3 ".on_blue(), "* Row4 ".on_blue(), ">>Row5 ".red()]);
+            assert_eq!(render(&mut state), expected);
+            assert!(state.selected_set().iter().eq(&[0, 3, 4]));
+        }
+
+        #[test]
+        fn render_removes_set_rows_beyond_the_end() {
+            let mut state = TableState::new();
+            for index in [2, 6, 10] {
+                state.toggle(index);
+            }
+            render(&mut state);
+            assert!(state.selected_set().iter().eq(&[2]));
+        }
+    }
+
+    mod row_style {
+        use super::*;
+
+        fn numbers(count: usize) -> impl Iterator<Item = Row<'static>> {
+            (0..count).map(|i| Row::new([i.to_string()]))
+        }
+
+        fn striped(bg: [Color; 3]) -> Buffer {
+            let mut expected = Buffer::empty(Rect::new(0, 0, 3, 3));
+            for (y, bg) in (0..).zip(bg) {
+                expected.set_style(Rect::new(0, y, 3, 1), Style::new().bg(bg));
+            }
+            expected
+        }
+
+        #[rstest]
+        #[case::no_offset(0, ["0", "1", "2"], [Color::Black, Color::White, Color::Black])]
+        #[case::odd_offset(3, ["3", "4", "5"], [Color::White, Color::Black, Color::White])]
+        #[case::even_offset(4, ["4", "5", "6"], [Color::Black, Color::White, Color::Black])]
+        fn zebra_keeps_phase_when_scrolled(
+            #[case] offset: usize,
+            #[case] lines: [&str; 3],
+            #[case] bg: [Color; 3],
+        ) {
+            let table = Table::new(numbers(10), [Constraint::Length(3)])
+                .zebra(Style::new().on_black(), Style::new().on_white());
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+            let mut state = TableState::new().with_offset(offset);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            let mut expected = striped(bg);
+            for (y, line) in (0..).zip(lines) {
+                expected.set_string(0, y, line, Style::new());
+            }
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn alternating_row_styles_four_rows() {
+            let table = Table::new(numbers(4), [Constraint::Length(3)])
+                .alternating_row_styles(Style::new().on_black(), Style::new().on_white());
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 6));
+            Widget::render(table, buf.area, &mut buf);
+            let mut expected = Buffer::with_lines(["0  ", "1  ", "2  ", "3  ", "   ", "   "]);
+            expected.set_style(Rect::new(0, 0, 3, 1), Style::new().on_black());
+            expected.set_style(Rect::new(0, 1, 3, 1), Style::new().on_white());
+            expected.set_style(Rect::new(0, 2, 3, 1), Style::new().on_black());
+            expected.set_style(Rect::new(0, 3, 3, 1), Style::new().on_white());
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn row_style_fn_receives_row() {
+            let rows = [
+                Row::new(["a"]),
+                Row::new(["b"]).bottom_margin(1),
+                Row::new(["c"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(3)]).row_style_fn(|i, row| {
+                if row.bottom_margin > 0 {
+                    Style::new().on_red()
+                } else {
+                    Style::new().fg(Color::Indexed(u8::try_from(i).unwrap()))
+                }
+            });
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 4));
+            Widget::render(table, buf.area, &mut buf);
+            let mut expected = Buffer::with_lines(["a  ", "b  ", "   ", "c  "]);
+            expected.set_style(Rect::new(0, 0, 3, 1), Style::new().fg(Color::Indexed(0)));
+            expected.set_style(Rect::new(0, 1, 3, 1), Style::new().on_red());
+            expected.set_style(Rect::new(0, 3, 3, 1), Style::new().fg(Color::Indexed(2)));
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn layered_under_row_cell_and_highlight_styles() {
+            let rows = [
+                Row::new(["a"]),
+                Row::new(["b"]).on_blue(),
+                Row::new([Cell::new("c").on_green()]),
+                Row::new(["d"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(3)])
+                .zebra(
+                    Style::new().on_black().italic(),
+                    Style::new().on_white().italic(),
+                )
+                .row_highlight_style(Style::new().on_red());
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 4));
+            let mut state = TableState::new().with_selected(3);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            let mut expected = Buffer::with_lines(["a  ", "b  ", "c  ", "d  "]);
+            expected.set_style(expected.area, Style::new().italic());
+            expected.set_style(Rect::new(0, 0, 3, 1), Style::new().on_black());
+            expected.set_style(Rect::new(0, 1, 3, 1), Style::new().on_blue());
+            expected.set_style(Rect::new(0, 2, 3, 1), Style::new().on_green());
+            expected.set_style(Rect::new(0, 3, 3, 1), Style::new().on_red());
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn zebra_follows_sorted_order() {
+            let rows = [Row::new(["b"]), Row::new(["c"]), Row::new(["a"])];
+            let values = ["b", "c", "a"];
+            let mut state = TableState::new().with_sort_column(Some((0, SortDirection::Ascending)));
+            let table = Table::new(rows, [Constraint::Length(3)])
+                .zebra(Style::new().on_black(), Style::new().on_white())
+                .sorted_by(&mut state, |_, a, b| values[a].cmp(values[b]));
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            let mut expected = striped([Color::Black, Color::White, Color::Black]);
+            for (y, line) in (0..).zip(["a", "b", "c"]) {
+                expected.set_string(0, y, line, Style::new());
+            }
+            assert_eq!(buf, expected);
+        }
+    }
+    mod scroll_padding {
+        use super::*;
+
+        fn render(table: &Table, height: u16, state: &mut TableState) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, height));
+            StatefulWidget::render(table, buf.area, &mut buf, state);
+        }
+
+        fn table() -> Table<'static> {
+            let rows = (0..100).map(|i| Row::new([i.to_string()]));
+            Table::new(rows, [Constraint::Length(3)]).scroll_padding(3)
+        }
+
+        #[test]
+        fn step_down_and_up() {
+            let table = table();
+            let mut state = TableState::new().with_selected(0);
+            render(&table, 10, &mut state);
+            assert_eq!(state.offset(), 0);
+
+            for selected in 1..100 {
+                state.select_next();
+                render(&table, 10, &mut state);
+                assert_eq!(state.selected(), Some(selected));
+                // 3 rows are kept below the selection until the end of the table
+                let expected = selected.saturating_sub(6).min(90);
+                assert_eq!(
+                    state.offset(),
+                    expected,
+                    "offset when {selected} is selected"
+                );
+            }
+
+            for selected in (0..99_usize).rev() {
+                state.select_previous();
+                render(&table, 10, &mut state);
+                // 3 rows are kept above the selection until the start of the table
+                let expected = selected.saturating_sub(3).min(90);
+                assert_eq!(
+                    state.offset(),
+                    expected,
+                    "offset when {selected} is selected"
+                );
+            }
+        }
+
+        #[rstest]
+        #[case::start(0)]
+        #[case::middle(50)]
+        #[case::near_end(97)]
+        #[case::end(99)]
+        fn stable_offset(#[case] selected: usize) {
+            let table = table();
+            let mut state = TableState::new().with_selected(selected);
+            render(&table, 10, &mut state);
+            let offset = state.offset();
+            for _ in 0..3 {
+                render(&table, 10, &mut state);
+                assert_eq!(state.offset(), offset);
+            }
+        }
+
+        #[test]
+        fn short_viewport_centers_selection() {
+            // a viewport of 5 rows can only keep 2 rows around the selection
+            let table = table();
+            let mut state = TableState::new().with_selected(0);
+            for selected in 1..98_usize {
+                state.select_next();
+                render(&table, 5, &mut state);
+                let expected = selected.saturating_sub(2);
+                assert_eq!(
+                    state.offset(),
+                    expected,
+                    "offset when {selected} is selected"
+                );
+            }
+        }
+
+        #[test]
+        fn tall_rows_reduce_padding() {
+            let rows = (0..10).map(|i| Row::new([i.to_string()]).height(2));
+            let table = Table::new(rows, [Constraint::Length(3)]).scroll_padding(2);
+            let mut state = TableState::new().with_selected(5);
+            // 3 rows of height 2 fit, so the padding is reduced to 1
+            render(&table, 6, &mut state);
+            assert_eq!(state.offset(), 4);
+        }
+    }
+
+    mod scroll_to {
+        use super::*;
+
+        /// A table of 20 rows, where the rows at odd indexes are 2 lines high
+        fn table() -> Table<'static> {
+            let rows = (0..20).map(|i| Row::new([i.to_string()]).height(1 + i as u16 % 2));
+            Table::new(rows, [Constraint::Length(3)])
+        }
+
+        /// Renders the table in a 7 lines high area, scrolled from the offset 5 to the target
+        fn render(table: &Table, target: usize, behavior: ScrollBehavior) -> TableState {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 7));
+            let mut state = TableState::new().with_offset(5).with_selected(6);
+            state.scroll_to(target, behavior);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            state
+        }
+
+        #[rstest]
+        #[case::top(ScrollBehavior::Top, 10, 10)]
+        #[case::top_past_end(ScrollBehavior::Top, 50, 19)]
+        #[case::center(ScrollBehavior::Center, 10, 8)]
+        #[case::center_tall_row(ScrollBehavior::Center, 11, 10)]
+        #[case::center_start(ScrollBehavior::Center, 1, 0)]
+        #[case::center_past_end(ScrollBehavior::Center, 50, 16)]
+        #[case::nearest_above(ScrollBehavior::Nearest, 2, 2)]
+        #[case::nearest_visible(ScrollBehavior::Nearest, 7, 5)]
+        #[case::nearest_below(ScrollBehavior::Nearest, 10, 6)]
+        #[case::nearest_past_end(ScrollBehavior::Nearest, 50, 16)]
+        fn offset(
+            #[case] behavior: ScrollBehavior,
+            #[case] target: usize,
+            #[case] expected: usize,
+        ) {
+            let state = render(&table(), target, behavior);
+            assert_eq!(state.offset(), expected);
+            assert_eq!(state.selected(), Some(6));
+        }
+
+        #[test]
+        fn center_with_header_and_margins() {
+            // the rows take 2 lines with their margin, and the header leaves 6 lines for them
+            let rows = (0..20).map(|i| Row::new([i.to_string()]).bottom_margin(1));
+            let table = Table::new(rows, [Constraint::Length(3)]).header(Row::new(["n"]));
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 7));
+            let mut state = TableState::new();
+            state.scroll_to(10, ScrollBehavior::Center);
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            assert_eq!(state.offset(), 9);
+            assert_eq!(
+                buf,
+                Buffer::with_lines(["n  ", "9  ", "   ", "10 ", "   ", "11 ", "   "])
+            );
+        }
+
+        #[test]
+        fn target_is_kept_until_selection_changes() {
+            let table = table();
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+            let mut state = TableState::new().with_selected(0);
+            state.scroll_to(10, ScrollBehavior::Top);
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            assert_eq!(state.offset(), 10);
+
+            state.select_next();
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            assert_eq!(state.offset(), 1);
+        }
+
+        #[test]
+        fn select_wrapping_scrolls_to_other_end() {
+            let rows = (0..10).map(|i| Row::new([i.to_string()]));
+            let table = Table::new(rows, [Constraint::Length(3)]);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+            let mut state = TableState::new().with_offset(7).with_selected(9);
+
+            state.select_next_wrapping(10);
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            assert_eq!((state.offset(), state.selected()), (0, Some(0)));
+            assert_eq!(buf, Buffer::with_lines(["0  ", "1  ", "2  "]));
+
+            state.select_previous_wrapping(10);
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            assert_eq!((state.offset(), state.selected()), (7, Some(9)));
+            assert_eq!(buf, Buffer::with_lines(["7  ", "8  ", "9  "]));
+        }
+    }
+
+    mod column_resizing {
+        use super::*;
+
+        fn table() -> Table<'static> {
+            Table::new(
+                [Row::new(["aaaa", "bbbb", "cccc"])],
+                [Constraint::Length(4); 3],
+            )
+            .column_spacing(2)
+        }
+
+        fn render(table: &Table, state: &mut TableState) -> Buffer {
+            let mut buf = Buffer::empty(Rect::new(1, 0, 20, 1));
+            StatefulWidget::render(table, buf.area, &mut buf, state);
+            buf
+        }
+
+        #[test]
+        fn column_boundaries_with_spacing() {
+            let mut state = TableState::new();
+            render(&table(), &mut state);
+            assert_eq!(state.column_boundaries().collect_vec(), [5, 11, 17]);
+            assert_eq!(
+                (0..20)
+                    .filter_map(|x| state.column_boundary_at(x))
+                    .collect_vec(),
+                [0, 0, 0, 1, 1, 1, 2]
+            );
+        }
+
+        #[test]
+        fn column_boundaries_with_selection() {
+            let table = table().highlight_symbol(">>");
+            let mut state = TableState::new().with_selected(0);
+            render(&table, &mut state);
+            assert_eq!(state.column_boundaries().collect_vec(), [7, 13, 19]);
+        }
+
+        #[test]
+        fn override_takes_precedence() {
+            let mut state = TableState::new();
+            state.set_column_width_override(0, Some(2));
+            let buf = render(&table(), &mut state);
+            let mut expected = Buffer::with_lines(["aa  bbbb  cccc      "]);
+            expected.area.x = 1;
+            assert_eq!(buf, expected);
+            assert_eq!(state.column_boundaries().collect_vec(), [3, 9, 15]);
+        }
+
+        #[test]
+        fn override_sized_to_content_column() {
+            let table = table().column_widths([
+                ColumnWidth::Auto { min: 1, max: 10 },
+                ColumnWidth::from(4),
+                ColumnWidth::from(4),
+            ]);
+            let mut state = TableState::new();
+            state.set_column_width_override(0, Some(6));
+            render(&table, &mut state);
+            assert_eq!(state.column_boundaries().collect_vec(), [7, 13, 19]);
+        }
+
+        #[test]
+        fn drag_and_reset() {
+            let table = table();
+            let mut state = TableState::new().with_min_column_width(2);
+            render(&table, &mut state);
+
+            let column = state.column_boundary_at(11).unwrap();
+            state.begin_resize(column);
+            state.resize_to(13);
+            render(&table, &mut state);
+            assert_eq!(state.column_boundaries().collect_vec(), [5, 14, 20]);
+            // the width is clamped when dragging past the start of the column
+            state.resize_to(2);
+            render(&table, &mut state);
+            assert_eq!(state.column_boundaries().collect_vec(), [5, 9, 15]);
+            state.end_resize();
+
+            state.reset_column_widths();
+            render(&table, &mut state);
+            assert_eq!(state.column_boundaries().collect_vec(), [5, 11, 17]);
+        }
+    }
+
+    mod hit_testing {
+        use super::*;
+
+        fn table() -> Table<'static> {
+            let rows = [
+                Row::new(["a", "b"]),
+                Row::new(["c\nc", "d"]).height(2).bottom_margin(1),
+                Row::new(["e", "f"]).details("details"),
+            ];
+            Table::new(rows, [Constraint::Length(3); 2])
+                .header(Row::new(["A", "B"]))
+                .block(Block::bordered())
+                .highlight_symbol(">>")
+                .highlight_spacing(HighlightSpacing::Always)
+        }
+
+        fn render(table: &Table, state: &mut TableState) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 10));
+            StatefulWidget::render(table, buf.area, &mut buf, state);
+        }
+
+        #[rstest]
+        #[case::first_row((3, 2), Some(0))]
+        #[case::selection_symbol_column((1, 2), Some(0))]
+        #[case::second_line_of_tall_row((3, 4), Some(1))]
+        #[case::row_margin((3, 5), None)]
+        #[case::border((0, 2), None)]
+        #[case::header((3, 1), None)]
+        #[case::padding((3, 8), None)]
+        fn row_at(#[case] position: (u16, u16), #[case] expected: Option<usize>) {
+            let mut state = TableState::new();
+            render(&table(), &mut state);
+            assert_eq!(state.row_at(position), expected);
+        }
+
+        #[test]
+        fn row_at_expanded_details() {
+            let mut state = TableState::new();
+            state.toggle_expanded(2);
+            render(&table(), &mut state);
+            assert_eq!(state.row_at((3, 7)), Some(2));
+            assert_eq!(state.row_at((3, 8)), None);
+        }
+
+        #[test]
+        fn row_at_scrolled_table() {
+            let rows = (0..10).map(|i| Row::new([i.to_string()]));
+            let table = Table::new(rows, [Constraint::Length(3)]);
+            let mut state = TableState::new().with_selected(9);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            assert_eq!(state.row_at((0, 0)), Some(7));
+            assert_eq!(state.row_at((0, 2)), Some(9));
+        }
+
+        #[test]
+        fn row_at_virtual_rows() {
+            let table = Table::default()
+                .widths([Constraint::Length(3)])
+                .rows_from(100, |i| Row::new([i.to_string()]));
+            let mut state = TableState::new().with_selected(50);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            assert_eq!(state.row_at((0, 0)), Some(48));
+            assert_eq!(state.row_at((0, 2)), Some(50));
+        }
+
+        #[rstest]
+        #[case::first_column((3, 4), Some((1, 0)))]
+        #[case::second_column((7, 2), Some((0, 1)))]
+        #[case::column_spacing((6, 2), None)]
+        #[case::selection_symbol_column((1, 2), None)]
+        #[case::header((3, 1), None)]
+        fn cell_at(#[case] position: (u16, u16), #[case] expected: Option<(usize, usize)>) {
+            let mut state = TableState::new();
+            render(&table(), &mut state);
+            assert_eq!(state.cell_at(position), expected);
+        }
+    }
+
+    mod auto_column_widths {
+        use alloc::string::String;
+
+        use ratatui_core::buffer;
+
+        use super::*;
+
+        fn render(table: Table, width: u16, height: u16) -> Buffer {
+            let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+            let mut state = TableState::new();
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            buf
+        }
+
+        fn people() -> Table<'static> {
+            let rows = [
+                Row::new(["1", "Alice", "reads"]),
+                Row::new(["2", "Bob", "runs"]),
+            ];
+            Table::new(rows, [0; 3])
+                .header(Row::new(["#", "Name", "Note"]))
+                .column_widths([
+                    ColumnWidth::from(2),
+                    ColumnWidth::Auto { min: 3, max: 6 },
+                    ColumnWidth::from(Constraint::Fill(1)),
+                ])
+        }
+
+        #[rstest]
+        #[case::wide(15, ["#  Name  Note  ", "1  Alice reads ", "2  Bob   runs  "])]
+        #[case::narrow(11, ["#  Name  No", "1  Alice re", "2  Bob   ru"])]
+        fn fixed_auto_and_fill(#[case] width: u16, #[case] expected: [&str; 3]) {
+            assert_eq!(render(people(), width, 3), Buffer::with_lines(expected));
+        }
+
+        #[rstest]
+        #[case::min(["a", "b"], 4)]
+        #[case::max(["Alexandria", "b"], 6)]
+        #[case::wide_characters(["コンピ", "b"], 6)]
+        #[case::colspan_not_measured(["a", "spanning"], 4)]
+        fn clamped_content_width(#[case] cells: [&str; 2], #[case] expected: usize) {
+            let row = Row::new([Cell::new(cells[0]), Cell::new(cells[1]).colspan(2)]);
+            let others = Row::new(["", "", "|"]);
+            let table = Table::new([row, others], [0; 3])
+                .column_spacing(0)
+                .column_widths([
+                    ColumnWidth::Auto { min: 4, max: 6 },
+                    ColumnWidth::from(0),
+                    ColumnWidth::from(Constraint::Fill(1)),
+                ]);
+            let buf = render(table, 10, 2);
+            let line: String = buf.content[10..20]
+                .iter()
+                .map(buffer::Cell::symbol)
+                .collect();
+            assert_eq!(line.find('|'), Some(expected));
+        }
+
+        #[test]
+        fn header_with_sort_indicator() {
+            let table = Table::new([Row::new(["ab", "|"])], [0; 2])
+                .header(Row::new(["Name", ""]))
+                .column_widths([
+                    ColumnWidth::Auto { min: 0, max: 10 },
+                    ColumnWidth::from(Constraint::Fill(1)),
+                ]);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 2));
+            let mut state = TableState::new().with_sort_column(Some((0, SortDirection::Ascending)));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["Name ▲    ", "ab     |  "]));
+        }
+
+        #[rstest]
+        #[case::visible_rows(false, ["a   |     "])]
+        #[case::all_rows(true, ["a        |"])]
+        fn measured_rows(#[case] measure_all_rows: bool, #[case] expected: [&str; 1]) {
+            let rows = [Row::new(["a", "|"]), Row::new(["abcdefgh", "|"])];
+            let table = Table::new(rows, [0; 2])
+                .column_widths([
+                    ColumnWidth::Auto { min: 3, max: 20 },
+                    ColumnWidth::from(Constraint::Fill(1)),
+                ])
+                .measure_all_rows(measure_all_rows);
+            assert_eq!(render(table, 10, 1), Buffer::with_lines(expected));
+        }
+
+        #[test]
+        fn virtual_rows_measured_on_visible_rows() {
+            let table = Table::default()
+                .column_widths([
+                    ColumnWidth::Auto { min: 1, max: 20 },
+                    ColumnWidth::from(Constraint::Fill(1)),
+                ])
+                .rows_from(1000, |i| Row::new([i.to_string(), "|".to_string()]));
+            let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+            let mut state = TableState::new().with_offset(99);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["99  |   ", "100 |   "]));
+        }
+
+        #[test]
+        fn widths_replaces_column_widths() {
+            let table = Table::default().column_widths([ColumnWidth::Auto { min: 2, max: 5 }]);
+            assert_eq!(table.widths, [Constraint::Length(2)]);
+            assert_eq!(table.column_widths, [ColumnWidth::Auto { min: 2, max: 5 }]);
+            let table = table.widths([Constraint::Length(3)]);
+            assert_eq!(table.widths, [Constraint::Length(3)]);
+            assert_eq!(table.column_widths, []);
+        }
+
+        #[test]
+        #[should_panic = "Percentages should be between 0 and 100 inclusively"]
+        fn column_widths_percentage_over_100() {
+            let _ = Table::default().column_widths([Constraint::Percentage(110)]);
+        }
+    }
+
+    mod frozen_columns {
+        use core::iter;
+
+        use super::*;
+
+        fn frozen_table() -> Table<'static> {
+            let header =
+                Row::new(iter::once("Name".to_string()).chain((1..10).map(|i| format!("Col{i}"))));
+            let rows = [Row::new(
+                iter::once("n".to_string()).chain((1..10).map(|i| format!("v{i}"))),
+            )];
+            Table::new(rows, [Constraint::Length(4); 10])
+                .header(header)
+                .horizontal_scroll(true)
+                .frozen_columns(1)
+        }
+
+        #[test]
+        fn render_frozen_column_without_offset() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 22, 2));
+            let mut state = TableState::new();
+            StatefulWidget::render(frozen_table(), buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Name │ Col1 Col2 Col3 ",
+                "n    │ v1   v2   v3   ",
+            ]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.column_offset, 1);
+        }
+
+        #[test]
+        fn render_frozen_column_with_offset() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 22, 2));
+            let mut state = TableState::new().with_column_offset(5);
+            StatefulWidget::render(frozen_table(), buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Name │ Col5 Col6 Col7 ",
+                "n    │ v5   v6   v7   ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_frozen_column_without_column_spacing() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 18, 2));
+            let table = frozen_table().column_spacing(0);
+            let mut state = TableState::new().with_column_offset(3);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Name│Col3Col4Col5C",
+                "n   │v3  v4  v5  v",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_frozen_columns_wider_than_area() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 7, 2));
+            let table = frozen_table().frozen_columns(2);
+            let mut state = TableState::new().with_column_offset(5);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Name Co",
+                "n    v1",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_all_columns_frozen() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+            let table = Table::new([Row::new(["a", "b"])], [Constraint::Length(4); 2])
+                .horizontal_scroll(true)
+                .frozen_columns(5);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut TableState::new());
+            assert_eq!(buf, Buffer::with_lines(["a    b      "]));
+        }
+
+        #[test]
+        fn render_selected_column_scrolls_after_frozen_column() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 22, 2));
+            let table = frozen_table().column_highlight_style(Style::new().bold());
+            let mut state = TableState::new().with_selected_column(8);
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            let mut expected =
+                Buffer::with_lines(["Name │ Col6 Col7 Col8 ", "n    │ v6   v7   v8   "]);
+            expected.set_style(Rect::new(17, 1, 4, 1), Style::new().bold());
+            assert_eq!(buf, expected);
+            assert_eq!(state.column_offset, 6);
+
+            // selecting the frozen column does not scroll
+            state.select_column(Some(0));
+            let mut buf = Buffer::empty(Rect::new(0, 0, 22, 2));
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            let mut expected =
+                Buffer::with_lines(["Name │ Col6 Col7 Col8 ", "n    │ v6   v7   v8   "]);
+            expected.set_style(Rect::new(0, 1, 4, 1), Style::new().bold());
+            assert_eq!(buf, expected);
+            assert_eq!(state.column_offset, 6);
+        }
+
+        #[test]
+        fn render_frozen_column_with_highlighted_row() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 24, 2));
+            let table = frozen_table()
+                .highlight_symbol(">>")
+                .row_highlight_style(Style::new().reversed());
+            let mut state = TableState::new().with_selected(0).with_column_offset(2);
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            let mut expected =
+                Buffer::with_lines(["  Name │ Col2 Col3 Col4 ", ">>n    │ v2   v3   v4   "]);
+            expected.set_style(Rect::new(0, 1, 24, 1), Style::new().reversed());
+            assert_eq!(buf, expected);
+        }
     }
 
     // test how constraints interact with table column width allocation
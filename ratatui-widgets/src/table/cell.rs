@@ -1,9 +1,12 @@
 use ratatui_core::buffer::Buffer;
-use ratatui_core::layout::Rect;
+use ratatui_core::layout::{Alignment, Rect};
 use ratatui_core::style::{Style, Styled};
-use ratatui_core::text::Text;
+use ratatui_core::text::{Span, StyledGrapheme, Text};
 use ratatui_core::widgets::Widget;
 
+use crate::paragraph::render_lines;
+use crate::reflow::{LineComposer, WordWrapper};
+
 /// A [`Cell`] contains the [`Text`] to be displayed in a [`Row`] of a [`Table`].
 ///
 /// You can apply a [`Style`] to the [`Cell`] using [`Cell::style`]. This will set the style for the
@@ -13,6 +16,8 @@ use ratatui_core::widgets::Widget;
 ///
 /// You can use [`Text::alignment`] when creating a cell to align its content.
 ///
+/// A cell can span several columns with [`Cell::colspan`].
+///
 /// # Examples
 ///
 /// You can create a `Cell` from anything that can be converted to a [`Text`].
@@ -47,10 +52,21 @@ use ratatui_core::widgets::Widget;
 /// [`Row`]: super::Row
 /// [`Table`]: super::Table
 /// [`Stylize`]: ratatui_core::style::Stylize
-#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Cell<'a> {
     content: Text<'a>,
     style: Style,
+    colspan: u16,
+}
+
+impl Default for Cell<'_> {
+    fn default() -> Self {
+        Self {
+            content: Text::default(),
+            style: Style::default(),
+            colspan: 1,
+        }
+    }
 }
 
 impl<'a> Cell<'a> {
@@ -80,6 +96,7 @@ impl<'a> Cell<'a> {
         Self {
             content: content.into(),
             style: Style::default(),
+            colspan: 1,
         }
     }
 
@@ -150,12 +167,135 @@ impl<'a> Cell<'a> {
         self.style = style.into();
         self
     }
+
+    /// Set the number of columns this [`Cell`] spans
+    ///
+    /// The cell is drawn over the widths of `colspan` columns, including the spacing between them,
+    /// and the following cells of the row start after the spanned columns. A span that goes beyond
+    /// the last column is cut off at the last column, and the cells after it are not displayed. A
+    /// `colspan` of 0 is treated as 1. Spans don't affect the width of the columns.
+    ///
+    /// When the selected column of a [`Table`] is one of the spanned columns, the whole cell is
+    /// highlighted.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Cell, Row};
+    ///
+    /// // a section header spanning the three columns of a table
+    /// let row = Row::new([Cell::new("── Production ──").colspan(3)]);
+    /// ```
+    ///
+    /// [`Table`]: super::Table
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn colspan(mut self, colspan: u16) -> Self {
+        self.colspan = colspan;
+        self
+    }
 }
 
 impl Cell<'_> {
-    pub(crate) fn render(&self, area: Rect, buf: &mut Buffer) {
+    /// Returns the number of columns spanned by the cell, which is at least 1.
+    pub(crate) fn column_span(&self) -> usize {
+        usize::from(self.colspan.max(1))
+    }
+
+    /// Returns the number of lines of the content.
+    pub(crate) fn height(&self) -> u16 {
+        u16::try_from(self.content.height()).unwrap_or(u16::MAX)
+    }
+
+    /// Returns the display width of the widest line of the content.
+    pub(crate) fn content_width(&self) -> u16 {
+        u16::try_from(self.content.width()).unwrap_or(u16::MAX)
+    }
+
+    /// Renders the content, aligning text that has no alignment of its own to `alignment`.
+    pub(crate) fn render(&self, area: Rect, buf: &mut Buffer, alignment: Option<Alignment>) {
+        buf.set_style(area, self.style);
+        let Some(alignment) = alignment.filter(|_| self.content.alignment.is_none()) else {
+            Widget::render(&self.content, area, buf);
+            return;
+        };
+        let area = area.intersection(buf.area);
+        buf.set_style(area, self.content.style);
+        for (line, line_area) in self.content.iter().zip(area.rows()) {
+            if line.alignment.is_some() {
+                Widget::render(line, line_area, buf);
+            } else {
+                Widget::render(line.clone().alignment(alignment), line_area, buf);
+            }
+        }
+    }
+
+    /// Renders the content followed by `suffix` at the end of its last line.
+    ///
+    /// The end of the last line is cut when the suffix doesn't fit after it in the area.
+    pub(crate) fn render_with_suffix(
+        &self,
+        suffix: &Span,
+        area: Rect,
+        buf: &mut Buffer,
+        alignment: Option<Alignment>,
+    ) {
+        buf.set_style(area, self.style);
+        let mut content = self.content.clone();
+        let max_width = usize::from(area.width).saturating_sub(suffix.width());
+        if let Some(line) = content.lines.last_mut() {
+            if line.width() > max_width {
+                *line = core::mem::take(line).truncate_with_ellipsis(max_width, "");
+            }
+        }
+        content.push_span(suffix.clone());
+        content.alignment = content.alignment.or(alignment);
+        Widget::render(&content, area, buf);
+    }
+
+    /// Renders the content word-wrapped to the width of the area.
+    pub(crate) fn render_wrapped(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        alignment: Option<Alignment>,
+    ) {
         buf.set_style(area, self.style);
-        Widget::render(&self.content, area, buf);
+        if area.is_empty() {
+            return;
+        }
+        buf.set_style(area, self.content.style);
+        let composer = WordWrapper::new(self.styled_lines(alignment), area.width, true);
+        render_lines(composer, area, buf);
+    }
+
+    /// Returns the number of lines of the content once word-wrapped to `width`.
+    pub(crate) fn wrapped_height(&self, width: u16) -> u16 {
+        if width == 0 {
+            return 0;
+        }
+        let mut composer = WordWrapper::new(self.styled_lines(None), width, true);
+        let mut height = 0_u16;
+        while composer.next_line().is_some() {
+            height = height.saturating_add(1);
+        }
+        height
+    }
+
+    fn styled_lines(
+        &self,
+        alignment: Option<Alignment>,
+    ) -> impl Iterator<Item = (impl Iterator<Item = StyledGrapheme<'_>>, Alignment)> {
+        self.content.iter().map(move |line| {
+            let graphemes = line.styled_graphemes(self.content.style);
+            let alignment = line
+                .alignment
+                .or(self.content.alignment)
+                .or(alignment)
+                .unwrap_or_default();
+            (graphemes, alignment)
+        })
     }
 }
 
@@ -167,6 +307,7 @@ where
         Self {
             content: content.into(),
             style: Style::default(),
+            colspan: 1,
         }
     }
 }
@@ -208,6 +349,49 @@ mod tests {
         assert_eq!(cell.style, style);
     }
 
+    #[test]
+    fn colspan() {
+        assert_eq!(Cell::new("").colspan, 1);
+        assert_eq!(Cell::default().colspan, 1);
+        assert_eq!(Cell::from("").colspan, 1);
+        let cell = Cell::new("").colspan(3);
+        assert_eq!(cell.colspan, 3);
+        assert_eq!(cell.column_span(), 3);
+        assert_eq!(Cell::new("").colspan(0).column_span(), 1);
+    }
+
+    #[test]
+    fn content_width() {
+        assert_eq!(Cell::new("").content_width(), 0);
+        assert_eq!(Cell::new("ab\nabcd").content_width(), 4);
+        assert_eq!(Cell::new("コンピ").content_width(), 6);
+    }
+
+    #[test]
+    fn wrapped_height() {
+        let cell = Cell::new("a few words\nto wrap");
+        assert_eq!(cell.wrapped_height(0), 0);
+        assert_eq!(cell.wrapped_height(20), 2);
+        assert_eq!(cell.wrapped_height(7), 3);
+        assert_eq!(cell.wrapped_height(3), 7);
+    }
+
+    #[test]
+    fn render_wrapped() {
+        let cell = Cell::new("a few words");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 3));
+        cell.render_wrapped(buf.area, &mut buf, None);
+        assert_eq!(buf, Buffer::with_lines(["a few ", "words ", "      "]));
+    }
+
+    #[test]
+    fn render_wrapped_wide_characters() {
+        let cell = Cell::new("コンピュータ");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        cell.render_wrapped(buf.area, &mut buf, None);
+        assert_eq!(buf, Buffer::with_lines(["コン ", "ピュ ", "ータ "]));
+    }
+
     #[test]
     fn stylize() {
         assert_eq!(
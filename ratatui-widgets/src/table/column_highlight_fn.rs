@@ -0,0 +1,92 @@
+use alloc::rc::Rc;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use ratatui_core::style::Style;
+
+/// A callback computing the highlight style of the cells of the selected column of a [`Table`],
+/// see [`Table::column_highlight_style_fn`].
+///
+/// Two instances are equal when they share the same callback.
+///
+/// [`Table`]: super::Table
+/// [`Table::column_highlight_style_fn`]: super::Table::column_highlight_style_fn
+#[derive(Clone)]
+pub(crate) struct ColumnHighlightFn<'a>(Rc<dyn Fn(usize) -> Style + 'a>);
+
+impl<'a> ColumnHighlightFn<'a> {
+    pub(crate) fn new<F>(style: F) -> Self
+    where
+        F: Fn(usize) -> Style + 'a,
+    {
+        Self(Rc::new(style))
+    }
+
+    /// Returns the highlight style of the cell of the selected column in the row at `index`.
+    pub(crate) fn style(&self, index: usize) -> Style {
+        (self.0)(index)
+    }
+
+    /// Returns a callback for rows indexed from `offset`, which calls this callback with the
+    /// index of the row shifted by `offset`.
+    pub(crate) fn with_offset(&self, offset: usize) -> Self {
+        let style = Rc::clone(&self.0);
+        Self(Rc::new(move |index| style(index.saturating_add(offset))))
+    }
+}
+
+impl fmt::Debug for ColumnHighlightFn<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ColumnHighlightFn").finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for ColumnHighlightFn<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for ColumnHighlightFn<'_> {}
+
+impl Hash for ColumnHighlightFn<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.0).cast::<()>().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bold_first_row() -> ColumnHighlightFn<'static> {
+        ColumnHighlightFn::new(|i| {
+            if i == 0 {
+                Style::new().bold()
+            } else {
+                Style::new().dim()
+            }
+        })
+    }
+
+    #[test]
+    fn style() {
+        let style = bold_first_row();
+        assert_eq!(style.style(0), Style::new().bold());
+        assert_eq!(style.style(3), Style::new().dim());
+    }
+
+    #[test]
+    fn with_offset() {
+        let style = bold_first_row().with_offset(3);
+        assert_eq!(style.style(0), Style::new().dim());
+    }
+
+    #[test]
+    fn eq() {
+        let a = bold_first_row();
+        assert_eq!(a, a.clone());
+        assert_ne!(a, bold_first_row());
+        assert_ne!(a, a.with_offset(0));
+    }
+}
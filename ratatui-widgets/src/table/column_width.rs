@@ -0,0 +1,99 @@
+use ratatui_core::layout::Constraint;
+
+/// The width of a column of a [`Table`]
+///
+/// A column is either sized by a layout [`Constraint`], in the same way as with [`Table::widths`],
+/// or sized to fit its content with [`ColumnWidth::Auto`]. See [`Table::column_widths`].
+///
+/// `ColumnWidth` can be created from a [`Constraint`] or from a `u16`, which is a fixed width.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::layout::Constraint;
+/// use ratatui::widgets::ColumnWidth;
+///
+/// let fixed = ColumnWidth::from(5);
+/// let fill = ColumnWidth::from(Constraint::Fill(1));
+/// let auto = ColumnWidth::Auto { min: 4, max: 20 };
+/// ```
+///
+/// [`Table`]: super::Table
+/// [`Table::widths`]: super::Table::widths
+/// [`Table::column_widths`]: super::Table::column_widths
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColumnWidth {
+    /// The column is sized by a layout constraint
+    Constraint(Constraint),
+
+    /// The column is as wide as its widest cell, but at least `min` and at most `max` columns
+    Auto {
+        /// The minimum width of the column
+        min: u16,
+        /// The maximum width of the column
+        max: u16,
+    },
+}
+
+impl ColumnWidth {
+    /// Returns the constraint used to lay out the column, given the width of its widest cell.
+    pub(crate) fn constraint(self, content_width: u16) -> Constraint {
+        match self {
+            Self::Constraint(constraint) => constraint,
+            Self::Auto { min, max } => Constraint::Length(content_width.min(max).max(min)),
+        }
+    }
+}
+
+impl Default for ColumnWidth {
+    fn default() -> Self {
+        Self::Constraint(Constraint::default())
+    }
+}
+
+impl From<Constraint> for ColumnWidth {
+    fn from(constraint: Constraint) -> Self {
+        Self::Constraint(constraint)
+    }
+}
+
+impl From<u16> for ColumnWidth {
+    fn from(width: u16) -> Self {
+        Self::Constraint(Constraint::Length(width))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn from() {
+        assert_eq!(
+            ColumnWidth::from(5),
+            ColumnWidth::Constraint(Constraint::Length(5))
+        );
+        assert_eq!(
+            ColumnWidth::from(Constraint::Fill(1)),
+            ColumnWidth::Constraint(Constraint::Fill(1))
+        );
+    }
+
+    #[rstest]
+    #[case::narrower_than_min(2, Constraint::Length(4))]
+    #[case::between(6, Constraint::Length(6))]
+    #[case::wider_than_max(20, Constraint::Length(10))]
+    fn auto_constraint(#[case] content_width: u16, #[case] expected: Constraint) {
+        let width = ColumnWidth::Auto { min: 4, max: 10 };
+        assert_eq!(width.constraint(content_width), expected);
+    }
+
+    #[test]
+    fn constraint_ignores_content() {
+        let width = ColumnWidth::from(Constraint::Min(3));
+        assert_eq!(width.constraint(20), Constraint::Min(3));
+    }
+}
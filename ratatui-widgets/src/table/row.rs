@@ -1,6 +1,7 @@
 use alloc::vec::Vec;
 
 use ratatui_core::style::{Style, Styled};
+use ratatui_core::text::Text;
 
 use super::Cell;
 
@@ -14,6 +15,9 @@ use super::Cell;
 /// with the [`Style`] of each individual [`Cell`] by adding the [`Style`] of the [`Cell`] to the
 /// [`Style`] of the [`Row`].
 ///
+/// A row can have details, set with [`Row::details`], which are displayed below it when the row is
+/// expanded with [`TableState::toggle_expanded`].
+///
 /// # Examples
 ///
 /// You can create `Row`s from simple strings.
@@ -69,15 +73,18 @@ use super::Cell;
 /// ```
 ///
 /// [`Table`]: super::Table
-/// [`Text`]: ratatui_core::text::Text
+/// [`TableState::toggle_expanded`]: super::TableState::toggle_expanded
 /// [`Stylize`]: ratatui_core::style::Stylize
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct Row<'a> {
     pub(crate) cells: Vec<Cell<'a>>,
     pub(crate) height: u16,
+    /// Whether the height was set with [`Row::height`], which a header keeps
+    pub(crate) fixed_height: bool,
     pub(crate) top_margin: u16,
     pub(crate) bottom_margin: u16,
     pub(crate) style: Style,
+    pub(crate) details: Option<Cell<'a>>,
 }
 
 impl<'a> Row<'a> {
@@ -143,7 +150,8 @@ impl<'a> Row<'a> {
     ///
     /// Any [`Cell`] whose content has more lines than this height will see its content truncated.
     ///
-    /// By default, the height is `1`.
+    /// By default, the height is `1`. When the row is the header of a [`Table`], it is as tall as
+    /// its tallest cell instead, unless the height is set with this method.
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
@@ -155,9 +163,12 @@ impl<'a> Row<'a> {
     /// let cells = vec!["Cell 1\nline 2", "Cell 2", "Cell 3"];
     /// let row = Row::new(cells).height(2);
     /// ```
+    ///
+    /// [`Table`]: super::Table
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn height(mut self, height: u16) -> Self {
         self.height = height;
+        self.fixed_height = true;
         self
     }
 
@@ -239,13 +250,40 @@ impl<'a> Row<'a> {
         self.style = style.into();
         self
     }
+
+    /// Set the details of the row, which are displayed below it when the row is expanded
+    ///
+    /// The details span all the columns and are wrapped to the width of the table. They are
+    /// displayed when the index of the row is expanded with [`TableState::toggle_expanded`], and
+    /// are part of the row when the table is scrolled and the row is selected. The details of rows
+    /// created with [`Table::rows_from`] are displayed too, but the heights set with
+    /// [`Table::row_heights_from`] must include them.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Row;
+    ///
+    /// let row = Row::new(["notes.txt", "4 KiB"]).details("Modified yesterday by alice");
+    /// ```
+    ///
+    /// [`TableState::toggle_expanded`]: super::TableState::toggle_expanded
+    /// [`Table::rows_from`]: super::Table::rows_from
+    /// [`Table::row_heights_from`]: super::Table::row_heights_from
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn details<T: Into<Text<'a>>>(mut self, details: T) -> Self {
+        self.details = Some(Cell::new(details));
+        self
+    }
 }
 
 // private methods for rendering
 impl Row<'_> {
-    /// Returns the total height of the row.
-    pub(crate) const fn height_with_margin(&self) -> u16 {
-        self.height
+    /// Returns the total height of the row when its content is `height` lines tall.
+    pub(crate) const fn height_with_margin(&self, height: u16) -> u16 {
+        height
             .saturating_add(self.top_margin)
             .saturating_add(self.bottom_margin)
     }
@@ -305,6 +343,8 @@ mod tests {
     fn height() {
         let row = Row::default().height(2);
         assert_eq!(row.height, 2);
+        assert!(row.fixed_height);
+        assert!(!Row::new(["a"]).fixed_height);
     }
 
     #[test]
@@ -319,6 +359,12 @@ mod tests {
         assert_eq!(row.bottom_margin, 1);
     }
 
+    #[test]
+    fn details() {
+        let row = Row::default().details("details");
+        assert_eq!(row.details, Some(Cell::new("details")));
+    }
+
     #[test]
     fn style() {
         let style = Style::default().red().italic();
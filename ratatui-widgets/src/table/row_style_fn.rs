@@ -0,0 +1,100 @@
+use alloc::rc::Rc;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use ratatui_core::style::Style;
+
+use super::Row;
+
+/// A callback computing the style of the rows of a [`Table`], see [`Table::row_style_fn`].
+///
+/// Two instances are equal when they share the same callback.
+///
+/// [`Table`]: super::Table
+/// [`Table::row_style_fn`]: super::Table::row_style_fn
+#[derive(Clone)]
+pub(crate) struct RowStyleFn<'a>(Rc<StyleFn<'a>>);
+
+type StyleFn<'a> = dyn Fn(usize, &Row<'_>) -> Style + 'a;
+
+impl<'a> RowStyleFn<'a> {
+    pub(crate) fn new<F>(style: F) -> Self
+    where
+        F: Fn(usize, &Row<'_>) -> Style + 'a,
+    {
+        Self(Rc::new(style))
+    }
+
+    /// Returns the style of the row at `index`.
+    pub(crate) fn style(&self, index: usize, row: &Row<'_>) -> Style {
+        (self.0)(index, row)
+    }
+
+    /// Returns a callback for rows indexed from `offset`, which calls this callback with the
+    /// index of the row shifted by `offset`.
+    pub(crate) fn with_offset(&self, offset: usize) -> Self {
+        let style = Rc::clone(&self.0);
+        Self(Rc::new(move |index, row| {
+            style(index.saturating_add(offset), row)
+        }))
+    }
+}
+
+impl fmt::Debug for RowStyleFn<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RowStyleFn").finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for RowStyleFn<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for RowStyleFn<'_> {}
+
+impl Hash for RowStyleFn<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.0).cast::<()>().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zebra() -> RowStyleFn<'static> {
+        RowStyleFn::new(|i, _| {
+            if i % 2 == 0 {
+                Style::new().on_black()
+            } else {
+                Style::new().on_white()
+            }
+        })
+    }
+
+    #[test]
+    fn style() {
+        let style = zebra();
+        let row = Row::new(["a"]);
+        assert_eq!(style.style(0, &row), Style::new().on_black());
+        assert_eq!(style.style(3, &row), Style::new().on_white());
+    }
+
+    #[test]
+    fn with_offset() {
+        let style = zebra().with_offset(3);
+        let row = Row::new(["a"]);
+        assert_eq!(style.style(0, &row), Style::new().on_white());
+        assert_eq!(style.style(1, &row), Style::new().on_black());
+    }
+
+    #[test]
+    fn eq() {
+        let a = zebra();
+        assert_eq!(a, a.clone());
+        assert_ne!(a, zebra());
+        assert_ne!(a, a.with_offset(0));
+    }
+}
@@ -0,0 +1,130 @@
+use strum::{Display, EnumString};
+
+/// Where an item is displayed when a [`List`] or a [`Table`] is scrolled to it
+///
+/// See [`ListState::scroll_to`] and [`TableState::scroll_to`].
+///
+/// [`List`]: crate::list::List
+/// [`ListState::scroll_to`]: crate::list::ListState::scroll_to
+/// [`Table`]: super::Table
+/// [`TableState::scroll_to`]: super::TableState::scroll_to
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScrollBehavior {
+    /// Display the item at the top of the viewport
+    Top,
+
+    /// Display the item in the middle of the viewport
+    ///
+    /// The items at the end are displayed at the bottom of the viewport, as they can't be scrolled
+    /// any further.
+    Center,
+
+    /// Scroll as little as possible to display the item (default)
+    ///
+    /// The offset is unchanged when the item is already visible, and the item is displayed at the
+    /// top or the bottom of the viewport when it is above or below the visible items.
+    #[default]
+    Nearest,
+}
+
+impl ScrollBehavior {
+    /// Returns the offset at which the item at `target` is displayed as described by the behavior
+    ///
+    /// `offset` is the current offset of the `len` items of the viewport, and `size` returns the
+    /// size of an item along the direction of the viewport, given its index. The target is clamped
+    /// to the last item.
+    pub(crate) fn offset<F>(
+        self,
+        target: usize,
+        offset: usize,
+        len: usize,
+        viewport: usize,
+        size: F,
+    ) -> usize
+    where
+        F: Fn(usize) -> usize + Copy,
+    {
+        if len == 0 {
+            return 0;
+        }
+        let target = target.min(len - 1);
+        match self {
+            Self::Top => target,
+            Self::Center => {
+                let space_above = viewport.saturating_sub(size(target)) / 2;
+                let centered = first_fitting(target, space_above, size);
+                centered.min(bottom_aligned(len - 1, viewport, size))
+            }
+            Self::Nearest => {
+                if target < offset {
+                    target
+                } else if (offset..=target).map(size).sum::<usize>() <= viewport {
+                    offset
+                } else {
+                    bottom_aligned(target, viewport, size)
+                }
+            }
+        }
+    }
+}
+
+/// Returns the offset at which the item at `target` is displayed at the bottom of the viewport
+fn bottom_aligned<F>(target: usize, viewport: usize, size: F) -> usize
+where
+    F: Fn(usize) -> usize + Copy,
+{
+    first_fitting(target, viewport.saturating_sub(size(target)), size)
+}
+
+/// Returns the index of the first of the items before `target` which fit in `space`
+fn first_fitting<F>(target: usize, space: usize, size: F) -> usize
+where
+    F: Fn(usize) -> usize,
+{
+    let mut start = target;
+    let mut used = 0;
+    while start > 0 && used + size(start - 1) <= space {
+        start -= 1;
+        used += size(start);
+    }
+    start
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// The items at even indexes are 1 line high, and the others are 2 lines high
+    const fn size(index: usize) -> usize {
+        if index % 2 == 0 { 1 } else { 2 }
+    }
+
+    #[rstest]
+    #[case::top(ScrollBehavior::Top, 10, 10)]
+    #[case::top_past_end(ScrollBehavior::Top, 50, 19)]
+    // 3 lines above the target, which is 1 line high
+    #[case::center(ScrollBehavior::Center, 10, 8)]
+    // 2 lines above the target, which is 2 lines high
+    #[case::center_tall_item(ScrollBehavior::Center, 11, 10)]
+    #[case::center_start(ScrollBehavior::Center, 1, 0)]
+    // the last items are displayed at the bottom of the viewport
+    #[case::center_end(ScrollBehavior::Center, 18, 16)]
+    #[case::center_past_end(ScrollBehavior::Center, 50, 16)]
+    #[case::nearest_above(ScrollBehavior::Nearest, 2, 2)]
+    #[case::nearest_visible(ScrollBehavior::Nearest, 7, 5)]
+    #[case::nearest_below(ScrollBehavior::Nearest, 10, 6)]
+    #[case::nearest_past_end(ScrollBehavior::Nearest, 50, 16)]
+    fn offset(#[case] behavior: ScrollBehavior, #[case] target: usize, #[case] expected: usize) {
+        // the viewport is 7 lines high and the current offset is 5
+        assert_eq!(behavior.offset(target, 5, 20, 7, size), expected);
+    }
+
+    #[test]
+    fn offset_without_items() {
+        let size = |_| unreachable!("there are no items");
+        assert_eq!(ScrollBehavior::Center.offset(3, 0, 0, 7, size), 0);
+    }
+}
@@ -0,0 +1,82 @@
+use strum::{Display, EnumString};
+
+/// The direction in which the rows of a [`Table`] are sorted by a column
+///
+/// See [`TableState::sort_column`] and [`Table::sorted_by`].
+///
+/// [`Table`]: super::Table
+/// [`Table::sorted_by`]: super::Table::sorted_by
+/// [`TableState::sort_column`]: super::TableState::sort_column
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortDirection {
+    /// Sort from the smallest to the largest value (default)
+    #[default]
+    Ascending,
+
+    /// Sort from the largest to the smallest value
+    Descending,
+}
+
+impl SortDirection {
+    /// Returns the opposite direction
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::SortDirection;
+    ///
+    /// assert_eq!(
+    ///     SortDirection::Ascending.reversed(),
+    ///     SortDirection::Descending
+    /// );
+    /// ```
+    #[must_use = "method returns the reversed direction and does not modify self"]
+    pub const fn reversed(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn to_string() {
+        assert_eq!(SortDirection::Ascending.to_string(), "Ascending");
+        assert_eq!(SortDirection::Descending.to_string(), "Descending");
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            "Ascending".parse::<SortDirection>(),
+            Ok(SortDirection::Ascending)
+        );
+        assert_eq!(
+            "Descending".parse::<SortDirection>(),
+            Ok(SortDirection::Descending)
+        );
+        assert_eq!(
+            "".parse::<SortDirection>(),
+            Err(strum::ParseError::VariantNotFound)
+        );
+    }
+
+    #[test]
+    fn reversed() {
+        assert_eq!(
+            SortDirection::Ascending.reversed(),
+            SortDirection::Descending
+        );
+        assert_eq!(
+            SortDirection::Descending.reversed(),
+            SortDirection::Ascending
+        );
+    }
+}
@@ -1,3 +1,10 @@
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+use ratatui_core::layout::{Position, Rect};
+
+use super::{ScrollBehavior, SortDirection};
+
 /// State of a [`Table`] widget
 ///
 /// This state can be used to scroll through the rows and select one of them. When the table is
@@ -5,15 +12,41 @@
 /// table will be shifted to ensure that the selected row is visible. This will modify the
 /// [`TableState`] object passed to the `Frame::render_stateful_widget` method.
 ///
-/// The state consists of two fields:
+/// The state consists of the following fields:
 /// - [`offset`]: the index of the first row to be displayed
 /// - [`selected`]: the index of the selected row, which can be `None` if no row is selected
 /// - [`selected_column`]: the index of the selected column, which can be `None` if no column is
-///   selected
+///   selected. When a row is selected too, the two form the selected cell, which is kept within the
+///   cells of its row when the table is rendered
+/// - [`selected_range`]: the top left and bottom right cells of the selected rectangular range of
+///   cells, which can be `None` if no range is selected
+/// - [`selected_set`]: the indexes of the rows selected for bulk operations, independently of the
+///   selected row which acts as a cursor (see [`TableState::toggle`])
+/// - [`expanded`]: the indexes of the expanded rows, which display their details below them (see
+///   [`Row::details`])
+/// - [`column_offset`]: the index of the first column to be displayed when the table is
+///   horizontally scrollable (see [`Table::horizontal_scroll`])
+/// - [`sort_column`]: the index of the column the rows are sorted by and the direction of the sort,
+///   which can be `None` if the rows are not sorted (see [`Table::sorted_by`])
+/// - [`column_width_override`]: the width of each column resized by the user, which takes
+///   precedence over the widths of the table (see [`TableState::begin_resize`])
 ///
 /// [`offset`]: TableState::offset()
 /// [`selected`]: TableState::selected()
 /// [`selected_column`]: TableState::selected_column()
+/// [`selected_range`]: TableState::selected_range()
+/// [`selected_set`]: TableState::selected_set()
+/// [`expanded`]: TableState::expanded()
+/// [`Row::details`]: crate::table::Row::details
+/// [`column_offset`]: TableState::column_offset()
+/// [`sort_column`]: TableState::sort_column()
+/// [`column_width_override`]: TableState::column_width_override()
+/// [`Table::horizontal_scroll`]: crate::table::Table::horizontal_scroll
+/// [`Table::sorted_by`]: crate::table::Table::sorted_by
+///
+/// With the `serde` feature, the state can be serialized to restore it later. The missing fields
+/// are set to their default when deserializing, and the indexes that are out of range for the rows
+/// of the table are clamped when it is rendered.
 ///
 /// See the `table` example and the `recipe` and `traceroute` tabs in the demo2 example in the
 /// [Examples] directory for a more in depth example of the various configuration options and for
@@ -50,12 +83,43 @@
 ///
 /// [`Table`]: super::Table
 /// [`Table::widths`]: crate::table::Table::widths
-#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct TableState {
     pub(crate) offset: usize,
     pub(crate) selected: Option<usize>,
+    /// The row to scroll to and where to display it, until the selection changes
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) scroll_target: Option<(usize, ScrollBehavior)>,
     pub(crate) selected_column: Option<usize>,
+    /// The top left and bottom right cells of the selected range
+    pub(crate) selected_range: Option<((usize, usize), (usize, usize))>,
+    pub(crate) selected_set: BTreeSet<usize>,
+    pub(crate) expanded: BTreeSet<usize>,
+    pub(crate) column_offset: usize,
+    pub(crate) sort_column: Option<(usize, SortDirection)>,
+    pub(crate) selection_follows_sort: bool,
+    /// Indexes of the rows before sorting, in the order they were last displayed
+    pub(crate) sort_order: Vec<usize>,
+    /// Widths of the columns resized by the user, indexed by column
+    pub(crate) column_width_overrides: Vec<Option<u16>>,
+    pub(crate) min_column_width: u16,
+    /// The column being resized with the mouse
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) resizing_column: Option<usize>,
+    /// The x position and width of each column, as of the last render
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) column_positions: Vec<(u16, u16)>,
+    /// The index and area of each displayed row, including its details, as of the last render
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) row_areas: Vec<(usize, Rect)>,
+}
+
+impl Default for TableState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl TableState {
@@ -72,7 +136,20 @@ impl TableState {
         Self {
             offset: 0,
             selected: None,
+            scroll_target: None,
             selected_column: None,
+            selected_range: None,
+            selected_set: BTreeSet::new(),
+            expanded: BTreeSet::new(),
+            column_offset: 0,
+            sort_column: None,
+            selection_follows_sort: false,
+            sort_order: Vec::new(),
+            column_width_overrides: Vec::new(),
+            min_column_width: 1,
+            resizing_column: None,
+            column_positions: Vec::new(),
+            row_areas: Vec::new(),
         }
     }
 
@@ -93,6 +170,83 @@ impl TableState {
         self
     }
 
+    /// Sets the index of the first column to be displayed
+    ///
+    /// This is only used when the table is horizontally scrollable, see
+    /// [`Table::horizontal_scroll`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new().with_column_offset(2);
+    /// ```
+    ///
+    /// [`Table::horizontal_scroll`]: crate::table::Table::horizontal_scroll
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_column_offset(mut self, column_offset: usize) -> Self {
+        self.column_offset = column_offset;
+        self
+    }
+
+    /// Sets the column the rows are sorted by and the direction of the sort
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{SortDirection, TableState};
+    ///
+    /// let state = TableState::new().with_sort_column(Some((1, SortDirection::Descending)));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_sort_column(mut self, sort_column: Option<(usize, SortDirection)>) -> Self {
+        self.sort_column = sort_column;
+        self
+    }
+
+    /// Sets whether the selection stays on the same row when the rows are sorted
+    ///
+    /// See [`TableState::selection_follows_sort`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new().with_selection_follows_sort(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_selection_follows_sort(mut self, selection_follows_sort: bool) -> Self {
+        self.selection_follows_sort = selection_follows_sort;
+        self
+    }
+
+    /// Sets the minimum width of a column resized with [`TableState::resize_to`]
+    ///
+    /// The minimum width is 1 by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new().with_min_column_width(3);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_min_column_width(mut self, min_column_width: u16) -> Self {
+        self.min_column_width = min_column_width;
+        self
+    }
+
     /// Sets the index of the selected row
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -158,6 +312,27 @@ impl TableState {
         self
     }
 
+    /// Sets the selected rectangular range of cells
+    ///
+    /// See [`TableState::select_range`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::widgets::{TableState};
+    /// let state = TableState::new().with_selected_range(Some(((1, 0), (2, 2))));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_selected_range<T>(mut self, range: T) -> Self
+    where
+        T: Into<Option<((usize, usize), (usize, usize))>>,
+    {
+        self.select_range(range.into());
+        self
+    }
+
     /// Index of the first row to be displayed
     ///
     /// # Examples
@@ -186,6 +361,132 @@ impl TableState {
         &mut self.offset
     }
 
+    /// Index of the first column to be displayed
+    ///
+    /// This is only used when the table is horizontally scrollable, see
+    /// [`Table::horizontal_scroll`]. When rendering, the offset is adjusted so that the selected
+    /// column is visible.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new();
+    /// assert_eq!(state.column_offset(), 0);
+    /// ```
+    ///
+    /// [`Table::horizontal_scroll`]: crate::table::Table::horizontal_scroll
+    pub const fn column_offset(&self) -> usize {
+        self.column_offset
+    }
+
+    /// Mutable reference to the index of the first column to be displayed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default();
+    /// *state.column_offset_mut() = 1;
+    /// ```
+    pub const fn column_offset_mut(&mut self) -> &mut usize {
+        &mut self.column_offset
+    }
+
+    /// Index of the column the rows are sorted by and the direction of the sort
+    ///
+    /// Returns `None` if the rows are not sorted. The rows are sorted by [`Table::sorted_by`], and
+    /// the header cell of the sorted column displays a sort indicator (see
+    /// [`Table::sort_indicator_symbols`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new();
+    /// assert_eq!(state.sort_column(), None);
+    /// ```
+    ///
+    /// [`Table::sorted_by`]: crate::table::Table::sorted_by
+    /// [`Table::sort_indicator_symbols`]: crate::table::Table::sort_indicator_symbols
+    pub const fn sort_column(&self) -> Option<(usize, SortDirection)> {
+        self.sort_column
+    }
+
+    /// Mutable reference to the column the rows are sorted by and the direction of the sort
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{SortDirection, TableState};
+    ///
+    /// let mut state = TableState::default();
+    /// *state.sort_column_mut() = Some((0, SortDirection::Ascending));
+    /// ```
+    pub const fn sort_column_mut(&mut self) -> &mut Option<(usize, SortDirection)> {
+        &mut self.sort_column
+    }
+
+    /// Whether the selection stays on the same row when the rows are sorted
+    ///
+    /// By default, the selection is an index into the displayed rows, so sorting the rows in a
+    /// different order selects whichever row ends up at that index. When this is enabled,
+    /// [`Table::sorted_by`] remembers the order in which the rows were displayed and moves the
+    /// selection to the new position of the selected row.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new();
+    /// assert!(!state.selection_follows_sort());
+    /// ```
+    ///
+    /// [`Table::sorted_by`]: crate::table::Table::sorted_by
+    pub const fn selection_follows_sort(&self) -> bool {
+        self.selection_follows_sort
+    }
+
+    /// Mutable reference to whether the selection stays on the same row when the rows are sorted
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default();
+    /// *state.selection_follows_sort_mut() = true;
+    /// ```
+    pub const fn selection_follows_sort_mut(&mut self) -> &mut bool {
+        &mut self.selection_follows_sort
+    }
+
+    /// Sorts the rows by the given column, or reverses the sort if already sorted by it
+    ///
+    /// Sorting by a different column sorts in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{SortDirection, TableState};
+    ///
+    /// let mut state = TableState::default();
+    /// state.toggle_sort(1);
+    /// assert_eq!(state.sort_column(), Some((1, SortDirection::Ascending)));
+    /// state.toggle_sort(1);
+    /// assert_eq!(state.sort_column(), Some((1, SortDirection::Descending)));
+    /// ```
+    pub const fn toggle_sort(&mut self, column: usize) {
+        self.sort_column = match self.sort_column {
+            Some((sorted, direction)) if sorted == column => Some((column, direction.reversed())),
+            _ => Some((column, SortDirection::Ascending)),
+        };
+    }
+
     /// Index of the selected row
     ///
     /// Returns `None` if no row is selected
@@ -235,6 +536,82 @@ impl TableState {
         None
     }
 
+    /// Top left and bottom right cells of the selected rectangular range, as `(row, column)`
+    /// indexes
+    ///
+    /// Returns `None` if no range is selected
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::widgets::{TableState};
+    /// let state = TableState::new().with_selected_range(Some(((2, 3), (1, 0))));
+    /// assert_eq!(state.selected_range(), Some(((1, 0), (2, 3))));
+    /// ```
+    pub const fn selected_range(&self) -> Option<((usize, usize), (usize, usize))> {
+        self.selected_range
+    }
+
+    /// Iterates over the `(row, column)` indexes of the cells of the selected range, row by row
+    ///
+    /// The iterator is empty if no range is selected. The range is clamped to the rows and
+    /// columns of the table when it is rendered, so that the indexes can be used to extract the
+    /// selected data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::widgets::{TableState};
+    /// let state = TableState::new().with_selected_range(Some(((0, 1), (1, 2))));
+    /// let cells: Vec<_> = state.selected_range_cells().collect();
+    /// assert_eq!(cells, [(0, 1), (0, 2), (1, 1), (1, 2)]);
+    /// ```
+    pub fn selected_range_cells(&self) -> impl Iterator<Item = (usize, usize)> {
+        self.selected_range
+            .into_iter()
+            .flat_map(|((top, left), (bottom, right))| {
+                (top..=bottom).flat_map(move |row| (left..=right).map(move |column| (row, column)))
+            })
+    }
+
+    /// Indexes of the rows in the selection set, in ascending order
+    ///
+    /// The selection set is independent of the selected row, which acts as a cursor that can be
+    /// moved without changing the set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default();
+    /// state.toggle(3);
+    /// state.toggle(1);
+    /// assert!(state.selected_set().iter().eq(&[1, 3]));
+    /// ```
+    pub const fn selected_set(&self) -> &BTreeSet<usize> {
+        &self.selected_set
+    }
+
+    /// Indexes of the expanded rows, in ascending order
+    ///
+    /// An expanded row displays its details below it, see [`Row::details`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default();
+    /// state.toggle_expanded(1);
+    /// assert!(state.expanded().contains(&1));
+    /// ```
+    ///
+    /// [`Row::details`]: crate::table::Row::details
+    pub const fn expanded(&self) -> &BTreeSet<usize> {
+        &self.expanded
+    }
+
     /// Mutable reference to the index of the selected row
     ///
     /// Returns `None` if no row is selected
@@ -268,7 +645,8 @@ impl TableState {
 
     /// Sets the index of the selected row
     ///
-    /// Set to `None` if no row is selected. This will also reset the offset to `0`.
+    /// Set to `None` if no row is selected. This will also reset the offset to `0`, and cancel a
+    /// pending [`scroll_to`](Self::scroll_to).
     ///
     /// # Examples
     ///
@@ -280,6 +658,7 @@ impl TableState {
     /// ```
     pub const fn select(&mut self, index: Option<usize>) {
         self.selected = index;
+        self.scroll_target = None;
         if index.is_none() {
             self.offset = 0;
         }
@@ -320,82 +699,100 @@ impl TableState {
         }
     }
 
-    /// Selects the next row or the first one if no row is selected
+    /// Sets the selected rectangular range of cells, from an anchor cell to an extent cell
     ///
-    /// Note: until the table is rendered, the number of rows is not known, so the index is set to
-    /// `0` and will be corrected when the table is rendered
+    /// The cells are given as `(row, column)` indexes, and the anchor can be after the extent in
+    /// either direction: the range is normalized to its top left and bottom right cells. Set to
+    /// `None` to clear the range. The range is independent of the selected row, column and cell.
+    ///
+    /// Note: until the table is rendered, the number of rows and columns is not known, so the range
+    /// is not clamped and will be corrected when the table is rendered
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use ratatui::widgets::TableState;
-    ///
+    /// # use ratatui::widgets::{TableState};
     /// let mut state = TableState::default();
-    /// state.select_next();
+    /// // the anchor is below and to the right of the extent
+    /// state.select_range(Some(((3, 4), (1, 2))));
+    /// assert_eq!(state.selected_range(), Some(((1, 2), (3, 4))));
     /// ```
-    pub fn select_next(&mut self) {
-        let next = self.selected.map_or(0, |i| i.saturating_add(1));
-        self.select(Some(next));
+    pub fn select_range(&mut self, range: Option<((usize, usize), (usize, usize))>) {
+        self.selected_range = range.map(|((anchor_row, anchor_column), (row, column))| {
+            (
+                (anchor_row.min(row), anchor_column.min(column)),
+                (anchor_row.max(row), anchor_column.max(column)),
+            )
+        });
     }
 
-    /// Selects the next column or the first one if no column is selected
+    /// Adds a row to the selection set, or removes it if it is already in the set
     ///
-    /// Note: until the table is rendered, the number of columns is not known, so the index is set
-    /// to `0` and will be corrected when the table is rendered
+    /// The selected row is not changed. The rows of the set beyond the end of the table are
+    /// removed from the set when the table is rendered.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use ratatui::widgets::{TableState};
+    /// use ratatui::widgets::TableState;
+    ///
     /// let mut state = TableState::default();
-    /// state.select_next_column();
+    /// state.toggle(2);
+    /// assert!(state.selected_set().contains(&2));
+    /// state.toggle(2);
+    /// assert!(state.selected_set().is_empty());
     /// ```
-    pub fn select_next_column(&mut self) {
-        let next = self.selected_column.map_or(0, |i| i.saturating_add(1));
-        self.select_column(Some(next));
+    pub fn toggle(&mut self, index: usize) {
+        if !self.selected_set.remove(&index) {
+            self.selected_set.insert(index);
+        }
     }
 
-    /// Selects the previous row or the last one if no item is selected
+    /// Adds the rows from the selected row to `index` to the selection set, and selects `index`
     ///
-    /// Note: until the table is rendered, the number of rows is not known, so the index is set to
-    /// `usize::MAX` and will be corrected when the table is rendered
+    /// Both ends are included, and `index` can be before the selected row. If no row is selected,
+    /// only `index` is added to the set.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use ratatui::widgets::TableState;
     ///
-    /// let mut state = TableState::default();
-    /// state.select_previous();
+    /// let mut state = TableState::default().with_selected(Some(4));
+    /// state.select_range_to(2);
+    /// assert!(state.selected_set().iter().eq(&[2, 3, 4]));
+    /// assert_eq!(state.selected(), Some(2));
     /// ```
-    pub fn select_previous(&mut self) {
-        let previous = self.selected.map_or(usize::MAX, |i| i.saturating_sub(1));
-        self.select(Some(previous));
+    pub fn select_range_to(&mut self, index: usize) {
+        let anchor = self.selected.unwrap_or(index);
+        self.selected_set
+            .extend(anchor.min(index)..=anchor.max(index));
+        self.selected = Some(index);
     }
 
-    /// Selects the previous column or the last one if no column is selected
+    /// Expands a row to display its details below it, or collapses it if it is already expanded
     ///
-    /// Note: until the table is rendered, the number of columns is not known, so the index is set
-    /// to `usize::MAX` and will be corrected when the table is rendered
+    /// Rows without details are not affected by being expanded. The selected row is not changed,
+    /// so the selection stays on the row when its details are displayed.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use ratatui::widgets::{TableState};
+    /// use ratatui::widgets::TableState;
+    ///
     /// let mut state = TableState::default();
-    /// state.select_previous_column();
+    /// state.toggle_expanded(2);
+    /// assert!(state.expanded().contains(&2));
+    /// state.toggle_expanded(2);
+    /// assert!(state.expanded().is_empty());
     /// ```
-    pub fn select_previous_column(&mut self) {
-        let previous = self
-            .selected_column
-            .map_or(usize::MAX, |i| i.saturating_sub(1));
-        self.select_column(Some(previous));
+    pub fn toggle_expanded(&mut self, index: usize) {
+        if !self.expanded.remove(&index) {
+            self.expanded.insert(index);
+        }
     }
 
-    /// Selects the first row
-    ///
-    /// Note: until the table is rendered, the number of rows is not known, so the index is set to
-    /// `0` and will be corrected when the table is rendered
+    /// Collapses all the expanded rows
     ///
     /// # Examples
     ///
@@ -403,32 +800,191 @@ impl TableState {
     /// use ratatui::widgets::TableState;
     ///
     /// let mut state = TableState::default();
-    /// state.select_first();
+    /// state.toggle_expanded(2);
+    /// state.collapse_all();
+    /// assert!(state.expanded().is_empty());
     /// ```
-    pub const fn select_first(&mut self) {
-        self.select(Some(0));
+    pub fn collapse_all(&mut self) {
+        self.expanded.clear();
     }
 
-    /// Selects the first column
-    ///
-    /// Note: until the table is rendered, the number of columns is not known, so the index is set
-    /// to `0` and will be corrected when the table is rendered
+    /// Removes all the rows from the selection set
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use ratatui::widgets::{TableState};
+    /// use ratatui::widgets::TableState;
+    ///
     /// let mut state = TableState::default();
-    /// state.select_first_column();
+    /// state.toggle(2);
+    /// state.clear_set();
+    /// assert!(state.selected_set().is_empty());
     /// ```
-    pub const fn select_first_column(&mut self) {
-        self.select_column(Some(0));
+    pub fn clear_set(&mut self) {
+        self.selected_set.clear();
     }
 
-    /// Selects the last row
+    /// Selects the next row or the first one if no row is selected
     ///
     /// Note: until the table is rendered, the number of rows is not known, so the index is set to
-    /// `usize::MAX` and will be corrected when the table is rendered
+    /// `0` and will be corrected when the table is rendered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default();
+    /// state.select_next();
+    /// ```
+    pub fn select_next(&mut self) {
+        let next = self.selected.map_or(0, |i| i.saturating_add(1));
+        self.select(Some(next));
+    }
+
+    /// Selects the next column or the first one if no column is selected
+    ///
+    /// Note: until the table is rendered, the number of columns is not known, so the index is set
+    /// to `0` and will be corrected when the table is rendered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::widgets::{TableState};
+    /// let mut state = TableState::default();
+    /// state.select_next_column();
+    /// ```
+    pub fn select_next_column(&mut self) {
+        let next = self.selected_column.map_or(0, |i| i.saturating_add(1));
+        self.select_column(Some(next));
+    }
+
+    /// Selects the previous row or the last one if no item is selected
+    ///
+    /// Note: until the table is rendered, the number of rows is not known, so the index is set to
+    /// `usize::MAX` and will be corrected when the table is rendered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default();
+    /// state.select_previous();
+    /// ```
+    pub fn select_previous(&mut self) {
+        let previous = self.selected.map_or(usize::MAX, |i| i.saturating_sub(1));
+        self.select(Some(previous));
+    }
+
+    /// Selects the previous column or the last one if no column is selected
+    ///
+    /// Note: until the table is rendered, the number of columns is not known, so the index is set
+    /// to `usize::MAX` and will be corrected when the table is rendered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::widgets::{TableState};
+    /// let mut state = TableState::default();
+    /// state.select_previous_column();
+    /// ```
+    pub fn select_previous_column(&mut self) {
+        let previous = self
+            .selected_column
+            .map_or(usize::MAX, |i| i.saturating_sub(1));
+        self.select_column(Some(previous));
+    }
+
+    /// Selects the next column, wrapping around to the first one after the last column
+    ///
+    /// The first column is selected if no column is selected. This does nothing when there are no
+    /// columns. Unlike [`TableState::select_next_column`], this needs the number of columns
+    /// (`column_count`) to know where to wrap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default().with_selected_column(Some(2));
+    /// state.select_next_column_wrapping(3);
+    /// assert_eq!(state.selected_column(), Some(0));
+    /// ```
+    pub fn select_next_column_wrapping(&mut self, column_count: usize) {
+        if column_count == 0 {
+            return;
+        }
+        let next = self
+            .selected_column
+            .map_or(0, |i| if i >= column_count - 1 { 0 } else { i + 1 });
+        self.select_column(Some(next));
+    }
+
+    /// Selects the previous column, wrapping around to the last one before the first column
+    ///
+    /// The last column is selected if no column is selected. This does nothing when there are no
+    /// columns. Unlike [`TableState::select_previous_column`], this needs the number of columns
+    /// (`column_count`) to know where to wrap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default().with_selected_column(Some(0));
+    /// state.select_previous_column_wrapping(3);
+    /// assert_eq!(state.selected_column(), Some(2));
+    /// ```
+    pub fn select_previous_column_wrapping(&mut self, column_count: usize) {
+        if column_count == 0 {
+            return;
+        }
+        let last = column_count - 1;
+        let previous = self.selected_column.map_or(last, |i| match i.min(last) {
+            0 => last,
+            i => i - 1,
+        });
+        self.select_column(Some(previous));
+    }
+
+    /// Selects the first row
+    ///
+    /// Note: until the table is rendered, the number of rows is not known, so the index is set to
+    /// `0` and will be corrected when the table is rendered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default();
+    /// state.select_first();
+    /// ```
+    pub const fn select_first(&mut self) {
+        self.select(Some(0));
+    }
+
+    /// Selects the first column
+    ///
+    /// Note: until the table is rendered, the number of columns is not known, so the index is set
+    /// to `0` and will be corrected when the table is rendered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::widgets::{TableState};
+    /// let mut state = TableState::default();
+    /// state.select_first_column();
+    /// ```
+    pub const fn select_first_column(&mut self) {
+        self.select_column(Some(0));
+    }
+
+    /// Selects the last row
+    ///
+    /// Note: until the table is rendered, the number of rows is not known, so the index is set to
+    /// `usize::MAX` and will be corrected when the table is rendered
     ///
     /// # Examples
     ///
@@ -458,6 +1014,82 @@ impl TableState {
         self.select_column(Some(usize::MAX));
     }
 
+    /// Selects the next cell in the selected row, or the first cell of the first row if no cell
+    /// is selected
+    ///
+    /// Note: until the table is rendered, the number of cells in the row is not known, so the
+    /// index is not clamped and will be corrected to the last cell of the row when the table is
+    /// rendered. Rows can have fewer cells than the table has columns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::widgets::{TableState};
+    /// let mut state = TableState::default().with_selected_cell((1, 2));
+    /// state.select_next_cell_in_row();
+    /// assert_eq!(state.selected_cell(), Some((1, 3)));
+    /// ```
+    pub fn select_next_cell_in_row(&mut self) {
+        let row = self.selected.unwrap_or(0);
+        let next = self.selected_column.map_or(0, |i| i.saturating_add(1));
+        self.select_cell(Some((row, next)));
+    }
+
+    /// Selects the previous cell in the selected row, or the last cell of the first row if no
+    /// cell is selected
+    ///
+    /// Note: until the table is rendered, the number of cells in the row is not known, so the
+    /// index is set to `usize::MAX` and will be corrected when the table is rendered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::widgets::{TableState};
+    /// let mut state = TableState::default().with_selected_cell((1, 2));
+    /// state.select_previous_cell_in_row();
+    /// assert_eq!(state.selected_cell(), Some((1, 1)));
+    /// ```
+    pub fn select_previous_cell_in_row(&mut self) {
+        let row = self.selected.unwrap_or(0);
+        let previous = self
+            .selected_column
+            .map_or(usize::MAX, |i| i.saturating_sub(1));
+        self.select_cell(Some((row, previous)));
+    }
+
+    /// Selects the first cell of the selected row, or of the first row if no row is selected
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::widgets::{TableState};
+    /// let mut state = TableState::default().with_selected_cell((1, 2));
+    /// state.select_first_cell_in_row();
+    /// assert_eq!(state.selected_cell(), Some((1, 0)));
+    /// ```
+    pub fn select_first_cell_in_row(&mut self) {
+        let row = self.selected.unwrap_or(0);
+        self.select_cell(Some((row, 0)));
+    }
+
+    /// Selects the last cell of the selected row, or of the first row if no row is selected
+    ///
+    /// Note: until the table is rendered, the number of cells in the row is not known, so the
+    /// index is set to `usize::MAX` and will be corrected when the table is rendered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::widgets::{TableState};
+    /// let mut state = TableState::default().with_selected_cell((1, 2));
+    /// state.select_last_cell_in_row();
+    /// assert_eq!(state.selected_cell(), Some((1, usize::MAX)));
+    /// ```
+    pub fn select_last_cell_in_row(&mut self) {
+        let row = self.selected.unwrap_or(0);
+        self.select_cell(Some((row, usize::MAX)));
+    }
+
     /// Scrolls down by a specified `amount` in the table.
     ///
     /// This method updates the selected index by moving it down by the given `amount`.
@@ -496,6 +1128,103 @@ impl TableState {
         self.select(Some(selected.saturating_sub(amount as usize)));
     }
 
+    /// Selects the next row, wrapping around to the first one after the last row
+    ///
+    /// The first row is selected if no row is selected. This does nothing when there are no
+    /// rows. Unlike [`TableState::select_next`], this needs the number of rows (`len`) to
+    /// know where to wrap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default().with_selected(Some(2));
+    /// state.select_next_wrapping(3);
+    /// assert_eq!(state.selected(), Some(0));
+    /// ```
+    pub fn select_next_wrapping(&mut self, len: usize) {
+        if len == 0 {
+            return;
+        }
+        let next = self
+            .selected
+            .map_or(0, |i| if i >= len - 1 { 0 } else { i + 1 });
+        self.select(Some(next));
+    }
+
+    /// Selects the previous row, wrapping around to the last one before the first row
+    ///
+    /// The last row is selected if no row is selected. This does nothing when there are no
+    /// rows. Unlike [`TableState::select_previous`], this needs the number of rows (`len`)
+    /// to know where to wrap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default().with_selected(Some(0));
+    /// state.select_previous_wrapping(3);
+    /// assert_eq!(state.selected(), Some(2));
+    /// ```
+    pub fn select_previous_wrapping(&mut self, len: usize) {
+        if len == 0 {
+            return;
+        }
+        let previous = self.selected.map_or(len - 1, |i| match i.min(len - 1) {
+            0 => len - 1,
+            i => i - 1,
+        });
+        self.select(Some(previous));
+    }
+
+    /// Scrolls down by a specified `amount`, wrapping around to the first row after the last
+    /// row
+    ///
+    /// This does nothing when there are no rows. Unlike [`TableState::scroll_down_by`], this
+    /// needs the number of rows (`len`) to know where to wrap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default().with_selected(Some(8));
+    /// state.scroll_down_by_wrapping(4, 10);
+    /// assert_eq!(state.selected(), Some(2));
+    /// ```
+    pub fn scroll_down_by_wrapping(&mut self, amount: u16, len: usize) {
+        if len == 0 {
+            return;
+        }
+        let selected = self.selected.unwrap_or_default().min(len - 1);
+        self.select(Some((selected + usize::from(amount) % len) % len));
+    }
+
+    /// Scrolls up by a specified `amount`, wrapping around to the last row before the first
+    /// row
+    ///
+    /// This does nothing when there are no rows. Unlike [`TableState::scroll_up_by`], this needs
+    /// the number of rows (`len`) to know where to wrap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default().with_selected(Some(1));
+    /// state.scroll_up_by_wrapping(4, 10);
+    /// assert_eq!(state.selected(), Some(7));
+    /// ```
+    pub fn scroll_up_by_wrapping(&mut self, amount: u16, len: usize) {
+        if len == 0 {
+            return;
+        }
+        let selected = self.selected.unwrap_or_default().min(len - 1);
+        self.select(Some((selected + len - usize::from(amount) % len) % len));
+    }
+
     /// Scrolls right by a specified `amount` in the table.
     ///
     /// This method updates the selected index by moving it right by the given `amount`.
@@ -531,10 +1260,326 @@ impl TableState {
         let selected = self.selected_column.unwrap_or_default();
         self.select_column(Some(selected.saturating_sub(amount as usize)));
     }
+
+    /// Width of a column resized by the user
+    ///
+    /// Returns `None` if the column is not resized, in which case it is sized by the widths of the
+    /// table.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// state.set_column_width_override(1, Some(10));
+    /// assert_eq!(state.column_width_override(1), Some(10));
+    /// assert_eq!(state.column_width_override(0), None);
+    /// ```
+    pub fn column_width_override(&self, column: usize) -> Option<u16> {
+        self.column_width_overrides.get(column).copied().flatten()
+    }
+
+    /// Sets the width of a column, which takes precedence over the widths of the table
+    ///
+    /// Set to `None` to size the column by the widths of the table again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// state.set_column_width_override(1, Some(10));
+    /// ```
+    pub fn set_column_width_override(&mut self, column: usize, width: Option<u16>) {
+        if column >= self.column_width_overrides.len() {
+            if width.is_none() {
+                return;
+            }
+            self.column_width_overrides.resize(column + 1, None);
+        }
+        self.column_width_overrides[column] = width;
+    }
+
+    /// Restores the widths of all the resized columns to the widths of the table
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// state.set_column_width_override(1, Some(10));
+    /// state.reset_column_widths();
+    /// assert_eq!(state.column_width_override(1), None);
+    /// ```
+    pub fn reset_column_widths(&mut self) {
+        self.column_width_overrides.clear();
+        self.resizing_column = None;
+    }
+
+    /// Minimum width of a column resized with [`TableState::resize_to`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new();
+    /// assert_eq!(state.min_column_width(), 1);
+    /// ```
+    pub const fn min_column_width(&self) -> u16 {
+        self.min_column_width
+    }
+
+    /// The x position of the right edge of each column, as of the last render
+    ///
+    /// The positions are in the coordinates of the buffer the table was rendered to, and each one
+    /// is the position just after the last cell of its column. The column separators that can be
+    /// dragged to resize the columns are found with [`TableState::column_boundary_at`]. Columns
+    /// that are scrolled out of view have a width of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::{Constraint, Rect};
+    /// use ratatui::widgets::{Row, StatefulWidget, Table, TableState};
+    ///
+    /// let table = Table::new([Row::new(["a", "b"])], [Constraint::Length(3); 2]);
+    /// let area = Rect::new(0, 0, 10, 1);
+    /// let mut state = TableState::new();
+    /// StatefulWidget::render(table, area, &mut Buffer::empty(area), &mut state);
+    /// assert_eq!(state.column_boundaries().collect::<Vec<_>>(), [3, 7]);
+    /// ```
+    pub fn column_boundaries(&self) -> impl Iterator<Item = u16> + '_ {
+        self.column_positions
+            .iter()
+            .map(|&(x, width)| x.saturating_add(width))
+    }
+
+    /// Index of the column whose separator is at the given x position, as of the last render
+    ///
+    /// The separator of a column covers its last cell and the spacing after it, so this can be used
+    /// to hit-test the position of a mouse click before calling [`TableState::begin_resize`].
+    /// Returns `None` if the position is not on a separator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::{Constraint, Rect};
+    /// use ratatui::widgets::{Row, StatefulWidget, Table, TableState};
+    ///
+    /// let table = Table::new([Row::new(["a", "b"])], [Constraint::Length(3); 2]);
+    /// let area = Rect::new(0, 0, 10, 1);
+    /// let mut state = TableState::new();
+    /// StatefulWidget::render(table, area, &mut Buffer::empty(area), &mut state);
+    /// assert_eq!(state.column_boundary_at(1), None);
+    /// assert_eq!(state.column_boundary_at(3), Some(0));
+    /// ```
+    pub fn column_boundary_at(&self, x: u16) -> Option<usize> {
+        let positions = &self.column_positions;
+        positions
+            .iter()
+            .enumerate()
+            .filter(|&(_, &(_, width))| width > 0)
+            .find_map(|(column, &(column_x, width))| {
+                let boundary = column_x.saturating_add(width);
+                let next_x = positions
+                    .get(column + 1)
+                    .map_or(boundary, |&(next_x, _)| next_x.max(boundary));
+                (boundary - 1..next_x.max(boundary))
+                    .contains(&x)
+                    .then_some(column)
+            })
+    }
+
+    /// Index of the row displayed at the given position, as of the last render
+    ///
+    /// This can be used to hit-test the position of a mouse click. The area of a row covers all of
+    /// its lines and its [details](crate::table::Row::details) when it is expanded, including the
+    /// selection symbol column, but not the borders of the block, the header, the footer, the
+    /// margins of the row, or the area below the last row. Returns `None` if no row is displayed at
+    /// the position.
+    ///
+    /// The index is the index of the row as displayed, which is the one used by
+    /// [`TableState::select`], even when the rows are sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::{Constraint, Rect};
+    /// use ratatui::widgets::{Row, StatefulWidget, Table, TableState};
+    ///
+    /// let rows = [Row::new(["a", "b"]), Row::new(["c", "d"]).height(2)];
+    /// let table = Table::new(rows, [Constraint::Length(3); 2]).header(Row::new(["A", "B"]));
+    /// let area = Rect::new(0, 0, 10, 5);
+    /// let mut state = TableState::new();
+    /// StatefulWidget::render(table, area, &mut Buffer::empty(area), &mut state);
+    /// assert_eq!(state.row_at((0, 0)), None);
+    /// assert_eq!(state.row_at((0, 1)), Some(0));
+    /// assert_eq!(state.row_at((5, 3)), Some(1));
+    /// assert_eq!(state.row_at((0, 4)), None);
+    /// ```
+    pub fn row_at<P: Into<Position>>(&self, position: P) -> Option<usize> {
+        let position = position.into();
+        self.row_areas
+            .iter()
+            .find(|(_, area)| area.contains(position))
+            .map(|&(row, _)| row)
+    }
+
+    /// Row and column of the cell displayed at the given position, as of the last render
+    ///
+    /// The row is found as with [`TableState::row_at`], and the column is the displayed column
+    /// whose cells cover the x position. Returns `None` if no row is displayed at the position, or
+    /// if the position is on the selection symbol column or the spacing between the columns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::{Constraint, Rect};
+    /// use ratatui::widgets::{Row, StatefulWidget, Table, TableState};
+    ///
+    /// let table = Table::new([Row::new(["a", "b"])], [Constraint::Length(3); 2]);
+    /// let area = Rect::new(0, 0, 10, 1);
+    /// let mut state = TableState::new();
+    /// StatefulWidget::render(table, area, &mut Buffer::empty(area), &mut state);
+    /// assert_eq!(state.cell_at((1, 0)), Some((0, 0)));
+    /// assert_eq!(state.cell_at((3, 0)), None);
+    /// assert_eq!(state.cell_at((4, 0)), Some((0, 1)));
+    /// ```
+    pub fn cell_at<P: Into<Position>>(&self, position: P) -> Option<(usize, usize)> {
+        let position = position.into();
+        let row = self.row_at(position)?;
+        let column = self
+            .column_positions
+            .iter()
+            .position(|&(x, width)| (x..x.saturating_add(width)).contains(&position.x))?;
+        Some((row, column))
+    }
+
+    /// Scrolls the table to the row at the given index without changing the selection
+    ///
+    /// The offset is adjusted at the next render so that the row is displayed as described by the
+    /// [`ScrollBehavior`], accounting for the height and margins of each row. An index past the
+    /// end of the table scrolls to the last row. The table keeps this position, instead of
+    /// scrolling the selected row into view, until the selection changes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::{Constraint, Rect};
+    /// use ratatui::widgets::{Row, ScrollBehavior, StatefulWidget, Table, TableState};
+    ///
+    /// let rows = (0..20).map(|i| Row::new([i.to_string()]));
+    /// let table = Table::new(rows, [Constraint::Length(5)]);
+    /// let area = Rect::new(0, 0, 5, 5);
+    /// let mut state = TableState::new().with_selected(Some(0));
+    /// state.scroll_to(10, ScrollBehavior::Center);
+    /// StatefulWidget::render(table, area, &mut Buffer::empty(area), &mut state);
+    /// assert_eq!(state.offset(), 8);
+    /// assert_eq!(state.selected(), Some(0));
+    /// ```
+    pub const fn scroll_to(&mut self, index: usize, behavior: ScrollBehavior) {
+        self.scroll_target = Some((index, behavior));
+    }
+
+    /// Starts resizing a column, usually when its separator is clicked
+    ///
+    /// The column is resized with [`TableState::resize_to`] until [`TableState::end_resize`] is
+    /// called.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// # fn on_mouse_down(state: &mut TableState, x: u16) {
+    /// if let Some(column) = state.column_boundary_at(x) {
+    ///     state.begin_resize(column);
+    /// }
+    /// # }
+    /// ```
+    pub const fn begin_resize(&mut self, column: usize) {
+        self.resizing_column = Some(column);
+    }
+
+    /// Resizes the column being resized so that its separator is at the given x position
+    ///
+    /// The width of the column is set to cover the cells up to and including `x`, and is clamped
+    /// to [`TableState::min_column_width`]. This does nothing if no column is being resized or if
+    /// the column was not rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// # fn on_mouse_drag(state: &mut TableState, x: u16) {
+    /// state.resize_to(x);
+    /// # }
+    /// ```
+    pub fn resize_to(&mut self, x: u16) {
+        let Some(column) = self.resizing_column else {
+            return;
+        };
+        let Some(&(column_x, _)) = self.column_positions.get(column) else {
+            return;
+        };
+        let width = x
+            .saturating_add(1)
+            .saturating_sub(column_x)
+            .max(self.min_column_width);
+        self.set_column_width_override(column, Some(width));
+    }
+
+    /// Stops resizing the column, usually when the mouse button is released
+    ///
+    /// The column keeps its new width until it is reset with
+    /// [`TableState::reset_column_widths`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// state.begin_resize(0);
+    /// state.end_resize();
+    /// assert_eq!(state.resizing_column(), None);
+    /// ```
+    pub const fn end_resize(&mut self) {
+        self.resizing_column = None;
+    }
+
+    /// Index of the column being resized
+    ///
+    /// Returns `None` if no column is being resized
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new();
+    /// state.begin_resize(2);
+    /// assert_eq!(state.resizing_column(), Some(2));
+    /// ```
+    pub const fn resizing_column(&self) -> Option<usize> {
+        self.resizing_column
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
+
     use super::*;
 
     #[test]
@@ -543,6 +1588,9 @@ mod tests {
         assert_eq!(state.offset, 0);
         assert_eq!(state.selected, None);
         assert_eq!(state.selected_column, None);
+        assert_eq!(state.column_offset, 0);
+        assert_eq!(state.sort_column, None);
+        assert!(!state.selection_follows_sort);
     }
 
     #[test]
@@ -583,6 +1631,65 @@ mod tests {
         assert_eq!(state.offset, 1);
     }
 
+    #[test]
+    fn with_column_offset() {
+        let state = TableState::new().with_column_offset(2);
+        assert_eq!(state.column_offset, 2);
+    }
+
+    #[test]
+    fn column_offset() {
+        let state = TableState::new().with_column_offset(2);
+        assert_eq!(state.column_offset(), 2);
+    }
+
+    #[test]
+    fn column_offset_mut() {
+        let mut state = TableState::new();
+        *state.column_offset_mut() = 1;
+        assert_eq!(state.column_offset, 1);
+    }
+
+    #[test]
+    fn with_sort_column() {
+        let state = TableState::new().with_sort_column(Some((1, SortDirection::Descending)));
+        assert_eq!(state.sort_column, Some((1, SortDirection::Descending)));
+    }
+
+    #[test]
+    fn sort_column() {
+        let state = TableState::new().with_sort_column(Some((1, SortDirection::Ascending)));
+        assert_eq!(state.sort_column(), Some((1, SortDirection::Ascending)));
+    }
+
+    #[test]
+    fn sort_column_mut() {
+        let mut state = TableState::new();
+        *state.sort_column_mut() = Some((2, SortDirection::Ascending));
+        assert_eq!(state.sort_column, Some((2, SortDirection::Ascending)));
+    }
+
+    #[test]
+    fn selection_follows_sort() {
+        let mut state = TableState::new().with_selection_follows_sort(true);
+        assert!(state.selection_follows_sort());
+        *state.selection_follows_sort_mut() = false;
+        assert!(!state.selection_follows_sort);
+    }
+
+    #[test]
+    fn toggle_sort() {
+        let mut state = TableState::new();
+        state.toggle_sort(1);
+        assert_eq!(state.sort_column, Some((1, SortDirection::Ascending)));
+        state.toggle_sort(1);
+        assert_eq!(state.sort_column, Some((1, SortDirection::Descending)));
+        state.toggle_sort(1);
+        assert_eq!(state.sort_column, Some((1, SortDirection::Ascending)));
+        state.toggle_sort(0);
+        assert_eq!(state.sort_column, Some((0, SortDirection::Ascending)));
+    }
+
     #[test]
     fn selected() {
         let state = TableState::new();
@@ -659,6 +1766,71 @@ mod tests {
         assert_eq!(state.selected_cell(), None);
     }
 
+    #[test]
+    fn select_range() {
+        let mut state = TableState::new();
+        assert_eq!(state.selected_range(), None);
+        state.select_range(Some(((1, 2), (3, 4))));
+        assert_eq!(state.selected_range(), Some(((1, 2), (3, 4))));
+        state.select_range(None);
+        assert_eq!(state.selected_range(), None);
+    }
+
+    #[test]
+    fn select_range_normalizes_anchor_after_extent() {
+        let mut state = TableState::new();
+        state.select_range(Some(((3, 4), (1, 2))));
+        assert_eq!(state.selected_range(), Some(((1, 2), (3, 4))));
+        state.select_range(Some(((1, 4), (3, 2))));
+        assert_eq!(state.selected_range(), Some(((1, 2), (3, 4))));
+        let state = TableState::new().with_selected_range(((3, 2), (1, 4)));
+        assert_eq!(state.selected_range(), Some(((1, 2), (3, 4))));
+    }
+
+    #[test]
+    fn selected_range_cells() {
+        let state = TableState::new();
+        assert_eq!(state.selected_range_cells().count(), 0);
+        let state = TableState::new().with_selected_range(((2, 1), (1, 3)));
+        assert_eq!(
+            state.selected_range_cells().collect::<Vec<_>>(),
+            [(1, 1), (1, 2), (1, 3), (2, 1), (2, 2), (2, 3)]
+        );
+        let state = TableState::new().with_selected_range(((4, 4), (4, 4)));
+        assert_eq!(state.selected_range_cells().collect::<Vec<_>>(), [(4, 4)]);
+    }
+
+    #[test]
+    fn cell_in_row_navigation() {
+        let mut state = TableState::default();
+        state.select_next_cell_in_row();
+        assert_eq!(state.selected_cell(), Some((0, 0)));
+
+        state.select_previous_cell_in_row(); // should not go below 0
+        assert_eq!(state.selected_cell(), Some((0, 0)));
+
+        state.select(Some(3));
+        state.select_next_cell_in_row();
+        assert_eq!(state.selected_cell(), Some((3, 1)));
+
+        state.select_last_cell_in_row();
+        assert_eq!(state.selected_cell(), Some((3, usize::MAX)));
+
+        state.select_next_cell_in_row(); // should not go above usize::MAX
+        assert_eq!(state.selected_cell(), Some((3, usize::MAX)));
+
+        state.select_first_cell_in_row();
+        assert_eq!(state.selected_cell(), Some((3, 0)));
+
+        let mut state = TableState::default();
+        state.select_previous_cell_in_row();
+        assert_eq!(state.selected_cell(), Some((0, usize::MAX)));
+
+        let mut state = TableState::default();
+        state.select_last_cell_in_row();
+        assert_eq!(state.selected_cell(), Some((0, usize::MAX)));
+    }
+
     #[test]
     fn test_table_state_navigation() {
         let mut state = TableState::default();
@@ -742,4 +1914,226 @@ mod tests {
         state.scroll_left_by(20);
         assert_eq!(state.selected_column, Some(80));
     }
+
+    #[test]
+    fn wrapping_navigation() {
+        let mut state = TableState::new().with_selected(4);
+        state.select_next_wrapping(5);
+        assert_eq!(state.selected, Some(0));
+        state.select_previous_wrapping(5);
+        assert_eq!(state.selected, Some(4));
+        state.select_previous_wrapping(5);
+        assert_eq!(state.selected, Some(3));
+        state.scroll_down_by_wrapping(3, 5);
+        assert_eq!(state.selected, Some(1));
+        state.scroll_up_by_wrapping(3, 5);
+        assert_eq!(state.selected, Some(3));
+
+        let mut state = TableState::new();
+        state.select_previous_wrapping(5);
+        assert_eq!(state.selected, Some(4));
+        let mut state = TableState::new();
+        state.select_next_wrapping(5);
+        assert_eq!(state.selected, Some(0));
+    }
+
+    #[test]
+    fn wrapping_column_navigation() {
+        let mut state = TableState::new().with_selected_column(2);
+        state.select_next_column_wrapping(3);
+        assert_eq!(state.selected_column, Some(0));
+        state.select_previous_column_wrapping(3);
+        assert_eq!(state.selected_column, Some(2));
+        state.select_previous_column_wrapping(3);
+        assert_eq!(state.selected_column, Some(1));
+
+        let mut state = TableState::new();
+        state.select_previous_column_wrapping(3);
+        assert_eq!(state.selected_column, Some(2));
+    }
+
+    #[test]
+    fn wrapping_without_rows_or_columns_does_nothing() {
+        let mut state = TableState::new().with_offset(2).with_selected_cell((3, 1));
+        let expected = state.clone();
+        state.select_next_wrapping(0);
+        state.select_previous_wrapping(0);
+        state.scroll_down_by_wrapping(1, 0);
+        state.scroll_up_by_wrapping(1, 0);
+        state.select_next_column_wrapping(0);
+        state.select_previous_column_wrapping(0);
+        assert_eq!(state, expected);
+    }
+
+    #[test]
+    fn toggle() {
+        let mut state = TableState::new().with_selected(Some(1));
+        state.toggle(3);
+        state.toggle(0);
+        assert!(state.selected_set.iter().eq(&[0, 3]));
+        state.toggle(3);
+        assert!(state.selected_set.iter().eq(&[0]));
+        assert_eq!(state.selected, Some(1));
+    }
+
+    #[test]
+    fn select_range_to() {
+        let mut state = TableState::new();
+        state.select_range_to(2);
+        assert!(state.selected_set.iter().eq(&[2]));
+        state.select_range_to(5);
+        assert!(state.selected_set.iter().eq(&[2, 3, 4, 5]));
+        state.select(Some(9));
+        state.select_range_to(8);
+        assert!(state.selected_set.iter().eq(&[2, 3, 4, 5, 8, 9]));
+        assert_eq!(state.selected, Some(8));
+    }
+
+    #[test]
+    fn clear_set() {
+        let mut state = TableState::new().with_selected(Some(1));
+        state.select_range_to(4);
+        state.clear_set();
+        assert!(state.selected_set.is_empty());
+        assert_eq!(state.selected, Some(4));
+    }
+
+    #[test]
+    fn toggle_expanded() {
+        let mut state = TableState::new().with_selected(Some(1));
+        state.toggle_expanded(1);
+        state.toggle_expanded(4);
+        assert!(state.expanded.iter().eq(&[1, 4]));
+        state.toggle_expanded(1);
+        assert!(state.expanded.iter().eq(&[4]));
+        assert_eq!(state.selected, Some(1));
+        state.collapse_all();
+        assert!(state.expanded.is_empty());
+    }
+
+    #[test]
+    fn set_column_width_override() {
+        let mut state = TableState::new();
+        state.set_column_width_override(2, Some(5));
+        assert_eq!(state.column_width_overrides, [None, None, Some(5)]);
+        state.set_column_width_override(2, None);
+        assert_eq!(state.column_width_override(2), None);
+        state.set_column_width_override(5, None);
+        assert_eq!(state.column_width_overrides.len(), 3);
+    }
+
+    #[test]
+    fn reset_column_widths() {
+        let mut state = TableState::new();
+        state.set_column_width_override(0, Some(5));
+        state.begin_resize(0);
+        state.reset_column_widths();
+        assert_eq!(state.column_width_override(0), None);
+        assert_eq!(state.resizing_column(), None);
+    }
+
+    #[test]
+    fn column_boundary_at() {
+        let mut state = TableState::new();
+        // a column scrolled out of view, and columns with a spacing of 2
+        state.column_positions = vec![(1, 0), (1, 4), (7, 3), (12, 2)];
+        let columns: Vec<_> = (0..16).map(|x| state.column_boundary_at(x)).collect();
+        assert_eq!(
+            columns,
+            [
+                None,
+                None,
+                None,
+                None,
+                Some(1),
+                Some(1),
+                Some(1),
+                None,
+                None,
+                Some(2),
+                Some(2),
+                Some(2),
+                None,
+                Some(3),
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn resize_to() {
+        let mut state = TableState::new();
+        state.column_positions = vec![(0, 4), (5, 4)];
+        // does nothing when no column is being resized
+        state.resize_to(12);
+        assert_eq!(state.column_width_override(1), None);
+
+        state.begin_resize(1);
+        state.resize_to(12);
+        assert_eq!(state.column_width_override(1), Some(8));
+        state.resize_to(7);
+        assert_eq!(state.column_width_override(1), Some(3));
+        state.end_resize();
+        state.resize_to(12);
+        assert_eq!(state.column_width_override(1), Some(3));
+    }
+
+    #[test]
+    fn resize_to_clamps_to_min_width() {
+        let mut state = TableState::new();
+        state.column_positions = vec![(0, 4), (5, 4)];
+        state.begin_resize(1);
+        state.resize_to(2);
+        assert_eq!(state.column_width_override(1), Some(1));
+
+        let mut state = state.with_min_column_width(3);
+        state.resize_to(5);
+        assert_eq!(state.column_width_override(1), Some(3));
+    }
+
+    #[test]
+    fn resize_to_column_not_rendered() {
+        let mut state = TableState::new();
+        state.column_positions = vec![(0, 4)];
+        state.begin_resize(3);
+        state.resize_to(10);
+        assert_eq!(state.column_width_override(3), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_then_deserialize() -> Result<(), serde_json::Error> {
+        let mut state = TableState::new()
+            .with_offset(2)
+            .with_selected(3)
+            .with_selected_column(1)
+            .with_sort_column(Some((1, SortDirection::Descending)));
+        state.toggle(4);
+        state.toggle_expanded(5);
+        state.set_column_width_override(0, Some(12));
+        let json = serde_json::to_string(&state)?;
+        assert_eq!(serde_json::from_str::<TableState>(&json)?, state);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_with_missing_fields() -> Result<(), serde_json::Error> {
+        let state: TableState = serde_json::from_str(r#"{"offset":2,"selected":3}"#)?;
+        assert_eq!(state, TableState::new().with_offset(2).with_selected(3));
+        assert_eq!(state.min_column_width(), 1);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_skips_render_state() -> Result<(), serde_json::Error> {
+        let mut state = TableState::new();
+        state.column_positions = vec![(0, 5), (6, 5)];
+        state.begin_resize(1);
+        let state: TableState = serde_json::from_str(&serde_json::to_string(&state)?)?;
+        assert_eq!(state, TableState::new());
+        Ok(())
+    }
 }
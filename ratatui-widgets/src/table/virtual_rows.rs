@@ -0,0 +1,114 @@
+use alloc::rc::Rc;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use super::Row;
+
+/// Rows of a [`Table`] that are created on demand, see [`Table::rows_from`].
+///
+/// Two instances are equal when they have the same length and share the same callbacks.
+///
+/// [`Table`]: super::Table
+/// [`Table::rows_from`]: super::Table::rows_from
+#[derive(Clone)]
+pub(crate) struct VirtualRows<'a> {
+    /// The number of rows
+    pub(crate) len: usize,
+    /// Creates the row at the given index
+    row: Rc<dyn Fn(usize) -> Row<'a> + 'a>,
+    /// Returns the height of the row at the given index, including its margins
+    height: Option<Rc<dyn Fn(usize) -> u16 + 'a>>,
+}
+
+impl<'a> VirtualRows<'a> {
+    pub(crate) fn new<F>(len: usize, row: F) -> Self
+    where
+        F: Fn(usize) -> Row<'a> + 'a,
+    {
+        Self {
+            len,
+            row: Rc::new(row),
+            height: None,
+        }
+    }
+
+    pub(crate) fn set_height<F>(&mut self, height: F)
+    where
+        F: Fn(usize) -> u16 + 'a,
+    {
+        self.height = Some(Rc::new(height));
+    }
+
+    /// Creates the row at `index`.
+    pub(crate) fn row(&self, index: usize) -> Row<'a> {
+        (self.row)(index)
+    }
+
+    /// Returns the height of the row at `index` including its margins, which is 1 by default.
+    pub(crate) fn height(&self, index: usize) -> u16 {
+        self.height.as_ref().map_or(1, |height| height(index))
+    }
+}
+
+impl fmt::Debug for VirtualRows<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VirtualRows")
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for VirtualRows<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        let same_height = match (&self.height, &other.height) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+        self.len == other.len && Rc::ptr_eq(&self.row, &other.row) && same_height
+    }
+}
+
+impl Eq for VirtualRows<'_> {}
+
+impl Hash for VirtualRows<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        Rc::as_ptr(&self.row).cast::<()>().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    fn rows(len: usize) -> VirtualRows<'static> {
+        VirtualRows::new(len, |i| Row::new([i.to_string()]))
+    }
+
+    #[test]
+    fn row() {
+        assert_eq!(rows(10).row(3), Row::new(["3"]));
+    }
+
+    #[test]
+    fn height() {
+        let mut rows = rows(10);
+        assert_eq!(rows.height(3), 1);
+        rows.set_height(|i| if i % 2 == 0 { 2 } else { 1 });
+        assert_eq!(rows.height(3), 1);
+        assert_eq!(rows.height(4), 2);
+    }
+
+    #[test]
+    fn eq() {
+        let a = rows(10);
+        assert_eq!(a, a.clone());
+        assert_ne!(a, rows(10));
+        let mut b = a.clone();
+        b.set_height(|_| 1);
+        assert_ne!(a, b);
+    }
+}
@@ -0,0 +1,162 @@
+use super::TableState;
+
+/// Returns the indexes of the visible rows of a table of `len` rows displayed in `area_height`
+/// lines, scrolled from the offset of `state`.
+///
+/// `height` returns the height of the row at the given index, without and with its margins.
+///
+/// The algorithm works as follows:
+/// - start at the offset and calculate the height of the rows that can be displayed within the
+///   area.
+/// - if the selected row, or the `scroll_padding` rows around it, are not visible, scroll the table
+///   to ensure they are visible. When scrolling to the target of [`TableState::scroll_to`], start
+///   at the offset given by its behavior instead.
+/// - if there is still space to fill then there's a partial row at the end which should be
+///   included in the view.
+pub(crate) fn visible_rows<F>(
+    len: usize,
+    state: &TableState,
+    scroll_padding: usize,
+    area_height: u16,
+    height: F,
+) -> (usize, usize)
+where
+    F: Fn(usize) -> (u16, u16),
+{
+    let last_row = len.saturating_sub(1);
+    let mut start = state.offset.min(last_row);
+    let height_with_margin = |index| height(index).1;
+
+    let mut selected = state.selected;
+    if let Some((target, behavior)) = state.scroll_target {
+        let height = |index| usize::from(height_with_margin(index));
+        start = behavior.offset(target, start, len, area_height.into(), height);
+        selected = None;
+    }
+
+    // the rows that should be visible around the selected row
+    let padded_selection = selected.map(|selected| {
+        let selected = selected.min(last_row);
+        let mut padding = scroll_padding;
+        loop {
+            let top = selected.saturating_sub(padding);
+            let bottom = selected.saturating_add(padding).min(last_row);
+            let rows_height = (top..=bottom)
+                .map(height_with_margin)
+                .fold(0, u16::saturating_add);
+            // reduce the padding until the rows fit in the area
+            if padding == 0 || rows_height <= area_height {
+                break (top, bottom);
+            }
+            padding -= 1;
+        }
+    });
+
+    if let Some((top, _)) = padded_selection {
+        start = start.min(top);
+    }
+
+    let mut end = start;
+    let mut visible_height = 0_u16;
+
+    for index in start..len {
+        let (row_height, row_height_with_margin) = height(index);
+        // the margins of the last row may be cut
+        if visible_height.saturating_add(row_height) > area_height {
+            break;
+        }
+        visible_height = visible_height.saturating_add(row_height_with_margin);
+        end += 1;
+    }
+
+    if let Some((_, bottom)) = padded_selection {
+        // scroll down until the selected row and the padding below it are visible
+        while bottom >= end {
+            visible_height = visible_height.saturating_add(height_with_margin(end));
+            end += 1;
+            while visible_height > area_height && start < end - 1 {
+                visible_height = visible_height.saturating_sub(height_with_margin(start));
+                start += 1;
+            }
+        }
+    }
+
+    // Include a partial row if there is space
+    if visible_height < area_height && end < len {
+        end += 1;
+    }
+
+    (start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::table::ScrollBehavior;
+
+    #[rstest]
+    #[case::empty(0, TableState::new(), (0, 0))]
+    #[case::start(100, TableState::new(), (0, 5))]
+    #[case::offset(100, TableState::new().with_offset(50), (50, 55))]
+    #[case::offset_past_end(100, TableState::new().with_offset(200), (99, 100))]
+    #[case::selected_before_offset(100, TableState::new().with_offset(50).with_selected(20), (20, 25))]
+    #[case::selected_in_view(100, TableState::new().with_offset(50).with_selected(52), (50, 55))]
+    #[case::selected_after_offset(100, TableState::new().with_offset(50).with_selected(80), (76, 81))]
+    #[case::selected_past_end(100, TableState::new().with_selected(500), (95, 100))]
+    #[case::fewer_rows_than_height(3, TableState::new(), (0, 3))]
+    fn visible_rows(
+        #[case] len: usize,
+        #[case] state: TableState,
+        #[case] expected: (usize, usize),
+    ) {
+        assert_eq!(super::visible_rows(len, &state, 0, 5, |_| (1, 1)), expected);
+    }
+
+    #[test]
+    fn visible_rows_with_heights() {
+        let state = TableState::new().with_offset(10);
+        // 2 full rows and a partial row
+        assert_eq!(super::visible_rows(100, &state, 0, 5, |_| (2, 2)), (10, 13));
+        let state = TableState::new().with_selected(10);
+        assert_eq!(super::visible_rows(100, &state, 0, 5, |_| (2, 2)), (9, 12));
+    }
+
+    #[test]
+    fn visible_rows_with_margins() {
+        // the bottom margin of the last row is cut
+        let state = TableState::new();
+        assert_eq!(super::visible_rows(100, &state, 0, 5, |_| (1, 2)), (0, 3));
+    }
+
+    #[test]
+    fn visible_rows_taller_than_area() {
+        let state = TableState::new().with_selected(3);
+        assert_eq!(super::visible_rows(10, &state, 0, 5, |_| (8, 8)), (3, 4));
+    }
+
+    #[test]
+    fn visible_rows_scroll_target() {
+        let mut state = TableState::new().with_selected(1);
+        state.scroll_to(50, ScrollBehavior::Top);
+        assert_eq!(super::visible_rows(100, &state, 0, 5, |_| (1, 1)), (50, 55));
+    }
+
+    #[rstest]
+    #[case::down(0, 10, (8, 13))]
+    #[case::up(50, 40, (38, 43))]
+    #[case::in_view(38, 40, (38, 43))]
+    #[case::start(5, 1, (0, 5))]
+    #[case::end(0, 99, (95, 100))]
+    fn visible_rows_with_padding(
+        #[case] offset: usize,
+        #[case] selected: usize,
+        #[case] expected: (usize, usize),
+    ) {
+        let state = TableState::new()
+            .with_offset(offset)
+            .with_selected(selected);
+        assert_eq!(super::visible_rows(100, &state, 2, 5, |_| (1, 1)), expected);
+    }
+}
@@ -1,5 +1,6 @@
 //! The [`Tabs`] widget displays a horizontal set of tabs with a single tab selected.
 use alloc::vec::Vec;
+use core::ops::Range;
 
 use itertools::Itertools;
 use ratatui_core::buffer::Buffer;
@@ -8,11 +9,18 @@ use ratatui_core::style::{Style, Styled};
 use ratatui_core::symbols;
 use ratatui_core::text::{Line, Span};
 use ratatui_core::widgets::Widget;
+use strum::{Display, EnumString};
 
 use crate::block::{Block, BlockExt};
 
 const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().reversed();
 
+/// Symbol displayed on the left when tabs are scrolled out of view on that side
+const LEFT_OVERFLOW_SYMBOL: &str = "‹";
+
+/// Symbol displayed on the right when tabs are scrolled out of view on that side
+const RIGHT_OVERFLOW_SYMBOL: &str = "›";
+
 /// A widget that displays a horizontal set of Tabs with a single tab selected.
 ///
 /// Each tab title is stored as a [`Line`] which can be individually styled. The selected tab is set
@@ -22,6 +30,13 @@ const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().reversed();
 ///
 /// The divider defaults to |, and padding defaults to a singular space on each side.
 ///
+/// By default the selected tab is only highlighted with the highlight style. Setting
+/// [`Tabs::indicator`] to [`TabIndicator::Underline`] draws [`Tabs::underline_symbol`] under the
+/// selected tab instead, which uses a second row of the render area.
+///
+/// When there are more tabs than fit in the area, the tabs are cut off at the right edge. Use
+/// [`Tabs::scrollable`] to scroll the tabs so that the selected tab is always visible instead.
+///
 /// # Example
 ///
 /// ```
@@ -64,6 +79,29 @@ pub struct Tabs<'a> {
     padding_left: Line<'a>,
     /// Tab Right Padding
     padding_right: Line<'a>,
+    /// How the selected tab is indicated
+    indicator: TabIndicator,
+    /// Symbol used to underline the selected tab when using [`TabIndicator::Underline`]
+    underline_symbol: Span<'a>,
+    /// Whether the tabs are scrolled to keep the selected tab visible
+    scrollable: bool,
+}
+
+/// Defines how the selected tab is indicated.
+///
+/// See [`Tabs::indicator`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TabIndicator {
+    /// The selected tab title is styled with [`Tabs::highlight_style`]
+    #[default]
+    Highlight,
+    /// The selected tab title is underlined with [`Tabs::underline_symbol`] on the row below the
+    /// titles
+    ///
+    /// The highlight style is not applied to the title in this mode, the underline symbol carries
+    /// its own style instead.
+    Underline,
 }
 
 impl Default for Tabs<'_> {
@@ -142,6 +180,9 @@ impl<'a> Tabs<'a> {
             divider: Span::raw(symbols::line::VERTICAL),
             padding_left: Line::from(" "),
             padding_right: Line::from(" "),
+            indicator: TabIndicator::Highlight,
+            underline_symbol: Span::raw(symbols::line::THICK_HORIZONTAL),
+            scrollable: false,
         }
     }
 
@@ -351,6 +392,74 @@ impl<'a> Tabs<'a> {
         self.padding_right = padding.into();
         self
     }
+
+    /// Sets how the selected tab is indicated.
+    ///
+    /// Defaults to [`TabIndicator::Highlight`], which styles the selected title with
+    /// [`Tabs::highlight_style`]. [`TabIndicator::Underline`] draws [`Tabs::underline_symbol`]
+    /// under the selected title instead. The underline is drawn on the row below the titles, so
+    /// the widget needs an area at least two rows high to display it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{TabIndicator, Tabs};
+    ///
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2"]).indicator(TabIndicator::Underline);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn indicator(mut self, indicator: TabIndicator) -> Self {
+        self.indicator = indicator;
+        self
+    }
+
+    /// Sets the symbol used to underline the selected tab.
+    ///
+    /// The symbol is repeated once per column of the selected title and is only used when the
+    /// indicator is [`TabIndicator::Underline`]. It should be a single column wide. Defaults to a
+    /// thick horizontal line (`━`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::Stylize;
+    /// use ratatui::widgets::{TabIndicator, Tabs};
+    ///
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2"])
+    ///     .indicator(TabIndicator::Underline)
+    ///     .underline_symbol("▔".yellow());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn underline_symbol<T>(mut self, symbol: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.underline_symbol = symbol.into();
+        self
+    }
+
+    /// Sets whether the tabs are scrolled to keep the selected tab visible.
+    ///
+    /// By default, tabs that don't fit in the area are cut off at the right edge. When scrolling is
+    /// enabled, only the tabs that fully fit are displayed, starting from a tab chosen so that the
+    /// selected tab is visible. A `‹` or `›` is displayed on the side where tabs are hidden.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::Tabs;
+    ///
+    /// let tabs = (0..10)
+    ///     .map(|i| format!("Tab {i}"))
+    ///     .collect::<Tabs>()
+    ///     .scrollable(true)
+    ///     .select(7);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn scrollable(mut self, scrollable: bool) -> Self {
+        self.scrollable = scrollable;
+        self
+    }
 }
 
 impl Styled for Tabs<'_> {
@@ -386,10 +495,47 @@ impl Tabs<'_> {
             return;
         }
 
+        if !self.scrollable {
+            self.render_titles(0..self.titles.len(), tabs_area, buf);
+            return;
+        }
+
+        let (start, end) = self.visible_tabs(tabs_area.width);
+        let mut titles_area = tabs_area;
+        if start > 0 {
+            buf.set_string(
+                titles_area.x,
+                titles_area.y,
+                LEFT_OVERFLOW_SYMBOL,
+                self.style,
+            );
+            titles_area.x += 1;
+            titles_area.width -= 1;
+        }
+        if end < self.titles.len() {
+            titles_area.width = titles_area.width.saturating_sub(1);
+            buf.set_string(
+                titles_area.right(),
+                titles_area.y,
+                RIGHT_OVERFLOW_SYMBOL,
+                self.style,
+            );
+        }
+        self.render_titles(start..end, titles_area, buf);
+    }
+
+    /// Renders the titles in the given range, cutting them off at the right edge of `tabs_area`.
+    fn render_titles(&self, range: Range<usize>, tabs_area: Rect, buf: &mut Buffer) {
         let mut x = tabs_area.left();
-        let titles_length = self.titles.len();
-        for (i, title) in self.titles.iter().enumerate() {
-            let last_title = titles_length - 1 == i;
+        let last_index = range.end.saturating_sub(1);
+        for (i, title) in self
+            .titles
+            .iter()
+            .enumerate()
+            .take(range.end)
+            .skip(range.start)
+        {
+            let last_title = last_index == i;
             let remaining_width = tabs_area.right().saturating_sub(x);
 
             if remaining_width == 0 {
@@ -407,15 +553,16 @@ impl Tabs<'_> {
             // Title
             let pos = buf.set_line(x, tabs_area.top(), title, remaining_width);
             if Some(i) == self.selected {
-                buf.set_style(
-                    Rect {
-                        x,
-                        y: tabs_area.top(),
-                        width: pos.0.saturating_sub(x),
-                        height: 1,
-                    },
-                    self.highlight_style,
-                );
+                let title_area = Rect {
+                    x,
+                    y: tabs_area.top(),
+                    width: pos.0.saturating_sub(x),
+                    height: 1,
+                };
+                match self.indicator {
+                    TabIndicator::Highlight => buf.set_style(title_area, self.highlight_style),
+                    TabIndicator::Underline => self.render_underline(title_area, tabs_area, buf),
+                }
             }
             x = pos.0;
             let remaining_width = tabs_area.right().saturating_sub(x);
@@ -435,6 +582,57 @@ impl Tabs<'_> {
             x = pos.0;
         }
     }
+
+    /// Returns the range of tabs that are displayed when scrolling within `width`.
+    ///
+    /// The first displayed tab is the first one that leaves enough room for the selected tab to be
+    /// displayed, taking into account the overflow symbols. The following tabs are displayed as
+    /// long as they fully fit.
+    fn visible_tabs(&self, width: u16) -> (usize, usize) {
+        let len = self.titles.len();
+        if len == 0 {
+            return (0, 0);
+        }
+        let padding_width = self.padding_left.width() + self.padding_right.width();
+        let tab_widths = self
+            .titles
+            .iter()
+            .map(|title| title.width() + padding_width)
+            .collect_vec();
+        let divider_width = self.divider.width();
+        let fits = |start: usize, end: usize| {
+            let overflow_symbols = usize::from(start > 0) + usize::from(end < len);
+            let tabs_width: usize = tab_widths[start..end].iter().sum();
+            let separators_width = divider_width * (end - start).saturating_sub(1);
+            tabs_width + separators_width + overflow_symbols <= usize::from(width)
+        };
+
+        let selected = self
+            .selected
+            .filter(|&selected| selected < len)
+            .unwrap_or(0);
+        let start = (0..=selected)
+            .find(|&start| fits(start, selected + 1))
+            .unwrap_or(selected);
+        let mut end = selected + 1;
+        while end < len && fits(start, end + 1) {
+            end += 1;
+        }
+        (start, end)
+    }
+
+    /// Draws the underline symbol on the row below `title_area`, if `tabs_area` has room for it.
+    fn render_underline(&self, title_area: Rect, tabs_area: Rect, buf: &mut Buffer) {
+        if tabs_area.height < 2 {
+            return;
+        }
+        let y = title_area.y + 1;
+        for x in title_area.left()..title_area.right() {
+            buf[(x, y)]
+                .set_symbol(&self.underline_symbol.content)
+                .set_style(self.underline_symbol.style);
+        }
+    }
 }
 
 impl<'a, Item> FromIterator<Item> for Tabs<'a>
@@ -474,6 +672,9 @@ mod tests {
                 divider: Span::raw(symbols::line::VERTICAL),
                 padding_right: Line::from(" "),
                 padding_left: Line::from(" "),
+                indicator: TabIndicator::Highlight,
+                underline_symbol: Span::raw(symbols::line::THICK_HORIZONTAL),
+                scrollable: false,
             }
         );
     }
@@ -491,6 +692,9 @@ mod tests {
                 divider: Span::raw(symbols::line::VERTICAL),
                 padding_right: Line::from(" "),
                 padding_left: Line::from(" "),
+                indicator: TabIndicator::Highlight,
+                underline_symbol: Span::raw(symbols::line::THICK_HORIZONTAL),
+                scrollable: false,
             }
         );
     }
@@ -664,6 +868,84 @@ mod tests {
         test_case(tabs, Rect::new(0, 0, 30, 1), &expected);
     }
 
+    #[test]
+    fn render_underline_indicator() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4"])
+            .indicator(TabIndicator::Underline)
+            .select(1);
+        let expected = Buffer::with_lines([
+            " Tab1 │ Tab2 │ Tab3 │ Tab4    ",
+            "        ━━━━                  ",
+        ]);
+        test_case(tabs, Rect::new(0, 0, 30, 2), &expected);
+    }
+
+    #[test]
+    fn render_underline_indicator_styled_symbol() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4"])
+            .indicator(TabIndicator::Underline)
+            .underline_symbol("▔".yellow())
+            .padding("", "")
+            .select(3);
+        let expected = Buffer::with_lines([
+            Line::from("Tab1│Tab2│Tab3│Tab4"),
+            Line::from(vec!["               ".into(), "▔▔▔▔".yellow(), " ".into()]),
+        ]);
+        test_case(tabs, Rect::new(0, 0, 20, 2), &expected);
+    }
+
+    #[test]
+    fn render_underline_indicator_single_row() {
+        // without a second row there is nowhere to draw the underline
+        let tabs =
+            Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4"]).indicator(TabIndicator::Underline);
+        let expected = Buffer::with_lines([" Tab1 │ Tab2 │ Tab3 │ Tab4    "]);
+        test_case(tabs, Rect::new(0, 0, 30, 1), &expected);
+    }
+
+    fn ten_tabs() -> Tabs<'static> {
+        (0..10)
+            .map(|i| format!("Tab{i}"))
+            .collect::<Tabs>()
+            .scrollable(true)
+    }
+
+    #[test]
+    fn render_scrollable_all_tabs_fit() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2"]).scrollable(true);
+        let mut expected = Buffer::with_lines([" Tab1 │ Tab2    "]);
+        expected.set_style(Rect::new(1, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 16, 1), &expected);
+    }
+
+    #[test]
+    fn render_scrollable_first_tab_selected() {
+        let mut expected = Buffer::with_lines([" Tab0 │ Tab1 │ Tab2  ›"]);
+        expected.set_style(Rect::new(1, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(ten_tabs().select(0), Rect::new(0, 0, 22, 1), &expected);
+    }
+
+    #[test]
+    fn render_scrollable_middle_tab_selected() {
+        let mut expected = Buffer::with_lines(["‹ Tab3 │ Tab4 │ Tab5 ›"]);
+        expected.set_style(Rect::new(16, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(ten_tabs().select(5), Rect::new(0, 0, 22, 1), &expected);
+    }
+
+    #[test]
+    fn render_scrollable_last_tab_selected() {
+        let mut expected = Buffer::with_lines(["‹ Tab7 │ Tab8 │ Tab9  "]);
+        expected.set_style(Rect::new(16, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(ten_tabs().select(9), Rect::new(0, 0, 22, 1), &expected);
+    }
+
+    #[test]
+    fn render_scrollable_selected_tab_wider_than_area() {
+        let mut expected = Buffer::with_lines(["‹ Ta›"]);
+        expected.set_style(Rect::new(2, 0, 2, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(ten_tabs().select(4), Rect::new(0, 0, 5, 1), &expected);
+    }
+
     #[test]
     fn can_be_stylized() {
         assert_eq!(
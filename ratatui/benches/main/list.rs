@@ -39,6 +39,41 @@ fn list(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark for rendering a window of a large dataset, either by creating all the items on each
+/// frame or by only creating the visible items with `List::items_from`.
+fn virtual_items(c: &mut Criterion) {
+    let mut group = c.benchmark_group("list_virtual_items");
+
+    for item_count in [2048, 65536] {
+        let data: Vec<String> = (0..item_count).map(|i| i.to_string()).collect();
+        let state = ListState::default()
+            .with_offset(item_count / 2)
+            .with_selected(Some(item_count / 2));
+
+        group.bench_with_input(BenchmarkId::new("vec", item_count), &data, |b, data| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 200, 50));
+            b.iter(|| {
+                let list = List::new(data.iter().map(String::as_str));
+                StatefulWidget::render(list, buffer.area, &mut buffer, &mut state.clone());
+            });
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("items_from", item_count),
+            &data,
+            |b, data| {
+                let mut buffer = Buffer::empty(Rect::new(0, 0, 200, 50));
+                b.iter(|| {
+                    let list = List::default().items_from(data.len(), |i| data[i].as_str().into());
+                    StatefulWidget::render(list, buffer.area, &mut buffer, &mut state.clone());
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
 /// render the list into a common size buffer
 fn render(bencher: &mut Bencher, list: &List) {
     let mut buffer = Buffer::empty(Rect::new(0, 0, 200, 50));
@@ -67,4 +102,4 @@ fn render_stateful(bencher: &mut Bencher, list: &List, mut state: ListState) {
     );
 }
 
-criterion_group!(benches, list);
+criterion_group!(benches, list, virtual_items);
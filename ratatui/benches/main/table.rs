@@ -42,6 +42,44 @@ fn table(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark for rendering a window of a large dataset, either by creating all the rows on each
+/// frame or by only creating the visible rows with `Table::rows_from`.
+fn virtual_rows(c: &mut Criterion) {
+    let mut group = c.benchmark_group("table_virtual_rows");
+
+    for row_count in [2048, 65536] {
+        let data: Vec<String> = (0..row_count).map(|i| i.to_string()).collect();
+        let state = TableState::default()
+            .with_offset(row_count / 2)
+            .with_selected(Some(row_count / 2));
+
+        group.bench_with_input(BenchmarkId::new("vec", row_count), &data, |b, data| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 200, 50));
+            b.iter(|| {
+                let rows = data.iter().map(|item| Row::new([item.as_str()]));
+                let table = Table::new(rows, [Constraint::Length(10)]);
+                StatefulWidget::render(table, buffer.area, &mut buffer, &mut state.clone());
+            });
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("rows_from", row_count),
+            &data,
+            |b, data| {
+                let mut buffer = Buffer::empty(Rect::new(0, 0, 200, 50));
+                b.iter(|| {
+                    let table = Table::default()
+                        .widths([Constraint::Length(10)])
+                        .rows_from(data.len(), |i| Row::new([data[i].as_str()]));
+                    StatefulWidget::render(table, buffer.area, &mut buffer, &mut state.clone());
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
 fn render(bencher: &mut Bencher, table: &Table) {
     let mut buffer = Buffer::empty(Rect::new(0, 0, 200, 50));
     bencher.iter_batched(
@@ -64,4 +102,4 @@ fn render_stateful(bencher: &mut Bencher, table: &Table, mut state: TableState)
     );
 }
 
-criterion_group!(benches, table);
+criterion_group!(benches, table, virtual_rows);
@@ -13,7 +13,7 @@
 //! The available widgets are:
 //! - [`Block`]: a basic widget that draws a block with optional borders, titles and styles.
 //! - [`BarChart`]: displays multiple datasets as bars with optional grouping.
-//! - [`calendar::Monthly`]: displays a single month.
+//! - [`calendar::Monthly`]: displays a single month, or several months in a grid.
 //! - [`Canvas`]: draws arbitrary shapes using drawing characters.
 //! - [`Chart`]: displays multiple datasets as a lines or scatter graph.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
@@ -39,19 +39,28 @@ pub use ratatui_widgets::borders::{BorderType, Borders};
 #[cfg(feature = "widget-calendar")]
 pub use ratatui_widgets::calendar;
 pub use ratatui_widgets::canvas;
-pub use ratatui_widgets::chart::{Axis, Chart, Dataset, GraphType, LegendPosition};
-pub use ratatui_widgets::clear::Clear;
+pub use ratatui_widgets::chart::{
+    Axis, AxisTitlePosition, Chart, Dataset, GraphType, LegendPosition,
+};
+pub use ratatui_widgets::clear::{Clear, StyledClear};
 pub use ratatui_widgets::gauge::{Gauge, LineGauge};
-pub use ratatui_widgets::list::{List, ListDirection, ListItem, ListState};
+pub use ratatui_widgets::list::{
+    CheckboxList, CheckboxListState, List, ListAnchor, ListDirection, ListItem, ListNavigation,
+    ListSeparator, ListState,
+};
 pub use ratatui_widgets::logo::{RatatuiLogo, Size as RatatuiLogoSize};
 pub use ratatui_widgets::mascot::{MascotEyeColor, RatatuiMascot};
-pub use ratatui_widgets::paragraph::{Paragraph, Wrap};
+pub use ratatui_widgets::paragraph::{
+    Overflow, Paragraph, ParagraphState, TextExt, Wrap, WrapMode,
+};
 pub use ratatui_widgets::scrollbar::{
     ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState,
 };
-pub use ratatui_widgets::sparkline::{RenderDirection, Sparkline, SparklineBar};
-pub use ratatui_widgets::table::{Cell, HighlightSpacing, Row, Table, TableState};
-pub use ratatui_widgets::tabs::Tabs;
+pub use ratatui_widgets::sparkline::{RenderDirection, Sparkline, SparklineBar, SparklineStyle};
+pub use ratatui_widgets::table::{
+    Cell, ColumnWidth, HighlightSpacing, Row, ScrollBehavior, SortDirection, Table, TableState,
+};
+pub use ratatui_widgets::tabs::{TabIndicator, Tabs};
 #[instability::unstable(feature = "widget-ref")]
 pub use {stateful_widget_ref::StatefulWidgetRef, widget_ref::WidgetRef};
 
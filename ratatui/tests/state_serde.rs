@@ -106,7 +106,16 @@ const DEFAULT_STATE_REPR: &str = r#"{
   "table": {
     "offset": 0,
     "selected": null,
-    "selected_column": null
+    "selected_column": null,
+    "selected_range": null,
+    "selected_set": [],
+    "expanded": [],
+    "column_offset": 0,
+    "sort_column": null,
+    "selection_follows_sort": false,
+    "sort_order": [],
+    "column_width_overrides": [],
+    "min_column_width": 1
   },
   "scrollbar": {
     "content_length": 10,
@@ -144,7 +153,16 @@ const SELECTED_STATE_REPR: &str = r#"{
   "table": {
     "offset": 0,
     "selected": 1,
-    "selected_column": 0
+    "selected_column": 0,
+    "selected_range": null,
+    "selected_set": [],
+    "expanded": [],
+    "column_offset": 0,
+    "sort_column": null,
+    "selection_follows_sort": false,
+    "sort_order": [],
+    "column_width_overrides": [],
+    "min_column_width": 1
   },
   "scrollbar": {
     "content_length": 10,
@@ -184,7 +202,16 @@ const SCROLLED_STATE_REPR: &str = r#"{
   "table": {
     "offset": 4,
     "selected": 8,
-    "selected_column": 0
+    "selected_column": 0,
+    "selected_range": null,
+    "selected_set": [],
+    "expanded": [],
+    "column_offset": 0,
+    "sort_column": null,
+    "selection_follows_sort": false,
+    "sort_order": [],
+    "column_width_overrides": [],
+    "min_column_width": 1
   },
   "scrollbar": {
     "content_length": 10,
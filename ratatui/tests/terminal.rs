@@ -1,7 +1,8 @@
 use std::error::Error;
 
-use ratatui::backend::TestBackend;
-use ratatui::layout::Rect;
+use ratatui::backend::{Backend, TestBackend};
+use ratatui::layout::{Position, Rect};
+use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Paragraph, Widget};
 use ratatui::{Terminal, TerminalOptions, Viewport};
 
@@ -62,6 +63,26 @@ fn terminal_draw_increments_frame_count() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn terminal_draw_sets_cursor_of_the_frame() -> Result<(), Box<dyn Error>> {
+    let mut terminal = Terminal::new(TestBackend::new(10, 3))?;
+    terminal.draw(|f| {
+        f.render_widget(Paragraph::new("> input"), f.area());
+        f.set_cursor_position((7, 0));
+    })?;
+    assert!(terminal.backend().cursor_visible());
+    assert_eq!(
+        terminal.backend().cursor_position(),
+        Some(Position::new(7, 0))
+    );
+
+    // the cursor is hidden when the frame doesn't set its position
+    terminal.draw(|f| f.render_widget(Paragraph::new("done"), f.area()))?;
+    assert!(!terminal.backend().cursor_visible());
+    assert_eq!(terminal.backend().cursor_position(), None);
+    Ok(())
+}
+
 #[test]
 fn terminal_insert_before_moves_viewport() -> Result<(), Box<dyn Error>> {
     // When we have a terminal with 5 lines, and a single line viewport, if we insert a
@@ -480,3 +501,188 @@ fn terminal_insert_before_large_viewport_does_not_clobber() -> Result<(), Box<dy
 
     Ok(())
 }
+
+#[test]
+fn terminal_insert_before_fullscreen_viewport() -> Result<(), Box<dyn Error>> {
+    // A fullscreen viewport takes up the whole screen, so all the inserted lines go directly into
+    // the scrollback buffer and the viewport stays in place.
+
+    let backend = TestBackend::new(20, 3);
+    let mut terminal = Terminal::new(backend)?;
+
+    terminal.draw(|f| {
+        let paragraph = Paragraph::new("Viewport")
+            .centered()
+            .block(Block::bordered());
+        f.render_widget(paragraph, f.area());
+    })?;
+
+    terminal.insert_before(2, |buf| {
+        Paragraph::new(vec![
+            "------ Line 1 ------".into(),
+            "------ Line 2 ------".into(),
+        ])
+        .render(buf.area, buf);
+    })?;
+
+    terminal.draw(|f| {
+        let paragraph = Paragraph::new("Viewport")
+            .centered()
+            .block(Block::bordered());
+        f.render_widget(paragraph, f.area());
+    })?;
+
+    assert_eq!(terminal.get_frame().area(), Rect::new(0, 0, 20, 3));
+    terminal.backend().assert_buffer_lines([
+        "┌──────────────────┐",
+        "│     Viewport     │",
+        "└──────────────────┘",
+    ]);
+    terminal
+        .backend()
+        .assert_scrollback_lines(["------ Line 1 ------", "------ Line 2 ------"]);
+
+    Ok(())
+}
+
+#[test]
+fn terminal_insert_before_fixed_viewport_does_nothing() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(20, 3);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Fixed(Rect::new(0, 1, 20, 1)),
+        },
+    )?;
+
+    terminal.insert_before(1, |buf| {
+        Paragraph::new("------ Line 1 ------").render(buf.area, buf);
+    })?;
+
+    terminal
+        .backend()
+        .assert_buffer_lines(["                    "; 3]);
+    terminal.backend().assert_scrollback_empty();
+
+    Ok(())
+}
+
+#[test]
+fn terminal_set_viewport_height_grows_and_shrinks() -> Result<(), Box<dyn Error>> {
+    let mut backend = TestBackend::with_lines(["$ run               ", "", "", "", ""]);
+    backend.set_cursor_position((0, 1))?;
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(1),
+        },
+    )?;
+    terminal.draw(|f| f.render_widget(Paragraph::new("[progress]"), f.area()))?;
+
+    // grow the viewport below its top line
+    terminal.set_viewport_height(3)?;
+    assert_eq!(terminal.get_cursor_position()?, Position::new(0, 1));
+    assert_eq!(terminal.get_frame().area(), Rect::new(0, 1, 20, 3));
+    terminal.draw(|f| {
+        f.render_widget(Paragraph::new("[progress]\ndetail 1\ndetail 2"), f.area());
+    })?;
+    terminal.backend().assert_buffer_lines([
+        "$ run               ",
+        "[progress]          ",
+        "detail 1            ",
+        "detail 2            ",
+        "                    ",
+    ]);
+
+    // shrink the viewport, clearing the lines below it
+    terminal.set_viewport_height(1)?;
+    assert_eq!(terminal.get_cursor_position()?, Position::new(0, 1));
+    assert_eq!(terminal.get_frame().area(), Rect::new(0, 1, 20, 1));
+    terminal.draw(|f| f.render_widget(Paragraph::new("[done]"), f.area()))?;
+    terminal.backend().assert_buffer_lines([
+        "$ run               ",
+        "[done]              ",
+        "                    ",
+        "                    ",
+        "                    ",
+    ]);
+    terminal.backend().assert_scrollback_empty();
+
+    Ok(())
+}
+
+#[test]
+fn terminal_set_viewport_height_scrolls_past_bottom() -> Result<(), Box<dyn Error>> {
+    let mut backend = TestBackend::with_lines(["line 1              ", "line 2", "line 3", "", ""]);
+    backend.set_cursor_position((0, 3))?;
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(2),
+        },
+    )?;
+
+    // the height is limited to the height of the screen
+    terminal.set_viewport_height(10)?;
+    assert_eq!(terminal.get_cursor_position()?, Position::new(0, 0));
+    assert_eq!(terminal.get_frame().area(), Rect::new(0, 0, 20, 5));
+    terminal.draw(|f| f.render_widget(Paragraph::new("viewport"), f.area()))?;
+    terminal.backend().assert_scrollback_lines([
+        "line 1              ",
+        "line 2              ",
+        "line 3              ",
+    ]);
+
+    terminal.set_viewport_height(2)?;
+    assert_eq!(terminal.get_cursor_position()?, Position::new(0, 0));
+    assert_eq!(terminal.get_frame().area(), Rect::new(0, 0, 20, 2));
+    terminal.draw(|f| f.render_widget(Paragraph::new("viewport"), f.area()))?;
+    terminal.backend().assert_buffer_lines([
+        "viewport            ",
+        "                    ",
+        "                    ",
+        "                    ",
+        "                    ",
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn terminal_set_viewport_height_ignores_fullscreen() -> Result<(), Box<dyn Error>> {
+    let mut terminal = Terminal::new(TestBackend::new(20, 5))?;
+    terminal.set_viewport_height(2)?;
+    assert_eq!(terminal.get_frame().area(), Rect::new(0, 0, 20, 5));
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_updates_hyperlinks() -> Result<(), Box<dyn Error>> {
+    let mut terminal = Terminal::new(TestBackend::new(6, 1))?;
+    let draw = |terminal: &mut Terminal<TestBackend>, url: &'static str| {
+        terminal
+            .draw(|f| {
+                let line = Line::from(vec![Span::raw("go "), Span::raw("to").hyperlink(url)]);
+                f.render_widget(line, f.area());
+            })
+            .map(|_| ())
+    };
+    let links = |terminal: &Terminal<TestBackend>| {
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .iter()
+            .map(|cell| cell.hyperlink().map(str::to_string))
+            .collect::<Vec<_>>()
+    };
+
+    draw(&mut terminal, "https://ratatui.rs")?;
+    let url = Some("https://ratatui.rs".to_string());
+    assert_eq!(links(&terminal), [None, None, None, url.clone(), url, None]);
+
+    // only the URL changes, which updates the linked cells on the backend
+    draw(&mut terminal, "https://docs.rs")?;
+    let url = Some("https://docs.rs".to_string());
+    assert_eq!(links(&terminal), [None, None, None, url.clone(), url, None]);
+    Ok(())
+}